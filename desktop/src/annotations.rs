@@ -0,0 +1,460 @@
+//! Reader annotations (highlights with optional comments).
+//!
+//! This module provides:
+//! - `TextAnchor`: Quote + surrounding context used to locate a passage
+//! - `Annotation`: A highlighted passage with color and comment
+//! - `Annotations`: Per-file annotation collection with persistence
+//! - `ANNOTATIONS`: Global static for app-wide access
+//! - `ANNOTATIONS_CHANGED`: Broadcast channel for cross-window sync
+//! - `export_annotations`: Standalone HTML export of a file's annotations
+//!
+//! Annotations are anchored by text quote rather than by offset, so they
+//! survive edits to the file. The renderer re-locates each quote after every
+//! render and prefers the occurrence whose surrounding text best matches the
+//! stored prefix/suffix.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::pinned_search::HighlightColor;
+
+/// Unique identifier for an annotation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnnotationId(String);
+
+impl AnnotationId {
+    /// Generate a new unique ID.
+    pub fn new() -> Self {
+        Self(format!("an_{}", Uuid::new_v4().simple()))
+    }
+}
+
+impl Default for AnnotationId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for AnnotationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for AnnotationId {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+/// Text quote selector captured from the rendered document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextAnchor {
+    /// The selected text itself.
+    pub quote: String,
+    /// Text immediately before the quote.
+    #[serde(default)]
+    pub prefix: String,
+    /// Text immediately after the quote.
+    #[serde(default)]
+    pub suffix: String,
+}
+
+/// An annotation entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotation {
+    /// Unique identifier.
+    pub id: AnnotationId,
+    /// Where the highlighted passage is located in the document.
+    pub anchor: TextAnchor,
+    /// Highlight color.
+    pub color: HighlightColor,
+    /// Free-form note attached to the passage (empty if none).
+    #[serde(default)]
+    pub comment: String,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+impl Annotation {
+    /// Create a new annotation for the given anchor.
+    pub fn new(anchor: TextAnchor, color: HighlightColor) -> Self {
+        Self {
+            id: AnnotationId::new(),
+            anchor,
+            color,
+            comment: String::new(),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Annotations storage keyed by file path (saved to annotations.json).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotations {
+    /// File format version.
+    #[serde(default = "default_version")]
+    pub version: u32,
+    /// Annotations per file, in creation order.
+    #[serde(default)]
+    pub files: BTreeMap<PathBuf, Vec<Annotation>>,
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+impl Default for Annotations {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+impl Annotations {
+    /// Get the annotations file path.
+    fn path() -> PathBuf {
+        crate::storage::data_file("annotations.json")
+    }
+
+    /// Load annotations from file or return empty.
+    pub fn load() -> Self {
+        let path = Self::path();
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save annotations to file.
+    pub fn save(&self) {
+        let path = Self::path();
+
+        tracing::debug!(path = %path.display(), files = self.files.len(), "Saving annotations");
+
+        // If no annotations, remove the file
+        if self.files.is_empty() {
+            if path.exists() {
+                if let Err(e) = fs::remove_file(&path) {
+                    tracing::error!(?e, "Failed to remove empty annotations file");
+                }
+            }
+            return;
+        }
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                tracing::error!(?e, "Failed to create annotations directory");
+                return;
+            }
+        }
+
+        match serde_json::to_string_pretty(self) {
+            Ok(content) => {
+                if let Err(e) = fs::write(&path, content) {
+                    tracing::error!(?e, "Failed to save annotations");
+                }
+            }
+            Err(e) => {
+                tracing::error!(?e, "Failed to serialize annotations");
+            }
+        }
+    }
+
+    /// Get all annotations of a file.
+    pub fn for_file(&self, file: &Path) -> &[Annotation] {
+        self.files.get(file).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Add a new annotation to a file.
+    pub fn add(
+        &mut self,
+        file: impl Into<PathBuf>,
+        anchor: TextAnchor,
+        color: HighlightColor,
+    ) -> &Annotation {
+        let list = self.files.entry(file.into()).or_default();
+        list.push(Annotation::new(anchor, color));
+        list.last().unwrap()
+    }
+
+    /// Remove an annotation by ID.
+    pub fn remove(&mut self, file: &Path, id: &AnnotationId) -> bool {
+        let Some(list) = self.files.get_mut(file) else {
+            return false;
+        };
+        let len_before = list.len();
+        list.retain(|a| &a.id != id);
+        let removed = list.len() < len_before;
+        if list.is_empty() {
+            self.files.remove(file);
+        }
+        removed
+    }
+
+    /// Update the color of an annotation.
+    pub fn set_color(&mut self, file: &Path, id: &AnnotationId, color: HighlightColor) -> bool {
+        if let Some(annotation) = self.find_mut(file, id) {
+            annotation.color = color;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Update the comment of an annotation.
+    pub fn set_comment(&mut self, file: &Path, id: &AnnotationId, comment: String) -> bool {
+        match self.find_mut(file, id) {
+            Some(annotation) if annotation.comment != comment => {
+                annotation.comment = comment;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Render the annotations of a file as a standalone HTML document.
+    pub fn export_html(&self, file: &Path) -> String {
+        let title = html_escape::encode_text(
+            &file
+                .file_name()
+                .unwrap_or(file.as_os_str())
+                .to_string_lossy(),
+        )
+        .into_owned();
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>{EXPORT_STYLE}</style>\n</head>\n<body>\n\
+             <h1>{title}</h1>\n"
+        );
+        for annotation in self.for_file(file) {
+            html.push_str(&format!(
+                "<blockquote><mark class=\"{}\">{}</mark></blockquote>\n",
+                annotation.color.to_js_name(),
+                html_escape::encode_text(&annotation.anchor.quote),
+            ));
+            if !annotation.comment.is_empty() {
+                html.push_str(&format!(
+                    "<p>{}</p>\n",
+                    html_escape::encode_text(&annotation.comment)
+                ));
+            }
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    fn find_mut(&mut self, file: &Path, id: &AnnotationId) -> Option<&mut Annotation> {
+        self.files
+            .get_mut(file)
+            .and_then(|list| list.iter_mut().find(|a| &a.id == id))
+    }
+}
+
+/// Stylesheet of exported annotations (highlight colors match the light theme).
+const EXPORT_STYLE: &str =
+    "body{font-family:-apple-system,sans-serif;max-width:48em;margin:2em auto}\
+blockquote{margin:1em 0 .25em}p{margin:0 0 1em;white-space:pre-wrap}\
+mark.green{background:rgba(76,175,80,.35)}mark.blue{background:rgba(0,188,212,.35)}\
+mark.pink{background:rgba(233,30,99,.3)}mark.orange{background:rgba(255,152,0,.35)}\
+mark.purple{background:rgba(156,39,176,.3)}";
+
+/// Global annotations instance.
+pub static ANNOTATIONS: LazyLock<RwLock<Annotations>> =
+    LazyLock::new(|| RwLock::new(Annotations::load()));
+
+/// Broadcast channel for annotation changes.
+///
+/// The payload is empty since subscribers should read from ANNOTATIONS directly.
+pub static ANNOTATIONS_CHANGED: LazyLock<broadcast::Sender<()>> =
+    LazyLock::new(|| broadcast::channel(10).0);
+
+/// Add an annotation and broadcast the change.
+///
+/// Returns the ID of the newly created annotation.
+pub fn add_annotation(
+    file: impl Into<PathBuf>,
+    anchor: TextAnchor,
+    color: HighlightColor,
+) -> AnnotationId {
+    let id = {
+        let mut annotations = ANNOTATIONS.write();
+        let id = annotations.add(file, anchor, color).id.clone();
+        annotations.save();
+        id
+    };
+    ANNOTATIONS_CHANGED.send(()).ok();
+    id
+}
+
+/// Remove an annotation and broadcast the change.
+pub fn remove_annotation(file: &Path, id: &AnnotationId) -> bool {
+    update_annotations(|annotations| annotations.remove(file, id))
+}
+
+/// Update the color of an annotation and broadcast the change.
+pub fn set_annotation_color(file: &Path, id: &AnnotationId, color: HighlightColor) -> bool {
+    update_annotations(|annotations| annotations.set_color(file, id, color))
+}
+
+/// Update the comment of an annotation and broadcast the change.
+pub fn set_annotation_comment(file: &Path, id: &AnnotationId, comment: String) -> bool {
+    update_annotations(|annotations| annotations.set_comment(file, id, comment))
+}
+
+/// Export the annotations of a file to an HTML document.
+pub fn export_annotations(file: &Path, dest: &Path) -> Result<()> {
+    let html = ANNOTATIONS.read().export_html(file);
+    fs::write(dest, html).with_context(|| format!("Failed to write {}", dest.display()))
+}
+
+/// Apply a mutation, then save and broadcast only if something changed.
+fn update_annotations(f: impl FnOnce(&mut Annotations) -> bool) -> bool {
+    let result = {
+        let mut annotations = ANNOTATIONS.write();
+        let result = f(&mut annotations);
+        if result {
+            annotations.save();
+        }
+        result
+    };
+    if result {
+        ANNOTATIONS_CHANGED.send(()).ok();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchor(quote: &str) -> TextAnchor {
+        TextAnchor {
+            quote: quote.to_string(),
+            prefix: "before ".to_string(),
+            suffix: " after".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_annotation_id_generation() {
+        let id1 = AnnotationId::new();
+        let id2 = AnnotationId::new();
+        assert_ne!(id1, id2);
+        assert!(id1.0.starts_with("an_"));
+    }
+
+    #[test]
+    fn test_annotations_add_remove() {
+        let mut annotations = Annotations::default();
+        let file = Path::new("/docs/a.md");
+
+        let id = annotations
+            .add(file, anchor("quote"), HighlightColor::Blue)
+            .id
+            .clone();
+        assert_eq!(annotations.for_file(file).len(), 1);
+        assert_eq!(annotations.for_file(file)[0].color, HighlightColor::Blue);
+        assert!(annotations.for_file(Path::new("/docs/b.md")).is_empty());
+
+        assert!(annotations.remove(file, &id));
+        assert!(!annotations.remove(file, &id));
+        // Files without annotations are dropped from the map
+        assert!(annotations.files.is_empty());
+    }
+
+    #[test]
+    fn test_annotations_set_color_and_comment() {
+        let mut annotations = Annotations::default();
+        let file = Path::new("/docs/a.md");
+        let id = annotations
+            .add(file, anchor("quote"), HighlightColor::Green)
+            .id
+            .clone();
+
+        assert!(annotations.set_color(file, &id, HighlightColor::Pink));
+        assert!(annotations.set_comment(file, &id, "note".to_string()));
+        // Unchanged comment is reported as no-op
+        assert!(!annotations.set_comment(file, &id, "note".to_string()));
+        assert!(!annotations.set_color(Path::new("/other.md"), &id, HighlightColor::Blue));
+
+        let annotation = &annotations.for_file(file)[0];
+        assert_eq!(annotation.color, HighlightColor::Pink);
+        assert_eq!(annotation.comment, "note");
+    }
+
+    #[test]
+    fn test_annotations_serialization() {
+        let mut annotations = Annotations::default();
+        let file = Path::new("/docs/a.md");
+        annotations.add(file, anchor("quote"), HighlightColor::Orange);
+
+        let json = serde_json::to_string_pretty(&annotations).unwrap();
+        let parsed: Annotations = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.version, 1);
+        assert_eq!(parsed, annotations);
+        assert!(json.contains("\"/docs/a.md\""));
+    }
+
+    #[test]
+    fn test_annotation_without_comment_deserializes() {
+        let json = indoc::indoc! {r#"
+            {
+              "files": {
+                "/docs/a.md": [
+                  {
+                    "id": "an_1",
+                    "anchor": { "quote": "hello" },
+                    "color": "purple",
+                    "createdAt": "2025-01-01T00:00:00Z"
+                  }
+                ]
+              }
+            }
+        "#};
+        let parsed: Annotations = serde_json::from_str(json).unwrap();
+        let annotation = &parsed.for_file(Path::new("/docs/a.md"))[0];
+        assert_eq!(annotation.anchor.quote, "hello");
+        assert!(annotation.anchor.prefix.is_empty());
+        assert!(annotation.comment.is_empty());
+    }
+
+    #[test]
+    fn test_annotations_export_html() {
+        let mut annotations = Annotations::default();
+        let file = Path::new("/docs/a<b>.md");
+        let id = annotations
+            .add(file, anchor("x < y"), HighlightColor::Pink)
+            .id
+            .clone();
+        annotations.set_comment(file, &id, "note & more".to_string());
+        annotations.add(file, anchor("plain"), HighlightColor::Green);
+
+        let html = annotations.export_html(file);
+        assert!(html.contains("<title>a&lt;b&gt;.md</title>"));
+        assert!(html.contains("<mark class=\"pink\">x &lt; y</mark>"));
+        assert!(html.contains("<p>note &amp; more</p>"));
+        assert!(html.contains("<mark class=\"green\">plain</mark>"));
+        // Annotations without a comment get no paragraph
+        assert_eq!(html.matches("<p>").count(), 1);
+    }
+}
@@ -0,0 +1,562 @@
+//! Book navigation for documentation sites.
+//!
+//! This module provides:
+//! - `Book`: Chapter order declared by an mdBook `SUMMARY.md` or a MkDocs `mkdocs.yml` `nav`
+//! - `BookItem`: Entries of the book outline (section titles and chapters)
+//! - `Book::render_to_html`: All chapters rendered as one continuous document
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::Deserialize;
+use serde_yaml::Value as YamlValue;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::markdown::{render_to_html_with_slugs, HeadingInfo, HeadingSlugs};
+
+/// Site generator whose navigation file defines the book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookKind {
+    MdBook,
+    MkDocs,
+}
+
+/// Entry of the book outline, in declared order
+#[derive(Debug, Clone, PartialEq)]
+pub enum BookItem {
+    /// Title grouping the entries below it (mdBook part title or MkDocs nav section)
+    Section { title: String, depth: usize },
+    /// Chapter page; `path` is `None` for mdBook draft chapters
+    Chapter {
+        title: String,
+        path: Option<PathBuf>,
+        depth: usize,
+    },
+}
+
+impl BookItem {
+    pub fn title(&self) -> &str {
+        match self {
+            Self::Section { title, .. } | Self::Chapter { title, .. } => title,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Section { depth, .. } | Self::Chapter { depth, .. } => *depth,
+        }
+    }
+
+    /// File of a chapter that has one
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Chapter {
+                path: Some(path), ..
+            } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Chapter reached from another one with previous/next navigation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterLink {
+    pub title: String,
+    pub path: PathBuf,
+}
+
+/// Book defined by a navigation file in a directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct Book {
+    pub kind: BookKind,
+    /// Directory containing the navigation file (or `book.toml`)
+    pub root: PathBuf,
+    /// Navigation file (`SUMMARY.md` or `mkdocs.yml`), watched for changes
+    pub nav_file: PathBuf,
+    pub title: String,
+    pub items: Vec<BookItem>,
+}
+
+/// `[book]` table of mdBook's `book.toml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MdBookConfig {
+    book: MdBookSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MdBookSection {
+    title: Option<String>,
+    src: Option<PathBuf>,
+}
+
+impl Book {
+    /// Detect an mdBook or MkDocs book whose navigation file is in `root`
+    ///
+    /// Chapter paths are canonicalized when the file exists so they can be
+    /// compared with paths opened from links.
+    pub fn detect(root: &Path) -> Option<Self> {
+        let mut book = Self::detect_mdbook(root).or_else(|| Self::detect_mkdocs(root))?;
+        for item in &mut book.items {
+            if let BookItem::Chapter {
+                path: Some(path), ..
+            } = item
+            {
+                if let Ok(canonical) = path.canonicalize() {
+                    *path = canonical;
+                }
+            }
+        }
+        Some(book)
+    }
+
+    fn detect_mdbook(root: &Path) -> Option<Self> {
+        let config: MdBookConfig = fs::read_to_string(root.join("book.toml"))
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default();
+
+        // The summary lives in the source directory ("src" unless configured),
+        // but plain docs folders often keep it next to the chapters
+        let src_dir = root.join(config.book.src.unwrap_or_else(|| PathBuf::from("src")));
+        let nav_file = [src_dir.join("SUMMARY.md"), root.join("SUMMARY.md")]
+            .into_iter()
+            .find(|path| path.is_file())?;
+        let content = fs::read_to_string(&nav_file).ok()?;
+        let chapter_dir = nav_file.parent().unwrap_or(root);
+
+        Some(Self {
+            kind: BookKind::MdBook,
+            root: root.to_path_buf(),
+            title: config.book.title.unwrap_or_else(|| dir_title(root)),
+            items: parse_summary(&content, chapter_dir),
+            nav_file,
+        })
+    }
+
+    fn detect_mkdocs(root: &Path) -> Option<Self> {
+        let nav_file = [root.join("mkdocs.yml"), root.join("mkdocs.yaml")]
+            .into_iter()
+            .find(|path| path.is_file())?;
+        let content = fs::read_to_string(&nav_file).ok()?;
+        let config: YamlValue = serde_yaml::from_str(&content).ok()?;
+
+        let docs_dir = config
+            .get("docs_dir")
+            .and_then(YamlValue::as_str)
+            .unwrap_or("docs");
+        let items = parse_mkdocs_nav(config.get("nav")?, &root.join(docs_dir));
+        let title = config
+            .get("site_name")
+            .and_then(YamlValue::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| dir_title(root));
+
+        Some(Self {
+            kind: BookKind::MkDocs,
+            root: root.to_path_buf(),
+            nav_file,
+            title,
+            items,
+        })
+    }
+
+    /// Index into `items` of the chapter for `file`
+    pub fn chapter_index(&self, file: &Path) -> Option<usize> {
+        let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+        self.items
+            .iter()
+            .position(|item| item.path() == Some(&file))
+    }
+
+    /// Chapters before and after `file` in reading order
+    pub fn neighbors(&self, file: &Path) -> (Option<ChapterLink>, Option<ChapterLink>) {
+        let Some(index) = self.chapter_index(file) else {
+            return (None, None);
+        };
+        let link = |item: &BookItem| {
+            item.path().map(|path| ChapterLink {
+                title: item.title().to_string(),
+                path: path.to_path_buf(),
+            })
+        };
+        let previous = self.items[..index].iter().rev().find_map(link);
+        let next = self.items[index + 1..].iter().find_map(link);
+        (previous, next)
+    }
+
+    /// Render all chapters as one continuous document
+    ///
+    /// Each chapter is wrapped in a `section` carrying its directory so that
+    /// relative links can be resolved against the chapter they appear in.
+    /// Heading IDs are unique across the whole book.
+    pub fn render_to_html(&self) -> (String, Vec<HeadingInfo>) {
+        let mut slugs = HeadingSlugs::default();
+        let mut html = String::new();
+        let mut headings = Vec::new();
+
+        for (index, item) in self.items.iter().enumerate() {
+            match item {
+                BookItem::Section { title, .. } => {
+                    html.push_str(&format!(
+                        "<h1 class=\"book-section-title\">{}</h1>\n",
+                        html_escape::encode_text(title)
+                    ));
+                }
+                BookItem::Chapter {
+                    path: Some(path), ..
+                } => {
+                    let chapter_dir = path.parent().unwrap_or(&self.root);
+                    let rendered = fs::read_to_string(path)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|content| render_to_html_with_slugs(&content, path, &mut slugs));
+                    let body = match rendered {
+                        Ok((body, chapter_headings)) => {
+                            headings.extend(chapter_headings);
+                            body
+                        }
+                        Err(e) => {
+                            tracing::warn!(?path, %e, "Failed to render book chapter");
+                            format!(
+                                r#"<p class="error">Failed to load {}: {}</p>"#,
+                                html_escape::encode_text(&path.to_string_lossy()),
+                                html_escape::encode_text(&e.to_string())
+                            )
+                        }
+                    };
+                    html.push_str(&format!(
+                        "<section class=\"book-chapter\" id=\"book-chapter-{}\" data-chapter-dir=\"{}\">\n{}\n</section>\n",
+                        index,
+                        html_escape::encode_double_quoted_attribute(&chapter_dir.to_string_lossy()),
+                        body
+                    ));
+                }
+                // Draft chapters have no content yet
+                BookItem::Chapter { path: None, .. } => {}
+            }
+        }
+
+        (html, headings)
+    }
+}
+
+/// Book title fallback: the directory name
+fn dir_title(root: &Path) -> String {
+    root.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| root.to_string_lossy().into_owned())
+}
+
+/// Resolve a link target from a navigation file to a chapter path
+///
+/// Anchors are dropped and percent-encoded characters decoded.
+fn chapter_path(dir: &Path, target: &str) -> Option<PathBuf> {
+    let target = target.split('#').next().unwrap_or_default().trim();
+    if target.is_empty() || target.contains("://") {
+        return None;
+    }
+    let decoded = percent_encoding::percent_decode_str(target).decode_utf8_lossy();
+    Some(dir.join(decoded.as_ref()))
+}
+
+/// Parse an mdBook `SUMMARY.md`
+///
+/// Headings before the first chapter are the summary title and skipped;
+/// later headings are part titles. Nested list items become deeper chapters.
+pub fn parse_summary(content: &str, src_dir: &Path) -> Vec<BookItem> {
+    let mut items = Vec::new();
+    let mut list_depth = 0usize;
+    let mut heading: Option<String> = None;
+    let mut link: Option<(String, String)> = None;
+
+    for event in Parser::new_ext(content, Options::empty()) {
+        match event {
+            Event::Start(Tag::List(_)) => list_depth += 1,
+            Event::End(TagEnd::List(_)) => list_depth = list_depth.saturating_sub(1),
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::End(TagEnd::Heading(_)) => {
+                let title = heading.take().unwrap_or_default();
+                let title = title.trim();
+                if !items.is_empty() && !title.is_empty() {
+                    items.push(BookItem::Section {
+                        title: title.to_string(),
+                        depth: 0,
+                    });
+                }
+            }
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                link = Some((dest_url.to_string(), String::new()));
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some((dest, title)) = link.take() {
+                    items.push(BookItem::Chapter {
+                        title: title.trim().to_string(),
+                        path: chapter_path(src_dir, &dest),
+                        depth: list_depth.saturating_sub(1),
+                    });
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, title)) = link.as_mut() {
+                    title.push_str(&text);
+                } else if let Some(title) = heading.as_mut() {
+                    title.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    items
+}
+
+/// Parse the `nav` of a MkDocs config
+///
+/// Entries are either a page path, `Title: page.md` or `Section: [entries]`.
+/// External links are skipped.
+pub fn parse_mkdocs_nav(nav: &YamlValue, docs_dir: &Path) -> Vec<BookItem> {
+    fn walk(nav: &YamlValue, docs_dir: &Path, depth: usize, items: &mut Vec<BookItem>) {
+        let Some(entries) = nav.as_sequence() else {
+            return;
+        };
+        for entry in entries {
+            match entry {
+                YamlValue::String(page) => {
+                    if let Some(path) = chapter_path(docs_dir, page) {
+                        items.push(BookItem::Chapter {
+                            title: page_title(&path),
+                            path: Some(path),
+                            depth,
+                        });
+                    }
+                }
+                YamlValue::Mapping(mapping) => {
+                    for (title, value) in mapping {
+                        let Some(title) = title.as_str() else {
+                            continue;
+                        };
+                        match value {
+                            YamlValue::String(page) => {
+                                if let Some(path) = chapter_path(docs_dir, page) {
+                                    items.push(BookItem::Chapter {
+                                        title: title.to_string(),
+                                        path: Some(path),
+                                        depth,
+                                    });
+                                }
+                            }
+                            YamlValue::Sequence(_) => {
+                                items.push(BookItem::Section {
+                                    title: title.to_string(),
+                                    depth,
+                                });
+                                walk(value, docs_dir, depth + 1, items);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut items = Vec::new();
+    walk(nav, docs_dir, 0, &mut items);
+    items
+}
+
+/// Title for a MkDocs page listed without one, derived from its file name
+fn page_title(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().replace(['-', '_'], " "))
+        .unwrap_or_default();
+    let mut chars = stem.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => stem,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use tempfile::TempDir;
+
+    fn outline(items: &[BookItem]) -> Vec<String> {
+        items
+            .iter()
+            .map(|item| {
+                let indent = "  ".repeat(item.depth());
+                match item {
+                    BookItem::Section { title, .. } => format!("{indent}# {title}"),
+                    BookItem::Chapter { title, path, .. } => {
+                        let path = path
+                            .as_ref()
+                            .map(|p| p.to_string_lossy().into_owned())
+                            .unwrap_or_else(|| "(draft)".to_string());
+                        format!("{indent}{title} -> {path}")
+                    }
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_summary() {
+        let summary = indoc! {"
+            # Summary
+
+            [Introduction](README.md)
+
+            # User Guide
+
+            - [Installation](guide/installation.md)
+                - [From `source`](guide/from%20source.md#build)
+            - [Planned]()
+
+            ---
+
+            [Contributors](misc/contributors.md)
+        "};
+
+        let items = parse_summary(summary, Path::new("/book/src"));
+
+        assert_eq!(
+            outline(&items),
+            vec![
+                "Introduction -> /book/src/README.md",
+                "# User Guide",
+                "Installation -> /book/src/guide/installation.md",
+                "  From source -> /book/src/guide/from source.md",
+                "Planned -> (draft)",
+                "Contributors -> /book/src/misc/contributors.md",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_mkdocs_nav() {
+        let config: YamlValue = serde_yaml::from_str(indoc! {"
+            nav:
+              - Home: index.md
+              - User Guide:
+                  - Writing: user-guide/writing.md
+                  - user-guide/custom_themes.md
+              - Issues: https://example.com/issues
+        "})
+        .unwrap();
+
+        let items = parse_mkdocs_nav(config.get("nav").unwrap(), Path::new("/site/docs"));
+
+        assert_eq!(
+            outline(&items),
+            vec![
+                "Home -> /site/docs/index.md",
+                "# User Guide",
+                "  Writing -> /site/docs/user-guide/writing.md",
+                "  Custom themes -> /site/docs/user-guide/custom_themes.md",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_mdbook_with_custom_src() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = &temp_dir.path().canonicalize().unwrap();
+        fs::write(
+            root.join("book.toml"),
+            "[book]\ntitle = \"The Guide\"\nsrc = \"pages\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("pages")).unwrap();
+        fs::write(root.join("pages/SUMMARY.md"), "- [Intro](intro.md)\n").unwrap();
+        fs::write(root.join("pages/intro.md"), "# Intro").unwrap();
+
+        let book = Book::detect(root).unwrap();
+
+        assert_eq!(book.kind, BookKind::MdBook);
+        assert_eq!(book.title, "The Guide");
+        assert_eq!(book.nav_file, root.join("pages/SUMMARY.md"));
+        assert_eq!(
+            book.items[0].path(),
+            Some(root.join("pages/intro.md").as_path())
+        );
+    }
+
+    #[test]
+    fn test_detect_mkdocs() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = &temp_dir.path().canonicalize().unwrap();
+        fs::write(
+            root.join("mkdocs.yml"),
+            "site_name: Docs\ndocs_dir: content\nnav:\n  - index.md\n",
+        )
+        .unwrap();
+
+        let book = Book::detect(root).unwrap();
+
+        assert_eq!(book.kind, BookKind::MkDocs);
+        assert_eq!(book.title, "Docs");
+        assert_eq!(
+            book.items[0].path(),
+            Some(root.join("content/index.md").as_path())
+        );
+
+        // Without a navigation file there is no book
+        assert!(Book::detect(&root.join("content")).is_none());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let items = parse_summary(
+            "- [One](one.md)\n- [Draft]()\n- [Two](two.md)\n- [Three](three.md)\n",
+            Path::new("/book"),
+        );
+        let book = Book {
+            kind: BookKind::MdBook,
+            root: PathBuf::from("/book"),
+            nav_file: PathBuf::from("/book/SUMMARY.md"),
+            title: "Book".to_string(),
+            items,
+        };
+
+        let (previous, next) = book.neighbors(Path::new("/book/two.md"));
+        assert_eq!(previous.unwrap().title, "One");
+        assert_eq!(next.unwrap().title, "Three");
+
+        let (previous, next) = book.neighbors(Path::new("/book/one.md"));
+        assert!(previous.is_none());
+        assert_eq!(next.unwrap().path, PathBuf::from("/book/two.md"));
+
+        assert_eq!(book.chapter_index(Path::new("/book/three.md")), Some(3));
+        assert_eq!(book.neighbors(Path::new("/book/other.md")), (None, None));
+    }
+
+    #[test]
+    fn test_render_to_html() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = &temp_dir.path().canonicalize().unwrap();
+        fs::create_dir(root.join("guide")).unwrap();
+        fs::write(root.join("intro.md"), "# Overview\n\nHello").unwrap();
+        fs::write(root.join("guide/setup.md"), "# Overview\n\nSetup").unwrap();
+        fs::write(
+            root.join("SUMMARY.md"),
+            "- [Intro](intro.md)\n\n# Guide\n\n- [Setup](guide/setup.md)\n",
+        )
+        .unwrap();
+
+        let book = Book::detect(root).unwrap();
+        let (html, headings) = book.render_to_html();
+
+        assert!(html.contains(r#"<h1 class="book-section-title">Guide</h1>"#));
+        assert!(html.contains(&format!(
+            r#"data-chapter-dir="{}""#,
+            root.join("guide").display()
+        )));
+        let ids: Vec<&str> = headings.iter().map(|h| h.id.as_str()).collect();
+        assert_eq!(ids, vec!["overview", "overview-1"]);
+    }
+}
@@ -1,20 +1,87 @@
 //! Bookmark management for Quick Access feature.
 //!
 //! This module provides:
-//! - `Bookmark`: A single bookmarked file or directory
-//! - `Bookmarks`: Collection of bookmarks with persistence
+//! - `Bookmark`: A single bookmarked file or directory, optionally at a heading or scroll offset
+//! - `BookmarkFolder`: A named, collapsible group of bookmarks
+//! - `Bookmarks`: Collection of bookmarks with persistence, import/export and relinking
 //! - `BOOKMARKS`: Global static for app-wide bookmark access
 //! - `BOOKMARKS_CHANGED`: Broadcast channel for cross-window sync
 
+use anyhow::{Context, Result};
 use parking_lot::RwLock;
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Fragment prefix used for scroll offsets in exported links (`file.md#scroll=1200`)
+const SCROLL_FRAGMENT_PREFIX: &str = "scroll=";
+
+/// Unique identifier for a bookmark folder
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BookmarkFolderId(String);
+
+impl BookmarkFolderId {
+    /// Generate a new unique ID.
+    pub fn new() -> Self {
+        Self(format!("bf_{}", Uuid::new_v4().simple()))
+    }
+}
+
+impl Default for BookmarkFolderId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for BookmarkFolderId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Location inside a bookmarked file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BookmarkTarget {
+    /// Heading anchor (`file.md#slug`)
+    Heading { slug: String },
+    /// Vertical scroll offset in pixels
+    Scroll { offset: f64 },
+}
+
+impl BookmarkTarget {
+    /// URL fragment (without `#`) used in exported Markdown links
+    pub fn fragment(&self) -> String {
+        match self {
+            Self::Heading { slug } => slug.clone(),
+            Self::Scroll { offset } => format!("{SCROLL_FRAGMENT_PREFIX}{}", offset.round()),
+        }
+    }
+
+    /// Parse a URL fragment written by [`BookmarkTarget::fragment`]
+    pub fn from_fragment(fragment: &str) -> Option<Self> {
+        if fragment.is_empty() {
+            return None;
+        }
+        if let Some(offset) = fragment
+            .strip_prefix(SCROLL_FRAGMENT_PREFIX)
+            .and_then(|offset| offset.parse::<f64>().ok())
+        {
+            return Some(Self::Scroll { offset });
+        }
+        Some(Self::Heading {
+            slug: fragment.to_string(),
+        })
+    }
+}
 
 /// A single bookmark entry for Quick Access
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Bookmark {
     /// Path to the bookmarked file or directory
@@ -22,6 +89,12 @@ pub struct Bookmark {
     /// Custom display name (if None, use file/directory name)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Heading or scroll offset to jump to (if None, open at the top)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<BookmarkTarget>,
+    /// Folder this bookmark belongs to (if None, shown ungrouped)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<BookmarkFolderId>,
 }
 
 impl Bookmark {
@@ -30,6 +103,34 @@ impl Bookmark {
         Self {
             path: path.into(),
             name: None,
+            target: None,
+            folder: None,
+        }
+    }
+
+    /// Create a bookmark pointing at a location inside a file
+    pub fn with_target(
+        path: impl Into<PathBuf>,
+        target: BookmarkTarget,
+        name: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            target: Some(target),
+            ..Self::new(path)
+        }
+    }
+
+    /// Check if this bookmark points at the given path and location
+    pub fn matches(&self, path: &Path, target: Option<&BookmarkTarget>) -> bool {
+        self.path == path && self.target.as_ref() == target
+    }
+
+    /// Stable key identifying the bookmark in lists (path and fragment)
+    pub fn key(&self) -> String {
+        match &self.target {
+            Some(target) => format!("{}#{}", self.path.display(), target.fragment()),
+            None => self.path.display().to_string(),
         }
     }
 
@@ -54,32 +155,63 @@ impl Bookmark {
     }
 }
 
+/// A named group of bookmarks in Quick Access
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkFolder {
+    /// Unique identifier referenced by `Bookmark::folder`
+    pub id: BookmarkFolderId,
+    /// Display name
+    pub name: String,
+    /// Whether the folder is collapsed in Quick Access
+    #[serde(default)]
+    pub collapsed: bool,
+}
+
+impl BookmarkFolder {
+    /// Create a new expanded folder
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: BookmarkFolderId::new(),
+            name: name.into(),
+            collapsed: false,
+        }
+    }
+}
+
+/// File format used to import and export bookmarks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkFormat {
+    /// Same structure as bookmarks.json
+    Json,
+    /// List of links grouped under `##` headings per folder
+    Markdown,
+}
+
+impl BookmarkFormat {
+    /// Detect the format from a file extension (anything but `.json` is Markdown)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Markdown,
+        }
+    }
+}
+
 /// Bookmarks storage (saved to bookmarks.json)
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Bookmarks {
     /// List of bookmarked paths
     pub items: Vec<Bookmark>,
+    /// Folders in display order
+    pub folders: Vec<BookmarkFolder>,
 }
 
 impl Bookmarks {
     /// Get the bookmarks file path
     fn path() -> PathBuf {
-        const FILENAME: &str = "bookmarks.json";
-        if let Some(mut path) = dirs::data_local_dir() {
-            path.push("arto");
-            path.push(FILENAME);
-            return path;
-        }
-
-        // Fallback to home directory
-        if let Some(mut path) = dirs::home_dir() {
-            path.push(".arto");
-            path.push(FILENAME);
-            return path;
-        }
-
-        PathBuf::from(FILENAME)
+        crate::storage::data_file("bookmarks.json")
     }
 
     /// Load bookmarks from file or return empty
@@ -122,8 +254,21 @@ impl Bookmarks {
     }
 
     /// Remove a bookmark by path
+    ///
+    /// Bookmarks pointing at a heading or scroll offset inside the file are kept.
     pub fn remove(&mut self, path: &Path) {
-        self.items.retain(|b| b.path != path);
+        self.items.retain(|b| !b.matches(path, None));
+    }
+
+    /// Remove the bookmark at the given index
+    ///
+    /// Returns `true` if a bookmark was removed.
+    pub fn remove_at(&mut self, index: usize) -> bool {
+        if index >= self.items.len() {
+            return false;
+        }
+        self.items.remove(index);
+        true
     }
 
     /// Toggle bookmark (add if not present, remove if present)
@@ -140,13 +285,42 @@ impl Bookmarks {
         }
     }
 
+    /// Toggle a bookmark pointing at a location inside a file
+    ///
+    /// Returns `true` if the location is now bookmarked, `false` if removed.
+    pub fn toggle_target(
+        &mut self,
+        path: impl Into<PathBuf>,
+        target: BookmarkTarget,
+        name: Option<String>,
+    ) -> bool {
+        let path = path.into();
+        if let Some(index) = self.position(&path, Some(&target)) {
+            self.items.remove(index);
+            false
+        } else {
+            self.items.push(Bookmark::with_target(path, target, name));
+            true
+        }
+    }
+
     /// Check if a path is already bookmarked
     pub fn contains(&self, path: &Path) -> bool {
-        self.items.iter().any(|b| b.path == path)
+        self.position(path, None).is_some()
+    }
+
+    /// Check if a location inside a file is already bookmarked
+    pub fn contains_target(&self, path: &Path, target: &BookmarkTarget) -> bool {
+        self.position(path, Some(target)).is_some()
+    }
+
+    fn position(&self, path: &Path, target: Option<&BookmarkTarget>) -> Option<usize> {
+        self.items.iter().position(|b| b.matches(path, target))
     }
 
     /// Move a bookmark from one index to another
     ///
+    /// The moved bookmark joins the folder of the bookmark it is dropped on.
     /// Returns `true` if the move was successful.
     pub fn reorder(&mut self, from_index: usize, to_index: usize) -> bool {
         if from_index >= self.items.len() || to_index >= self.items.len() {
@@ -156,17 +330,261 @@ impl Bookmarks {
             return true;
         }
 
-        let item = self.items.remove(from_index);
+        let mut item = self.items.remove(from_index);
         // After removing, indices shift: if from < to, we need to insert at to - 1
         let insert_at = if from_index < to_index {
             to_index - 1
         } else {
             to_index
         };
+        item.folder = self.items[insert_at].folder.clone();
         self.items.insert(insert_at, item);
         true
     }
 
+    /// Move the bookmark at `index` into a folder (or out of any folder with `None`)
+    ///
+    /// Returns `true` if the bookmark was moved.
+    pub fn set_folder(&mut self, index: usize, folder: Option<BookmarkFolderId>) -> bool {
+        if folder
+            .as_ref()
+            .is_some_and(|id| !self.folders.iter().any(|f| f.id == *id))
+        {
+            return false;
+        }
+        match self.items.get_mut(index) {
+            Some(item) => {
+                item.folder = folder;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Create a new folder and return its ID
+    pub fn add_folder(&mut self, name: impl Into<String>) -> BookmarkFolderId {
+        let folder = BookmarkFolder::new(name);
+        let id = folder.id.clone();
+        self.folders.push(folder);
+        id
+    }
+
+    /// Rename a folder
+    ///
+    /// Returns `true` if the folder exists.
+    pub fn rename_folder(&mut self, id: &BookmarkFolderId, name: impl Into<String>) -> bool {
+        match self.folders.iter_mut().find(|f| f.id == *id) {
+            Some(folder) => {
+                folder.name = name.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove a folder, keeping its bookmarks as ungrouped entries
+    ///
+    /// Returns `true` if the folder existed.
+    pub fn remove_folder(&mut self, id: &BookmarkFolderId) -> bool {
+        let len = self.folders.len();
+        self.folders.retain(|f| f.id != *id);
+        if self.folders.len() == len {
+            return false;
+        }
+        for item in &mut self.items {
+            if item.folder.as_ref() == Some(id) {
+                item.folder = None;
+            }
+        }
+        true
+    }
+
+    /// Toggle the collapse state of a folder
+    ///
+    /// Returns `true` if the folder exists.
+    pub fn toggle_folder_collapsed(&mut self, id: &BookmarkFolderId) -> bool {
+        match self.folders.iter_mut().find(|f| f.id == *id) {
+            Some(folder) => {
+                folder.collapsed = !folder.collapsed;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Point bookmarks at `from` (or inside it, when `from` is a directory) to `to`
+    ///
+    /// Returns the number of bookmarks that were relinked.
+    pub fn relink(&mut self, from: &Path, to: &Path) -> usize {
+        let mut count = 0;
+        for item in &mut self.items {
+            if let Ok(rest) = item.path.strip_prefix(from) {
+                item.path = if rest.as_os_str().is_empty() {
+                    to.to_path_buf()
+                } else {
+                    to.join(rest)
+                };
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Merge imported bookmarks into this collection
+    ///
+    /// Folders are matched by name and bookmarks already present (same path and
+    /// location) are skipped. Returns the number of bookmarks added.
+    pub fn merge(&mut self, other: Bookmarks) -> usize {
+        let mut folder_ids = std::collections::HashMap::new();
+        for folder in other.folders {
+            let id = match self.folders.iter().find(|f| f.name == folder.name) {
+                Some(existing) => existing.id.clone(),
+                None => {
+                    let id = BookmarkFolderId::new();
+                    self.folders.push(BookmarkFolder {
+                        id: id.clone(),
+                        ..folder.clone()
+                    });
+                    id
+                }
+            };
+            folder_ids.insert(folder.id, id);
+        }
+
+        let mut added = 0;
+        for mut item in other.items {
+            if self.position(&item.path, item.target.as_ref()).is_some() {
+                continue;
+            }
+            item.folder = item.folder.and_then(|id| folder_ids.get(&id).cloned());
+            self.items.push(item);
+            added += 1;
+        }
+        added
+    }
+
+    /// Serialize bookmarks for sharing
+    ///
+    /// Paths inside `base_dir` are written relative to it so the exported file
+    /// can be shared alongside the documents it refers to.
+    pub fn export(&self, format: BookmarkFormat, base_dir: &Path) -> Result<String> {
+        let mut exported = self.clone();
+        for item in &mut exported.items {
+            if let Ok(relative) = item.path.strip_prefix(base_dir) {
+                item.path = relative.to_path_buf();
+            }
+        }
+        match format {
+            BookmarkFormat::Json => Ok(serde_json::to_string_pretty(&exported)?),
+            BookmarkFormat::Markdown => Ok(exported.to_markdown()),
+        }
+    }
+
+    /// Parse bookmarks written by [`Bookmarks::export`]
+    ///
+    /// Relative paths are resolved against `base_dir`.
+    pub fn import(content: &str, format: BookmarkFormat, base_dir: &Path) -> Result<Self> {
+        let mut imported = match format {
+            BookmarkFormat::Json => serde_json::from_str(content)?,
+            BookmarkFormat::Markdown => Self::from_markdown(content),
+        };
+        for item in &mut imported.items {
+            if item.path.is_relative() {
+                item.path = base_dir.join(&item.path);
+            }
+        }
+        Ok(imported)
+    }
+
+    fn to_markdown(&self) -> String {
+        fn link(item: &Bookmark) -> String {
+            let name = item
+                .display_name()
+                .replace('\\', "\\\\")
+                .replace('[', "\\[")
+                .replace(']', "\\]");
+            let mut dest = item.path.display().to_string();
+            if let Some(target) = &item.target {
+                dest.push('#');
+                dest.push_str(&target.fragment());
+            }
+            format!("- [{name}](<{dest}>)\n")
+        }
+
+        let mut out = String::from("# Bookmarks\n\n");
+        for item in self.items.iter().filter(|b| b.folder.is_none()) {
+            out.push_str(&link(item));
+        }
+        for folder in &self.folders {
+            out.push_str(&format!("\n## {}\n\n", folder.name));
+            for item in self
+                .items
+                .iter()
+                .filter(|b| b.folder.as_ref() == Some(&folder.id))
+            {
+                out.push_str(&link(item));
+            }
+        }
+        out
+    }
+
+    fn from_markdown(content: &str) -> Self {
+        let mut bookmarks = Self::default();
+        let mut folder: Option<BookmarkFolderId> = None;
+        // Heading text or link text being collected, with the link destination
+        let mut heading: Option<String> = None;
+        let mut link: Option<(String, String)> = None;
+
+        for event in Parser::new(content) {
+            match event {
+                Event::Start(Tag::Heading {
+                    level: HeadingLevel::H2,
+                    ..
+                }) => heading = Some(String::new()),
+                Event::End(TagEnd::Heading(HeadingLevel::H2)) => {
+                    if let Some(name) = heading.take() {
+                        folder = Some(bookmarks.add_folder(name.trim()));
+                    }
+                }
+                Event::Start(Tag::Link { dest_url, .. }) => {
+                    link = Some((dest_url.to_string(), String::new()));
+                }
+                Event::End(TagEnd::Link) => {
+                    let Some((dest, text)) = link.take() else {
+                        continue;
+                    };
+                    if dest.contains("://") {
+                        continue;
+                    }
+                    let (path, target) = match dest.rsplit_once('#') {
+                        Some((path, fragment)) => (path, BookmarkTarget::from_fragment(fragment)),
+                        None => (dest.as_str(), None),
+                    };
+                    if path.is_empty() {
+                        continue;
+                    }
+                    let mut item = Bookmark::new(path);
+                    // Keep the link text only when it differs from the default name
+                    if target.is_some() || text != item.display_name() {
+                        item.name = Some(text).filter(|text| !text.is_empty());
+                    }
+                    item.target = target;
+                    item.folder = folder.clone();
+                    bookmarks.items.push(item);
+                }
+                Event::Text(text) | Event::Code(text) => {
+                    if let Some((_, name)) = link.as_mut() {
+                        name.push_str(&text);
+                    } else if let Some(name) = heading.as_mut() {
+                        name.push_str(&text);
+                    }
+                }
+                _ => {}
+            }
+        }
+        bookmarks
+    }
+
     // Test-only methods
     #[cfg(test)]
     pub fn add(&mut self, path: impl Into<PathBuf>) {
@@ -231,9 +649,166 @@ pub fn reorder_bookmark(from_index: usize, to_index: usize) -> bool {
     result
 }
 
+/// Apply a change to the global bookmarks, then save and broadcast it
+///
+/// The closure returns whether anything changed; nothing is saved otherwise.
+fn update_bookmarks(f: impl FnOnce(&mut Bookmarks) -> bool) -> bool {
+    let changed = {
+        let mut bookmarks = BOOKMARKS.write();
+        let changed = f(&mut bookmarks);
+        if changed {
+            bookmarks.save();
+        }
+        changed
+    };
+    if changed {
+        BOOKMARKS_CHANGED.send(()).ok();
+    }
+    changed
+}
+
+/// Toggle a bookmark on a heading or scroll offset and broadcast the change
+///
+/// Returns `true` if the location is now bookmarked, `false` if removed.
+pub fn toggle_bookmark_target(
+    path: impl AsRef<Path>,
+    target: BookmarkTarget,
+    name: Option<String>,
+) -> bool {
+    let mut result = false;
+    update_bookmarks(|bookmarks| {
+        result = bookmarks.toggle_target(path.as_ref(), target, name);
+        true
+    });
+    result
+}
+
+/// Remove the bookmark at `index` and broadcast the change
+pub fn remove_bookmark_at(index: usize) -> bool {
+    update_bookmarks(|bookmarks| bookmarks.remove_at(index))
+}
+
+/// Move the bookmark at `index` into a folder and broadcast the change
+pub fn set_bookmark_folder(index: usize, folder: Option<BookmarkFolderId>) -> bool {
+    update_bookmarks(|bookmarks| bookmarks.set_folder(index, folder))
+}
+
+/// Create a bookmark folder and broadcast the change
+pub fn add_bookmark_folder(name: impl Into<String>) -> BookmarkFolderId {
+    let mut id = None;
+    update_bookmarks(|bookmarks| {
+        id = Some(bookmarks.add_folder(name));
+        true
+    });
+    id.expect("folder is always created")
+}
+
+/// Rename a bookmark folder and broadcast the change
+pub fn rename_bookmark_folder(id: &BookmarkFolderId, name: impl Into<String>) -> bool {
+    update_bookmarks(|bookmarks| bookmarks.rename_folder(id, name))
+}
+
+/// Remove a bookmark folder (keeping its bookmarks) and broadcast the change
+pub fn remove_bookmark_folder(id: &BookmarkFolderId) -> bool {
+    update_bookmarks(|bookmarks| bookmarks.remove_folder(id))
+}
+
+/// Collapse or expand a bookmark folder and broadcast the change
+pub fn toggle_bookmark_folder_collapsed(id: &BookmarkFolderId) -> bool {
+    update_bookmarks(|bookmarks| bookmarks.toggle_folder_collapsed(id))
+}
+
+/// Relink bookmarks from a moved path and broadcast the change
+///
+/// Returns the number of bookmarks that were relinked.
+pub fn relink_bookmarks(from: &Path, to: &Path) -> usize {
+    let mut count = 0;
+    update_bookmarks(|bookmarks| {
+        count = bookmarks.relink(from, to);
+        count > 0
+    });
+    count
+}
+
+/// Import bookmarks from a JSON or Markdown file and broadcast the change
+///
+/// Returns the number of bookmarks added.
+pub fn import_bookmarks(path: &Path) -> Result<usize> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let base_dir = path.parent().unwrap_or(Path::new("/"));
+    let imported = Bookmarks::import(&content, BookmarkFormat::from_path(path), base_dir)?;
+    Ok(merge_bookmarks(imported))
+}
+
+/// Merge imported bookmarks and broadcast the change
+///
+/// Returns the number of bookmarks added.
+pub fn merge_bookmarks(imported: Bookmarks) -> usize {
+    let mut added = 0;
+    update_bookmarks(|bookmarks| {
+        added = bookmarks.merge(imported);
+        added > 0
+    });
+    added
+}
+
+/// Export all bookmarks to a JSON or Markdown file
+pub fn export_bookmarks(path: &Path) -> Result<()> {
+    let base_dir = path.parent().unwrap_or(Path::new("/"));
+    let content = BOOKMARKS
+        .read()
+        .export(BookmarkFormat::from_path(path), base_dir)?;
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Find files named like a missing bookmark target under `root`
+///
+/// Candidates sharing more trailing path components with the missing path are
+/// listed first. Hidden directories are skipped.
+pub fn find_relink_candidates(missing: &Path, root: &Path, limit: usize) -> Vec<PathBuf> {
+    const MAX_DEPTH: usize = 8;
+
+    fn walk(dir: &Path, name: &std::ffi::OsStr, depth: usize, found: &mut Vec<PathBuf>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            if file_name == name {
+                found.push(path.clone());
+            }
+            let is_hidden = file_name.to_string_lossy().starts_with('.');
+            if depth < MAX_DEPTH && !is_hidden && entry.file_type().is_ok_and(|t| t.is_dir()) {
+                walk(&path, name, depth + 1, found);
+            }
+        }
+    }
+
+    let Some(name) = missing.file_name() else {
+        return Vec::new();
+    };
+    let mut found = Vec::new();
+    walk(root, name, 0, &mut found);
+    found.retain(|path| path != missing);
+
+    let shared_suffix = |path: &Path| {
+        path.components()
+            .rev()
+            .zip(missing.components().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+    found.sort_by_key(|path| std::cmp::Reverse(shared_suffix(path)));
+    found.truncate(limit);
+    found
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use indoc::indoc;
     use tempfile::TempDir;
 
     #[test]
@@ -242,8 +817,8 @@ mod tests {
         assert_eq!(bookmark.display_name(), "file.md");
 
         let bookmark_with_name = Bookmark {
-            path: PathBuf::from("/path/to/file.md"),
             name: Some("My Notes".to_string()),
+            ..Bookmark::new("/path/to/file.md")
         };
         assert_eq!(bookmark_with_name.display_name(), "My Notes");
     }
@@ -375,4 +950,182 @@ mod tests {
         assert_eq!(parsed.items[0].path, PathBuf::from("/path/to/file.md"));
         assert_eq!(parsed.items[0].name, Some("My File".to_string()));
     }
+
+    #[test]
+    fn test_bookmarks_target_toggle_is_independent_of_path() {
+        let mut bookmarks = Bookmarks::default();
+        let heading = BookmarkTarget::Heading {
+            slug: "install".to_string(),
+        };
+
+        bookmarks.add("/doc.md");
+        assert!(bookmarks.toggle_target("/doc.md", heading.clone(), Some("Install".into())));
+        assert_eq!(bookmarks.len(), 2);
+        assert!(bookmarks.contains_target(Path::new("/doc.md"), &heading));
+
+        // Removing the path bookmark keeps the heading bookmark
+        bookmarks.remove(Path::new("/doc.md"));
+        assert!(!bookmarks.contains(Path::new("/doc.md")));
+        assert!(bookmarks.contains_target(Path::new("/doc.md"), &heading));
+
+        assert!(!bookmarks.toggle_target("/doc.md", heading.clone(), None));
+        assert_eq!(bookmarks.len(), 0);
+    }
+
+    #[test]
+    fn test_bookmark_target_fragment_roundtrip() {
+        let heading = BookmarkTarget::Heading {
+            slug: "getting-started".to_string(),
+        };
+        assert_eq!(heading.fragment(), "getting-started");
+        assert_eq!(
+            BookmarkTarget::from_fragment("getting-started"),
+            Some(heading)
+        );
+
+        let scroll = BookmarkTarget::Scroll { offset: 1200.4 };
+        assert_eq!(scroll.fragment(), "scroll=1200");
+        assert_eq!(
+            BookmarkTarget::from_fragment("scroll=1200"),
+            Some(BookmarkTarget::Scroll { offset: 1200.0 })
+        );
+        assert_eq!(BookmarkTarget::from_fragment(""), None);
+    }
+
+    #[test]
+    fn test_bookmarks_folders() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.add("/a");
+        bookmarks.add("/b");
+        let id = bookmarks.add_folder("Team");
+
+        assert!(bookmarks.set_folder(1, Some(id.clone())));
+        assert!(!bookmarks.set_folder(1, Some(BookmarkFolderId::new())));
+        assert!(bookmarks.toggle_folder_collapsed(&id));
+        assert!(bookmarks.folders[0].collapsed);
+        assert!(bookmarks.rename_folder(&id, "Shared"));
+        assert_eq!(bookmarks.folders[0].name, "Shared");
+
+        // Dropping onto a grouped bookmark moves into its folder
+        assert!(bookmarks.reorder(0, 1));
+        assert_eq!(bookmarks.items[0].folder, Some(id.clone()));
+
+        assert!(bookmarks.remove_folder(&id));
+        assert!(bookmarks.folders.is_empty());
+        assert!(bookmarks.items.iter().all(|b| b.folder.is_none()));
+    }
+
+    #[test]
+    fn test_bookmarks_relink() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.add("/docs/old/guide.md");
+        bookmarks.add("/docs/old");
+        bookmarks.add("/docs/other.md");
+
+        assert_eq!(
+            bookmarks.relink(Path::new("/docs/old"), Path::new("/docs/new")),
+            2
+        );
+        assert_eq!(bookmarks.items[0].path, PathBuf::from("/docs/new/guide.md"));
+        assert_eq!(bookmarks.items[1].path, PathBuf::from("/docs/new"));
+        assert_eq!(bookmarks.items[2].path, PathBuf::from("/docs/other.md"));
+    }
+
+    #[test]
+    fn test_find_relink_candidates() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("a/notes")).unwrap();
+        std::fs::create_dir_all(root.join("b")).unwrap();
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::write(root.join("a/notes/todo.md"), "").unwrap();
+        std::fs::write(root.join("b/todo.md"), "").unwrap();
+        std::fs::write(root.join(".git/todo.md"), "").unwrap();
+
+        let missing = root.join("old/notes/todo.md");
+        let candidates = find_relink_candidates(&missing, root, 10);
+        assert_eq!(
+            candidates,
+            vec![root.join("a/notes/todo.md"), root.join("b/todo.md")]
+        );
+        assert_eq!(find_relink_candidates(&missing, root, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_bookmarks_markdown_roundtrip() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.add("/share/readme.md");
+        let id = bookmarks.add_folder("Guides");
+        bookmarks.items.push(Bookmark {
+            folder: Some(id),
+            ..Bookmark::with_target(
+                "/share/docs/setup guide.md",
+                BookmarkTarget::Heading {
+                    slug: "install".to_string(),
+                },
+                Some("Install [macOS]".to_string()),
+            )
+        });
+        bookmarks.add("/elsewhere/notes.md");
+
+        let exported = bookmarks
+            .export(BookmarkFormat::Markdown, Path::new("/share"))
+            .unwrap();
+        assert_eq!(
+            exported,
+            indoc! {r"
+                # Bookmarks
+
+                - [readme.md](<readme.md>)
+                - [notes.md](</elsewhere/notes.md>)
+
+                ## Guides
+
+                - [Install \[macOS\]](<docs/setup guide.md#install>)
+            "}
+        );
+
+        let imported =
+            Bookmarks::import(&exported, BookmarkFormat::Markdown, Path::new("/team")).unwrap();
+        assert_eq!(imported.folders.len(), 1);
+        assert_eq!(imported.folders[0].name, "Guides");
+        assert_eq!(imported.items.len(), 3);
+        assert_eq!(imported.items[0].path, PathBuf::from("/team/readme.md"));
+        assert_eq!(imported.items[0].name, None);
+        assert_eq!(imported.items[1].path, PathBuf::from("/elsewhere/notes.md"));
+        assert_eq!(
+            imported.items[2].path,
+            PathBuf::from("/team/docs/setup guide.md")
+        );
+        assert_eq!(imported.items[2].name.as_deref(), Some("Install [macOS]"));
+        assert_eq!(
+            imported.items[2].folder,
+            Some(imported.folders[0].id.clone())
+        );
+    }
+
+    #[test]
+    fn test_bookmarks_json_import_merges() {
+        let mut shared = Bookmarks::default();
+        let id = shared.add_folder("Team");
+        shared.add("/repo/a.md");
+        shared.items[0].folder = Some(id);
+        let exported = shared
+            .export(BookmarkFormat::Json, Path::new("/repo"))
+            .unwrap();
+
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.add_folder("Team");
+        bookmarks.add("/repo/a.md");
+        let imported =
+            Bookmarks::import(&exported, BookmarkFormat::Json, Path::new("/repo")).unwrap();
+        // Already bookmarked paths are skipped and folders matched by name
+        assert_eq!(bookmarks.merge(imported.clone()), 0);
+        assert_eq!(bookmarks.folders.len(), 1);
+
+        let mut empty = Bookmarks::default();
+        assert_eq!(empty.merge(imported), 1);
+        assert_eq!(empty.items[0].path, PathBuf::from("/repo/a.md"));
+        assert_eq!(empty.items[0].folder, Some(empty.folders[0].id.clone()));
+    }
 }
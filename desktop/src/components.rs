@@ -1,8 +1,10 @@
 pub mod app;
 pub mod bookmark_button;
+pub mod command_palette;
 pub mod content;
 pub mod header;
 pub mod icon;
+pub mod link_graph_window;
 pub mod main_app;
 pub mod mermaid_window;
 pub mod pinned_chips;
@@ -9,8 +9,10 @@ use mouse_position::mouse_position::Mouse;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use super::command_palette::CommandPalette;
 use super::content::{
-    close_context_menu, use_search_handler, Content, ContentContextMenu, CONTENT_CONTEXT_MENU,
+    close_context_menu, use_annotation_handler, use_search_handler, use_vim_handler, Content,
+    ContentContextMenu, CONTENT_CONTEXT_MENU,
 };
 use super::header::Header;
 use super::icon::{Icon, IconName};
@@ -19,12 +21,16 @@ use super::search_bar::SearchBar;
 use super::sidebar::Sidebar;
 use super::tab::TabBar;
 use crate::assets::MAIN_SCRIPT;
+use crate::config::{CONFIG, CONFIG_CHANGED};
 use crate::drag;
 use crate::events::{
-    ActiveDragUpdate, ACTIVE_DRAG_UPDATE, OPEN_DIRECTORY_IN_WINDOW, OPEN_FILE_IN_WINDOW,
+    ActiveDragUpdate, DocumentEvent, WindowCommand, ACTIVE_DRAG_UPDATE, DOCUMENT_EVENT,
+    OPEN_DIRECTORY_IN_WINDOW, OPEN_FILE_IN_WINDOW, WINDOW_COMMAND,
 };
+use crate::i18n::{self, t};
 use crate::menu;
-use crate::state::{AppState, PersistedState, Tab, LAST_FOCUSED_STATE};
+use crate::recent::record_recent;
+use crate::state::{AppState, PersistedState, Tab, TabContent, LAST_FOCUSED_STATE};
 use crate::theme::Theme;
 
 const WINDOW_METRICS_DEBOUNCE_MS: u64 = 200;
@@ -139,8 +145,25 @@ pub fn App(
         });
     });
 
-    // Setup search handlers at App level (window-wide feature)
+    // Setup search and annotation handlers at App level (window-wide features)
     use_search_handler(state);
+    use_annotation_handler(state);
+    use_vim_handler(state);
+
+    // Track the UI language so the window re-renders its strings when it changes
+    let mut locale = use_signal(i18n::current_locale);
+    use_future(move || async move {
+        let mut rx = CONFIG_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            let current = i18n::current_locale();
+            if *locale.peek() != current {
+                locale.set(current);
+            }
+        }
+    });
+
+    // Carry changed defaults over to this window
+    use_live_defaults(state);
 
     // Handle menu events (only state-dependent events, not global ones)
     use_muda_event_handler(move |event| {
@@ -215,6 +238,7 @@ pub fn App(
 
     // Listen for cross-window file/directory open events (from sidebar context menu)
     setup_cross_window_open_listeners(state);
+    setup_remote_control(state);
 
     // Update window title when active tab changes
     use_effect(move || {
@@ -222,7 +246,7 @@ pub fn App(
         let tabs = state.tabs.read();
 
         if let Some(tab) = tabs.get(active_index) {
-            let title = crate::utils::window_title::generate_window_title(&tab.content);
+            let title = crate::utils::window_title::generate_window_title(tab);
             window().set_title(&title);
         }
     });
@@ -296,6 +320,7 @@ pub fn App(
         // Clear last focused window if this window was the last focused
         // Prevents stale WindowId references in find_window_at_point
         crate::window::clear_last_focused_if_matches(window_id);
+        crate::remote::unregister_window(window_id);
 
         // Save last used state from this window
         // Read directly from state signals instead of global statics
@@ -316,53 +341,67 @@ pub fn App(
     });
 
     rsx! {
-        div {
-            class: "app-container",
-            class: if is_dragging() { "drag-over" },
-            ondragover: move |evt| {
-                evt.prevent_default();
-                is_dragging.set(true);
-            },
-            ondragleave: move |evt| {
-                evt.prevent_default();
-                is_dragging.set(false);
-            },
-            ondrop: move |evt| {
-                evt.prevent_default();
-                is_dragging.set(false);
+        // Keyed on the locale so that changing the UI language re-mounts every
+        // component with its translated strings (app state lives above this)
+        for locale in [locale()] {
+            div {
+                key: "{locale.code()}",
+                class: "app-container",
+                class: if is_dragging() { "drag-over" },
+                ondragover: move |evt| {
+                    evt.prevent_default();
+                    is_dragging.set(true);
+                },
+                ondragleave: move |evt| {
+                    evt.prevent_default();
+                    is_dragging.set(false);
+                },
+                ondrop: move |evt| {
+                    evt.prevent_default();
+                    is_dragging.set(false);
 
-                spawn(async move {
-                    handle_dropped_files(evt, state).await;
-                });
-            },
+                    spawn(async move {
+                        handle_dropped_files(evt, state).await;
+                    });
+                },
 
-            Sidebar {},
+                Sidebar {},
 
-            div {
-                class: "main-area",
-                Header {},
-                SearchBar {},
-                TabBar {},
-                Content {},
-            }
+                div {
+                    class: "main-area",
+                    Header {},
+                    SearchBar {},
+                    TabBar {},
+                    Content {},
+                }
 
-            RightSidebar { headings: state.toc_headings.read().clone() }
+                RightSidebar { headings: state.toc_headings.read().clone() }
 
-            // Drag and drop overlay
-            if is_dragging() {
-                DragDropOverlay {}
-            }
+                // Drag and drop overlay
+                if is_dragging() {
+                    DragDropOverlay {}
+                }
+
+                if *state.command_palette_open.read() {
+                    CommandPalette {}
+                }
 
-            // Content context menu (rendered at App level to prevent FileViewer re-renders)
-            if let Some(menu_state) = CONTENT_CONTEXT_MENU.read().as_ref() {
-                ContentContextMenu {
-                    position: (menu_state.data.x, menu_state.data.y),
-                    context: menu_state.data.context.clone(),
-                    has_selection: menu_state.data.has_selection,
-                    selected_text: menu_state.data.selected_text.clone(),
-                    current_file: menu_state.current_file.clone(),
-                    base_dir: menu_state.base_dir.clone(),
-                    on_close: move |_| close_context_menu(),
+                // Content context menu (rendered at App level to prevent FileViewer re-renders)
+                if let Some(menu_state) = CONTENT_CONTEXT_MENU.read().as_ref() {
+                    ContentContextMenu {
+                        position: (menu_state.data.x, menu_state.data.y),
+                        context: menu_state.data.context.clone(),
+                        has_selection: menu_state.data.has_selection,
+                        selected_text: menu_state.data.selected_text.clone(),
+                        selection_anchor: menu_state.data.selection_anchor.clone(),
+                        section_heading: menu_state.data.section_heading.clone(),
+                        source_position: menu_state.data.source_line.map(|line| {
+                            (line, menu_state.data.source_column.unwrap_or(1))
+                        }),
+                        current_file: menu_state.current_file.clone(),
+                        base_dir: menu_state.base_dir.clone(),
+                        on_close: move |_| close_context_menu(),
+                    }
                 }
             }
         }
@@ -400,6 +439,7 @@ async fn handle_dropped_files(evt: Event<DragData>, mut state: AppState) {
         if resolved_path.is_dir() {
             // If it's a directory, set it as root and show the sidebar
             tracing::info!("Setting dropped directory as root: {:?}", resolved_path);
+            record_recent(&resolved_path);
             state.set_root_directory(resolved_path);
             // Show the sidebar if it's hidden so users can see the directory tree
             if !state.sidebar.read().open {
@@ -467,6 +507,117 @@ fn setup_cross_window_open_listeners(mut state: AppState) {
     });
 }
 
+/// Publish this window to remote control clients and run their commands
+fn setup_remote_control(mut state: AppState) {
+    let current_window_id = window().id();
+
+    // Keep the tabs seen by clients current, announcing navigation
+    use_effect(move || {
+        let active_tab = *state.active_tab.read();
+        let tabs: Vec<_> = state
+            .tabs
+            .read()
+            .iter()
+            .map(|tab| crate::remote::TabInfo {
+                title: tab.display_name(),
+                path: tab.file().map(|p| p.to_path_buf()),
+                preview: match &tab.content {
+                    TabContent::LivePreview(id) => Some(id.clone()),
+                    _ => None,
+                },
+            })
+            .collect();
+        let path = tabs.get(active_tab).and_then(|tab| tab.path.clone());
+        let previous = crate::remote::update_window(current_window_id, |w| {
+            let previous = w.active_path();
+            w.tabs = tabs;
+            w.active_tab = active_tab;
+            previous
+        });
+        if previous != path {
+            DOCUMENT_EVENT
+                .send(DocumentEvent::Navigated {
+                    window_id: current_window_id,
+                    path,
+                })
+                .ok();
+        }
+    });
+
+    use_effect(move || {
+        let headings = state.toc_headings.read().clone();
+        crate::remote::update_window(current_window_id, |w| w.headings = headings);
+    });
+
+    use_effect(move || {
+        let theme = state.current_theme.read().clone();
+        crate::remote::update_window(current_window_id, |w| w.theme = theme);
+    });
+
+    use_future(move || async move {
+        let mut rx = WINDOW_COMMAND.subscribe();
+
+        while let Ok((target_window_id, command)) = rx.recv().await {
+            // Only handle if this window is the target
+            if target_window_id != current_window_id {
+                continue;
+            }
+            tracing::info!(?command, "Running remote control command");
+            match command {
+                WindowCommand::ScrollToHeading(id) => {
+                    let id = serde_json::to_string(&id).unwrap_or_default();
+                    let js = format!(
+                        "document.getElementById({id})?.scrollIntoView({{ behavior: 'smooth', block: 'start' }});"
+                    );
+                    let _ = document::eval(&js).await;
+                }
+                WindowCommand::Reload => state.reload_current_tab(),
+                WindowCommand::SetTheme(theme) => state.current_theme.set(theme),
+                WindowCommand::RunCommand(id) => menu::run_command(id, &mut state),
+                WindowCommand::OpenLivePreview(id) => state.open_live_preview(id),
+            }
+        }
+    });
+}
+
+/// Apply edited defaults (theme, sidebars) to an already open window
+///
+/// Only settings whose default changed are touched, so choices made in the
+/// window survive unrelated edits of the configuration.
+fn use_live_defaults(mut state: AppState) {
+    use_future(move || async move {
+        let mut previous = CONFIG.read().clone();
+        let mut rx = CONFIG_CHANGED.subscribe();
+
+        while rx.recv().await.is_ok() {
+            let current = CONFIG.read().clone();
+            if current.theme.default_theme != previous.theme.default_theme {
+                state.current_theme.set(current.theme.default_theme.clone());
+            }
+            if current.sidebar.default_open != previous.sidebar.default_open {
+                state.sidebar.write().open = current.sidebar.default_open;
+            }
+            if current.sidebar.default_width != previous.sidebar.default_width {
+                state.sidebar.write().width = current.sidebar.default_width;
+            }
+            if current.sidebar.default_show_all_files != previous.sidebar.default_show_all_files {
+                state.sidebar.write().show_all_files = current.sidebar.default_show_all_files;
+            }
+            if current.right_sidebar.default_open != previous.right_sidebar.default_open {
+                state
+                    .right_sidebar_open
+                    .set(current.right_sidebar.default_open);
+            }
+            if current.right_sidebar.default_width != previous.right_sidebar.default_width {
+                state
+                    .right_sidebar_width
+                    .set(current.right_sidebar.default_width);
+            }
+            previous = current;
+        }
+    });
+}
+
 #[component]
 fn DragDropOverlay() -> Element {
     rsx! {
@@ -480,7 +631,7 @@ fn DragDropOverlay() -> Element {
                 }
                 div {
                     class: "drag-drop-text",
-                    "Drop Markdown file or directory to open"
+                    {t("app.drop_hint")}
                 }
             }
         }
@@ -815,7 +966,7 @@ fn compute_detach_transition(
                     is_single_tab: active.source_tab_count == 1,
                     directory: state.sidebar.read().root_directory.clone(),
                     sidebar: state.sidebar.read().clone(),
-                    theme: *state.current_theme.read(),
+                    theme: state.current_theme.read().clone(),
                     size: win.inner_size().to_logical::<u32>(win.scale_factor()),
                 });
                 (DetachState::Creating, None)
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 
 use crate::bookmarks::{toggle_bookmark, BOOKMARKS, BOOKMARKS_CHANGED};
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 
 /// Reusable bookmark toggle button
 #[component]
@@ -45,9 +46,9 @@ pub fn BookmarkButton(
     };
 
     let title = if *is_bookmarked.read() {
-        "Remove from Quick Access"
+        t("context_menu.remove_from_quick_access")
     } else {
-        "Add to Quick Access"
+        t("context_menu.add_to_quick_access")
     };
 
     let bookmarked_class = if *is_bookmarked.read() {
@@ -0,0 +1,272 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use super::content::{highlight_selection, select_all_content};
+use super::icon::{Icon, IconName};
+use crate::annotations::TextAnchor;
+use crate::config::{KeymapConfig, CONFIG};
+use crate::i18n::t;
+use crate::keymap;
+use crate::menu::{self, MenuId};
+use crate::state::AppState;
+
+/// A command that can be run from the palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PaletteCommand {
+    /// Any menu bar command
+    Menu(MenuId),
+    /// Context menu actions on the rendered content
+    Copy,
+    SelectAll,
+    HighlightSelection,
+}
+
+impl PaletteCommand {
+    fn all() -> Vec<Self> {
+        MenuId::ALL
+            .into_iter()
+            // Opening the palette from itself is meaningless
+            .filter(|id| *id != MenuId::CommandPalette)
+            .map(Self::Menu)
+            .chain([Self::Copy, Self::SelectAll, Self::HighlightSelection])
+            .collect()
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Menu(id) => id.label().trim_end_matches("..."),
+            Self::Copy => t("context_menu.copy"),
+            Self::SelectAll => t("context_menu.select_all"),
+            Self::HighlightSelection => t("context_menu.highlight_selection"),
+        }
+    }
+
+    fn category(self) -> &'static str {
+        match self {
+            Self::Menu(id) => id.category(),
+            _ => t("command_palette.content"),
+        }
+    }
+
+    fn shortcut(self, config: &KeymapConfig) -> Option<String> {
+        match self {
+            Self::Menu(id) => keymap::shortcut_label(id, config),
+            Self::Copy => Some("⌘C".to_string()),
+            Self::SelectAll => Some("⌘A".to_string()),
+            Self::HighlightSelection => None,
+        }
+    }
+}
+
+/// Score how well a query matches a command title (lower is better).
+///
+/// Substring matches rank before subsequence matches, and matches at a word
+/// start rank first, so "zi" finds "Zoom In" and "tog" finds "Toggle Sidebar".
+fn match_score(query: &str, title: &str) -> Option<usize> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let title = title.to_lowercase();
+
+    if let Some(pos) = title.find(&query) {
+        let at_word_start = pos == 0 || !title[..pos].ends_with(char::is_alphanumeric);
+        return Some(if at_word_start { 0 } else { 1 });
+    }
+
+    let mut chars = title.chars();
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|q| chars.any(|c| c == q))
+        .then_some(2)
+}
+
+/// Commands matching the query, best matches first (menu order within a tier).
+fn filter_commands(query: &str) -> Vec<PaletteCommand> {
+    let mut scored: Vec<(usize, PaletteCommand)> = PaletteCommand::all()
+        .into_iter()
+        .filter_map(|command| {
+            let title = format!("{}: {}", command.category(), command.label());
+            match_score(query, command.label())
+                .or_else(|| match_score(query, &title).map(|s| s + 3))
+                .map(|score| (score, command))
+        })
+        .collect();
+    scored.sort_by_key(|(score, _)| *score);
+    scored.into_iter().map(|(_, command)| command).collect()
+}
+
+/// Text selection captured from JavaScript when the palette opens
+#[derive(Debug, Clone, Default, Deserialize)]
+struct SelectionData {
+    has_selection: bool,
+    selected_text: String,
+    selection_anchor: Option<TextAnchor>,
+}
+
+#[component]
+pub fn CommandPalette() -> Element {
+    let mut state = use_context::<AppState>();
+    let mut query = use_signal(String::new);
+    let mut selected_index = use_signal(|| 0usize);
+    let mut selection = use_signal(SelectionData::default);
+
+    // Capture the selection before the input takes focus and clears it
+    use_hook(move || {
+        spawn(async move {
+            let mut eval = document::eval(
+                "dioxus.send(window.Arto?.captureSelection?.() ?? { has_selection: false, selected_text: '', selection_anchor: null });",
+            );
+            if let Ok(data) = eval.recv::<SelectionData>().await {
+                selection.set(data);
+            }
+        });
+    });
+
+    let current_file = state
+        .current_tab()
+        .and_then(|tab| tab.file().map(PathBuf::from));
+    let has_selection = selection.read().has_selection;
+    let can_highlight = current_file.is_some() && selection.read().selection_anchor.is_some();
+
+    // Content actions are only offered when they can do something
+    let commands: Vec<PaletteCommand> = filter_commands(&query())
+        .into_iter()
+        .filter(|command| match command {
+            PaletteCommand::Copy => has_selection,
+            PaletteCommand::HighlightSelection => can_highlight,
+            _ => true,
+        })
+        .collect();
+    let selected = selected_index().min(commands.len().saturating_sub(1));
+    let keymap_config = CONFIG.read().keymap.clone();
+
+    let mut run = move |command: PaletteCommand| {
+        state.command_palette_open.set(false);
+        match command {
+            PaletteCommand::Menu(id) => menu::run_command(id, &mut state),
+            PaletteCommand::Copy => {
+                crate::utils::clipboard::copy_text(&selection.read().selected_text);
+            }
+            PaletteCommand::SelectAll => select_all_content(),
+            PaletteCommand::HighlightSelection => {
+                let anchor = selection.read().selection_anchor.clone();
+                let file = state
+                    .current_tab()
+                    .and_then(|tab| tab.file().map(PathBuf::from));
+                if let (Some(anchor), Some(file)) = (anchor, file) {
+                    highlight_selection(state, file, anchor);
+                }
+            }
+        }
+    };
+
+    rsx! {
+        // Backdrop to close the palette on outside click
+        div {
+            class: "command-palette-backdrop",
+            onclick: move |_| state.command_palette_open.set(false),
+        }
+
+        div {
+            class: "command-palette",
+
+            div {
+                class: "command-palette-input",
+                Icon { name: IconName::Command, size: 16 }
+                input {
+                    r#type: "text",
+                    placeholder: t("command_palette.placeholder"),
+                    autofocus: true,
+                    autocorrect: "off",
+                    autocapitalize: "off",
+                    spellcheck: "false",
+                    value: "{query}",
+                    oninput: move |evt| {
+                        query.set(evt.value());
+                        selected_index.set(0);
+                    },
+                    onkeydown: {
+                        let commands = commands.clone();
+                        move |evt: Event<KeyboardData>| match evt.key() {
+                            Key::ArrowDown => {
+                                evt.prevent_default();
+                                if selected + 1 < commands.len() {
+                                    selected_index.set(selected + 1);
+                                }
+                            }
+                            Key::ArrowUp => {
+                                evt.prevent_default();
+                                selected_index.set(selected.saturating_sub(1));
+                            }
+                            Key::Enter => {
+                                if let Some(command) = commands.get(selected) {
+                                    run(*command);
+                                }
+                            }
+                            Key::Escape => state.command_palette_open.set(false),
+                            _ => {}
+                        }
+                    },
+                }
+            }
+
+            ul {
+                class: "command-palette-list",
+                if commands.is_empty() {
+                    li { class: "command-palette-empty", {t("command_palette.empty")} }
+                }
+                for (index, command) in commands.iter().copied().enumerate() {
+                    li {
+                        key: "{index}",
+                        class: "command-palette-item",
+                        class: if index == selected { "selected" },
+                        onmouseenter: move |_| selected_index.set(index),
+                        onclick: move |_| run(command),
+                        span { class: "command-palette-category", "{command.category()}" }
+                        span { class: "command-palette-label", "{command.label()}" }
+                        if let Some(shortcut) = command.shortcut(&keymap_config) {
+                            kbd { class: "command-palette-shortcut", "{shortcut}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_score() {
+        assert_eq!(match_score("", "Zoom In"), Some(0));
+        assert_eq!(match_score("zoom", "Zoom In"), Some(0));
+        assert_eq!(match_score("in", "Zoom In"), Some(0));
+        assert_eq!(match_score("oom", "Zoom In"), Some(1));
+        assert_eq!(match_score("zi", "Zoom In"), Some(2));
+        assert_eq!(match_score("xyz", "Zoom In"), None);
+    }
+
+    #[test]
+    fn test_filter_commands() {
+        let commands = filter_commands("sidebar");
+        assert_eq!(commands[0], PaletteCommand::Menu(MenuId::ToggleSidebar));
+
+        // Category prefix matches too
+        let commands = filter_commands("history: go");
+        assert_eq!(
+            commands,
+            vec![
+                PaletteCommand::Menu(MenuId::GoBack),
+                PaletteCommand::Menu(MenuId::GoForward)
+            ]
+        );
+
+        // The palette never lists itself
+        assert!(!filter_commands("palette").contains(&PaletteCommand::Menu(MenuId::CommandPalette)));
+    }
+}
@@ -1,30 +1,45 @@
+mod annotation_handler;
+mod book_viewer;
+mod chapter_navigation;
 mod context_menu;
 mod context_menu_state;
+mod directory_viewer;
+mod file_deleted_view;
 mod file_error_view;
 mod file_viewer;
+mod folder_viewer;
 mod inline_viewer;
+mod live_preview_viewer;
 mod no_file_view;
 mod preferences_view;
 mod search_handler;
+mod source_view;
+mod vim_handler;
 
 use dioxus::prelude::*;
 
 use crate::state::{AppState, TabContent};
+use book_viewer::BookViewer;
+use directory_viewer::DirectoryViewer;
 use file_error_view::FileErrorView;
 use file_viewer::FileViewer;
+use folder_viewer::FolderViewer;
 use inline_viewer::InlineViewer;
+use live_preview_viewer::LivePreviewViewer;
 use no_file_view::NoFileView;
 use preferences_view::PreferencesView;
 
 // Re-export for menu system
-pub use preferences_view::set_preferences_tab_to_about;
+pub use preferences_view::{set_preferences_tab_to_about, set_preferences_tab_to_watcher};
 
 // Re-export context menu types for App-level rendering
-pub use context_menu::ContentContextMenu;
+pub use context_menu::{highlight_selection, select_all_content, ContentContextMenu};
 pub use context_menu_state::{close_context_menu, CONTENT_CONTEXT_MENU};
 
-// Re-export search handler for App-level setup
+// Re-export search, annotation and vim handlers for App-level setup
+pub use annotation_handler::use_annotation_handler;
 pub use search_handler::use_search_handler;
+pub use vim_handler::use_vim_handler;
 
 #[component]
 pub fn Content() -> Element {
@@ -53,6 +68,9 @@ pub fn Content() -> Element {
                 Some(TabContent::Inline(markdown)) => {
                     rsx! { InlineViewer { markdown } }
                 },
+                Some(TabContent::LivePreview(id)) => {
+                    rsx! { LivePreviewViewer { id } }
+                },
                 Some(TabContent::FileError(file, error)) => {
                     let filename = file
                         .file_name()
@@ -64,6 +82,15 @@ pub fn Content() -> Element {
                 Some(TabContent::Preferences) => {
                     rsx! { PreferencesView {} }
                 },
+                Some(TabContent::Book(root)) => {
+                    rsx! { BookViewer { root } }
+                },
+                Some(TabContent::Folder(dir)) => {
+                    rsx! { FolderViewer { dir } }
+                },
+                Some(TabContent::Directory(dir)) => {
+                    rsx! { DirectoryViewer { dir } }
+                },
                 _ => rsx! { NoFileView {} },
             }
         }
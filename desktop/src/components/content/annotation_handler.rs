@@ -0,0 +1,79 @@
+use dioxus::document;
+use dioxus::prelude::*;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::annotations::{AnnotationId, ANNOTATIONS, ANNOTATIONS_CHANGED};
+use crate::state::AppState;
+
+/// Anchoring result reported from JavaScript after highlights are applied
+#[derive(Deserialize)]
+struct AnnotationResultData {
+    detached: Vec<String>,
+}
+
+/// Hook to keep annotation highlights of the current file in sync with JavaScript.
+///
+/// Annotations are pushed whenever the active file changes or any window edits
+/// annotations. Re-anchoring after content re-renders is triggered by FileViewer.
+pub fn use_annotation_handler(mut state: AppState) {
+    // Bumped on every annotation change broadcast
+    let mut revision = use_signal(|| 0usize);
+    use_future(move || async move {
+        let mut rx = ANNOTATIONS_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            revision += 1;
+        }
+    });
+
+    let current_file = use_memo(move || {
+        state
+            .current_tab()
+            .and_then(|tab| tab.file().map(PathBuf::from))
+    });
+
+    use_effect(move || {
+        let mut eval_provider = document::eval(indoc::indoc! {r#"
+            (async () => {
+                // Wait for window.Arto to be initialized
+                while (!window.Arto?.annotations?.setup) {
+                    await new Promise(resolve => setTimeout(resolve, 10));
+                }
+                window.Arto.annotations.setup((data) => {
+                    dioxus.send(data);
+                });
+            })();
+        "#});
+
+        spawn(async move {
+            while let Ok(data) = eval_provider.recv::<AnnotationResultData>().await {
+                let detached: HashSet<AnnotationId> =
+                    data.detached.into_iter().map(AnnotationId::from).collect();
+                if *state.detached_annotations.peek() != detached {
+                    state.detached_annotations.set(detached);
+                }
+            }
+        });
+    });
+
+    use_effect(move || {
+        let _ = revision();
+        let annotations = current_file()
+            .map(|file| ANNOTATIONS.read().for_file(&file).to_vec())
+            .unwrap_or_default();
+        let json = serde_json::to_string(&annotations).unwrap_or_else(|_| "[]".to_string());
+
+        spawn(async move {
+            let js = indoc::formatdoc! {r#"
+                (async () => {{
+                    while (!window.Arto?.annotations?.set) {{
+                        await new Promise(resolve => setTimeout(resolve, 10));
+                    }}
+                    window.Arto.annotations.set({json});
+                }})();
+            "#};
+            let _ = document::eval(&js).await;
+        });
+    });
+}
@@ -0,0 +1,176 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::file_viewer::{reapply_highlights, use_mermaid_window_handler};
+use crate::book::Book;
+use crate::state::AppState;
+use crate::watcher::FILE_WATCHER;
+
+/// Data structure for markdown link clicks inside a book chapter
+#[derive(Serialize, Deserialize)]
+struct BookLinkClickData {
+    path: String,
+    button: u32,
+    scroll_position: f64,
+    /// Directory of the chapter containing the link
+    base_dir: Option<String>,
+}
+
+/// Mouse button constants
+const LEFT_CLICK: u32 = 0;
+const MIDDLE_CLICK: u32 = 1;
+
+/// All chapters of a book rendered as one continuous document ("read whole book")
+#[component]
+pub fn BookViewer(root: PathBuf) -> Element {
+    let state = use_context::<AppState>();
+    let html = use_signal(String::new);
+    let book = use_signal(|| None::<Book>);
+    let reload_trigger = use_signal(|| 0usize);
+
+    use_book_loader(root.clone(), html, book, reload_trigger, state);
+    use_book_watcher(root.clone(), reload_trigger);
+    use_book_link_click_handler(root, book, state);
+    use_mermaid_window_handler();
+
+    rsx! {
+        div {
+            class: "markdown-viewer book-viewer",
+            article {
+                class: "markdown-body",
+                dangerous_inner_html: "{html}"
+            }
+        }
+    }
+}
+
+/// Hook to detect the book and render all of its chapters
+fn use_book_loader(
+    root: PathBuf,
+    html: Signal<String>,
+    book: Signal<Option<Book>>,
+    reload_trigger: Signal<usize>,
+    mut state: AppState,
+) {
+    use_effect(use_reactive!(|root, reload_trigger| {
+        let mut html = html;
+        let mut book = book;
+        let root = root.clone();
+
+        // Start at the beginning of the book; reloads keep the position
+        if reload_trigger() == 0 {
+            let _ = document::eval("document.querySelector('.content')?.scrollTo(0, 0);");
+        }
+
+        spawn(async move {
+            tracing::info!(?root, "Loading book");
+            match Book::detect(&root) {
+                Some(detected) => {
+                    let (rendered, headings) = detected.render_to_html();
+                    html.set(format!(
+                        "<h1 class=\"book-title\">{}</h1>\n{}",
+                        html_escape::encode_text(&detected.title),
+                        rendered
+                    ));
+                    state.toc_headings.set(headings);
+                    state.document_metadata.set(None);
+                    book.set(Some(detected));
+                    reapply_highlights().await;
+                }
+                None => {
+                    tracing::warn!(?root, "No SUMMARY.md or mkdocs.yml nav found");
+                    html.set(format!(
+                        r#"<p class="error">No SUMMARY.md or mkdocs.yml navigation found in {}</p>"#,
+                        html_escape::encode_text(&root.to_string_lossy())
+                    ));
+                    state.toc_headings.set(Vec::new());
+                    state.document_metadata.set(None);
+                    book.set(None);
+                }
+            }
+        });
+    }));
+}
+
+/// Hook to reload the book when its navigation file or any chapter changes
+fn use_book_watcher(root: PathBuf, reload_trigger: Signal<usize>) {
+    use_effect(use_reactive!(|root| {
+        let mut reload_trigger = reload_trigger;
+        let root = root.clone();
+
+        spawn(async move {
+            let Ok(mut watcher) = FILE_WATCHER.watch_directory(root.clone()).await else {
+                tracing::error!(?root, "Failed to start book watcher");
+                return;
+            };
+
+            while watcher.recv().await.is_some() {
+                tracing::info!(?root, "Book changed, reloading");
+                reload_trigger.set(reload_trigger() + 1);
+            }
+
+            let _ = FILE_WATCHER.unwatch_directory(root).await;
+        });
+    }));
+}
+
+/// Hook to handle markdown link clicks relative to the chapter they appear in
+///
+/// Links to other chapters scroll to that chapter instead of leaving the book.
+fn use_book_link_click_handler(root: PathBuf, book: Signal<Option<Book>>, state: AppState) {
+    use_effect(use_reactive!(|root| {
+        let root = root.clone();
+        let mut state = state;
+        let mut eval_provider = document::eval(indoc::indoc! {r#"
+            window.handleMarkdownLinkClick = (path, button) => {
+                const section = window.event?.target?.closest?.('[data-chapter-dir]');
+                const scrollPosition = document.querySelector('.content')?.scrollTop || 0;
+                dioxus.send({
+                    path,
+                    button,
+                    scroll_position: scrollPosition,
+                    base_dir: section?.dataset.chapterDir ?? null,
+                });
+            };
+        "#});
+
+        spawn(async move {
+            while let Ok(click_data) = eval_provider.recv::<BookLinkClickData>().await {
+                let base_dir = click_data
+                    .base_dir
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| root.clone());
+                let target_path = base_dir.join(&click_data.path);
+                let Ok(canonical_path) = target_path.canonicalize() else {
+                    tracing::error!("Failed to resolve path: {:?}", target_path);
+                    continue;
+                };
+
+                let chapter_index = book
+                    .peek()
+                    .as_ref()
+                    .and_then(|book| book.chapter_index(&canonical_path));
+
+                match (click_data.button, chapter_index) {
+                    (LEFT_CLICK, Some(index)) => {
+                        let js = format!(
+                            "document.getElementById('book-chapter-{index}')?.scrollIntoView({{ behavior: 'smooth', block: 'start' }});"
+                        );
+                        let _ = document::eval(&js);
+                    }
+                    (LEFT_CLICK, None) => {
+                        state.save_current_scroll_position(click_data.scroll_position);
+                        state.navigate_to_file(canonical_path);
+                    }
+                    (MIDDLE_CLICK, _) => {
+                        state.add_file_tab(canonical_path, true);
+                    }
+                    (button, _) => {
+                        tracing::debug!("Ignoring click with button: {}", button);
+                    }
+                }
+            }
+        });
+    }));
+}
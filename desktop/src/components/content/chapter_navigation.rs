@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+use crate::book::ChapterLink;
+use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
+use crate::state::AppState;
+
+/// Previous/next chapter links shown below a chapter of the sidebar's book
+#[component]
+pub fn ChapterNavigation(file: PathBuf) -> Element {
+    let state = use_context::<AppState>();
+
+    let (previous, next) = match state.book.read().as_ref() {
+        Some(book) => book.neighbors(&file),
+        None => (None, None),
+    };
+
+    if previous.is_none() && next.is_none() {
+        return rsx! {};
+    }
+
+    rsx! {
+        nav {
+            class: "chapter-navigation",
+            if let Some(chapter) = previous {
+                ChapterButton { chapter, is_next: false }
+            } else {
+                span {}
+            }
+            if let Some(chapter) = next {
+                ChapterButton { chapter, is_next: true }
+            }
+        }
+    }
+}
+
+#[component]
+fn ChapterButton(chapter: ChapterLink, is_next: bool) -> Element {
+    let mut state = use_context::<AppState>();
+    let path = chapter.path.clone();
+
+    rsx! {
+        button {
+            class: "chapter-navigation-button",
+            class: if is_next { "next" } else { "previous" },
+            title: "{path.display()}",
+            onclick: move |_| {
+                // Keep the scroll position in history like a link click does
+                let scroll = *state.current_scroll_position.read();
+                state.save_current_scroll_position(scroll);
+                state.navigate_to_file(path.clone());
+            },
+            if !is_next {
+                Icon { name: IconName::ChevronLeft, size: 16 }
+            }
+            span {
+                class: "chapter-navigation-text",
+                span {
+                    class: "chapter-navigation-label",
+                    if is_next { {t("book.next_chapter")} } else { {t("book.previous_chapter")} }
+                }
+                span { class: "chapter-navigation-title", "{chapter.title}" }
+            }
+            if is_next {
+                Icon { name: IconName::ChevronRight, size: 16 }
+            }
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::annotations::{add_annotation, TextAnchor};
+use crate::bookmarks::{toggle_bookmark_target, BookmarkTarget, BOOKMARKS};
 use crate::components::icon::{Icon, IconName};
+use crate::components::right_sidebar::RightSidebarTab;
+use crate::editor::open_in_editor;
+use crate::i18n::{t, t_args};
+use crate::markdown::section_source;
+use crate::pinned_search::HighlightColor;
 use crate::state::AppState;
 
 /// Context type for right-click detection
@@ -35,6 +42,17 @@ pub struct ContextMenuData {
     /// The selected text (captured at context menu open time)
     #[serde(default)]
     pub selected_text: String,
+    /// Anchor of the selected text for annotations (None if not annotatable)
+    #[serde(default)]
+    pub selection_anchor: Option<TextAnchor>,
+    /// ID of the heading of the section under the cursor
+    #[serde(default)]
+    pub section_heading: Option<String>,
+    /// Source position of the block under the cursor (1-based)
+    #[serde(default)]
+    pub source_line: Option<usize>,
+    #[serde(default)]
+    pub source_column: Option<usize>,
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -43,11 +61,76 @@ pub struct ContentContextMenuProps {
     pub context: ContentContext,
     pub has_selection: bool,
     pub selected_text: String,
+    pub selection_anchor: Option<TextAnchor>,
+    pub section_heading: Option<String>,
+    pub source_position: Option<(usize, usize)>,
     pub current_file: Option<PathBuf>,
     pub base_dir: PathBuf,
     pub on_close: EventHandler<()>,
 }
 
+/// Annotate a passage with the default color and reveal it in the Annotations tab.
+pub fn highlight_selection(mut state: AppState, file: PathBuf, anchor: TextAnchor) {
+    add_annotation(file, anchor, HighlightColor::default());
+    // Show the new annotation so a comment can be added right away
+    state.set_right_sidebar_tab(RightSidebarTab::Annotations);
+    if !*state.right_sidebar_open.read() {
+        state.toggle_right_sidebar();
+    }
+}
+
+/// Bookmark the current scroll offset of a file in Quick Access.
+pub fn bookmark_scroll_position(state: AppState, file: PathBuf) {
+    let offset = state.current_scroll_position.read().round();
+    let file_name = file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let target = BookmarkTarget::Scroll { offset };
+    if !BOOKMARKS.read().contains_target(&file, &target) {
+        let name = t_args(
+            "bookmarks.position_name",
+            &[("name", &file_name), ("offset", &offset.to_string())],
+        );
+        toggle_bookmark_target(&file, target, Some(name));
+    }
+}
+
+/// Copy the Markdown source of the section starting at a heading.
+pub fn copy_section_source(file: &Path, heading_id: &str) {
+    let content = match std::fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(e) => {
+            tracing::error!(%e, ?file, "Failed to read file for section source");
+            return;
+        }
+    };
+    match section_source(&content, heading_id) {
+        Some(section) => crate::utils::clipboard::copy_text(section),
+        None => tracing::warn!(?file, heading_id, "Section not found in source"),
+    }
+}
+
+/// Select the whole rendered document.
+pub fn select_all_content() {
+    // Inject JS that schedules itself with setTimeout
+    // This runs after the menu/palette closes without needing async in Rust
+    let _ = document::eval(
+        r#"
+        setTimeout(() => {
+            const el = document.querySelector('.markdown-body');
+            if (el) {
+                const range = document.createRange();
+                range.selectNodeContents(el);
+                const selection = window.getSelection();
+                selection.removeAllRanges();
+                selection.addRange(range);
+            }
+        }, 50);
+    "#,
+    );
+}
+
 #[component]
 pub fn ContentContextMenu(props: ContentContextMenuProps) -> Element {
     let has_context_specific = !matches!(props.context, ContentContext::General);
@@ -72,7 +155,7 @@ pub fn ContentContextMenu(props: ContentContextMenuProps) -> Element {
             // === Section 1: Basic text operations ===
             if props.has_selection {
                 ContextMenuItem {
-                    label: "Copy",
+                    label: t("context_menu.copy"),
                     shortcut: Some("⌘C"),
                     icon: Some(IconName::Copy),
                     on_click: {
@@ -86,27 +169,31 @@ pub fn ContentContextMenu(props: ContentContextMenuProps) -> Element {
                 }
             }
 
+            if let (Some(anchor), Some(file)) = (&props.selection_anchor, &props.current_file) {
+                ContextMenuItem {
+                    label: t("context_menu.highlight_selection"),
+                    icon: Some(IconName::Highlight),
+                    on_click: {
+                        let anchor = anchor.clone();
+                        let file = file.clone();
+                        let on_close = props.on_close;
+                        move |_| {
+                            let state = use_context::<AppState>();
+                            highlight_selection(state, file.clone(), anchor.clone());
+                            on_close.call(());
+                        }
+                    },
+                }
+            }
+
             ContextMenuItem {
-                label: "Select All",
+                label: t("context_menu.select_all"),
                 shortcut: Some("⌘A"),
                 icon: Some(IconName::SelectAll),
                 on_click: {
                     let on_close = props.on_close;
                     move |_| {
-                        // Inject JS that schedules itself with setTimeout
-                        // This runs after menu closes without needing async in Rust
-                        let _ = document::eval(r#"
-                            setTimeout(() => {
-                                const el = document.querySelector('.markdown-body');
-                                if (el) {
-                                    const range = document.createRange();
-                                    range.selectNodeContents(el);
-                                    const selection = window.getSelection();
-                                    selection.removeAllRanges();
-                                    selection.addRange(range);
-                                }
-                            }, 50);
-                        "#);
+                        select_all_content();
                         on_close.call(());
                     }
                 },
@@ -116,7 +203,7 @@ pub fn ContentContextMenu(props: ContentContextMenuProps) -> Element {
             ContextMenuSeparator {}
 
             ContextMenuItem {
-                label: "Find in Page",
+                label: t("context_menu.find_in_page"),
                 shortcut: Some("⌘F"),
                 icon: Some(IconName::Search),
                 on_click: {
@@ -136,6 +223,54 @@ pub fn ContentContextMenu(props: ContentContextMenuProps) -> Element {
                 },
             }
 
+            // Only offered where the source of the block is known
+            if let (Some(file), Some((line, column))) = (&props.current_file, props.source_position) {
+                ContextMenuItem {
+                    label: t("context_menu.edit_here"),
+                    icon: Some(IconName::Pencil),
+                    on_click: {
+                        let file = file.clone();
+                        let on_close = props.on_close;
+                        move |_| {
+                            open_in_editor(&file, line, column);
+                            on_close.call(());
+                        }
+                    },
+                }
+            }
+
+            if let Some(file) = &props.current_file {
+                ContextMenuItem {
+                    label: t("context_menu.bookmark_position"),
+                    icon: Some(IconName::Star),
+                    on_click: {
+                        let file = file.clone();
+                        let on_close = props.on_close;
+                        move |_| {
+                            let state = use_context::<AppState>();
+                            bookmark_scroll_position(state, file.clone());
+                            on_close.call(());
+                        }
+                    },
+                }
+            }
+
+            if let (Some(heading_id), Some(file)) = (&props.section_heading, &props.current_file) {
+                ContextMenuItem {
+                    label: t("context_menu.copy_section_source"),
+                    icon: Some(IconName::Copy),
+                    on_click: {
+                        let heading_id = heading_id.clone();
+                        let file = file.clone();
+                        let on_close = props.on_close;
+                        move |_| {
+                            copy_section_source(&file, &heading_id);
+                            on_close.call(());
+                        }
+                    },
+                }
+            }
+
             // === Section 3: Context-specific items ===
             if has_context_specific {
                 ContextMenuSeparator {}
@@ -235,7 +370,7 @@ fn LinkContextItems(href: String, base_dir: PathBuf, on_close: EventHandler<()>)
 
     rsx! {
         ContextMenuItem {
-            label: "Open Link",
+            label: t("context_menu.open_link"),
             icon: Some(IconName::ExternalLink),
             on_click: {
                 let target_path = target_path.clone();
@@ -250,7 +385,7 @@ fn LinkContextItems(href: String, base_dir: PathBuf, on_close: EventHandler<()>)
         }
 
         ContextMenuItem {
-            label: "Open Link in New Tab",
+            label: t("context_menu.open_link_in_new_tab"),
             icon: Some(IconName::Add),
             on_click: {
                 let target_path = target_path.clone();
@@ -265,7 +400,7 @@ fn LinkContextItems(href: String, base_dir: PathBuf, on_close: EventHandler<()>)
         }
 
         ContextMenuItem {
-            label: "Copy Link Path",
+            label: t("context_menu.copy_link_path"),
             icon: Some(IconName::Copy),
             on_click: {
                 let href = href.clone();
@@ -283,7 +418,7 @@ fn LinkContextItems(href: String, base_dir: PathBuf, on_close: EventHandler<()>)
 fn ImageContextItems(src: String, on_close: EventHandler<()>) -> Element {
     rsx! {
         ContextMenuItem {
-            label: "Copy Image",
+            label: t("context_menu.copy_image"),
             icon: Some(IconName::Photo),
             on_click: {
                 let src = src.clone();
@@ -296,7 +431,7 @@ fn ImageContextItems(src: String, on_close: EventHandler<()>) -> Element {
         }
 
         ContextMenuItem {
-            label: "Save Image As...",
+            label: t("context_menu.save_image_as"),
             icon: Some(IconName::Download),
             on_click: {
                 let src = src.clone();
@@ -313,7 +448,7 @@ fn ImageContextItems(src: String, on_close: EventHandler<()>) -> Element {
         }
 
         ContextMenuItem {
-            label: "Copy Image Path",
+            label: t("context_menu.copy_image_path"),
             icon: Some(IconName::Copy),
             on_click: {
                 let src = src.clone();
@@ -332,7 +467,7 @@ fn ImageContextItems(src: String, on_close: EventHandler<()>) -> Element {
 fn CodeBlockContextItems(content: String, on_close: EventHandler<()>) -> Element {
     rsx! {
         ContextMenuItem {
-            label: "Copy Code",
+            label: t("context_menu.copy_code"),
             icon: Some(IconName::Copy),
             on_click: {
                 let content = content.clone();
@@ -350,7 +485,7 @@ fn CodeBlockContextItems(content: String, on_close: EventHandler<()>) -> Element
 fn MermaidContextItems(source: String, on_close: EventHandler<()>) -> Element {
     rsx! {
         ContextMenuItem {
-            label: "Copy Code",
+            label: t("context_menu.copy_code"),
             icon: Some(IconName::Copy),
             on_click: {
                 let source = source.clone();
@@ -0,0 +1,105 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+use super::file_viewer::{
+    handle_scroll_position, reapply_highlights, use_link_click_handler, use_mermaid_window_handler,
+};
+use crate::directory_index::{find_index_file, list_entries, render_listing};
+use crate::frontmatter::DocumentMetadata;
+use crate::markdown::render_to_html_with_toc;
+use crate::state::AppState;
+use crate::watcher::FILE_WATCHER;
+
+/// Landing page of a directory
+///
+/// Shows the directory's `README.md` or `index.md` when present, otherwise a
+/// generated listing of its subdirectories and Markdown files.
+#[component]
+pub fn DirectoryViewer(dir: PathBuf) -> Element {
+    let state = use_context::<AppState>();
+    let html = use_signal(String::new);
+    let reload_trigger = use_signal(|| 0usize);
+
+    use_directory_loader(dir.clone(), html, reload_trigger, state);
+    use_directory_watcher(dir.clone(), reload_trigger);
+    use_link_click_handler(dir, state);
+    use_mermaid_window_handler();
+
+    rsx! {
+        div {
+            class: "markdown-viewer directory-viewer",
+            article {
+                class: "markdown-body",
+                dangerous_inner_html: "{html}"
+            }
+        }
+    }
+}
+
+/// Hook to render the index file or the generated listing
+fn use_directory_loader(
+    dir: PathBuf,
+    html: Signal<String>,
+    reload_trigger: Signal<usize>,
+    mut state: AppState,
+) {
+    use_effect(use_reactive!(|dir, reload_trigger| {
+        let mut html = html;
+        let _ = reload_trigger();
+        let dir = dir.clone();
+
+        handle_scroll_position(&mut state);
+
+        spawn(async move {
+            tracing::info!(?dir, "Loading directory landing page");
+            let index = find_index_file(&dir).and_then(|file| {
+                let content = std::fs::read_to_string(&file).ok()?;
+                match render_to_html_with_toc(&content, &file) {
+                    Ok((rendered, headings)) => {
+                        Some((rendered, headings, DocumentMetadata::parse(&content)))
+                    }
+                    Err(e) => {
+                        tracing::warn!(?file, %e, "Failed to render index file");
+                        None
+                    }
+                }
+            });
+
+            match index {
+                Some((rendered, headings, metadata)) => {
+                    html.set(rendered);
+                    state.toc_headings.set(headings);
+                    state.document_metadata.set(metadata);
+                }
+                None => {
+                    html.set(render_listing(&dir, &list_entries(&dir)));
+                    state.toc_headings.set(Vec::new());
+                    state.document_metadata.set(None);
+                }
+            }
+            reapply_highlights().await;
+        });
+    }));
+}
+
+/// Hook to refresh the landing page when the directory changes
+fn use_directory_watcher(dir: PathBuf, reload_trigger: Signal<usize>) {
+    use_effect(use_reactive!(|dir| {
+        let mut reload_trigger = reload_trigger;
+        let dir = dir.clone();
+
+        spawn(async move {
+            let Ok(mut watcher) = FILE_WATCHER.watch_directory(dir.clone()).await else {
+                tracing::error!(?dir, "Failed to start directory landing page watcher");
+                return;
+            };
+
+            while watcher.recv().await.is_some() {
+                tracing::trace!(?dir, "Directory changed, refreshing landing page");
+                reload_trigger.set(reload_trigger() + 1);
+            }
+
+            let _ = FILE_WATCHER.unwatch_directory(dir).await;
+        });
+    }));
+}
@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+
+use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
+
+/// Shown in place of a file deleted while open, until it is recreated
+#[component]
+pub fn FileDeletedView(filename: String) -> Element {
+    rsx! {
+        div {
+            class: "no-file file-error file-deleted",
+            div {
+                class: "no-file-container",
+                div {
+                    class: "no-file-icon file-error-icon",
+                    Icon { name: IconName::Trash, size: 64 }
+                }
+                h2 {
+                    class: "no-file-title file-error-title",
+                    {t("file_deleted.title")}
+                }
+                p {
+                    class: "no-file-description file-error-filename",
+                    "{filename}"
+                }
+                div {
+                    class: "no-file-hints",
+                    div {
+                        class: "no-file-hint",
+                        span {
+                            class: "no-file-hint-icon",
+                            Icon { name: IconName::AlertCircle, size: 20 }
+                        }
+                        span {
+                            class: "no-file-hint-text",
+                            {t("file_deleted.deleted_hint")}
+                        }
+                    }
+                    div {
+                        class: "no-file-hint",
+                        span {
+                            class: "no-file-hint-icon",
+                            Icon { name: IconName::Refresh, size: 20 }
+                        }
+                        span {
+                            class: "no-file-hint-text",
+                            {t("file_deleted.reopen_hint")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
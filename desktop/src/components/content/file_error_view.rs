@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::{t, t_args};
 
 #[component]
 pub fn FileErrorView(filename: String, error_message: String) -> Element {
@@ -15,7 +16,7 @@ pub fn FileErrorView(filename: String, error_message: String) -> Element {
                 }
                 h2 {
                     class: "no-file-title file-error-title",
-                    "Cannot Open File"
+                    {t("file_error.title")}
                 }
                 p {
                     class: "no-file-description file-error-filename",
@@ -31,7 +32,7 @@ pub fn FileErrorView(filename: String, error_message: String) -> Element {
                         }
                         span {
                             class: "no-file-hint-text",
-                            "This file cannot be opened. It may be a binary file or an unsupported format."
+                            {t("file_error.description")}
                         }
                     }
                     div {
@@ -42,7 +43,7 @@ pub fn FileErrorView(filename: String, error_message: String) -> Element {
                         }
                         span {
                             class: "no-file-hint-text",
-                            "Error: {error_message}"
+                            {t_args("file_error.error", &[("error", &error_message)])}
                         }
                     }
                 }
@@ -1,13 +1,23 @@
+use dioxus::desktop::window;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
+use super::chapter_navigation::ChapterNavigation;
 use super::context_menu::ContextMenuData;
 use super::context_menu_state::{open_context_menu, ContentContextMenuState};
-use crate::markdown::render_to_html_with_toc;
-use crate::state::{AppState, TabContent};
+use super::file_deleted_view::FileDeletedView;
+use super::source_view::SourceView;
+use crate::config::{CONFIG, CONFIG_CHANGED};
+use crate::events::{DocumentEvent, DOCUMENT_EVENT};
+use crate::frontmatter::DocumentMetadata;
+use crate::markdown::render_to_html_with_dependencies;
+use crate::recent::record_recent;
+use crate::state::{AppState, TabContent, ViewMode};
+use crate::tasks::{enable_task_checkboxes, update_task_in_file};
 use crate::utils::file::is_markdown_file;
-use crate::watcher::FILE_WATCHER;
+use crate::watcher::{FileEvent, FILE_WATCHER};
 
 /// Data structure for markdown link clicks from JavaScript
 #[derive(Serialize, Deserialize)]
@@ -18,6 +28,13 @@ struct LinkClickData {
     scroll_position: f64,
 }
 
+/// Data structure for task checkbox clicks from JavaScript
+#[derive(Serialize, Deserialize)]
+struct TaskCheckboxData {
+    index: usize,
+    checked: bool,
+}
+
 /// Mouse button constants
 const LEFT_CLICK: u32 = 0;
 const MIDDLE_CLICK: u32 = 1;
@@ -26,6 +43,12 @@ const MIDDLE_CLICK: u32 = 1;
 pub fn FileViewer(file: PathBuf) -> Element {
     let state = use_context::<AppState>();
     let html = use_signal(String::new);
+    // Source the current HTML was rendered from, to detect changes before writing back
+    let source = use_signal(String::new);
+    // Local files the rendered HTML embeds (e.g. images), reloaded on change like the file
+    let dependencies = use_signal(BTreeSet::new);
+    // The file was deleted while open; cleared when it is recreated
+    let deleted = use_signal(|| false);
     let reload_trigger = use_signal(|| 0usize);
 
     // Get base directory for link resolution
@@ -35,33 +58,108 @@ pub fn FileViewer(file: PathBuf) -> Element {
         .unwrap_or_else(|| PathBuf::from("."));
 
     // Setup component hooks
-    use_file_loader(file.clone(), html, reload_trigger, state);
-    use_file_watcher(file.clone(), reload_trigger);
-    use_link_click_handler(file.clone(), state);
+    use_file_loader(
+        file.clone(),
+        html,
+        source,
+        dependencies,
+        deleted,
+        reload_trigger,
+        state,
+    );
+    use_file_watcher(file.clone(), deleted, reload_trigger, state);
+    use_dependency_watcher(dependencies, reload_trigger);
+    use_task_checkbox_handler(file.clone(), source, reload_trigger);
+    use_link_click_handler(base_dir.clone(), state);
     use_mermaid_window_handler();
     use_context_menu_handler(file.clone(), base_dir);
 
+    // Only Markdown files have a rendered form to compare the source with
+    let view_mode = if is_markdown_file(&file) {
+        state
+            .current_tab()
+            .map(|tab| tab.view_mode)
+            .unwrap_or_default()
+    } else {
+        ViewMode::Rendered
+    };
+    use_view_mode_highlights(view_mode);
+
     rsx! {
         div {
-            class: "markdown-viewer",
-            article {
-                class: "markdown-body",
-                dangerous_inner_html: "{html}"
+            class: if view_mode == ViewMode::SideBySide { "markdown-viewer side-by-side" } else { "markdown-viewer" },
+            if deleted() {
+                FileDeletedView {
+                    filename: file
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default(),
+                }
+            } else {
+                match view_mode {
+                    ViewMode::Rendered => rsx! {
+                        article {
+                            class: "markdown-body",
+                            dangerous_inner_html: "{html}"
+                        }
+                    },
+                    ViewMode::Source => rsx! {
+                        SourceView { source: source() }
+                    },
+                    ViewMode::SideBySide => rsx! {
+                        div {
+                            class: "split-view",
+                            div {
+                                class: "source-pane",
+                                SourceView { source: source() }
+                            }
+                            div {
+                                class: "rendered-pane",
+                                article {
+                                    class: "markdown-body",
+                                    dangerous_inner_html: "{html}"
+                                }
+                                ChapterNavigation { file: file.clone() }
+                            }
+                        }
+                    },
+                }
+            }
+            // Previous/next chapter when the file belongs to the sidebar's book
+            if view_mode != ViewMode::SideBySide {
+                ChapterNavigation { file: file.clone() }
             }
             // Context menu is rendered at App level to avoid re-rendering content
         }
     }
 }
 
+/// Hook to re-apply search and annotation highlights when the view mode changes
+///
+/// Switching modes recreates the rendered article, dropping the highlights.
+fn use_view_mode_highlights(view_mode: ViewMode) {
+    use_effect(use_reactive!(|view_mode| {
+        if view_mode.shows_rendered() {
+            spawn(reapply_highlights());
+        }
+    }));
+}
+
 /// Hook to load and render file content
 fn use_file_loader(
     file: PathBuf,
     html: Signal<String>,
+    source: Signal<String>,
+    dependencies: Signal<BTreeSet<PathBuf>>,
+    deleted: Signal<bool>,
     reload_trigger: Signal<usize>,
     mut state: AppState,
 ) {
     use_effect(use_reactive!(|file, reload_trigger| {
         let mut html = html;
+        let mut source = source;
+        let mut dependencies = dependencies;
+        let mut deleted = deleted;
         let _ = reload_trigger();
         let file = file.clone();
 
@@ -76,13 +174,39 @@ fn use_file_loader(
             // Try to read as string (UTF-8 text file)
             match tokio::fs::read_to_string(file.as_path()).await {
                 Ok(content) => {
+                    record_recent(&file);
+                    if *deleted.peek() {
+                        tracing::info!("Deleted file was recreated: {:?}", &file);
+                        deleted.set(false);
+                    }
+
+                    // Frontmatter metadata for the tab title and the Info panel
+                    let metadata = if is_markdown_file(&file) {
+                        DocumentMetadata::parse(&content)
+                    } else {
+                        None
+                    };
+                    state
+                        .set_document_title(&file, metadata.as_ref().and_then(|m| m.title.clone()));
+                    state.document_metadata.set(metadata);
+
                     // Check if file has markdown extension
                     if is_markdown_file(&file) {
                         // Render as markdown with TOC heading extraction
-                        match render_to_html_with_toc(&content, &file) {
-                            Ok((rendered, headings)) => {
-                                html.set(rendered);
-                                state.toc_headings.set(headings);
+                        match render_to_html_with_dependencies(&content, &file) {
+                            Ok(rendered) => {
+                                let html_output = if CONFIG.read().editing.interactive_tasks {
+                                    enable_task_checkboxes(&rendered.html)
+                                } else {
+                                    rendered.html
+                                };
+                                html.set(html_output);
+                                source.set(content.clone());
+                                state.toc_headings.set(rendered.headings);
+                                // Only restart the dependency watcher when the set changed
+                                if *dependencies.peek() != rendered.dependencies {
+                                    dependencies.set(rendered.dependencies);
+                                }
                                 tracing::trace!("Rendered as Markdown: {:?}", &file);
                             }
                             Err(e) => {
@@ -113,9 +237,15 @@ fn use_file_loader(
                         state.toc_headings.set(Vec::new());
                     }
 
-                    // Re-apply search highlighting after content changes
+                    // Re-apply annotation and search highlighting after content changes
                     // This preserves search state across tab switches
-                    reapply_search().await;
+                    reapply_highlights().await;
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    // Deleted (or not yet recreated); the watcher reopens it once it exists
+                    tracing::warn!("File not found: {:?}", file);
+                    deleted.set(true);
+                    html.set(String::new());
                 }
                 Err(e) => {
                     // Failed to read as UTF-8 text (likely binary file)
@@ -138,12 +268,31 @@ fn use_file_loader(
 ///
 /// If pending_scroll_position is set (from back/forward navigation), wait for
 /// Mermaid/KaTeX rendering to complete, then restore that position.
+/// If pending_heading is set (from a heading bookmark), scroll that heading into
+/// view once rendering completes; likewise for pending_task (from the Tasks tab).
 /// Otherwise, reset to top immediately (for new navigation like clicking a link).
-fn handle_scroll_position(state: &mut AppState) {
+pub(super) fn handle_scroll_position(state: &mut AppState) {
     // Check if there's a pending scroll position to restore (from back/forward)
     let pending_scroll = state.pending_scroll_position.take();
 
-    if let Some(scroll) = pending_scroll {
+    if let Some(slug) = state.pending_heading.take() {
+        let id = serde_json::to_string(&slug).unwrap_or_default();
+        let scroll_js = format!(
+            r#"window.Arto.onRenderComplete(() => {{
+                document.getElementById({id})?.scrollIntoView({{ block: 'start' }});
+            }});"#
+        );
+        let _ = document::eval(&scroll_js);
+        tracing::debug!(slug, "Scheduled scroll to heading after render");
+    } else if let Some(index) = state.pending_task.take() {
+        let scroll_js = format!(
+            r#"window.Arto.onRenderComplete(() => {{
+                document.querySelector('.markdown-body [data-task-index="{index}"]')?.closest('li')?.scrollIntoView({{ block: 'center' }});
+            }});"#
+        );
+        let _ = document::eval(&scroll_js);
+        tracing::debug!(index, "Scheduled scroll to task after render");
+    } else if let Some(scroll) = pending_scroll {
         // For back/forward: wait for Mermaid/KaTeX rendering to complete before restoring
         // This ensures the content height is final before scrolling
         let scroll_js = format!(
@@ -162,9 +311,10 @@ fn handle_scroll_position(state: &mut AppState) {
     }
 }
 
-/// Re-apply search highlighting after DOM changes.
-/// This is called after content rendering to preserve search state across tab switches.
-async fn reapply_search() {
+/// Re-apply annotation and search highlighting after DOM changes.
+/// This is called after content rendering to preserve search state across tab switches
+/// and to re-anchor annotations when the file changes.
+pub(super) async fn reapply_highlights() {
     // Use MutationObserver to detect when DOM is actually updated, then reapply.
     // This is more robust than RAF-based timing which is not guaranteed.
     //
@@ -180,6 +330,8 @@ async fn reapply_search() {
             const doReapply = () => {
                 if (called) return;
                 called = true;
+                // Annotations first so search highlights nest inside them
+                window.Arto.annotations.reapply();
                 window.Arto.search.reapply();
             };
 
@@ -229,10 +381,20 @@ async fn reapply_search() {
 }
 
 /// Hook to watch file for changes and trigger reload
-fn use_file_watcher(file: PathBuf, reload_trigger: Signal<usize>) {
+///
+/// A deleted file is shown as such and reopened once recreated; a file
+/// renamed within its directory is followed by the tabs showing it.
+fn use_file_watcher(
+    file: PathBuf,
+    deleted: Signal<bool>,
+    reload_trigger: Signal<usize>,
+    mut state: AppState,
+) {
     use_effect(use_reactive!(|file| {
+        let mut deleted = deleted;
         let mut reload_trigger = reload_trigger;
         let file = file.clone();
+        let window_id = window().id();
 
         spawn(async move {
             let file_path = file.clone();
@@ -248,9 +410,29 @@ fn use_file_watcher(file: PathBuf, reload_trigger: Signal<usize>) {
                 }
             };
 
-            while watcher.recv().await.is_some() {
-                tracing::info!("File change detected, reloading: {:?}", file_path);
-                reload_trigger.set(reload_trigger() + 1);
+            while let Some(event) = watcher.recv().await {
+                match event {
+                    FileEvent::Modified => {
+                        tracing::info!("File change detected, reloading: {:?}", file_path);
+                        reload_trigger.set(reload_trigger() + 1);
+                        DOCUMENT_EVENT
+                            .send(DocumentEvent::Reloaded {
+                                window_id,
+                                path: file_path.clone(),
+                            })
+                            .ok();
+                    }
+                    FileEvent::Removed => {
+                        tracing::info!("File deleted: {:?}", file_path);
+                        deleted.set(true);
+                    }
+                    FileEvent::Renamed(to) => {
+                        // The tab now shows `to`, which starts a watcher of its own
+                        tracing::info!("File renamed: {:?} -> {:?}", file_path, to);
+                        state.follow_rename(&file_path, &to);
+                        return;
+                    }
+                }
             }
 
             if let Err(e) = FILE_WATCHER.unwatch(file_path.clone()).await {
@@ -264,10 +446,118 @@ fn use_file_watcher(file: PathBuf, reload_trigger: Signal<usize>) {
     }));
 }
 
-/// Hook to setup JavaScript handler for markdown link clicks
-fn use_link_click_handler(file: PathBuf, state: AppState) {
+/// Hook to watch the files embedded in the document and trigger reload
+///
+/// A render that changes the set starts a new watcher; the previous one stops
+/// at its next notification instead of reloading.
+fn use_dependency_watcher(dependencies: Signal<BTreeSet<PathBuf>>, reload_trigger: Signal<usize>) {
+    use_effect(move || {
+        let paths = dependencies();
+        let mut reload_trigger = reload_trigger;
+        if paths.is_empty() {
+            return;
+        }
+
+        spawn(async move {
+            let mut watcher = match FILE_WATCHER.watch_all(paths.iter().cloned()).await {
+                Ok(watcher) => watcher,
+                Err(e) => {
+                    tracing::error!(?paths, "Failed to register dependency watcher: {:?}", e);
+                    return;
+                }
+            };
+
+            while watcher.recv().await.is_some() {
+                if *dependencies.peek() != paths {
+                    break;
+                }
+                tracing::info!(?paths, "Embedded file change detected, reloading");
+                reload_trigger.set(reload_trigger() + 1);
+            }
+
+            // Close the channel first so the watcher releases this one
+            drop(watcher);
+            for path in paths {
+                if let Err(e) = FILE_WATCHER.unwatch(path.clone()).await {
+                    tracing::error!(?path, "Failed to unregister dependency watcher: {:?}", e);
+                }
+            }
+        });
+    });
+}
+
+/// Hook to write task checkbox clicks back to the source file
+///
+/// Checkboxes are only clickable while `editing.interactive_tasks` is enabled,
+/// so the document is re-rendered whenever that setting changes. A successful
+/// write is picked up by the file watcher like any other change.
+fn use_task_checkbox_handler(file: PathBuf, source: Signal<String>, reload_trigger: Signal<usize>) {
+    let mut enabled = use_signal(|| CONFIG.read().editing.interactive_tasks);
+    use_future(move || async move {
+        let mut reload_trigger = reload_trigger;
+        let mut rx = CONFIG_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            let value = CONFIG.read().editing.interactive_tasks;
+            if *enabled.peek() != value {
+                enabled.set(value);
+                reload_trigger.set(reload_trigger() + 1);
+            }
+        }
+    });
+
     use_effect(use_reactive!(|file| {
         let file = file.clone();
+        let mut source = source;
+        let mut eval_provider = document::eval(indoc::indoc! {r#"
+            window.handleTaskCheckboxClick = (checkbox) => {
+                checkbox.classList.remove('refused');
+                checkbox.removeAttribute('title');
+                dioxus.send({ index: Number(checkbox.dataset.taskIndex), checked: checkbox.checked });
+            };
+        "#});
+
+        spawn(async move {
+            while let Ok(data) = eval_provider.recv::<TaskCheckboxData>().await {
+                let TaskCheckboxData { index, checked } = data;
+                let path = file.clone();
+                let rendered = source.peek().clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    update_task_in_file(&path, &rendered, index, checked)
+                })
+                .await;
+
+                match result {
+                    Ok(Ok(updated)) => {
+                        tracing::info!(?file, index, checked, "Updated task in file");
+                        // Later clicks compare against the written content until the reload
+                        source.set(updated);
+                    }
+                    Ok(Err(e)) => {
+                        tracing::warn!(?file, index, %e, "Refused to update task");
+                        let message = serde_json::to_string(&e.to_string()).unwrap_or_default();
+                        let js = format!(
+                            r#"(() => {{
+                                const checkbox = document.querySelector('[data-task-index="{index}"]');
+                                if (!checkbox) return;
+                                checkbox.checked = {revert};
+                                checkbox.classList.add('refused');
+                                checkbox.title = {message};
+                            }})();"#,
+                            revert = !checked,
+                        );
+                        let _ = document::eval(&js);
+                    }
+                    Err(e) => tracing::error!(%e, "Task update panicked"),
+                }
+            }
+        });
+    }));
+}
+
+/// Hook to setup JavaScript handler for markdown link clicks relative to `base_dir`
+pub(super) fn use_link_click_handler(base_dir: PathBuf, state: AppState) {
+    use_effect(use_reactive!(|base_dir| {
+        let base_dir = base_dir.clone();
         let mut eval_provider = document::eval(indoc::indoc! {r#"
             window.handleMarkdownLinkClick = (path, button) => {
                 const scrollPosition = document.querySelector('.content')?.scrollTop || 0;
@@ -275,11 +565,6 @@ fn use_link_click_handler(file: PathBuf, state: AppState) {
             };
         "#});
 
-        let base_dir = file
-            .parent()
-            .map(|p| p.to_path_buf())
-            .unwrap_or_else(|| PathBuf::from("."));
-
         let mut state_clone = state;
 
         spawn(async move {
@@ -327,7 +612,7 @@ fn handle_link_click(click_data: LinkClickData, base_dir: &Path, state: &mut App
 }
 
 /// Hook to setup Mermaid window open handler
-fn use_mermaid_window_handler() {
+pub(super) fn use_mermaid_window_handler() {
     use_effect(|| {
         let mut eval_provider = document::eval(indoc::indoc! {r#"
             window.handleMermaidWindowOpen = (source) => {
@@ -341,7 +626,7 @@ fn use_mermaid_window_handler() {
                     if msg_type == "open_mermaid_window" {
                         if let Some(source) = data.get("source").and_then(|v| v.as_str()) {
                             let state = use_context::<AppState>();
-                            let theme = *state.current_theme.read();
+                            let theme = state.current_theme.read().clone();
                             tracing::info!("Opening mermaid window for diagram");
                             crate::window::open_or_focus_mermaid_window(source.to_string(), theme);
                         }
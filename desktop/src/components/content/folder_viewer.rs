@@ -0,0 +1,175 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::file_viewer::{reapply_highlights, use_mermaid_window_handler};
+use crate::folder_document::{folder_files, render_to_html};
+use crate::state::AppState;
+use crate::watcher::FILE_WATCHER;
+
+/// Data structure for markdown link clicks inside a folder document
+#[derive(Serialize, Deserialize)]
+struct FolderLinkClickData {
+    path: String,
+    button: u32,
+    scroll_position: f64,
+    /// Directory of the section containing the link
+    base_dir: Option<String>,
+    /// Open the file itself even when it is part of the document
+    open: bool,
+}
+
+/// Mouse button constants
+const LEFT_CLICK: u32 = 0;
+const MIDDLE_CLICK: u32 = 1;
+
+/// All Markdown files of a directory rendered as one continuous document
+#[component]
+pub fn FolderViewer(dir: PathBuf) -> Element {
+    let state = use_context::<AppState>();
+    let html = use_signal(String::new);
+    let files = use_signal(Vec::<PathBuf>::new);
+    let reload_trigger = use_signal(|| 0usize);
+
+    use_folder_loader(dir.clone(), html, files, reload_trigger, state);
+    use_folder_watcher(dir.clone(), reload_trigger);
+    use_folder_link_click_handler(dir, files, state);
+    use_mermaid_window_handler();
+
+    rsx! {
+        div {
+            class: "markdown-viewer folder-viewer",
+            article {
+                class: "markdown-body",
+                dangerous_inner_html: "{html}"
+            }
+        }
+    }
+}
+
+/// Hook to render the Markdown files of the directory
+fn use_folder_loader(
+    dir: PathBuf,
+    html: Signal<String>,
+    files: Signal<Vec<PathBuf>>,
+    reload_trigger: Signal<usize>,
+    mut state: AppState,
+) {
+    use_effect(use_reactive!(|dir, reload_trigger| {
+        let mut html = html;
+        let mut files = files;
+        let dir = dir.clone();
+
+        // Start at the first file; reloads keep the position
+        if reload_trigger() == 0 {
+            let _ = document::eval("document.querySelector('.content')?.scrollTo(0, 0);");
+        }
+
+        spawn(async move {
+            tracing::info!(?dir, "Loading folder document");
+            let (rendered, headings) = render_to_html(&dir);
+            if headings.is_empty() {
+                html.set(format!(
+                    r#"<p class="error">No Markdown files found in {}</p>"#,
+                    html_escape::encode_text(&dir.to_string_lossy())
+                ));
+            } else {
+                html.set(rendered);
+            }
+            state.toc_headings.set(headings);
+            state.document_metadata.set(None);
+            files.set(
+                folder_files(&dir)
+                    .into_iter()
+                    .filter_map(|path| path.canonicalize().ok())
+                    .collect(),
+            );
+            reapply_highlights().await;
+        });
+    }));
+}
+
+/// Hook to reload the document when a file in the directory changes
+fn use_folder_watcher(dir: PathBuf, reload_trigger: Signal<usize>) {
+    use_effect(use_reactive!(|dir| {
+        let mut reload_trigger = reload_trigger;
+        let dir = dir.clone();
+
+        spawn(async move {
+            let Ok(mut watcher) = FILE_WATCHER.watch_directory(dir.clone()).await else {
+                tracing::error!(?dir, "Failed to start folder document watcher");
+                return;
+            };
+
+            while watcher.recv().await.is_some() {
+                tracing::info!(?dir, "Folder changed, reloading document");
+                reload_trigger.set(reload_trigger() + 1);
+            }
+
+            let _ = FILE_WATCHER.unwatch_directory(dir).await;
+        });
+    }));
+}
+
+/// Hook to handle markdown link clicks inside the folder document
+///
+/// Links to other files of the document scroll to that file, while the
+/// "Open File" link in each file header opens the file on its own.
+fn use_folder_link_click_handler(dir: PathBuf, files: Signal<Vec<PathBuf>>, state: AppState) {
+    use_effect(use_reactive!(|dir| {
+        let dir = dir.clone();
+        let mut state = state;
+        let mut eval_provider = document::eval(indoc::indoc! {r#"
+            window.handleMarkdownLinkClick = (path, button, open) => {
+                const section = window.event?.target?.closest?.('[data-chapter-dir]');
+                const scrollPosition = document.querySelector('.content')?.scrollTop || 0;
+                dioxus.send({
+                    path,
+                    button,
+                    scroll_position: scrollPosition,
+                    base_dir: section?.dataset.chapterDir ?? null,
+                    open: open === true,
+                });
+            };
+        "#});
+
+        spawn(async move {
+            while let Ok(click_data) = eval_provider.recv::<FolderLinkClickData>().await {
+                let base_dir = click_data
+                    .base_dir
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| dir.clone());
+                let target_path = base_dir.join(&click_data.path);
+                let Ok(canonical_path) = target_path.canonicalize() else {
+                    tracing::error!("Failed to resolve path: {:?}", target_path);
+                    continue;
+                };
+
+                let file_index = if click_data.open {
+                    None
+                } else {
+                    files.peek().iter().position(|file| *file == canonical_path)
+                };
+
+                match (click_data.button, file_index) {
+                    (LEFT_CLICK, Some(index)) => {
+                        let js = format!(
+                            "document.getElementById('folder-document-file-{index}')?.scrollIntoView({{ behavior: 'smooth', block: 'start' }});"
+                        );
+                        let _ = document::eval(&js);
+                    }
+                    (LEFT_CLICK, None) => {
+                        state.save_current_scroll_position(click_data.scroll_position);
+                        state.navigate_to_file(canonical_path);
+                    }
+                    (MIDDLE_CLICK, _) => {
+                        state.add_file_tab(canonical_path, true);
+                    }
+                    (button, _) => {
+                        tracing::debug!("Ignoring click with button: {}", button);
+                    }
+                }
+            }
+        });
+    }));
+}
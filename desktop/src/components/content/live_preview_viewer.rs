@@ -0,0 +1,156 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::file_viewer::{reapply_highlights, use_mermaid_window_handler};
+use crate::live_preview::{get_preview, LIVE_PREVIEW_UPDATED};
+use crate::markdown::render_to_html_with_toc;
+use crate::state::AppState;
+
+/// Data structure for markdown link clicks inside a live preview
+#[derive(Serialize, Deserialize)]
+struct PreviewLinkClickData {
+    path: String,
+    button: u32,
+}
+
+/// Mouse button constants
+const LEFT_CLICK: u32 = 0;
+const MIDDLE_CLICK: u32 = 1;
+
+/// Unsaved editor buffer, re-rendered whenever the editor pushes an update
+#[component]
+pub fn LivePreviewViewer(id: String) -> Element {
+    let state = use_context::<AppState>();
+    let html = use_signal(String::new);
+    let base_dir = use_signal(|| PathBuf::from("."));
+    let revision = use_signal(|| 0usize);
+
+    use_preview_updates(id.clone(), revision);
+    use_preview_renderer(id, html, base_dir, revision, state);
+    use_preview_link_click_handler(base_dir, state);
+    use_mermaid_window_handler();
+
+    rsx! {
+        div {
+            class: "markdown-viewer",
+            article {
+                class: "markdown-body",
+                dangerous_inner_html: "{html}"
+            }
+        }
+    }
+}
+
+/// Hook to bump `revision` whenever the preview with this ID is updated
+fn use_preview_updates(id: String, revision: Signal<usize>) {
+    use_effect(use_reactive!(|id| {
+        let mut revision = revision;
+        let id = id.clone();
+
+        spawn(async move {
+            let mut rx = LIVE_PREVIEW_UPDATED.subscribe();
+            while let Ok(updated) = rx.recv().await {
+                if updated == id {
+                    revision.set(revision() + 1);
+                }
+            }
+        });
+    }));
+}
+
+/// Hook to render the latest content of the preview
+///
+/// The scroll position is kept across updates unless the editor reports a
+/// cursor line, in which case the block under the cursor is brought into view.
+fn use_preview_renderer(
+    id: String,
+    html: Signal<String>,
+    base_dir: Signal<PathBuf>,
+    revision: Signal<usize>,
+    mut state: AppState,
+) {
+    use_effect(use_reactive!(|id, revision| {
+        let mut html = html;
+        let mut base_dir = base_dir;
+        let _ = revision();
+        let id = id.clone();
+
+        spawn(async move {
+            let Some(buffer) = get_preview(&id) else {
+                html.set("<p>Waiting for the editor to send content…</p>".to_string());
+                state.toc_headings.set(Vec::new());
+                return;
+            };
+
+            let path = buffer.document_path();
+            let (rendered, headings) = render_to_html_with_toc(&buffer.markdown, &path)
+                .unwrap_or_else(|e| {
+                    tracing::error!(%e, id, "Failed to render live preview");
+                    (
+                        format!(r#"<p class="error">Error rendering markdown: {}</p>"#, e),
+                        Vec::new(),
+                    )
+                });
+
+            // Scroll once the new HTML (and its diagrams) are in place, or right
+            // away when only the cursor moved
+            let changed = *html.peek() != rendered;
+            if let Some(line) = buffer.cursor_line {
+                // The last top-level block starting at or before the cursor
+                let scroll = format!(
+                    "Array.from(document.querySelectorAll('.markdown-body > [data-source-line]')).filter((el) => Number(el.dataset.sourceLine) <= {line}).pop()?.scrollIntoView({{ block: 'center' }});"
+                );
+                let js = if changed {
+                    format!("window.Arto.onRenderComplete(() => {{ {scroll} }});")
+                } else {
+                    scroll
+                };
+                let _ = document::eval(&js);
+            }
+
+            if changed {
+                html.set(rendered);
+            }
+            if *base_dir.peek() != buffer.base_dir() {
+                base_dir.set(buffer.base_dir());
+            }
+            state.toc_headings.set(headings);
+            state.document_metadata.set(None);
+            reapply_highlights().await;
+        });
+    }));
+}
+
+/// Hook to open links of the preview without replacing it
+///
+/// The editor keeps pushing to this tab, so linked files open in a tab of
+/// their own.
+fn use_preview_link_click_handler(base_dir: Signal<PathBuf>, state: AppState) {
+    use_effect(move || {
+        let mut state = state;
+        let mut eval_provider = document::eval(indoc::indoc! {r#"
+            window.handleMarkdownLinkClick = (path, button) => {
+                dioxus.send({ path, button });
+            };
+        "#});
+
+        spawn(async move {
+            while let Ok(click_data) = eval_provider.recv::<PreviewLinkClickData>().await {
+                let target_path = base_dir.peek().join(&click_data.path);
+                let Ok(canonical_path) = target_path.canonicalize() else {
+                    tracing::error!("Failed to resolve path: {:?}", target_path);
+                    continue;
+                };
+
+                match click_data.button {
+                    LEFT_CLICK => state.open_file(canonical_path),
+                    MIDDLE_CLICK => {
+                        state.add_file_tab(canonical_path, true);
+                    }
+                    button => tracing::debug!("Ignoring click with button: {}", button),
+                }
+            }
+        });
+    });
+}
@@ -1,9 +1,16 @@
 use dioxus::prelude::*;
 
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 
 #[component]
 pub fn NoFileView() -> Element {
+    // The shortcut is rendered as a key cap in the middle of the sentence
+    let open_hint = t("no_file.open_hint");
+    let (before_shortcut, after_shortcut) = open_hint
+        .split_once("{shortcut}")
+        .unwrap_or((open_hint, ""));
+
     rsx! {
         div {
             class: "no-file",
@@ -15,11 +22,11 @@ pub fn NoFileView() -> Element {
                 }
                 h2 {
                     class: "no-file-title",
-                    "No File Opened"
+                    {t("no_file.title")}
                 }
                 p {
                     class: "no-file-description",
-                    "Open a markdown file to view its beautifully rendered content with full support for code highlighting, diagrams, and mathematics."
+                    {t("no_file.description")}
                 }
                 div {
                     class: "no-file-hints",
@@ -29,7 +36,7 @@ pub fn NoFileView() -> Element {
                             class: "no-file-hint-icon",
                             Icon { name: IconName::FolderOpen, size: 20 }
                         }
-                        span { class: "no-file-hint-text", "Drag and drop a Markdown file or directory here" }
+                        span { class: "no-file-hint-text", {t("no_file.drop_hint")} }
                     }
                     div {
                         class: "no-file-hint",
@@ -39,12 +46,12 @@ pub fn NoFileView() -> Element {
                         }
                         span {
                             class: "no-file-hint-text",
-                            "Use "
+                            "{before_shortcut}"
                             kbd {
                                 class: "no-file-hint-kbd",
                                 "Cmd+O"
                             }
-                            " to open a file"
+                            "{after_shortcut}"
                         }
                     }
                     div {
@@ -53,7 +60,7 @@ pub fn NoFileView() -> Element {
                             class: "no-file-hint-icon",
                             Icon { name: IconName::Click, size: 20 }
                         }
-                        span { class: "no-file-hint-text", "Right-click in Finder and choose \"Open with Arto\"" }
+                        span { class: "no-file-hint-text", {t("no_file.finder_hint")} }
                     }
                 }
             }
@@ -1,4 +1,5 @@
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 use dioxus::prelude::*;
 use std::path::PathBuf;
 
@@ -46,7 +47,7 @@ pub fn DirectoryPicker(
             }
             button {
                 class: "icon-button",
-                title: "Browse...",
+                title: t("preferences.browse"),
                 onclick: handle_browse,
                 Icon { name: IconName::FolderOpen, size: 18 }
             }
@@ -54,7 +55,7 @@ pub fn DirectoryPicker(
                 class: "use-current-button",
                 disabled: current_directory.is_none(),
                 onclick: handle_use_current,
-                "Use Current"
+                {t("preferences.use_current")}
             }
         }
     }
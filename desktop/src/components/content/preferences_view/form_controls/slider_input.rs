@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::i18n::t;
+
 /// Slider input component with numeric input and "Use Current" option
 #[component]
 pub fn SliderInput(
@@ -55,7 +57,7 @@ pub fn SliderInput(
                 class: "use-current-button",
                 disabled: current_value.is_none(),
                 onclick: handle_use_current,
-                "Use Current"
+                {t("preferences.use_current")}
             }
         }
     }
@@ -1,10 +1,12 @@
 use super::tabs::{
-    about_tab::AboutTab, directory_tab::DirectoryTab, right_sidebar_tab::RightSidebarTab,
-    sidebar_tab::SidebarTab, theme_tab::ThemeTab, window_position_tab::WindowPositionTab,
-    window_size_tab::WindowSizeTab,
+    about_tab::AboutTab, directory_tab::DirectoryTab, editing_tab::EditingTab,
+    keymap_tab::KeymapTab, language_tab::LanguageTab, right_sidebar_tab::RightSidebarTab,
+    sidebar_tab::SidebarTab, theme_tab::ThemeTab, watcher_tab::WatcherTab,
+    window_position_tab::WindowPositionTab, window_size_tab::WindowSizeTab,
 };
 use crate::components::icon::{Icon, IconName};
-use crate::config::{Config, CONFIG};
+use crate::config::{apply_config, Config, CONFIG, CONFIG_CHANGED, CONFIG_ERROR};
+use crate::i18n::{t, t_args};
 use crate::state::AppState;
 use dioxus::prelude::*;
 use parking_lot::RwLock;
@@ -19,6 +21,10 @@ pub enum PreferencesTab {
     Sidebar,
     RightSidebar,
     Directory,
+    Editing,
+    Watcher,
+    Keymap,
+    Language,
     About,
 }
 
@@ -31,6 +37,11 @@ pub fn set_preferences_tab_to_about() {
     *LAST_PREFERENCES_TAB.write() = PreferencesTab::About;
 }
 
+/// Set the preferences tab to File Watching (called from the watcher notice)
+pub fn set_preferences_tab_to_watcher() {
+    *LAST_PREFERENCES_TAB.write() = PreferencesTab::Watcher;
+}
+
 /// Save status for the preferences page
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
 enum SaveStatus {
@@ -55,6 +66,21 @@ pub fn PreferencesView() -> Element {
         has_changes.set(false);
     });
 
+    // Follow changes made elsewhere (hand edits of config.json, other windows)
+    // unless there are unsaved changes here
+    let mut config_error = use_signal(|| CONFIG_ERROR.read().clone());
+    let mut newer_version = use_signal(Config::newer_file_version);
+    use_future(move || async move {
+        let mut rx = CONFIG_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            config_error.set(CONFIG_ERROR.read().clone());
+            newer_version.set(Config::newer_file_version());
+            if !*has_changes.peek() {
+                config.set(CONFIG.read().clone());
+            }
+        }
+    });
+
     let handle_save = move |_| {
         let cfg = config().clone();
         save_status.set(SaveStatus::Saving);
@@ -63,8 +89,8 @@ pub fn PreferencesView() -> Element {
                 tracing::error!("Failed to save configuration: {:?}", e);
                 save_status.set(SaveStatus::Idle);
             } else {
-                *CONFIG.write() = cfg.clone();
                 has_changes.set(false);
+                apply_config(cfg).await;
                 save_status.set(SaveStatus::Saved);
                 // Reset to idle after showing success
                 tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
@@ -94,7 +120,7 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::Theme;
                         },
                         Icon { name: IconName::SunMoon, size: 18 }
-                        span { "Theme" }
+                        span { {t("preferences.nav.theme")} }
                     }
                     button {
                         class: if current_tab == PreferencesTab::WindowPosition { "nav-tab active" } else { "nav-tab" },
@@ -103,7 +129,7 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::WindowPosition;
                         },
                         Icon { name: IconName::ArrowsMove, size: 18 }
-                        span { "Window Position" }
+                        span { {t("preferences.nav.window_position")} }
                     }
                     button {
                         class: if current_tab == PreferencesTab::WindowSize { "nav-tab active" } else { "nav-tab" },
@@ -112,7 +138,7 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::WindowSize;
                         },
                         Icon { name: IconName::ArrowsDiagonal, size: 18 }
-                        span { "Window Size" }
+                        span { {t("preferences.nav.window_size")} }
                     }
                     button {
                         class: if current_tab == PreferencesTab::Sidebar { "nav-tab active" } else { "nav-tab" },
@@ -121,7 +147,7 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::Sidebar;
                         },
                         Icon { name: IconName::Sidebar, size: 18 }
-                        span { "Sidebar" }
+                        span { {t("preferences.nav.sidebar")} }
                     }
                     button {
                         class: if current_tab == PreferencesTab::RightSidebar { "nav-tab active" } else { "nav-tab" },
@@ -130,7 +156,7 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::RightSidebar;
                         },
                         Icon { name: IconName::List, size: 18 }
-                        span { "Right Sidebar" }
+                        span { {t("preferences.nav.right_sidebar")} }
                     }
                     button {
                         class: if current_tab == PreferencesTab::Directory { "nav-tab active" } else { "nav-tab" },
@@ -139,7 +165,43 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::Directory;
                         },
                         Icon { name: IconName::Folder, size: 18 }
-                        span { "Directory" }
+                        span { {t("preferences.nav.directory")} }
+                    }
+                    button {
+                        class: if current_tab == PreferencesTab::Editing { "nav-tab active" } else { "nav-tab" },
+                        onclick: move |_| {
+                            active_tab.set(PreferencesTab::Editing);
+                            *LAST_PREFERENCES_TAB.write() = PreferencesTab::Editing;
+                        },
+                        Icon { name: IconName::Check, size: 18 }
+                        span { {t("preferences.nav.editing")} }
+                    }
+                    button {
+                        class: if current_tab == PreferencesTab::Watcher { "nav-tab active" } else { "nav-tab" },
+                        onclick: move |_| {
+                            active_tab.set(PreferencesTab::Watcher);
+                            *LAST_PREFERENCES_TAB.write() = PreferencesTab::Watcher;
+                        },
+                        Icon { name: IconName::Refresh, size: 18 }
+                        span { {t("preferences.nav.watcher")} }
+                    }
+                    button {
+                        class: if current_tab == PreferencesTab::Keymap { "nav-tab active" } else { "nav-tab" },
+                        onclick: move |_| {
+                            active_tab.set(PreferencesTab::Keymap);
+                            *LAST_PREFERENCES_TAB.write() = PreferencesTab::Keymap;
+                        },
+                        Icon { name: IconName::Command, size: 18 }
+                        span { {t("preferences.nav.keymap")} }
+                    }
+                    button {
+                        class: if current_tab == PreferencesTab::Language { "nav-tab active" } else { "nav-tab" },
+                        onclick: move |_| {
+                            active_tab.set(PreferencesTab::Language);
+                            *LAST_PREFERENCES_TAB.write() = PreferencesTab::Language;
+                        },
+                        Icon { name: IconName::Language, size: 18 }
+                        span { {t("preferences.nav.language")} }
                     }
 
                     // Spacer to push About to bottom
@@ -152,7 +214,7 @@ pub fn PreferencesView() -> Element {
                             *LAST_PREFERENCES_TAB.write() = PreferencesTab::About;
                         },
                         Icon { name: IconName::InfoCircle, size: 18 }
-                        span { "About" }
+                        span { {t("preferences.nav.about")} }
                     }
                 }
 
@@ -169,21 +231,49 @@ pub fn PreferencesView() -> Element {
                                 SaveStatus::Idle if has_changes() => rsx! {
                                     button {
                                         class: "save-button",
+                                        disabled: newer_version().is_some(),
                                         onclick: handle_save,
-                                        "Save Changes"
+                                        {t("preferences.save")}
                                     }
                                 },
                                 SaveStatus::Saving => rsx! {
-                                    span { class: "saving", "Saving..." }
+                                    span { class: "saving", {t("preferences.saving")} }
                                 },
                                 SaveStatus::Saved => rsx! {
-                                    span { class: "saved", "Saved!" }
+                                    span { class: "saved", {t("preferences.saved")} }
                                 },
                                 _ => rsx! {},
                             }
                         }
                     }
 
+                    // Problem with config.json that kept it from being applied
+                    if let Some(error) = config_error() {
+                        div {
+                            class: "preferences-config-error",
+                            Icon { name: IconName::AlertTriangle, size: 18 }
+                            div {
+                                p { class: "preferences-config-error-title", {t("preferences.config_error")} }
+                                p { class: "preferences-config-error-detail", "{Config::path().display()}: {error}" }
+                            }
+                        }
+                    }
+
+                    // config.json from a newer Arto is never overwritten
+                    if let Some(version) = newer_version() {
+                        div {
+                            class: "preferences-config-error",
+                            Icon { name: IconName::AlertTriangle, size: 18 }
+                            div {
+                                p {
+                                    class: "preferences-config-error-title",
+                                    {t_args("preferences.config_newer", &[("version", &version.to_string())])}
+                                }
+                                p { class: "preferences-config-error-detail", "{Config::path().display()}" }
+                            }
+                        }
+                    }
+
                     // Tab content
                     match current_tab {
                         PreferencesTab::Theme => rsx! {
@@ -225,6 +315,30 @@ pub fn PreferencesView() -> Element {
                                 current_directory: state.sidebar.read().root_directory.clone(),
                             }
                         },
+                        PreferencesTab::Editing => rsx! {
+                            EditingTab {
+                                config,
+                                has_changes,
+                            }
+                        },
+                        PreferencesTab::Watcher => rsx! {
+                            WatcherTab {
+                                config,
+                                has_changes,
+                            }
+                        },
+                        PreferencesTab::Keymap => rsx! {
+                            KeymapTab {
+                                config,
+                                has_changes,
+                            }
+                        },
+                        PreferencesTab::Language => rsx! {
+                            LanguageTab {
+                                config,
+                                has_changes,
+                            }
+                        },
                         PreferencesTab::About => rsx! {
                             AboutTab {}
                         },
@@ -1,7 +1,11 @@
 pub mod about_tab;
 pub mod directory_tab;
+pub mod editing_tab;
+pub mod keymap_tab;
+pub mod language_tab;
 pub mod right_sidebar_tab;
 pub mod sidebar_tab;
 pub mod theme_tab;
+pub mod watcher_tab;
 pub mod window_position_tab;
 pub mod window_size_tab;
@@ -1,11 +1,15 @@
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::{t, t_args};
 use dioxus::prelude::*;
 
 const ARTO_ICON: Asset = asset!("/assets/arto-app.png");
 
 #[component]
 pub fn AboutTab() -> Element {
-    let version_text = format!("Version {}", env!("CARGO_PKG_VERSION"));
+    let version_text = t_args(
+        "preferences.about.version",
+        &[("version", env!("CARGO_PKG_VERSION"))],
+    );
 
     rsx! {
         div {
@@ -30,12 +34,10 @@ pub fn AboutTab() -> Element {
                 p { class: "about-version", "{version_text}" }
 
                 // Tagline
-                p { class: "about-tagline", "The Art of Reading Markdown." }
+                p { class: "about-tagline", {t("preferences.about.tagline")} }
 
                 // Description
-                p { class: "about-description",
-                    "A local app that faithfully recreates GitHub-style Markdown rendering for a beautiful reading experience."
-                }
+                p { class: "about-description", {t("preferences.about.description")} }
 
                 // Links (card style like no-file-hints)
                 div {
@@ -46,7 +48,7 @@ pub fn AboutTab() -> Element {
                         rel: "noopener noreferrer",
                         class: "about-link",
                         span { class: "about-link-icon", Icon { name: IconName::BrandGithub, size: 20 } }
-                        span { class: "about-link-text", "View on GitHub" }
+                        span { class: "about-link-text", {t("preferences.about.view_on_github")} }
                     }
                     a {
                         href: "https://github.com/arto-app/Arto/issues",
@@ -54,14 +56,14 @@ pub fn AboutTab() -> Element {
                         rel: "noopener noreferrer",
                         class: "about-link",
                         span { class: "about-link-icon", Icon { name: IconName::Bug, size: 20 } }
-                        span { class: "about-link-text", "Report an Issue" }
+                        span { class: "about-link-text", {t("preferences.about.report_issue")} }
                     }
                 }
 
                 // Footer
                 div {
                     class: "about-footer",
-                    p { "Created by lambdalisue" }
+                    p { {t_args("preferences.about.created_by", &[("author", "lambdalisue")])} }
                     p { "Copyright © 2025 lambdalisue" }
                 }
             }
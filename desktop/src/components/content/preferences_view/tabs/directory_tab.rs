@@ -1,5 +1,7 @@
-use super::super::form_controls::{DirectoryPicker, OptionCardItem, OptionCards};
+use super::super::form_controls::{DirectoryPicker, OptionCardItem, OptionCards, SliderInput};
 use crate::config::{Config, NewWindowBehavior, StartupBehavior};
+use crate::i18n::{t, t_args};
+use crate::recent::{clear_recent, RECENT_ITEMS, RECENT_ITEMS_CHANGED};
 use dioxus::prelude::*;
 use std::path::PathBuf;
 
@@ -11,23 +13,33 @@ pub fn DirectoryTab(
 ) -> Element {
     // Extract values upfront to avoid holding read guard across closures
     let directory = config.read().directory.clone();
+    let recent_max_items = config.read().recent.max_items;
+
+    // Number of recent items, kept in sync with other windows
+    let mut recent_count = use_signal(|| RECENT_ITEMS.read().items.len());
+    use_future(move || async move {
+        let mut rx = RECENT_ITEMS_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            recent_count.set(RECENT_ITEMS.read().items.len());
+        }
+    });
 
     rsx! {
         div {
             class: "preferences-pane",
 
-            h3 { class: "preference-section-title", "Default Settings" }
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Default Directory" }
-                    p { class: "preference-description", "The directory to open when no specific directory is specified." }
+                    label { {t("preferences.directory.default_directory")} }
+                    p { class: "preference-description", {t("preferences.directory.default_directory_description")} }
                 }
                 DirectoryPicker {
                     value: directory.default_directory.to_owned(),
-                    placeholder: "Not set".to_string(),
+                    placeholder: t("preferences.directory.not_set").to_string(),
                     on_change: move |new_value| {
                         config.write().directory.default_directory = new_value;
                         has_changes.set(true);
@@ -36,14 +48,14 @@ pub fn DirectoryTab(
                 }
             }
 
-            h3 { class: "preference-section-title", "Behavior" }
+            h3 { class: "preference-section-title", {t("preferences.section.behavior")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On Startup" }
-                    p { class: "preference-description", "Which directory to open when the application starts." }
+                    label { {t("preferences.on_startup")} }
+                    p { class: "preference-description", {t("preferences.directory.on_startup_description")} }
                 }
                 OptionCards {
                     name: "dir-startup".to_string(),
@@ -51,14 +63,14 @@ pub fn DirectoryTab(
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default directory".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.directory.use_default").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::LastClosed,
-                            title: "Last Closed".to_string(),
-                            description: Some("Resume from last closed window".to_string()),
+                            title: t("preferences.option.last_closed").to_string(),
+                            description: Some(t("preferences.option.resume_last_closed").to_string()),
                         },
                     ],
                     selected: directory.on_startup,
@@ -73,8 +85,8 @@ pub fn DirectoryTab(
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On New Window" }
-                    p { class: "preference-description", "Which directory to open in new windows." }
+                    label { {t("preferences.on_new_window")} }
+                    p { class: "preference-description", {t("preferences.directory.on_new_window_description")} }
                 }
                 OptionCards {
                     name: "dir-new-window".to_string(),
@@ -82,14 +94,14 @@ pub fn DirectoryTab(
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default directory".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.directory.use_default").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::LastFocused,
-                            title: "Last Focused".to_string(),
-                            description: Some("Same as current window".to_string()),
+                            title: t("preferences.option.last_focused").to_string(),
+                            description: Some(t("preferences.option.same_as_current").to_string()),
                         },
                     ],
                     selected: directory.on_new_window,
@@ -99,6 +111,47 @@ pub fn DirectoryTab(
                     },
                 }
             }
+
+            h3 { class: "preference-section-title", {t("preferences.section.recent")} }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.recent.max_items")} }
+                    p { class: "preference-description", {t("preferences.recent.max_items_description")} }
+                }
+                SliderInput {
+                    value: recent_max_items as f64,
+                    min: 0.0,
+                    max: 50.0,
+                    step: 1.0,
+                    unit: t("preferences.recent.items_unit").to_string(),
+                    on_change: move |new_value: f64| {
+                        config.write().recent.max_items = new_value.round() as usize;
+                        has_changes.set(true);
+                    },
+                    current_value: None,
+                }
+            }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.recent.clear")} }
+                    p {
+                        class: "preference-description",
+                        {t_args("preferences.recent.clear_description", &[("count", &recent_count().to_string())])}
+                    }
+                }
+                button {
+                    class: "use-current-button",
+                    disabled: recent_count() == 0,
+                    onclick: move |_| clear_recent(),
+                    {t("preferences.recent.clear")}
+                }
+            }
         }
     }
 }
@@ -0,0 +1,69 @@
+use super::super::form_controls::{OptionCardItem, OptionCards};
+use crate::config::Config;
+use crate::i18n::t;
+use dioxus::prelude::*;
+
+#[component]
+pub fn EditingTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
+    // Extract values upfront to avoid holding read guard across closures
+    let editing = config.read().editing.clone();
+
+    rsx! {
+        div {
+            class: "preferences-pane",
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.editing.tasks")} }
+                    p { class: "preference-description", {t("preferences.editing.tasks_description")} }
+                }
+                OptionCards {
+                    name: "editing-interactive-tasks".to_string(),
+                    options: vec![
+                        OptionCardItem {
+                            icon: None,
+                            value: false,
+                            title: t("preferences.editing.read_only").to_string(),
+                            description: Some(t("preferences.editing.read_only_description").to_string()),
+                        },
+                        OptionCardItem {
+                            icon: None,
+                            value: true,
+                            title: t("preferences.editing.interactive").to_string(),
+                            description: Some(t("preferences.editing.interactive_description").to_string()),
+                        },
+                    ],
+                    selected: editing.interactive_tasks,
+                    on_change: move |enabled| {
+                        config.write().editing.interactive_tasks = enabled;
+                        has_changes.set(true);
+                    },
+                }
+            }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.editing.editor")} }
+                    p { class: "preference-description", {t("preferences.editing.editor_description")} }
+                }
+                input {
+                    class: "preference-text-input",
+                    r#type: "text",
+                    spellcheck: false,
+                    placeholder: "code -g {{file}}:{{line}}:{{column}}",
+                    value: editing.editor_command.clone().unwrap_or_default(),
+                    oninput: move |evt| {
+                        let command = evt.value();
+                        config.write().editing.editor_command =
+                            (!command.trim().is_empty()).then_some(command);
+                        has_changes.set(true);
+                    },
+                }
+            }
+        }
+    }
+}
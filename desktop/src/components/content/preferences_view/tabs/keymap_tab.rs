@@ -0,0 +1,161 @@
+use super::super::form_controls::{OptionCardItem, OptionCards};
+use crate::components::icon::{Icon, IconName};
+use crate::config::Config;
+use crate::i18n::{t, t_args};
+use crate::keymap::{find_conflicts, parse_shortcut, shortcut_label};
+use crate::menu::MenuId;
+use dioxus::prelude::*;
+
+#[component]
+pub fn KeymapTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
+    // Extract values upfront to avoid holding read guard across closures
+    let keymap = config.read().keymap.clone();
+    let conflicts = find_conflicts(&keymap);
+
+    let mut categories: Vec<&'static str> = Vec::new();
+    for id in MenuId::ALL {
+        if !categories.contains(&id.category()) {
+            categories.push(id.category());
+        }
+    }
+
+    rsx! {
+        div {
+            class: "preferences-pane",
+
+            h3 { class: "preference-section-title", {t("preferences.keymap.navigation")} }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.keymap.vim_keys")} }
+                    p { class: "preference-description", {t("preferences.keymap.vim_keys_description")} }
+                }
+                OptionCards {
+                    name: "keymap-vim-navigation".to_string(),
+                    options: vec![
+                        OptionCardItem {
+                            icon: None,
+                            value: false,
+                            title: t("preferences.keymap.off").to_string(),
+                            description: Some(t("preferences.keymap.off_description").to_string()),
+                        },
+                        OptionCardItem {
+                            icon: None,
+                            value: true,
+                            title: t("preferences.keymap.on").to_string(),
+                            description: Some(t("preferences.keymap.on_description").to_string()),
+                        },
+                    ],
+                    selected: keymap.vim_navigation,
+                    on_change: move |enabled| {
+                        config.write().keymap.vim_navigation = enabled;
+                        has_changes.set(true);
+                    },
+                }
+            }
+
+            h3 { class: "preference-section-title", {t("preferences.keymap.shortcuts")} }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.keymap.syntax")} }
+                    p { class: "preference-description", {t("preferences.keymap.syntax_description")} }
+                }
+            }
+
+            for category in categories {
+                h3 { class: "preference-section-title", "{category}" }
+
+                for id in MenuId::ALL.into_iter().filter(|id| id.category() == category) {
+                    KeymapRow {
+                        key: "{id.as_str()}",
+                        id,
+                        config,
+                        has_changes,
+                        value: keymap.bindings.get(id.as_str()).cloned(),
+                        conflicts: conflicts.get(&id).cloned().unwrap_or_default(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn KeymapRow(
+    id: MenuId,
+    config: Signal<Config>,
+    has_changes: Signal<bool>,
+    /// User override (None if the default is used)
+    value: Option<String>,
+    /// Labels of commands sharing the same shortcut
+    conflicts: Vec<String>,
+) -> Element {
+    let is_overridden = value.is_some();
+    let text = value
+        .clone()
+        .unwrap_or_else(|| id.default_shortcut().unwrap_or_default().to_string());
+    let error = parse_shortcut(&text).err();
+    let label = shortcut_label(id, &config.read().keymap);
+    let conflict_text = conflicts.join(", ");
+
+    rsx! {
+        div {
+            class: "keymap-row",
+            div {
+                class: "keymap-row-label",
+                span { "{id.label()}" }
+                code { class: "keymap-row-id", "{id.as_str()}" }
+            }
+            div {
+                class: "keymap-row-input",
+                class: if error.is_some() { "invalid" },
+                class: if !conflicts.is_empty() { "conflict" },
+                input {
+                    r#type: "text",
+                    value: "{text}",
+                    placeholder: t("preferences.keymap.none"),
+                    spellcheck: false,
+                    oninput: move |evt| {
+                        let new_value = evt.value();
+                        let mut cfg = config.write();
+                        if Some(new_value.trim()) == id.default_shortcut() {
+                            cfg.keymap.bindings.remove(id.as_str());
+                        } else {
+                            cfg.keymap.bindings.insert(id.as_str().to_string(), new_value);
+                        }
+                        has_changes.set(true);
+                    },
+                }
+                if let Some(label) = label {
+                    span { class: "keymap-row-symbols", "{label}" }
+                }
+                button {
+                    class: "icon-button",
+                    title: t("preferences.keymap.reset"),
+                    disabled: !is_overridden,
+                    onclick: move |_| {
+                        config.write().keymap.bindings.remove(id.as_str());
+                        has_changes.set(true);
+                    },
+                    Icon { name: IconName::Refresh, size: 16 }
+                }
+            }
+            if let Some(error) = &error {
+                p {
+                    class: "keymap-row-message",
+                    {t_args("preferences.keymap.invalid", &[("error", error.as_str())])}
+                }
+            } else if !conflicts.is_empty() {
+                p {
+                    class: "keymap-row-message",
+                    {t_args("preferences.keymap.conflicts", &[("commands", &conflict_text)])}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use super::super::form_controls::{OptionCardItem, OptionCards};
+use crate::config::Config;
+use crate::i18n::{t, Language};
+use dioxus::prelude::*;
+
+#[component]
+pub fn LanguageTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
+    let language = config.read().language;
+
+    rsx! {
+        div {
+            class: "preferences-pane",
+
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.language.language")} }
+                    p { class: "preference-description", {t("preferences.language.language_description")} }
+                }
+                OptionCards {
+                    name: "language".to_string(),
+                    options: vec![
+                        OptionCardItem {
+                            value: Language::Auto,
+                            icon: None,
+                            title: t("preferences.language.auto").to_string(),
+                            description: Some(t("preferences.language.auto_description").to_string()),
+                        },
+                        // Language names are shown in their own language
+                        OptionCardItem {
+                            value: Language::En,
+                            icon: None,
+                            title: "English".to_string(),
+                            description: None,
+                        },
+                        OptionCardItem {
+                            value: Language::Ja,
+                            icon: None,
+                            title: "日本語".to_string(),
+                            description: None,
+                        },
+                    ],
+                    selected: language,
+                    on_change: move |new_language| {
+                        config.write().language = new_language;
+                        has_changes.set(true);
+                    },
+                }
+            }
+        }
+    }
+}
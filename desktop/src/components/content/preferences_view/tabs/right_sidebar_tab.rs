@@ -1,5 +1,6 @@
 use super::super::form_controls::{OptionCardItem, OptionCards, SliderInput};
 use crate::config::{Config, NewWindowBehavior, StartupBehavior};
+use crate::i18n::t;
 use dioxus::prelude::*;
 
 #[component]
@@ -15,14 +16,14 @@ pub fn RightSidebarTab(
         div {
             class: "preferences-pane",
 
-            h3 { class: "preference-section-title", "Default Settings" }
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Open by Default" }
-                    p { class: "preference-description", "Whether the right sidebar panel is open when starting." }
+                    label { {t("preferences.open_by_default")} }
+                    p { class: "preference-description", {t("preferences.right_sidebar.open_by_default_description")} }
                 }
                 OptionCards {
                     name: "right-sidebar-default-open".to_string(),
@@ -30,14 +31,14 @@ pub fn RightSidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: false,
-                            title: "Closed".to_string(),
-                            description: Some("Right sidebar closed by default".to_string()),
+                            title: t("preferences.option.closed").to_string(),
+                            description: Some(t("preferences.right_sidebar.closed_description").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: true,
-                            title: "Open".to_string(),
-                            description: Some("Right sidebar open by default".to_string()),
+                            title: t("preferences.option.open").to_string(),
+                            description: Some(t("preferences.right_sidebar.open_description").to_string()),
                         },
                     ],
                     selected: right_sidebar.default_open,
@@ -52,8 +53,8 @@ pub fn RightSidebarTab(
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Default Width" }
-                    p { class: "preference-description", "The default right sidebar panel width in pixels." }
+                    label { {t("preferences.default_width")} }
+                    p { class: "preference-description", {t("preferences.right_sidebar.default_width_description")} }
                 }
                 SliderInput {
                     value: right_sidebar.default_width,
@@ -69,14 +70,14 @@ pub fn RightSidebarTab(
                 }
             }
 
-            h3 { class: "preference-section-title", "Behavior" }
+            h3 { class: "preference-section-title", {t("preferences.section.behavior")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On Startup" }
-                    p { class: "preference-description", "Right sidebar panel state when the application starts." }
+                    label { {t("preferences.on_startup")} }
+                    p { class: "preference-description", {t("preferences.right_sidebar.on_startup_description")} }
                 }
                 OptionCards {
                     name: "right-sidebar-startup".to_string(),
@@ -84,14 +85,14 @@ pub fn RightSidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default settings".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.option.use_default_settings").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::LastClosed,
-                            title: "Last Closed".to_string(),
-                            description: Some("Resume from last closed window".to_string()),
+                            title: t("preferences.option.last_closed").to_string(),
+                            description: Some(t("preferences.option.resume_last_closed").to_string()),
                         },
                     ],
                     selected: right_sidebar.on_startup,
@@ -106,8 +107,8 @@ pub fn RightSidebarTab(
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On New Window" }
-                    p { class: "preference-description", "Right sidebar panel state in new windows." }
+                    label { {t("preferences.on_new_window")} }
+                    p { class: "preference-description", {t("preferences.right_sidebar.on_new_window_description")} }
                 }
                 OptionCards {
                     name: "right-sidebar-new-window".to_string(),
@@ -115,14 +116,14 @@ pub fn RightSidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default settings".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.option.use_default_settings").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::LastFocused,
-                            title: "Last Focused".to_string(),
-                            description: Some("Same as current window".to_string()),
+                            title: t("preferences.option.last_focused").to_string(),
+                            description: Some(t("preferences.option.same_as_current").to_string()),
                         },
                     ],
                     selected: right_sidebar.on_new_window,
@@ -132,6 +133,60 @@ pub fn RightSidebarTab(
                     },
                 }
             }
+
+            h3 { class: "preference-section-title", {t("preferences.section.table_of_contents")} }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.right_sidebar.toc_max_depth")} }
+                    p { class: "preference-description", {t("preferences.right_sidebar.toc_max_depth_description")} }
+                }
+                SliderInput {
+                    value: right_sidebar.toc_max_depth as f64,
+                    min: 1.0,
+                    max: 6.0,
+                    step: 1.0,
+                    unit: t("preferences.right_sidebar.toc_levels_unit").to_string(),
+                    on_change: move |new_depth: f64| {
+                        config.write().right_sidebar.toc_max_depth = new_depth.round() as usize;
+                        has_changes.set(true);
+                    },
+                    current_value: None,
+                }
+            }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.right_sidebar.toc_numbering")} }
+                    p { class: "preference-description", {t("preferences.right_sidebar.toc_numbering_description")} }
+                }
+                OptionCards {
+                    name: "right-sidebar-toc-numbering".to_string(),
+                    options: vec![
+                        OptionCardItem {
+                            icon: None,
+                            value: false,
+                            title: t("preferences.option.off").to_string(),
+                            description: Some(t("preferences.right_sidebar.toc_numbering_off_description").to_string()),
+                        },
+                        OptionCardItem {
+                            icon: None,
+                            value: true,
+                            title: t("preferences.option.on").to_string(),
+                            description: Some(t("preferences.right_sidebar.toc_numbering_on_description").to_string()),
+                        },
+                    ],
+                    selected: right_sidebar.toc_numbering,
+                    on_change: move |numbering| {
+                        config.write().right_sidebar.toc_numbering = numbering;
+                        has_changes.set(true);
+                    },
+                }
+            }
         }
     }
 }
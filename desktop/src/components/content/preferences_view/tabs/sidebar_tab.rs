@@ -1,5 +1,6 @@
 use super::super::form_controls::{OptionCardItem, OptionCards, SliderInput};
 use crate::config::{Config, NewWindowBehavior, StartupBehavior};
+use crate::i18n::t;
 use dioxus::prelude::*;
 
 #[component]
@@ -15,14 +16,14 @@ pub fn SidebarTab(
         div {
             class: "preferences-pane",
 
-            h3 { class: "preference-section-title", "Default Settings" }
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Open by Default" }
-                    p { class: "preference-description", "Whether the sidebar is open when starting." }
+                    label { {t("preferences.open_by_default")} }
+                    p { class: "preference-description", {t("preferences.sidebar.open_by_default_description")} }
                 }
                 OptionCards {
                     name: "sidebar-default-open".to_string(),
@@ -30,14 +31,14 @@ pub fn SidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: false,
-                            title: "Closed".to_string(),
-                            description: Some("Sidebar closed by default".to_string()),
+                            title: t("preferences.option.closed").to_string(),
+                            description: Some(t("preferences.sidebar.closed_description").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: true,
-                            title: "Open".to_string(),
-                            description: Some("Sidebar open by default".to_string()),
+                            title: t("preferences.option.open").to_string(),
+                            description: Some(t("preferences.sidebar.open_description").to_string()),
                         },
                     ],
                     selected: sidebar.default_open,
@@ -52,8 +53,8 @@ pub fn SidebarTab(
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Default Width" }
-                    p { class: "preference-description", "The default sidebar width in pixels." }
+                    label { {t("preferences.default_width")} }
+                    p { class: "preference-description", {t("preferences.sidebar.default_width_description")} }
                 }
                 SliderInput {
                     value: sidebar.default_width,
@@ -73,8 +74,8 @@ pub fn SidebarTab(
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Show All Files" }
-                    p { class: "preference-description", "Whether to show non-markdown files in the file explorer." }
+                    label { {t("preferences.sidebar.show_all_files")} }
+                    p { class: "preference-description", {t("preferences.sidebar.show_all_files_description")} }
                 }
                 OptionCards {
                     name: "sidebar-show-all-files".to_string(),
@@ -82,14 +83,14 @@ pub fn SidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: false,
-                            title: "Markdown Only".to_string(),
-                            description: Some("Show only markdown files".to_string()),
+                            title: t("preferences.sidebar.markdown_only").to_string(),
+                            description: Some(t("preferences.sidebar.markdown_only_description").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: true,
-                            title: "All Files".to_string(),
-                            description: Some("Show all file types".to_string()),
+                            title: t("preferences.sidebar.all_files").to_string(),
+                            description: Some(t("preferences.sidebar.all_files_description").to_string()),
                         },
                     ],
                     selected: sidebar.default_show_all_files,
@@ -100,14 +101,14 @@ pub fn SidebarTab(
                 }
             }
 
-            h3 { class: "preference-section-title", "Behavior" }
+            h3 { class: "preference-section-title", {t("preferences.section.behavior")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On Startup" }
-                    p { class: "preference-description", "Sidebar state when the application starts." }
+                    label { {t("preferences.on_startup")} }
+                    p { class: "preference-description", {t("preferences.sidebar.on_startup_description")} }
                 }
                 OptionCards {
                     name: "sidebar-startup".to_string(),
@@ -115,14 +116,14 @@ pub fn SidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default settings".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.option.use_default_settings").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::LastClosed,
-                            title: "Last Closed".to_string(),
-                            description: Some("Resume from last closed window".to_string()),
+                            title: t("preferences.option.last_closed").to_string(),
+                            description: Some(t("preferences.option.resume_last_closed").to_string()),
                         },
                     ],
                     selected: sidebar.on_startup,
@@ -137,8 +138,8 @@ pub fn SidebarTab(
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On New Window" }
-                    p { class: "preference-description", "Sidebar state in new windows." }
+                    label { {t("preferences.on_new_window")} }
+                    p { class: "preference-description", {t("preferences.sidebar.on_new_window_description")} }
                 }
                 OptionCards {
                     name: "sidebar-new-window".to_string(),
@@ -146,14 +147,14 @@ pub fn SidebarTab(
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default settings".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.option.use_default_settings").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::LastFocused,
-                            title: "Last Focused".to_string(),
-                            description: Some("Same as current window".to_string()),
+                            title: t("preferences.option.last_focused").to_string(),
+                            description: Some(t("preferences.option.same_as_current").to_string()),
                         },
                     ],
                     selected: sidebar.on_new_window,
@@ -1,6 +1,7 @@
 use super::super::form_controls::{OptionCardItem, OptionCards};
-use crate::components::icon::IconName;
+use crate::components::theme_selector::theme_icon;
 use crate::config::{Config, NewWindowBehavior, StartupBehavior};
+use crate::i18n::t;
 use crate::theme::Theme;
 use dioxus::prelude::*;
 
@@ -12,37 +13,26 @@ pub fn ThemeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
         div {
             class: "preferences-pane",
 
-            h3 { class: "preference-section-title", "Default Settings" }
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Default Theme" }
-                    p { class: "preference-description", "The color theme used by default." }
+                    label { {t("preferences.theme.default_theme")} }
+                    p { class: "preference-description", {t("preferences.theme.default_theme_description")} }
                 }
                 OptionCards {
                     name: "theme-default".to_string(),
-                    options: vec![
-                        OptionCardItem {
-                            value: Theme::Auto,
-                            icon: Some(IconName::SunMoon),
-                            title: "Auto".to_string(),
-                            description: None,
-                        },
-                        OptionCardItem {
-                            value: Theme::Light,
-                            icon: Some(IconName::Sun),
-                            title: "Light".to_string(),
+                    options: Theme::available()
+                        .into_iter()
+                        .map(|theme| OptionCardItem {
+                            icon: Some(theme_icon(&theme)),
+                            title: theme.label(),
                             description: None,
-                        },
-                        OptionCardItem {
-                            value: Theme::Dark,
-                            icon: Some(IconName::Moon),
-                            title: "Dark".to_string(),
-                            description: None,
-                        },
-                    ],
+                            value: theme,
+                        })
+                        .collect(),
                     selected: theme.default_theme,
                     on_change: move |new_theme| {
                         config.write().theme.default_theme = new_theme;
@@ -51,14 +41,14 @@ pub fn ThemeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
                 }
             }
 
-            h3 { class: "preference-section-title", "Behavior" }
+            h3 { class: "preference-section-title", {t("preferences.section.behavior")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On Startup" }
-                    p { class: "preference-description", "Which theme to apply when the application starts." }
+                    label { {t("preferences.on_startup")} }
+                    p { class: "preference-description", {t("preferences.theme.on_startup_description")} }
                 }
                 OptionCards {
                     name: "theme-startup".to_string(),
@@ -66,14 +56,14 @@ pub fn ThemeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
                         OptionCardItem {
                             value: StartupBehavior::Default,
                             icon: None,
-                            title: "Default".to_string(),
-                            description: Some("Use default theme".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.theme.use_default").to_string()),
                         },
                         OptionCardItem {
                             value: StartupBehavior::LastClosed,
                             icon: None,
-                            title: "Last Closed".to_string(),
-                            description: Some("Resume from last closed window".to_string()),
+                            title: t("preferences.option.last_closed").to_string(),
+                            description: Some(t("preferences.option.resume_last_closed").to_string()),
                         },
                     ],
                     selected: theme.on_startup,
@@ -88,8 +78,8 @@ pub fn ThemeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On New Window" }
-                    p { class: "preference-description", "Which theme to apply in new windows." }
+                    label { {t("preferences.on_new_window")} }
+                    p { class: "preference-description", {t("preferences.theme.on_new_window_description")} }
                 }
                 OptionCards {
                     name: "theme-new-window".to_string(),
@@ -97,14 +87,14 @@ pub fn ThemeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
                         OptionCardItem {
                             value: NewWindowBehavior::Default,
                             icon: None,
-                            title: "Default".to_string(),
-                            description: Some("Use default theme".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.theme.use_default").to_string()),
                         },
                         OptionCardItem {
                             value: NewWindowBehavior::LastFocused,
                             icon: None,
-                            title: "Last Focused".to_string(),
-                            description: Some("Same as current window".to_string()),
+                            title: t("preferences.option.last_focused").to_string(),
+                            description: Some(t("preferences.option.same_as_current").to_string()),
                         },
                     ],
                     selected: theme.on_new_window,
@@ -0,0 +1,163 @@
+use super::super::form_controls::{OptionCardItem, OptionCards, SliderInput};
+use crate::config::{Config, WatcherBackend};
+use crate::i18n::t;
+use crate::watcher::FILE_WATCHER;
+use dioxus::prelude::*;
+
+#[component]
+pub fn WatcherTab(config: Signal<Config>, has_changes: Signal<bool>) -> Element {
+    // Extract values upfront to avoid holding read guard across closures
+    let watcher = config.read().watcher.clone();
+
+    // Patterns as typed, blank lines included, so editing does not jump around
+    let mut ignore_text = use_signal(|| watcher.ignore.join("\n"));
+
+    // Current health of the watcher, kept in sync as paths are watched
+    let mut status = use_signal(|| FILE_WATCHER.status().borrow().clone());
+    use_future(move || async move {
+        let mut rx = FILE_WATCHER.status();
+        while rx.changed().await.is_ok() {
+            let current = rx.borrow_and_update().clone();
+            status.set(current);
+        }
+    });
+    let status = status();
+
+    rsx! {
+        div {
+            class: "preferences-pane",
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.watcher.backend")} }
+                    p { class: "preference-description", {t("preferences.watcher.backend_description")} }
+                }
+                OptionCards {
+                    name: "watcher-backend".to_string(),
+                    options: vec![
+                        OptionCardItem {
+                            icon: None,
+                            value: WatcherBackend::Auto,
+                            title: t("preferences.watcher.auto").to_string(),
+                            description: Some(t("preferences.watcher.auto_description").to_string()),
+                        },
+                        OptionCardItem {
+                            icon: None,
+                            value: WatcherBackend::Native,
+                            title: t("preferences.watcher.native").to_string(),
+                            description: Some(t("preferences.watcher.native_description").to_string()),
+                        },
+                        OptionCardItem {
+                            icon: None,
+                            value: WatcherBackend::Polling,
+                            title: t("preferences.watcher.polling").to_string(),
+                            description: Some(t("preferences.watcher.polling_description").to_string()),
+                        },
+                    ],
+                    selected: watcher.backend,
+                    on_change: move |backend| {
+                        config.write().watcher.backend = backend;
+                        has_changes.set(true);
+                    },
+                }
+            }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.watcher.poll_interval")} }
+                    p { class: "preference-description", {t("preferences.watcher.poll_interval_description")} }
+                }
+                SliderInput {
+                    value: watcher.poll_interval_ms as f64 / 1000.0,
+                    min: 1.0,
+                    max: 60.0,
+                    step: 1.0,
+                    unit: t("preferences.watcher.seconds_unit").to_string(),
+                    on_change: move |new_value: f64| {
+                        config.write().watcher.poll_interval_ms = (new_value.round() * 1000.0) as u64;
+                        has_changes.set(true);
+                    },
+                    current_value: None,
+                }
+            }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.watcher.debounce")} }
+                    p { class: "preference-description", {t("preferences.watcher.debounce_description")} }
+                }
+                SliderInput {
+                    value: watcher.debounce_ms as f64,
+                    min: 50.0,
+                    max: 5000.0,
+                    step: 50.0,
+                    unit: t("preferences.watcher.milliseconds_unit").to_string(),
+                    on_change: move |new_value: f64| {
+                        config.write().watcher.debounce_ms = new_value.round() as u64;
+                        has_changes.set(true);
+                    },
+                    current_value: None,
+                }
+            }
+
+            div {
+                class: "preference-item",
+                div {
+                    class: "preference-item-header",
+                    label { {t("preferences.watcher.ignore")} }
+                    p { class: "preference-description", {t("preferences.watcher.ignore_description")} }
+                }
+                textarea {
+                    class: "preference-textarea",
+                    rows: 5,
+                    spellcheck: false,
+                    placeholder: "node_modules\n*.tmp",
+                    value: "{ignore_text}",
+                    oninput: move |evt| {
+                        let text = evt.value();
+                        config.write().watcher.ignore = text
+                            .lines()
+                            .map(str::trim)
+                            .filter(|line| !line.is_empty())
+                            .map(str::to_string)
+                            .collect();
+                        ignore_text.set(text);
+                        has_changes.set(true);
+                    },
+                }
+            }
+
+            h3 { class: "preference-section-title", {t("preferences.watcher.status")} }
+
+            div {
+                class: "preference-item watcher-status",
+                class: if status.is_degraded() { "degraded" },
+                if !status.is_degraded() {
+                    p { class: "preference-description", {t("preferences.watcher.status_ok")} }
+                }
+                if !status.polled.is_empty() {
+                    p { class: "preference-description", {t("preferences.watcher.status_polled")} }
+                    ul {
+                        for path in status.polled.iter() {
+                            li { key: "{path.display()}", "{path.display()}" }
+                        }
+                    }
+                }
+                if !status.failed.is_empty() {
+                    p { class: "preference-description", {t("preferences.watcher.status_failed")} }
+                    ul {
+                        for (path, reason) in status.failed.iter() {
+                            li { key: "{path.display()}", "{path.display()} — {reason}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
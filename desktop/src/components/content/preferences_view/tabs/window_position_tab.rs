@@ -4,6 +4,7 @@ use crate::config::{
     Config, NewWindowBehavior, StartupBehavior, WindowDimension, WindowDimensionUnit,
     WindowPosition, WindowPositionMode,
 };
+use crate::i18n::t;
 use dioxus::prelude::*;
 use dioxus_desktop::window;
 
@@ -31,14 +32,14 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
         div {
             class: "preferences-pane",
 
-            h3 { class: "preference-section-title", "Default Settings" }
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Default Position" }
-                    p { class: "preference-description", "Set the default window position. Percent values place the window within the available screen area (0% = top/left, 100% = bottom/right)." }
+                    label { {t("preferences.window_position.default_position")} }
+                    p { class: "preference-description", {t("preferences.window_position.default_position_description")} }
                 }
                 OptionCards {
                     name: "window-position-mode".to_string(),
@@ -46,14 +47,14 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
                         OptionCardItem {
                             value: WindowPositionMode::Coordinates,
                             icon: Some(IconName::Command),
-                            title: "Coordinates".to_string(),
-                            description: Some("Use the X/Y values below".to_string()),
+                            title: t("preferences.window_position.coordinates").to_string(),
+                            description: Some(t("preferences.window_position.coordinates_description").to_string()),
                         },
                         OptionCardItem {
                             value: WindowPositionMode::Mouse,
                             icon: Some(IconName::Click),
-                            title: "Mouse Position".to_string(),
-                            description: Some("Open at the current mouse location".to_string()),
+                            title: t("preferences.window_position.mouse").to_string(),
+                            description: Some(t("preferences.window_position.mouse_description").to_string()),
                         },
                     ],
                     selected: window_config.default_position_mode,
@@ -98,19 +99,19 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
                     button {
                         class: "use-current-button",
                         onclick: use_current_position,
-                        "Use Current"
+                        {t("preferences.use_current")}
                     }
                 }
             }
 
-            h3 { class: "preference-section-title", "Behavior" }
+            h3 { class: "preference-section-title", {t("preferences.section.behavior")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On Startup" }
-                    p { class: "preference-description", "Which window position to use when the application starts." }
+                    label { {t("preferences.on_startup")} }
+                    p { class: "preference-description", {t("preferences.window_position.on_startup_description")} }
                 }
                 OptionCards {
                     name: "window-position-startup".to_string(),
@@ -118,14 +119,14 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default window position".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.window_position.use_default").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::LastClosed,
-                            title: "Last Closed".to_string(),
-                            description: Some("Resume from last closed window".to_string()),
+                            title: t("preferences.option.last_closed").to_string(),
+                            description: Some(t("preferences.option.resume_last_closed").to_string()),
                         },
                     ],
                     selected: window_config.on_startup,
@@ -140,8 +141,8 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On New Window" }
-                    p { class: "preference-description", "Which window position to use for new windows." }
+                    label { {t("preferences.on_new_window")} }
+                    p { class: "preference-description", {t("preferences.window_position.on_new_window_description")} }
                 }
                 OptionCards {
                     name: "window-position-new-window".to_string(),
@@ -149,14 +150,14 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default window position".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.window_position.use_default").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::LastFocused,
-                            title: "Last Focused".to_string(),
-                            description: Some("Same as current window".to_string()),
+                            title: t("preferences.option.last_focused").to_string(),
+                            description: Some(t("preferences.option.same_as_current").to_string()),
                         },
                     ],
                     selected: window_config.on_new_window,
@@ -171,8 +172,8 @@ pub fn WindowPositionTab(config: Signal<Config>, has_changes: Signal<bool>) -> E
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Position Offset" }
-                    p { class: "preference-description", "If another window already uses a nearby position, shift the new window by this offset (pixels only)." }
+                    label { {t("preferences.window_position.offset")} }
+                    p { class: "preference-description", {t("preferences.window_position.offset_description")} }
                 }
                 div {
                     class: "dimension-grid",
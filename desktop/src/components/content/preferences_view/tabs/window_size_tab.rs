@@ -2,6 +2,7 @@ use super::super::form_controls::{DimensionInput, OptionCardItem, OptionCards};
 use crate::config::{
     Config, NewWindowBehavior, StartupBehavior, WindowDimension, WindowDimensionUnit, WindowSize,
 };
+use crate::i18n::t;
 use dioxus::prelude::*;
 use dioxus_desktop::window;
 
@@ -27,14 +28,14 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
         div {
             class: "preferences-pane",
 
-            h3 { class: "preference-section-title", "Default Settings" }
+            h3 { class: "preference-section-title", {t("preferences.section.default_settings")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "Default Size" }
-                    p { class: "preference-description", "Set the default window size. Percent values are relative to the current screen." }
+                    label { {t("preferences.window_size.default_size")} }
+                    p { class: "preference-description", {t("preferences.window_size.default_size_description")} }
                 }
                 div {
                     class: "dimension-row",
@@ -42,7 +43,7 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
                         class: "dimension-grid",
                         div {
                             class: "dimension-field",
-                            label { "Width" }
+                            label { {t("preferences.window_size.width")} }
                             DimensionInput {
                                 value: window_config.default_size.width,
                                 min: 0.0,
@@ -56,7 +57,7 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
                         }
                         div {
                             class: "dimension-field",
-                            label { "Height" }
+                            label { {t("preferences.window_size.height")} }
                             DimensionInput {
                                 value: window_config.default_size.height,
                                 min: 0.0,
@@ -72,19 +73,19 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
                     button {
                         class: "use-current-button",
                         onclick: use_current_size,
-                        "Use Current"
+                        {t("preferences.use_current")}
                     }
                 }
             }
 
-            h3 { class: "preference-section-title", "Behavior" }
+            h3 { class: "preference-section-title", {t("preferences.section.behavior")} }
 
             div {
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On Startup" }
-                    p { class: "preference-description", "Which window size to use when the application starts." }
+                    label { {t("preferences.on_startup")} }
+                    p { class: "preference-description", {t("preferences.window_size.on_startup_description")} }
                 }
                 OptionCards {
                     name: "window-size-startup".to_string(),
@@ -92,14 +93,14 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default window size".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.window_size.use_default").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: StartupBehavior::LastClosed,
-                            title: "Last Closed".to_string(),
-                            description: Some("Resume from last closed window".to_string()),
+                            title: t("preferences.option.last_closed").to_string(),
+                            description: Some(t("preferences.option.resume_last_closed").to_string()),
                         },
                     ],
                     selected: window_config.on_startup,
@@ -114,8 +115,8 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
                 class: "preference-item",
                 div {
                     class: "preference-item-header",
-                    label { "On New Window" }
-                    p { class: "preference-description", "Which window size to use for new windows." }
+                    label { {t("preferences.on_new_window")} }
+                    p { class: "preference-description", {t("preferences.window_size.on_new_window_description")} }
                 }
                 OptionCards {
                     name: "window-size-new-window".to_string(),
@@ -123,14 +124,14 @@ pub fn WindowSizeTab(config: Signal<Config>, has_changes: Signal<bool>) -> Eleme
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::Default,
-                            title: "Default".to_string(),
-                            description: Some("Use default window size".to_string()),
+                            title: t("preferences.option.default").to_string(),
+                            description: Some(t("preferences.window_size.use_default").to_string()),
                         },
                         OptionCardItem {
                             icon: None,
                             value: NewWindowBehavior::LastFocused,
-                            title: "Last Focused".to_string(),
-                            description: Some("Same as current window".to_string()),
+                            title: t("preferences.option.last_focused").to_string(),
+                            description: Some(t("preferences.option.same_as_current").to_string()),
                         },
                     ],
                     selected: window_config.on_new_window,
@@ -24,6 +24,8 @@ struct SearchResultData {
     count: usize,
     current: usize,
     query: String,
+    /// Compile error of the query pattern
+    error: Option<String>,
     matches: Vec<SearchMatchData>,
     #[serde(rename = "pinnedMatches")]
     pinned_matches: HashMap<String, Vec<SearchMatchData>>,
@@ -72,6 +74,7 @@ pub fn use_search_handler(mut state: AppState) {
                 };
 
                 state.update_search_results_full(query, data.count, data.current, matches);
+                state.search_error.set(data.error);
 
                 // Convert pinned matches
                 let pinned_matches: HashMap<PinnedSearchId, Vec<crate::state::SearchMatch>> = data
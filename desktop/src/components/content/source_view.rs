@@ -0,0 +1,32 @@
+use dioxus::prelude::*;
+
+/// Raw Markdown with line numbers
+///
+/// The code is highlighted by the renderer, like fenced code blocks.
+#[component]
+pub fn SourceView(source: String) -> Element {
+    let line_count = source.lines().count().max(1);
+    let line_numbers = (1..=line_count)
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let code = format!(
+        r#"<code class="language-markdown">{}</code>"#,
+        html_escape::encode_text(&source)
+    );
+
+    rsx! {
+        div {
+            class: "source-view",
+            pre {
+                class: "source-gutter",
+                "aria-hidden": "true",
+                "{line_numbers}"
+            }
+            pre {
+                class: "source-code",
+                dangerous_inner_html: "{code}"
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use dioxus::document;
+use dioxus::prelude::*;
+
+use crate::config::{CONFIG, CONFIG_CHANGED};
+use crate::state::AppState;
+
+/// Hook to keep vim-style navigation in JavaScript in sync with the app.
+///
+/// Pushes the enabled flag from the keymap config (re-read whenever the config
+/// is saved) and the heading ids of the current document for `]]`/`[[`.
+pub fn use_vim_handler(state: AppState) {
+    let mut enabled = use_signal(|| CONFIG.read().keymap.vim_navigation);
+    use_future(move || async move {
+        let mut rx = CONFIG_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            let value = CONFIG.read().keymap.vim_navigation;
+            if *enabled.peek() != value {
+                enabled.set(value);
+            }
+        }
+    });
+
+    use_effect(move || {
+        let enabled = enabled();
+        spawn(async move {
+            let js = indoc::formatdoc! {r#"
+                (async () => {{
+                    while (!window.Arto?.vim?.setEnabled) {{
+                        await new Promise(resolve => setTimeout(resolve, 10));
+                    }}
+                    window.Arto.vim.setEnabled({enabled});
+                }})();
+            "#};
+            let _ = document::eval(&js).await;
+        });
+    });
+
+    use_effect(move || {
+        let ids: Vec<String> = state
+            .toc_headings
+            .read()
+            .iter()
+            .map(|heading| heading.id.clone())
+            .collect();
+        let json = serde_json::to_string(&ids).unwrap_or_else(|_| "[]".to_string());
+        spawn(async move {
+            let js = indoc::formatdoc! {r#"
+                (async () => {{
+                    while (!window.Arto?.vim?.setHeadings) {{
+                        await new Promise(resolve => setTimeout(resolve, 10));
+                    }}
+                    window.Arto.vim.setHeadings({json});
+                }})();
+            "#};
+            let _ = document::eval(&js).await;
+        });
+    });
+}
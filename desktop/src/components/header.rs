@@ -3,7 +3,9 @@ use dioxus::prelude::*;
 use crate::components::bookmark_button::BookmarkButton;
 use crate::components::icon::{Icon, IconName};
 use crate::components::theme_selector::ThemeSelector;
-use crate::state::AppState;
+use crate::i18n::t;
+use crate::state::{AppState, ViewMode};
+use crate::utils::file::is_markdown_file;
 
 #[component]
 pub fn Header() -> Element {
@@ -19,7 +21,7 @@ pub fn Header() -> Element {
                 .to_string_lossy()
                 .to_string()
         })
-        .unwrap_or_else(|| "No file opened".to_string());
+        .unwrap_or_else(|| t("header.no_file").to_string());
 
     let can_go_back = current_tab
         .as_ref()
@@ -30,6 +32,14 @@ pub fn Header() -> Element {
 
     let is_sidebar_open = state.sidebar.read().open;
 
+    // Source and side-by-side views only apply to Markdown files
+    let view_mode = current_tab
+        .as_ref()
+        .filter(|tab| {
+            matches!(&tab.content, crate::state::TabContent::File(path) if is_markdown_file(path))
+        })
+        .map(|tab| tab.view_mode);
+
     let on_back = move |_| {
         state.save_scroll_and_go_back();
     };
@@ -116,7 +126,7 @@ pub fn Header() -> Element {
                         button {
                             class: "nav-button copy-button",
                             class: if *is_copied.read() { "copied" },
-                            title: "Copy full path",
+                            title: t("header.copy_full_path"),
                             onclick: {
                                 let path_str = path.to_string_lossy().to_string();
                                 move |_| {
@@ -140,7 +150,7 @@ pub fn Header() -> Element {
                             class: "nav-button reload-button",
                             class: if *is_reloading.read() { "reloading" },
                             onclick: on_reload,
-                            title: "Reload file",
+                            title: t("header.reload"),
                             Icon { name: IconName::Refresh, size: 14 }
                         }
                     }
@@ -151,11 +161,32 @@ pub fn Header() -> Element {
             div {
                 class: "header-right",
 
+                // Rendered / source / side-by-side toggle
+                if let Some(view_mode) = view_mode {
+                    div {
+                        class: "view-mode-toggle",
+                        for (mode, icon, title) in [
+                            (ViewMode::Rendered, IconName::Eye, t("command.view_rendered")),
+                            (ViewMode::Source, IconName::Code, t("command.view_source")),
+                            (ViewMode::SideBySide, IconName::LayoutColumns, t("command.view_side_by_side")),
+                        ] {
+                            button {
+                                key: "{title}",
+                                class: "nav-button view-mode-button",
+                                class: if view_mode == mode { "active" },
+                                title: "{title}",
+                                onclick: move |_| state.set_view_mode(mode),
+                                Icon { name: icon, size: 18 }
+                            }
+                        }
+                    }
+                }
+
                 // Search button
                 button {
                     class: "nav-button search-button",
                     class: if *state.search_open.read() { "active" },
-                    title: "Search in page",
+                    title: t("header.search_in_page"),
                     onclick: move |_| {
                         let was_closed = !*state.search_open.read();
                         state.toggle_search();
@@ -179,7 +210,7 @@ pub fn Header() -> Element {
                 button {
                     class: "right-sidebar-toggle-button",
                     class: if *state.right_sidebar_open.read() { "active" },
-                    title: "Toggle Right Sidebar",
+                    title: t("header.toggle_right_sidebar"),
                     onclick: move |_| {
                         state.toggle_right_sidebar();
                     },
@@ -10,6 +10,7 @@ pub enum IconName {
     AlertTriangle,
     ArrowsDiagonal,
     ArrowsMove,
+    Book,
     BrandGithub,
     Bug,
     Check,
@@ -19,7 +20,9 @@ pub enum IconName {
     ChevronUp,
     Click,
     Close,
+    Code,
     Command,
+    Contrast,
     Copy,
     Download,
     ExternalLink,
@@ -30,9 +33,15 @@ pub enum IconName {
     Folder,
     FolderOpen,
     Gear,
+    Highlight,
+    History,
     InfoCircle,
+    Language,
+    LayoutColumns,
     List,
     Moon,
+    Palette,
+    Pencil,
     Photo,
     Pin,
     Refresh,
@@ -44,6 +53,7 @@ pub enum IconName {
     StarFilled,
     Sun,
     SunMoon,
+    Tag,
     Trash,
 }
 
@@ -55,6 +65,7 @@ impl fmt::Display for IconName {
             IconName::AlertTriangle => "alert-triangle",
             IconName::ArrowsDiagonal => "arrows-diagonal",
             IconName::ArrowsMove => "arrows-move",
+            IconName::Book => "book",
             IconName::BrandGithub => "brand-github",
             IconName::Bug => "bug",
             IconName::Check => "check",
@@ -64,7 +75,9 @@ impl fmt::Display for IconName {
             IconName::ChevronUp => "chevron-up",
             IconName::Click => "click",
             IconName::Close => "x",
+            IconName::Code => "code",
             IconName::Command => "command",
+            IconName::Contrast => "contrast",
             IconName::Copy => "copy",
             IconName::Download => "download",
             IconName::ExternalLink => "external-link",
@@ -75,9 +88,15 @@ impl fmt::Display for IconName {
             IconName::Folder => "folder",
             IconName::FolderOpen => "folder-open",
             IconName::Gear => "settings",
+            IconName::Highlight => "highlight",
+            IconName::History => "history",
             IconName::InfoCircle => "info-circle",
+            IconName::Language => "language",
+            IconName::LayoutColumns => "layout-columns",
             IconName::List => "list",
             IconName::Moon => "moon",
+            IconName::Palette => "palette",
+            IconName::Pencil => "pencil",
             IconName::Photo => "photo",
             IconName::Pin => "pin",
             IconName::Refresh => "refresh",
@@ -89,6 +108,7 @@ impl fmt::Display for IconName {
             IconName::StarFilled => "star-filled",
             IconName::Sun => "sun",
             IconName::SunMoon => "sun-moon",
+            IconName::Tag => "tag",
             IconName::Trash => "trash",
         };
         write!(f, "{}", name)
@@ -0,0 +1,300 @@
+use dioxus::desktop::tao::window::WindowId;
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+use crate::assets::MAIN_SCRIPT;
+use crate::components::icon::{Icon, IconName};
+use crate::components::theme_selector::ThemeSelector;
+use crate::events::OPEN_FILE_IN_WINDOW;
+use crate::i18n::{t, t_args};
+use crate::link_graph::{GraphData, LinkGraph};
+use crate::theme::Theme;
+use crate::watcher::FILE_WATCHER;
+
+/// Props for LinkGraphWindow component
+#[derive(Props, Clone, PartialEq)]
+pub struct LinkGraphWindowProps {
+    /// Directory whose Markdown files make up the graph
+    pub root: PathBuf,
+    /// Main window that opens the files clicked in the graph
+    pub parent_id: WindowId,
+    /// Initial theme
+    pub theme: Theme,
+}
+
+/// Link Graph Window Component
+#[component]
+pub fn LinkGraphWindow(props: LinkGraphWindowProps) -> Element {
+    let current_theme = use_signal(|| props.theme);
+    let zoom_level = use_signal(|| 100);
+    let graph = use_signal(|| None::<LinkGraph>);
+    let stats = use_signal(GraphStats::default);
+
+    // Build the graph, hand it to the viewer script and keep it up to date
+    use_link_graph_loader(props.root.clone(), graph, stats);
+
+    // Setup zoom update handler
+    use_zoom_update_handler(zoom_level);
+
+    // Open clicked files in the main window the graph belongs to
+    use_node_click_handler(props.parent_id);
+
+    let name = props
+        .root
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| props.root.to_string_lossy().to_string());
+    let stats = stats();
+
+    rsx! {
+        div {
+            class: "mermaid-window-container link-graph-window-container",
+
+            // Header with controls
+            div {
+                class: "mermaid-window-header",
+
+                div {
+                    class: "mermaid-window-title",
+                    title: "{props.root.display()}",
+                    {t_args("link_graph.title", &[("name", &name)])}
+                }
+
+                div {
+                    class: "mermaid-window-controls",
+                    ExportSvgButton {}
+                    ExportDotButton { graph }
+                    ThemeSelector { current_theme }
+                }
+            }
+
+            // Canvas container for the graph SVG
+            div {
+                id: "link-graph-canvas",
+                class: "mermaid-window-canvas link-graph-canvas",
+            }
+
+            // Status bar
+            div {
+                class: "mermaid-window-status",
+                {
+                    t_args(
+                        "link_graph.stats",
+                        &[
+                            ("files", &stats.files.to_string()),
+                            ("links", &stats.links.to_string()),
+                            ("orphans", &stats.orphans.to_string()),
+                        ],
+                    )
+                }
+                " | "
+                {t_args("viewer.zoom_help", &[("zoom", &zoom_level().to_string())])}
+            }
+        }
+    }
+}
+
+/// Counts shown in the status bar
+#[derive(Clone, Copy, Default, PartialEq)]
+struct GraphStats {
+    files: usize,
+    links: usize,
+    orphans: usize,
+}
+
+impl GraphStats {
+    fn of(data: &GraphData) -> Self {
+        Self {
+            files: data.nodes.len(),
+            links: data.edges.len(),
+            orphans: data.orphan_count(),
+        }
+    }
+}
+
+/// Hook to build the graph, load the viewer script and push updates on file changes
+fn use_link_graph_loader(
+    root: PathBuf,
+    graph: Signal<Option<LinkGraph>>,
+    stats: Signal<GraphStats>,
+) {
+    use_effect(move || {
+        let root = root.clone();
+        let mut graph = graph;
+        let mut stats = stats;
+
+        spawn(async move {
+            // Reading every file of the tree can take a while, keep it off the UI thread
+            let build_root = root.clone();
+            let Ok(built) =
+                tokio::task::spawn_blocking(move || LinkGraph::build(&build_root)).await
+            else {
+                return;
+            };
+            let data = built.data();
+            stats.set(GraphStats::of(&data));
+            graph.set(Some(built));
+
+            let eval_result = document::eval(&indoc::formatdoc! {r#"
+                (async () => {{
+                    try {{
+                        const {{ initLinkGraphWindow }} = await import("{MAIN_SCRIPT}");
+                        initLinkGraphWindow({data});
+                    }} catch (error) {{
+                        console.error("Failed to load link graph window module:", error);
+                    }}
+                }})();
+            "#, data = to_json(&data)});
+            if let Err(e) = eval_result.await {
+                tracing::error!("Failed to initialize link graph window: {}", e);
+            }
+
+            // Directory events carry no paths; the graph re-reads only modified files
+            let Ok(mut watcher) = FILE_WATCHER.watch_directory(root.clone()).await else {
+                tracing::error!(?root, "Failed to watch link graph directory");
+                return;
+            };
+            while watcher.recv().await.is_some() {
+                let Some(mut current) = graph.peek().clone() else {
+                    continue;
+                };
+                let Ok((current, changed)) = tokio::task::spawn_blocking(move || {
+                    let changed = current.refresh();
+                    (current, changed)
+                })
+                .await
+                else {
+                    continue;
+                };
+                if !changed {
+                    continue;
+                }
+
+                tracing::trace!(?root, "Link graph changed, updating");
+                let data = current.data();
+                stats.set(GraphStats::of(&data));
+                graph.set(Some(current));
+                let _ = document::eval(&format!(
+                    "window.linkGraphController?.update({});",
+                    to_json(&data)
+                ));
+            }
+
+            let _ = FILE_WATCHER.unwatch_directory(root).await;
+        });
+    });
+}
+
+fn to_json(data: &GraphData) -> String {
+    serde_json::to_string(data).unwrap_or_else(|_| "{\"nodes\":[],\"edges\":[]}".to_string())
+}
+
+/// Hook to listen for zoom updates from JavaScript
+fn use_zoom_update_handler(zoom_level: Signal<i32>) {
+    use_effect(move || {
+        let mut zoom_level = zoom_level;
+
+        spawn(async move {
+            let mut eval_provider = document::eval(indoc::indoc! {r#"
+                window.updateZoomLevel = (zoom) => {
+                    dioxus.send({ zoom: Math.round(zoom) });
+                };
+            "#});
+
+            while let Ok(data) = eval_provider.recv::<serde_json::Value>().await {
+                if let Some(zoom) = data.get("zoom").and_then(|v| v.as_i64()) {
+                    zoom_level.set(zoom as i32);
+                }
+            }
+        });
+    });
+}
+
+/// Hook to open the files of clicked nodes in the parent window
+fn use_node_click_handler(parent_id: WindowId) {
+    use_effect(move || {
+        spawn(async move {
+            let mut eval_provider = document::eval(indoc::indoc! {r#"
+                window.handleLinkGraphNodeClick = (path) => {
+                    dioxus.send(path);
+                };
+            "#});
+
+            while let Ok(path) = eval_provider.recv::<PathBuf>().await {
+                if OPEN_FILE_IN_WINDOW.send((parent_id, path)).is_err() {
+                    tracing::warn!(
+                        ?parent_id,
+                        "Failed to open file: parent window may be closed"
+                    );
+                }
+            }
+        });
+    });
+}
+
+/// Export the graph as it is drawn, as an SVG image
+#[component]
+fn ExportSvgButton() -> Element {
+    let handle_click = move |_| {
+        spawn(async move {
+            let mut eval = document::eval(indoc::indoc! {r#"
+                dioxus.send(window.linkGraphController?.exportSvg() ?? null);
+            "#});
+            let Ok(Some(svg)) = eval.recv::<Option<String>>().await else {
+                tracing::warn!("Link graph is not ready to export");
+                return;
+            };
+            save_export("link-graph.svg", t("link_graph.svg_filter"), "svg", svg).await;
+        });
+    };
+
+    rsx! {
+        button {
+            class: "viewer-control-btn",
+            "aria-label": t("link_graph.export_svg"),
+            title: t("link_graph.export_svg"),
+            onclick: handle_click,
+            Icon { name: IconName::Photo, size: 18 }
+        }
+    }
+}
+
+/// Export the graph as Graphviz DOT for further processing
+#[component]
+fn ExportDotButton(graph: Signal<Option<LinkGraph>>) -> Element {
+    let handle_click = move |_| {
+        let Some(dot) = graph.read().as_ref().map(LinkGraph::to_dot) else {
+            return;
+        };
+        spawn(save_export("link-graph.dot", "Graphviz DOT", "dot", dot));
+    };
+
+    rsx! {
+        button {
+            class: "viewer-control-btn",
+            "aria-label": t("link_graph.export_dot"),
+            title: t("link_graph.export_dot"),
+            onclick: handle_click,
+            Icon { name: IconName::Download, size: 18 }
+        }
+    }
+}
+
+/// Ask where to save an export and write it there
+async fn save_export(file_name: &str, filter_name: &str, extension: &str, contents: String) {
+    use rfd::AsyncFileDialog;
+
+    let Some(handle) = AsyncFileDialog::new()
+        .set_file_name(file_name)
+        .add_filter(filter_name, &[extension])
+        .save_file()
+        .await
+    else {
+        return;
+    };
+
+    match std::fs::write(handle.path(), contents) {
+        Ok(()) => tracing::info!(path = ?handle.path(), "Exported link graph"),
+        Err(e) => tracing::error!(%e, path = ?handle.path(), "Failed to export link graph"),
+    }
+}
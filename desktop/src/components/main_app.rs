@@ -1,4 +1,5 @@
-use crate::state::Tab;
+use crate::recent::{record_recent, RECENT_ITEMS_CHANGED};
+use crate::state::{Tab, TabContent};
 use crate::window as window_manager;
 use crate::window::{settings, CreateMainWindowConfigParams};
 use dioxus::core::spawn_forever;
@@ -49,13 +50,15 @@ fn handle_open_event(event: OpenEvent) {
             });
         }
         OpenEvent::Directory(dir) => {
+            record_recent(&dir);
             // Always create a new window for OS events (Finder, CLI, etc.)
             spawn(async move {
                 let params = CreateMainWindowConfigParams {
-                    directory: Some(dir),
+                    directory: Some(dir.clone()),
                     ..Default::default()
                 };
-                window_manager::create_new_main_window_with_empty(params).await;
+                // Show the directory's landing page in the first tab
+                window_manager::create_new_main_window_with_file(dir, params).await;
             });
         }
         OpenEvent::Reopen => {
@@ -108,6 +111,20 @@ pub fn MainApp() -> Element {
         crate::menu::handle_menu_event_global(event);
     });
 
+    // Keep the "Open Recent" menu in sync (the first window lives as long as the app)
+    use_future(|| async {
+        let mut rx = RECENT_ITEMS_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            crate::menu::apply_recent_items();
+        }
+    });
+
+    // Follow hand edits of config.json (the first window lives as long as the app)
+    use_future(crate::config::watch_config_file);
+
+    // Serve remote control clients (the first window lives as long as the app)
+    use_future(crate::remote::serve);
+
     // Get receiver and consume initial event
     let mut rx = OPEN_EVENT_RECEIVER
         .lock()
@@ -128,7 +145,17 @@ pub fn MainApp() -> Element {
     let is_first_window = true;
     let (tab, directory_override) = match &first_event {
         Some(OpenEvent::File(path)) => (Tab::new(path.clone()), None),
-        Some(OpenEvent::Directory(path)) => (Tab::default(), Some(path.clone())),
+        Some(OpenEvent::Directory(path)) => {
+            record_recent(path);
+            (Tab::new(path.clone()), Some(path.clone()))
+        }
+        _ if crate::live_preview::stdin_requested() => (
+            Tab {
+                content: TabContent::LivePreview(crate::live_preview::STDIN_PREVIEW.to_string()),
+                ..Default::default()
+            },
+            None,
+        ),
         _ => {
             let welcome_content = crate::assets::get_default_markdown_content();
             (Tab::with_inline_content(welcome_content), None)
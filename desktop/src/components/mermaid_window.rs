@@ -4,6 +4,7 @@ use sha2::{Digest, Sha256};
 use crate::assets::MAIN_SCRIPT;
 use crate::components::icon::{Icon, IconName};
 use crate::components::theme_selector::ThemeSelector;
+use crate::i18n::{t, t_args};
 use crate::theme::Theme;
 
 /// Props for MermaidWindow component
@@ -80,7 +81,7 @@ pub fn MermaidWindow(props: MermaidWindowProps) -> Element {
             // Status bar
             div {
                 class: "mermaid-window-status",
-                "Zoom: {zoom_level}% | Scroll to zoom, drag to pan, double-click to fit"
+                {t_args("viewer.zoom_help", &[("zoom", &zoom_level().to_string())])}
             }
         }
     }
@@ -191,8 +192,8 @@ fn CopyImageButton() -> Element {
     rsx! {
         button {
             class: "viewer-control-btn {extra_class}",
-            "aria-label": "Copy diagram as image",
-            title: "Copy diagram as image",
+            "aria-label": t("mermaid.copy_image"),
+            title: t("mermaid.copy_image"),
             onclick: handle_click,
             Icon { name: icon, size: 18 }
         }
@@ -3,12 +3,15 @@
 //! Displays pinned search items as compact chips with color and settings popover.
 
 use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
 
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 use crate::pinned_search::{
-    remove_pinned_search, set_pinned_search_color, toggle_pinned_search_disabled, HighlightColor,
-    PinnedSearch,
+    remove_pinned_search, set_pinned_search_color, set_pinned_search_kind, set_pinned_search_scope,
+    toggle_pinned_search_disabled, HighlightColor, PatternKind, PinnedSearch, SearchScope,
 };
+use crate::state::AppState;
 
 /// Pinned chips row displayed below the search bar.
 ///
@@ -47,11 +50,22 @@ pub fn PinnedChip(pinned: PinnedSearch) -> Element {
     let pattern = pinned.pattern.clone();
     let color = pinned.color;
     let disabled = pinned.disabled;
+    let kind = pinned.kind;
+    let scope = pinned.scope.clone();
+    let error = pinned.validate().err().map(|e| e.to_string());
 
-    let chip_class = if disabled {
-        format!("pinned-chip disabled {}", color.css_class())
-    } else {
-        format!("pinned-chip {}", color.css_class())
+    let mut chip_class = format!("pinned-chip {}", color.css_class());
+    if disabled {
+        chip_class.push_str(" disabled");
+    }
+    if error.is_some() {
+        chip_class.push_str(" invalid");
+    }
+
+    // Describe how the pattern is matched in the tooltip
+    let title = match &error {
+        Some(error) => error.clone(),
+        None => format!("{} · {}", kind.label(), scope.describe()),
     };
 
     rsx! {
@@ -61,18 +75,24 @@ pub fn PinnedChip(pinned: PinnedSearch) -> Element {
             // Chip body (clickable to show edit popover)
             button {
                 class: "pinned-chip-body",
-                title: "Edit",
+                title: "{title}",
                 onclick: move |e| {
                     e.stop_propagation();
                     show_popover.toggle();
                 },
+                if kind != PatternKind::Text {
+                    span { class: "pinned-chip-kind", {kind_symbol(kind)} }
+                }
                 "{pattern}"
+                if scope != SearchScope::All {
+                    Icon { name: IconName::Folder, size: 12 }
+                }
             }
 
             // Remove button (X)
             button {
                 class: "pinned-chip-remove",
-                title: "Remove",
+                title: t("search.remove"),
                 onclick: {
                     let id = id.clone();
                     move |e| {
@@ -87,6 +107,8 @@ pub fn PinnedChip(pinned: PinnedSearch) -> Element {
             if *show_popover.read() {
                 ColorPalettePopover {
                     current_color: color,
+                    current_kind: kind,
+                    current_scope: scope,
                     is_disabled: disabled,
                     on_select: {
                         let id = id.clone();
@@ -95,6 +117,18 @@ pub fn PinnedChip(pinned: PinnedSearch) -> Element {
                             show_popover.set(false);
                         }
                     },
+                    on_kind: {
+                        let id = id.clone();
+                        move |k| {
+                            set_pinned_search_kind(&id, k);
+                        }
+                    },
+                    on_scope: {
+                        let id = id.clone();
+                        move |s| {
+                            set_pinned_search_scope(&id, s);
+                        }
+                    },
                     on_toggle: {
                         let id = id.clone();
                         move |_| {
@@ -116,16 +150,56 @@ pub fn PinnedChip(pinned: PinnedSearch) -> Element {
     }
 }
 
+/// Symbol shown on chips and toggles for a pattern kind.
+fn kind_symbol(kind: PatternKind) -> &'static str {
+    match kind {
+        PatternKind::Text => "Aa",
+        PatternKind::Word => "ab",
+        PatternKind::Regex => ".*",
+    }
+}
+
+/// Scope choices offered for the file of the current tab.
+fn scope_options(file: Option<&Path>) -> Vec<(&'static str, SearchScope)> {
+    let mut options = vec![(t("search.scope.all"), SearchScope::All)];
+    if let Some(file) = file {
+        if let Some(dir) = file.parent() {
+            options.push((t("search.scope.this_folder"), SearchScope::directory(dir)));
+        }
+        options.push((
+            t("search.scope.this_file"),
+            SearchScope::Files {
+                paths: vec![file.to_path_buf()],
+            },
+        ));
+    }
+    options
+}
+
 /// Color palette popover for pinned search settings.
 #[component]
 fn ColorPalettePopover(
     current_color: HighlightColor,
+    current_kind: PatternKind,
+    current_scope: SearchScope,
     is_disabled: bool,
     on_select: EventHandler<HighlightColor>,
+    on_kind: EventHandler<PatternKind>,
+    on_scope: EventHandler<SearchScope>,
     on_toggle: EventHandler<()>,
     on_remove: EventHandler<()>,
     on_close: EventHandler<()>,
 ) -> Element {
+    let state = use_context::<AppState>();
+    let file: Option<PathBuf> = state
+        .current_tab()
+        .and_then(|tab| tab.file().map(Path::to_path_buf));
+    let mut scopes = scope_options(file.as_deref());
+    // Keep custom scopes (e.g. edited in the JSON file) selectable
+    if !scopes.iter().any(|(_, scope)| *scope == current_scope) {
+        scopes.push((t("search.scope.custom"), current_scope.clone()));
+    }
+
     rsx! {
         // Backdrop to close on outside click
         div {
@@ -157,7 +231,7 @@ fn ColorPalettePopover(
             // Visibility toggle (Eye icon)
             button {
                 class: "color-palette-action",
-                title: if is_disabled { "Enable" } else { "Disable" },
+                title: if is_disabled { t("search.enable") } else { t("search.disable") },
                 onclick: move |_| on_toggle.call(()),
                 if is_disabled {
                     Icon { name: IconName::EyeOff, size: 18 }
@@ -169,10 +243,39 @@ fn ColorPalettePopover(
             // Remove button
             button {
                 class: "color-palette-action color-palette-remove",
-                title: "Remove",
+                title: t("search.remove"),
                 onclick: move |_| on_remove.call(()),
                 Icon { name: IconName::Trash, size: 18 }
             }
+
+            // Pattern kind
+            div {
+                class: "color-palette-options",
+                for kind in PatternKind::ALL {
+                    button {
+                        class: "color-palette-option",
+                        class: if kind == current_kind { "selected" },
+                        title: kind.label(),
+                        onclick: move |_| on_kind.call(kind),
+                        span { class: "pinned-chip-kind", {kind_symbol(kind)} }
+                        {kind.label()}
+                    }
+                }
+            }
+
+            // Scope
+            div {
+                class: "color-palette-options",
+                for (label, scope) in scopes {
+                    button {
+                        class: "color-palette-option",
+                        class: if scope == current_scope { "selected" },
+                        title: scope.describe(),
+                        onclick: move |_| on_scope.call(scope.clone()),
+                        {label}
+                    }
+                }
+            }
         }
     }
 }
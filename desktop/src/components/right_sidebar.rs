@@ -1,13 +1,19 @@
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
+mod annotations_tab;
 mod contents_tab;
+mod info_tab;
 mod search_tab;
 mod tab_bar;
+mod tasks_tab;
 
+use annotations_tab::AnnotationsTab;
 use contents_tab::ContentsTab;
+use info_tab::InfoTab;
 use search_tab::SearchTab;
 use tab_bar::TabBar;
+use tasks_tab::TasksTab;
 
 use crate::markdown::HeadingInfo;
 use crate::state::AppState;
@@ -18,6 +24,19 @@ pub enum RightSidebarTab {
     #[default]
     Contents,
     Search,
+    Annotations,
+    Tasks,
+    Info,
+}
+
+/// Files scanned for task list items by the Tasks tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskScope {
+    /// The document shown in the current tab
+    #[default]
+    Document,
+    /// Every Markdown file under the sidebar root directory
+    Directory,
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -67,6 +86,9 @@ pub fn RightSidebar(props: RightSidebarProps) -> Element {
                 match active_tab {
                     RightSidebarTab::Contents => rsx! { ContentsTab { headings } },
                     RightSidebarTab::Search => rsx! { SearchTab {} },
+                    RightSidebarTab::Annotations => rsx! { AnnotationsTab {} },
+                    RightSidebarTab::Tasks => rsx! { TasksTab {} },
+                    RightSidebarTab::Info => rsx! { InfoTab {} },
                 }
             }
         }
@@ -0,0 +1,182 @@
+use dioxus::document;
+use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
+
+use crate::annotations::{
+    export_annotations, remove_annotation, set_annotation_color, set_annotation_comment,
+    Annotation, ANNOTATIONS, ANNOTATIONS_CHANGED,
+};
+use crate::components::icon::{Icon, IconName};
+use crate::i18n::{t, t_args};
+use crate::pinned_search::HighlightColor;
+use crate::state::AppState;
+
+#[component]
+pub fn AnnotationsTab() -> Element {
+    let state = use_context::<AppState>();
+    let current_file = state
+        .current_tab()
+        .and_then(|tab| tab.file().map(PathBuf::from));
+    let detached = state.detached_annotations.read().clone();
+
+    // Bumped on every annotation change broadcast to re-read ANNOTATIONS
+    let mut revision = use_signal(|| 0usize);
+    use_future(move || async move {
+        let mut rx = ANNOTATIONS_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            revision += 1;
+        }
+    });
+    let _ = revision();
+
+    let Some(file) = current_file else {
+        return rsx! {
+            div {
+                class: "right-sidebar-annotations",
+                div {
+                    class: "right-sidebar-annotations-empty",
+                    {t("annotations.no_file")}
+                }
+            }
+        };
+    };
+
+    let annotations = ANNOTATIONS.read().for_file(&file).to_vec();
+
+    rsx! {
+        div {
+            class: "right-sidebar-annotations",
+
+            if annotations.is_empty() {
+                div {
+                    class: "right-sidebar-annotations-empty",
+                    {t_args("annotations.empty", &[("command", t("context_menu.highlight_selection"))])}
+                }
+            } else {
+                button {
+                    class: "right-sidebar-annotations-export",
+                    onclick: {
+                        let file = file.clone();
+                        move |_| export_to_html(&file)
+                    },
+                    Icon { name: IconName::Download, size: 14 }
+                    {t("annotations.export")}
+                }
+                ul {
+                    class: "right-sidebar-annotations-list",
+                    for annotation in annotations {
+                        AnnotationItem {
+                            key: "{annotation.id}",
+                            is_detached: detached.contains(&annotation.id),
+                            file: file.clone(),
+                            annotation,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AnnotationItem(file: PathBuf, annotation: Annotation, is_detached: bool) -> Element {
+    let id = annotation.id.clone();
+    let color = annotation.color;
+
+    rsx! {
+        li {
+            class: "right-sidebar-annotation",
+            class: if is_detached { "detached" },
+
+            // Quote (click to scroll to the highlight)
+            div {
+                class: "right-sidebar-annotation-quote {color.css_class()}",
+                title: if is_detached { t("annotations.detached") },
+                onclick: {
+                    let id = id.to_string();
+                    move |_| scroll_to_annotation(&id)
+                },
+                "{annotation.anchor.quote}"
+            }
+
+            // Comment (saved when the textarea loses focus)
+            textarea {
+                class: "right-sidebar-annotation-comment",
+                placeholder: t("annotations.comment_placeholder"),
+                rows: 2,
+                value: "{annotation.comment}",
+                onchange: {
+                    let file = file.clone();
+                    let id = id.clone();
+                    move |evt: Event<FormData>| {
+                        set_annotation_comment(&file, &id, evt.value());
+                    }
+                },
+            }
+
+            // Color swatches + remove button
+            div {
+                class: "right-sidebar-annotation-actions",
+                for swatch in HighlightColor::ALL {
+                    button {
+                        class: if swatch == color {
+                            format!("color-palette-swatch selected {}", swatch.css_class())
+                        } else {
+                            format!("color-palette-swatch {}", swatch.css_class())
+                        },
+                        onclick: {
+                            let file = file.clone();
+                            let id = id.clone();
+                            move |_| {
+                                set_annotation_color(&file, &id, swatch);
+                            }
+                        },
+                    }
+                }
+
+                if is_detached {
+                    span {
+                        class: "right-sidebar-annotation-detached",
+                        Icon { name: IconName::AlertTriangle, size: 14 }
+                        {t("annotations.not_found")}
+                    }
+                }
+
+                button {
+                    class: "right-sidebar-annotation-remove",
+                    title: t("annotations.remove"),
+                    onclick: move |_| {
+                        remove_annotation(&file, &id);
+                    },
+                    Icon { name: IconName::Trash, size: 14 }
+                }
+            }
+        }
+    }
+}
+
+/// Ask for a destination and export the annotations of a file as HTML.
+fn export_to_html(file: &Path) {
+    let name = file
+        .file_stem()
+        .unwrap_or(file.as_os_str())
+        .to_string_lossy();
+    let Some(dest) = rfd::FileDialog::new()
+        .add_filter("HTML", &["html"])
+        .set_file_name(format!("{name}.html"))
+        .save_file()
+    else {
+        return;
+    };
+    if let Err(e) = export_annotations(file, &dest) {
+        tracing::error!(?e, ?dest, "Failed to export annotations");
+    }
+}
+
+/// Scroll the content to an annotation highlight.
+fn scroll_to_annotation(id: &str) {
+    let js = format!("window.Arto.annotations.scrollTo('{}');", id);
+    spawn(async move {
+        let _ = document::eval(&js).await;
+    });
+}
@@ -1,10 +1,87 @@
 use dioxus::document;
 use dioxus::prelude::*;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
-use crate::markdown::HeadingInfo;
+use crate::bookmarks::{toggle_bookmark_target, BookmarkTarget, BOOKMARKS, BOOKMARKS_CHANGED};
+use crate::components::icon::{Icon, IconName};
+use crate::config::{CONFIG, CONFIG_CHANGED};
+use crate::editor::open_heading_in_editor;
+use crate::i18n::t;
+use crate::markdown::{build_heading_tree, filter_heading_tree, HeadingInfo, HeadingNode};
+use crate::state::AppState;
+
+/// Table of contents settings from the right sidebar config
+#[derive(Clone, Copy, PartialEq)]
+struct TocSettings {
+    max_depth: usize,
+    numbering: bool,
+}
+
+impl TocSettings {
+    fn load() -> Self {
+        let config = CONFIG.read();
+        Self {
+            max_depth: config.right_sidebar.toc_max_depth,
+            numbering: config.right_sidebar.toc_numbering,
+        }
+    }
+}
 
 #[component]
 pub fn ContentsTab(headings: Vec<HeadingInfo>) -> Element {
+    let state = use_context::<AppState>();
+    let current_file = state
+        .current_tab()
+        .and_then(|tab| tab.file().map(Path::to_path_buf));
+
+    // Bumped on bookmark changes so heading stars reflect other windows
+    let mut bookmarks_version = use_signal(|| 0_u64);
+    use_future(move || async move {
+        let mut rx = BOOKMARKS_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            bookmarks_version += 1;
+        }
+    });
+    let _ = bookmarks_version();
+
+    // Follow depth and numbering changes made in Preferences
+    let mut settings = use_signal(TocSettings::load);
+    use_future(move || async move {
+        let mut rx = CONFIG_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            settings.set(TocSettings::load());
+        }
+    });
+
+    let mut filter = use_signal(String::new);
+    let active_heading = use_scroll_spy(&headings);
+
+    let TocSettings {
+        max_depth,
+        numbering,
+    } = settings();
+    let tree = build_heading_tree(&headings, max_depth);
+    let query = filter();
+    let filtering = !query.trim().is_empty();
+    let visible = filter_heading_tree(&tree, &query);
+
+    let bookmarked: HashSet<String> = match current_file.as_deref() {
+        Some(file) => headings
+            .iter()
+            .filter(|heading| {
+                BOOKMARKS.read().contains_target(
+                    file,
+                    &BookmarkTarget::Heading {
+                        slug: heading.id.clone(),
+                    },
+                )
+            })
+            .map(|heading| heading.id.clone())
+            .collect(),
+        None => HashSet::new(),
+    };
+
     rsx! {
         div {
             class: "right-sidebar-contents",
@@ -12,13 +89,45 @@ pub fn ContentsTab(headings: Vec<HeadingInfo>) -> Element {
             if headings.is_empty() {
                 div {
                     class: "right-sidebar-contents-empty",
-                    "No headings found"
+                    {t("contents.no_headings")}
                 }
             } else {
-                ul {
-                    class: "right-sidebar-contents-list",
-                    for heading in headings.iter() {
-                        HeadingItem { heading: heading.clone() }
+                div {
+                    class: "right-sidebar-contents-filter",
+                    Icon { name: IconName::Search, size: 12 }
+                    input {
+                        r#type: "text",
+                        placeholder: t("contents.filter_placeholder"),
+                        value: "{query}",
+                        oninput: move |evt| filter.set(evt.value()),
+                        onkeydown: move |evt| {
+                            if evt.key() == Key::Escape {
+                                filter.set(String::new());
+                            }
+                        },
+                    }
+                }
+
+                if visible.is_empty() {
+                    div {
+                        class: "right-sidebar-contents-empty",
+                        {t("contents.no_matching_headings")}
+                    }
+                } else {
+                    ul {
+                        class: "right-sidebar-contents-list",
+                        for node in visible {
+                            HeadingItem {
+                                key: "{node.heading.id}",
+                                node,
+                                depth: 0,
+                                file: current_file.clone(),
+                                active: active_heading(),
+                                bookmarked: bookmarked.clone(),
+                                numbering,
+                                filtering,
+                            }
+                        }
                     }
                 }
             }
@@ -26,36 +135,231 @@ pub fn ContentsTab(headings: Vec<HeadingInfo>) -> Element {
     }
 }
 
+/// Track the heading currently in view from scroll events of the content area
+///
+/// The last heading whose top has scrolled past the top of the content area
+/// is reported back through `dioxus.send`.
+fn use_scroll_spy(headings: &[HeadingInfo]) -> Signal<Option<String>> {
+    let mut active_heading = use_signal(|| None::<String>);
+    let ids: Vec<String> = headings.iter().map(|h| h.id.clone()).collect();
+
+    use_effect(use_reactive!(|ids| {
+        let ids_json = serde_json::to_string(&ids).unwrap_or_else(|_| "[]".to_string());
+        let mut eval = document::eval(&format!(
+            r#"
+            const content = document.querySelector('.content');
+            if (content) {{
+                if (window.__artoTocSpyHandler) {{
+                    content.removeEventListener('scroll', window.__artoTocSpyHandler);
+                }}
+
+                const ids = {ids_json};
+                let lastId;
+                let frame = 0;
+                const update = () => {{
+                    frame = 0;
+                    // A heading is current once it reaches the top of the content area
+                    const threshold = content.getBoundingClientRect().top + 24;
+                    let current = null;
+                    for (const id of ids) {{
+                        const el = document.getElementById(id);
+                        if (!el) continue;
+                        if (el.getBoundingClientRect().top > threshold) break;
+                        current = id;
+                    }}
+                    if (current !== lastId) {{
+                        lastId = current;
+                        dioxus.send(current);
+                    }}
+                }};
+
+                window.__artoTocSpyHandler = () => {{
+                    if (!frame) frame = requestAnimationFrame(update);
+                }};
+                content.addEventListener('scroll', window.__artoTocSpyHandler, {{ passive: true }});
+
+                // Content may still be rendering, so check again shortly
+                update();
+                setTimeout(update, 300);
+            }}
+            "#
+        ));
+
+        spawn(async move {
+            while let Ok(id) = eval.recv::<Option<String>>().await {
+                active_heading.set(id);
+                // Keep the highlighted entry visible in long outlines
+                let _ = document::eval(
+                    "document.querySelector('.right-sidebar-contents-item-button.active')?.scrollIntoView({ block: 'nearest' });",
+                )
+                .await;
+            }
+        });
+    }));
+
+    use_drop(|| {
+        document::eval(
+            r#"
+            const content = document.querySelector('.content');
+            if (content && window.__artoTocSpyHandler) {
+                content.removeEventListener('scroll', window.__artoTocSpyHandler);
+            }
+            window.__artoTocSpyHandler = null;
+            "#,
+        );
+    });
+
+    active_heading
+}
+
 #[component]
-fn HeadingItem(heading: HeadingInfo) -> Element {
-    let id = heading.id.clone();
-    let level = heading.level;
+fn HeadingItem(
+    node: HeadingNode,
+    depth: usize,
+    file: Option<PathBuf>,
+    active: Option<String>,
+    bookmarked: HashSet<String>,
+    numbering: bool,
+    filtering: bool,
+) -> Element {
+    let mut state = use_context::<AppState>();
+    let id = node.heading.id.clone();
+    let level = node.heading.level;
+    let has_children = !node.children.is_empty();
+    let collapse_key = file.clone().unwrap_or_default();
+
+    // Filtering shows every match regardless of collapsed sections
+    let collapsed = !filtering
+        && has_children
+        && state
+            .toc_collapsed
+            .read()
+            .get(&collapse_key)
+            .is_some_and(|ids| ids.contains(&id));
+
+    // Highlight the deepest visible entry containing the current heading
+    let is_active = active.as_deref().is_some_and(|active| {
+        node.contains(active)
+            && (collapsed || !node.children.iter().any(|child| child.contains(active)))
+    });
+    let is_bookmarked = bookmarked.contains(&id);
+    let number = node.number_label();
 
     rsx! {
         li {
-            class: "right-sidebar-contents-item",
-            "data-level": "{level}",
-
-            button {
-                class: "right-sidebar-contents-item-button",
-                onclick: move |_| {
-                    let id = id.clone();
-                    spawn(async move {
-                        let js = format!(
-                            r#"
-                            (() => {{
-                                const el = document.getElementById('{}');
-                                if (el) {{
-                                    el.scrollIntoView({{ behavior: 'smooth', block: 'start' }});
-                                }}
-                            }})();
-                            "#,
-                            id
-                        );
-                        let _ = document::eval(&js).await;
-                    });
-                },
-                "{heading.text}"
+            class: "right-sidebar-contents-node",
+
+            div {
+                class: "right-sidebar-contents-item",
+                "data-level": "{level}",
+                "data-depth": "{depth}",
+
+                if has_children {
+                    button {
+                        class: "right-sidebar-contents-item-toggle",
+                        disabled: filtering,
+                        onclick: {
+                            let id = id.clone();
+                            move |_| {
+                                let mut collapsed_map = state.toc_collapsed.write();
+                                let ids = collapsed_map.entry(collapse_key.clone()).or_default();
+                                if !ids.remove(&id) {
+                                    ids.insert(id.clone());
+                                }
+                            }
+                        },
+                        Icon {
+                            name: if collapsed { IconName::ChevronRight } else { IconName::ChevronDown },
+                            size: 12,
+                        }
+                    }
+                } else {
+                    span { class: "right-sidebar-contents-item-toggle-spacer" }
+                }
+
+                button {
+                    class: "right-sidebar-contents-item-button",
+                    class: if is_active { "active" },
+                    title: "{node.heading.text}",
+                    onclick: {
+                        let id = id.clone();
+                        move |_| {
+                            let id = id.clone();
+                            spawn(async move {
+                                let js = format!(
+                                    r#"
+                                    (() => {{
+                                        const el = document.getElementById('{}');
+                                        if (el) {{
+                                            el.scrollIntoView({{ behavior: 'smooth', block: 'start' }});
+                                        }}
+                                    }})();
+                                    "#,
+                                    id
+                                );
+                                let _ = document::eval(&js).await;
+                            });
+                        }
+                    },
+                    if numbering {
+                        span { class: "right-sidebar-contents-item-number", "{number}" }
+                    }
+                    "{node.heading.text}"
+                }
+
+                // Open the heading in the external editor
+                if let Some(file) = file.clone() {
+                    button {
+                        class: "right-sidebar-contents-item-edit",
+                        title: t("context_menu.edit_here"),
+                        onclick: {
+                            let id = id.clone();
+                            move |_| open_heading_in_editor(&file, &id)
+                        },
+                        Icon { name: IconName::Pencil, size: 12 }
+                    }
+                }
+
+                // Bookmark this heading in Quick Access
+                if let Some(file) = file.clone() {
+                    button {
+                        class: "right-sidebar-contents-item-bookmark",
+                        class: if is_bookmarked { "bookmarked" },
+                        title: if is_bookmarked { t("contents.remove_from_quick_access") } else { t("contents.add_to_quick_access") },
+                        onclick: {
+                            let heading = node.heading.clone();
+                            move |_| {
+                                toggle_bookmark_target(
+                                    &file,
+                                    BookmarkTarget::Heading { slug: heading.id.clone() },
+                                    Some(heading.text.clone()),
+                                );
+                            }
+                        },
+                        Icon {
+                            name: if is_bookmarked { IconName::StarFilled } else { IconName::Star },
+                            size: 12,
+                        }
+                    }
+                }
+            }
+
+            if has_children && !collapsed {
+                ul {
+                    class: "right-sidebar-contents-list",
+                    for child in node.children.iter().cloned() {
+                        HeadingItem {
+                            key: "{child.heading.id}",
+                            node: child,
+                            depth: depth + 1,
+                            file: file.clone(),
+                            active: active.clone(),
+                            bookmarked: bookmarked.clone(),
+                            numbering,
+                            filtering,
+                        }
+                    }
+                }
             }
         }
     }
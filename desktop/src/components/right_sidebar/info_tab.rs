@@ -0,0 +1,72 @@
+use dioxus::prelude::*;
+
+use crate::i18n::{t, t_args};
+use crate::state::AppState;
+
+/// Document Info panel showing the frontmatter metadata of the current document
+#[component]
+pub fn InfoTab() -> Element {
+    let mut state = use_context::<AppState>();
+    let metadata = state.document_metadata.read().clone();
+
+    let Some(metadata) = metadata else {
+        return rsx! {
+            div {
+                class: "right-sidebar-info",
+                div {
+                    class: "right-sidebar-info-empty",
+                    {t("info.no_frontmatter")}
+                }
+            }
+        };
+    };
+
+    rsx! {
+        div {
+            class: "right-sidebar-info",
+
+            if let Some(title) = metadata.title.clone() {
+                div { class: "right-sidebar-info-title", "{title}" }
+            }
+            if let Some(description) = metadata.description.clone() {
+                div { class: "right-sidebar-info-description", "{description}" }
+            }
+
+            if !metadata.tags.is_empty() {
+                div {
+                    class: "right-sidebar-info-tags",
+                    for tag in metadata.tags.iter().cloned() {
+                        button {
+                            key: "{tag}",
+                            class: "right-sidebar-info-tag",
+                            title: t_args("info.show_tagged", &[("tag", &tag)]),
+                            onclick: {
+                                let tag = tag.clone();
+                                move |_| state.reveal_tag(&tag)
+                            },
+                            "#{tag}"
+                        }
+                    }
+                }
+            }
+
+            table {
+                class: "right-sidebar-info-fields",
+                tbody {
+                    for (index, (key, value)) in metadata.fields.iter().cloned().enumerate() {
+                        tr {
+                            key: "{index}",
+                            th { "{key}" }
+                            td { "{value}" }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "right-sidebar-info-format",
+                {t_args("info.format", &[("format", metadata.format.label())])}
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@ use dioxus::document;
 use dioxus::prelude::*;
 
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::{t, t_args};
 use crate::pinned_search::{PinnedSearch, PINNED_SEARCHES, PINNED_SEARCHES_CHANGED};
 use crate::state::{AppState, SearchMatch};
 
@@ -55,7 +56,14 @@ pub fn SearchTab() -> Element {
 
             // Pinned search results (for all pinned searches, including disabled)
             for pinned in all_pinned.iter() {
-                if let Some(matches) = pinned_matches.get(&pinned.id) {
+                // Invalid patterns are never highlighted, so report the error instead
+                if pinned.validate().is_err() {
+                    PinnedResultsSection {
+                        key: "{pinned.id}",
+                        pinned: pinned.clone(),
+                        matches: Vec::new(),
+                    }
+                } else if let Some(matches) = pinned_matches.get(&pinned.id) {
                     if !matches.is_empty() {
                         PinnedResultsSection {
                             key: "{pinned.id}",
@@ -79,7 +87,7 @@ fn SearchTabPlaceholder() -> Element {
     rsx! {
         div {
             class: "right-sidebar-search-placeholder",
-            "Type in the search bar or add pinned searches"
+            {t("search.no_query")}
         }
     }
 }
@@ -88,6 +96,10 @@ fn SearchTabPlaceholder() -> Element {
 #[component]
 fn SearchResultsSection(query: String, matches: Vec<SearchMatch>, current_index: usize) -> Element {
     let mut expanded = use_signal(|| true);
+    let match_count = t_args(
+        "search.match_count",
+        &[("count", &matches.len().to_string())],
+    );
     let chevron = if *expanded.read() {
         IconName::ChevronDown
     } else {
@@ -106,7 +118,7 @@ fn SearchResultsSection(query: String, matches: Vec<SearchMatch>, current_index:
                 Icon { name: chevron, size: 14 }
                 Icon { name: IconName::Search, size: 14 }
                 span { class: "right-sidebar-search-query", "\"{query}\"" }
-                span { class: "right-sidebar-search-count", " - {matches.len()} matches" }
+                span { class: "right-sidebar-search-count", " - {match_count}" }
             }
 
             // Match list (collapsible)
@@ -114,7 +126,7 @@ fn SearchResultsSection(query: String, matches: Vec<SearchMatch>, current_index:
                 if matches.is_empty() {
                     div {
                         class: "right-sidebar-search-empty",
-                        "No matches found"
+                        {t("search.no_matches_found")}
                     }
                 } else {
                     ul {
@@ -3,6 +3,7 @@ use dioxus::prelude::*;
 
 use crate::components::icon::{Icon, IconName};
 use crate::components::pinned_chips::PinnedChip;
+use crate::i18n::{t, t_args};
 use crate::pinned_search::{HighlightColor, PinnedSearch};
 use crate::state::SearchMatch;
 
@@ -21,6 +22,7 @@ pub fn PinnedResultsSection(pinned: PinnedSearch, matches: Vec<SearchMatch>) ->
     let color = pinned.color;
     let count = matches.len();
     let pinned_id = pinned.id.clone();
+    let error = pinned.validate().err();
 
     rsx! {
         div {
@@ -34,15 +36,22 @@ pub fn PinnedResultsSection(pinned: PinnedSearch, matches: Vec<SearchMatch>) ->
                 Icon { name: chevron, size: 14 }
                 Icon { name: IconName::Pin, size: 14 }
                 PinnedChip { pinned: pinned.clone() }
-                span { class: "right-sidebar-pinned-results-count", "{count} matches" }
+                if error.is_none() {
+                    span { class: "right-sidebar-pinned-results-count", {t_args("search.match_count", &[("count", &count.to_string())])} }
+                }
             }
 
             // Match list (collapsible)
             if *expanded.read() {
-                if matches.is_empty() {
+                if let Some(error) = error {
+                    pre {
+                        class: "right-sidebar-pinned-results-error",
+                        "{error}"
+                    }
+                } else if matches.is_empty() {
                     div {
                         class: "right-sidebar-pinned-results-empty",
-                        "No matches"
+                        {t("search.no_matches")}
                     }
                 } else {
                     ul {
@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 
 use super::RightSidebarTab;
+use crate::i18n::t;
 
 #[component]
 pub fn TabBar(active_tab: RightSidebarTab, on_change: EventHandler<RightSidebarTab>) -> Element {
@@ -12,14 +13,35 @@ pub fn TabBar(active_tab: RightSidebarTab, on_change: EventHandler<RightSidebarT
             button {
                 class: if active_tab == RightSidebarTab::Contents { "right-sidebar-tab active" } else { "right-sidebar-tab" },
                 onclick: move |_| on_change.call(RightSidebarTab::Contents),
-                span { "Contents" }
+                span { {t("contents.tab")} }
             }
 
             // Search tab
             button {
                 class: if active_tab == RightSidebarTab::Search { "right-sidebar-tab active" } else { "right-sidebar-tab" },
                 onclick: move |_| on_change.call(RightSidebarTab::Search),
-                span { "Search" }
+                span { {t("search.tab")} }
+            }
+
+            // Annotations tab
+            button {
+                class: if active_tab == RightSidebarTab::Annotations { "right-sidebar-tab active" } else { "right-sidebar-tab" },
+                onclick: move |_| on_change.call(RightSidebarTab::Annotations),
+                span { {t("annotations.tab")} }
+            }
+
+            // Tasks tab
+            button {
+                class: if active_tab == RightSidebarTab::Tasks { "right-sidebar-tab active" } else { "right-sidebar-tab" },
+                onclick: move |_| on_change.call(RightSidebarTab::Tasks),
+                span { {t("tasks.tab")} }
+            }
+
+            // Document info tab
+            button {
+                class: if active_tab == RightSidebarTab::Info { "right-sidebar-tab active" } else { "right-sidebar-tab" },
+                onclick: move |_| on_change.call(RightSidebarTab::Info),
+                span { {t("info.tab")} }
             }
         }
     }
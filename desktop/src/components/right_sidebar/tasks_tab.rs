@@ -0,0 +1,262 @@
+use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
+
+use super::TaskScope;
+use crate::i18n::{t, t_args};
+use crate::state::AppState;
+use crate::tasks::{extract_tasks, group_by_heading, scan_tasks, FileTasks, TaskCounts, TaskItem};
+use crate::watcher::FILE_WATCHER;
+
+/// Tasks panel listing the GFM task list items of the document or the root directory
+#[component]
+pub fn TasksTab() -> Element {
+    let mut state = use_context::<AppState>();
+    let scope = *state.task_scope.read();
+    let root = state.sidebar.read().root_directory.clone();
+    let current_file = state
+        .current_tab()
+        .and_then(|tab| tab.file().map(PathBuf::from));
+    let target = match scope {
+        TaskScope::Document => current_file,
+        TaskScope::Directory => root.clone(),
+    };
+
+    let mut hide_completed = use_signal(|| false);
+    let reload_trigger = use_signal(|| 0usize);
+    use_task_watcher(scope, target.clone(), reload_trigger);
+
+    let files = use_resource(use_reactive!(|scope, target| {
+        let _ = reload_trigger();
+        async move {
+            let Some(target) = target else {
+                return Vec::new();
+            };
+            tokio::task::spawn_blocking(move || load_tasks(scope, &target))
+                .await
+                .unwrap_or_default()
+        }
+    }));
+    let files = files.read().clone().unwrap_or_default();
+
+    let counts = TaskCounts::of(files.iter().flat_map(|file| &file.tasks));
+    let hide = hide_completed();
+
+    rsx! {
+        div {
+            class: "right-sidebar-tasks",
+
+            div {
+                class: "right-sidebar-tasks-toolbar",
+                div {
+                    class: "right-sidebar-tasks-scope",
+                    button {
+                        class: "right-sidebar-tasks-scope-button",
+                        class: if scope == TaskScope::Document { "active" },
+                        onclick: move |_| state.task_scope.set(TaskScope::Document),
+                        {t("tasks.scope.document")}
+                    }
+                    button {
+                        class: "right-sidebar-tasks-scope-button",
+                        class: if scope == TaskScope::Directory { "active" },
+                        disabled: root.is_none(),
+                        onclick: move |_| state.task_scope.set(TaskScope::Directory),
+                        {t("tasks.scope.folder")}
+                    }
+                }
+                div {
+                    class: "right-sidebar-tasks-counts",
+                    span { class: "right-sidebar-tasks-open", {t_args("tasks.open_count", &[("count", &counts.open.to_string())])} }
+                    span { class: "right-sidebar-tasks-done", {t_args("tasks.done_count", &[("count", &counts.done.to_string())])} }
+                }
+                label {
+                    class: "right-sidebar-tasks-hide-completed",
+                    input {
+                        r#type: "checkbox",
+                        checked: hide,
+                        onchange: move |evt| hide_completed.set(evt.checked()),
+                    }
+                    {t("tasks.hide_completed")}
+                }
+            }
+
+            if target.is_none() {
+                div {
+                    class: "right-sidebar-tasks-empty",
+                    if scope == TaskScope::Document { {t("tasks.no_file")} } else { {t("tasks.no_folder")} }
+                }
+            } else if counts.open + counts.done == 0 {
+                div {
+                    class: "right-sidebar-tasks-empty",
+                    {t("tasks.no_tasks")}
+                }
+            } else {
+                for file in files {
+                    TaskFile {
+                        key: "{file.path.display()}",
+                        show_header: scope == TaskScope::Directory,
+                        root: root.clone(),
+                        hide_completed: hide,
+                        file,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TaskFile(
+    file: FileTasks,
+    show_header: bool,
+    root: Option<PathBuf>,
+    hide_completed: bool,
+) -> Element {
+    let mut state = use_context::<AppState>();
+    let counts = TaskCounts::of(&file.tasks);
+    if hide_completed && counts.open == 0 {
+        return rsx! {};
+    }
+
+    let label = root
+        .as_deref()
+        .and_then(|root| file.path.strip_prefix(root).ok())
+        .unwrap_or(&file.path)
+        .to_string_lossy()
+        .to_string();
+    let tasks: Vec<TaskItem> = file
+        .tasks
+        .iter()
+        .filter(|task| !hide_completed || !task.checked)
+        .cloned()
+        .collect();
+    let today = chrono::Local::now().date_naive();
+    let path = file.path.clone();
+
+    rsx! {
+        div {
+            class: "right-sidebar-tasks-file",
+
+            if show_header {
+                div {
+                    class: "right-sidebar-tasks-file-header",
+                    title: "{file.path.display()}",
+                    onclick: move |_| state.open_file(&path),
+                    span { class: "right-sidebar-tasks-file-name", "{label}" }
+                    span { class: "right-sidebar-tasks-file-count", "{counts.done}/{counts.open + counts.done}" }
+                }
+            }
+
+            for (index, (heading, tasks)) in group_by_heading(&tasks).into_iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "right-sidebar-tasks-group",
+                    if let Some(heading) = heading {
+                        div { class: "right-sidebar-tasks-heading", "{heading}" }
+                    }
+                    ul {
+                        class: "right-sidebar-tasks-list",
+                        for task in tasks {
+                            TaskRow {
+                                key: "{task.index}",
+                                path: file.path.clone(),
+                                is_overdue: task.is_overdue(today),
+                                task,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TaskRow(path: PathBuf, task: TaskItem, is_overdue: bool) -> Element {
+    let mut state = use_context::<AppState>();
+    let location = format!("{}:{}", path.display(), task.line);
+    let index = task.index;
+
+    rsx! {
+        li {
+            class: "right-sidebar-task",
+            class: if task.checked { "checked" },
+            title: "{location}",
+            onclick: move |_| state.open_task(&path, index),
+            input {
+                r#type: "checkbox",
+                class: "right-sidebar-task-checkbox",
+                checked: task.checked,
+                disabled: true,
+            }
+            span { class: "right-sidebar-task-text", "{task.text}" }
+            for owner in task.owners.iter() {
+                span { key: "{owner}", class: "right-sidebar-task-owner", "@{owner}" }
+            }
+            if let Some(due) = task.due {
+                span {
+                    class: "right-sidebar-task-due",
+                    class: if is_overdue { "overdue" },
+                    "{due}"
+                }
+            }
+        }
+    }
+}
+
+/// Read the tasks of the current document or of every file under the root directory
+fn load_tasks(scope: TaskScope, target: &Path) -> Vec<FileTasks> {
+    match scope {
+        TaskScope::Document => std::fs::read_to_string(target)
+            .map(|content| extract_tasks(&content))
+            .ok()
+            .filter(|tasks| !tasks.is_empty())
+            .map(|tasks| {
+                vec![FileTasks {
+                    path: target.to_path_buf(),
+                    tasks,
+                }]
+            })
+            .unwrap_or_default(),
+        TaskScope::Directory => scan_tasks(target),
+    }
+}
+
+/// Hook to rescan the tasks when the document or a file under the directory changes
+fn use_task_watcher(scope: TaskScope, target: Option<PathBuf>, reload_trigger: Signal<usize>) {
+    use_effect(use_reactive!(|scope, target| {
+        let mut reload_trigger = reload_trigger;
+        spawn(async move {
+            let Some(target) = target else {
+                return;
+            };
+
+            let mut rescan = move || {
+                tracing::trace!("Tasks changed, rescanning");
+                reload_trigger.set(reload_trigger() + 1);
+            };
+
+            match scope {
+                TaskScope::Document => {
+                    let Ok(mut watcher) = FILE_WATCHER.watch(target.clone()).await else {
+                        tracing::error!(?target, "Failed to start task watcher");
+                        return;
+                    };
+                    while watcher.recv().await.is_some() {
+                        rescan();
+                    }
+                    let _ = FILE_WATCHER.unwatch(target).await;
+                }
+                TaskScope::Directory => {
+                    let Ok(mut watcher) = FILE_WATCHER.watch_directory(target.clone()).await else {
+                        tracing::error!(?target, "Failed to start task watcher");
+                        return;
+                    };
+                    while watcher.recv().await.is_some() {
+                        rescan();
+                    }
+                    let _ = FILE_WATCHER.unwatch_directory(target).await;
+                }
+            }
+        });
+    }));
+}
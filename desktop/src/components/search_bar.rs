@@ -1,18 +1,26 @@
 use dioxus::document;
 use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
 
 use crate::components::icon::{Icon, IconName};
 use crate::components::pinned_chips::PinnedChipsRow;
+use crate::i18n::t;
 use crate::pinned_search::{
-    add_pinned_search, PinnedSearch, PINNED_SEARCHES, PINNED_SEARCHES_CHANGED,
+    add_pinned_search, validate_pattern, PatternKind, PinnedSearch, PINNED_SEARCHES,
+    PINNED_SEARCHES_CHANGED,
 };
 use crate::state::AppState;
 
 /// JavaScript to trigger search from input value
-const JS_FIND: &str = r#"
-    const input = document.querySelector('.search-input');
-    if (input) window.Arto.search.find(input.value);
-"#;
+fn js_find(kind: PatternKind) -> String {
+    format!(
+        r#"
+        const input = document.querySelector('.search-input');
+        if (input) window.Arto.search.find(input.value, '{}');
+        "#,
+        kind.to_js_name()
+    )
+}
 
 /// JavaScript to clear search input and highlights
 const JS_CLEAR: &str = r#"
@@ -33,15 +41,18 @@ fn navigate(direction: &'static str) {
 }
 
 /// Build JSON for pinned searches to sync to JavaScript.
-/// Includes all searches (enabled and disabled) so disabled ones still show matches in sidebar.
-fn build_pinned_json(searches: &[PinnedSearch]) -> String {
+/// Includes all searches (enabled and disabled) so disabled ones still show matches in sidebar,
+/// but skips searches scoped to other files and searches with invalid patterns.
+fn build_pinned_json(searches: &[PinnedSearch], file: Option<&Path>) -> String {
     let json_entries: Vec<String> = searches
         .iter()
+        .filter(|p| p.applies_to(file))
         .map(|p| {
             format!(
-                r#"{{"id":"{}","pattern":"{}","color":"{}","caseSensitive":{},"disabled":{}}}"#,
+                r#"{{"id":"{}","pattern":{},"kind":"{}","color":"{}","caseSensitive":{},"disabled":{}}}"#,
                 p.id,
-                p.pattern.replace('\\', "\\\\").replace('"', "\\\""),
+                serde_json::to_string(&p.pattern).unwrap_or_default(),
+                p.kind.to_js_name(),
                 p.color.to_js_name(),
                 p.case_sensitive,
                 p.disabled
@@ -71,8 +82,8 @@ async fn wait_for_js_ready() {
 }
 
 /// Sync pinned searches to JavaScript for highlighting (async version).
-async fn sync_pinned_to_js_async(searches: &[PinnedSearch]) {
-    let json = build_pinned_json(searches);
+async fn sync_pinned_to_js_async(searches: &[PinnedSearch], file: Option<&Path>) {
+    let json = build_pinned_json(searches, file);
     let js = format!("window.Arto.search.setPinned({});", json);
     let _ = document::eval(&js).await;
 }
@@ -84,37 +95,75 @@ pub fn SearchBar() -> Element {
     let match_count = *state.search_match_count.read();
     let current_index = *state.search_current_index.read();
     let initial_text = state.search_initial_text.read().clone();
+    let search_error = state.search_error.read().clone();
     let mut has_input = use_signal(|| false);
 
+    // How the search input is matched (also used when pinning)
+    let mut pattern_kind = use_signal(PatternKind::default);
+    let mut pattern_error = use_signal(|| None::<String>);
+
     // Local signal for pinned searches (updated via broadcast)
     let mut pinned_searches = use_signal(|| PINNED_SEARCHES.read().pinned_searches.clone());
 
-    // Subscribe to pinned search changes and sync to JavaScript
+    // File of the current tab (pinned searches may be scoped to some files)
+    let current_file = use_memo(move || {
+        state
+            .current_tab()
+            .and_then(|tab| tab.file().map(Path::to_path_buf))
+    });
+    let mut js_ready = use_signal(|| false);
+
+    // Subscribe to pinned search changes
     use_future(move || async move {
         // Wait for JavaScript search API to be ready before initial sync
         wait_for_js_ready().await;
-
-        // Initial sync to JavaScript on component mount
-        let searches = pinned_searches.read().clone();
-        sync_pinned_to_js_async(&searches).await;
+        js_ready.set(true);
 
         // Listen for changes
         let mut rx = PINNED_SEARCHES_CHANGED.subscribe();
         while rx.recv().await.is_ok() {
             let searches = PINNED_SEARCHES.read().pinned_searches.clone();
-            pinned_searches.set(searches.clone());
+            pinned_searches.set(searches);
+        }
+    });
 
-            // Update JavaScript with new pinned searches
-            sync_pinned_to_js_async(&searches).await;
+    // Sync pinned searches that apply to the current file to JavaScript
+    use_effect(move || {
+        if !js_ready() {
+            return;
         }
+        let searches = pinned_searches();
+        let file: Option<PathBuf> = current_file();
+        spawn(async move {
+            sync_pinned_to_js_async(&searches, file.as_deref()).await;
+        });
     });
 
+    // Validate the input and re-run the search with the selected kind
+    let mut refresh_search = move |value: String| {
+        let kind = pattern_kind();
+        pattern_error.set(
+            validate_pattern(&value, kind, false)
+                .err()
+                .map(|e| e.to_string()),
+        );
+        spawn(async move {
+            let _ = document::eval(&js_find(kind)).await;
+        });
+    };
+
     // Handle initial text when search bar opens
     use_effect(use_reactive!(|is_open, initial_text| {
         if is_open {
             if let Some(ref text) = initial_text {
                 if !text.is_empty() {
                     has_input.set(true);
+                    let kind = *pattern_kind.peek();
+                    pattern_error.set(
+                        validate_pattern(text, kind, false)
+                            .err()
+                            .map(|e| e.to_string()),
+                    );
                     // Use JSON encoding to safely escape the string for JavaScript
                     let json_encoded = serde_json::to_string(text).unwrap_or_default();
                     let js = format!(
@@ -124,10 +173,11 @@ pub fn SearchBar() -> Element {
                             input.value = {};
                             input.focus();
                             input.select();
-                            window.Arto.search.find(input.value);
+                            window.Arto.search.find(input.value, '{}');
                         }}
                         "#,
-                        json_encoded
+                        json_encoded,
+                        kind.to_js_name()
                     );
                     spawn(async move {
                         let _ = document::eval(&js).await;
@@ -139,6 +189,9 @@ pub fn SearchBar() -> Element {
         }
     }));
 
+    // Errors of the pattern found in Rust, or else by the renderer's RegExp
+    let input_error = pattern_error.read().clone().or(search_error);
+
     rsx! {
         div {
             class: if is_open { "search-bar search-bar--open" } else { "search-bar" },
@@ -157,16 +210,16 @@ pub fn SearchBar() -> Element {
                     input {
                         r#type: "text",
                         class: "search-input",
-                        placeholder: "Search...",
+                        class: if input_error.is_some() { "search-input--invalid" },
+                        title: input_error.clone().unwrap_or_default(),
+                        placeholder: t("search.placeholder"),
                         autofocus: true,
                         autocorrect: "off",
                         autocapitalize: "off",
                         spellcheck: "false",
                         oninput: move |evt| {
                             has_input.set(!evt.value().is_empty());
-                            spawn(async move {
-                                let _ = document::eval(JS_FIND).await;
-                            });
+                            refresh_search(evt.value());
                         },
                         onkeydown: move |evt| {
                             match evt.key() {
@@ -184,9 +237,10 @@ pub fn SearchBar() -> Element {
                     if has_input() {
                         button {
                             class: "search-clear-button",
-                            title: "Clear",
+                            title: t("search.clear"),
                             onclick: move |_| {
                                 has_input.set(false);
+                                pattern_error.set(None);
                                 state.update_search_results(0, 0);
                                 spawn(async move {
                                     let _ = document::eval(JS_CLEAR).await;
@@ -197,11 +251,43 @@ pub fn SearchBar() -> Element {
                     }
                 }
 
+                // Pattern kind toggles (whole word / regular expression)
+                for kind in [PatternKind::Word, PatternKind::Regex] {
+                    button {
+                        key: "{kind.to_js_name()}",
+                        class: "search-kind-button",
+                        class: if pattern_kind() == kind { "active" },
+                        title: kind.label(),
+                        "aria-pressed": if pattern_kind() == kind { "true" } else { "false" },
+                        onclick: move |_| {
+                            let next = if pattern_kind() == kind { PatternKind::Text } else { kind };
+                            pattern_kind.set(next);
+                            spawn(async move {
+                                #[derive(serde::Deserialize)]
+                                struct QueryValue {
+                                    value: String,
+                                }
+                                let mut eval = document::eval(r#"
+                                    const input = document.querySelector('.search-input');
+                                    dioxus.send({ value: input?.value || '' });
+                                "#);
+                                if let Ok(result) = eval.recv::<QueryValue>().await {
+                                    refresh_search(result.value);
+                                }
+                            });
+                        },
+                        match kind {
+                            PatternKind::Word => "ab",
+                            _ => ".*",
+                        }
+                    }
+                }
+
                 // Pin button - adds current search to pinned searches
                 button {
                     class: "search-pin-button",
-                    disabled: !has_input(),
-                    title: "Pin this search",
+                    disabled: !has_input() || input_error.is_some(),
+                    title: t("search.pin"),
                     onclick: move |_| {
                         // Get the current search value from the input, then clear
                         let mut has_input = has_input;
@@ -217,7 +303,7 @@ pub fn SearchBar() -> Element {
                             "#);
                             if let Ok(result) = eval.recv::<QueryValue>().await {
                                 if !result.value.is_empty() {
-                                    add_pinned_search(result.value);
+                                    add_pinned_search(result.value, pattern_kind());
                                     // Clear search input after pinning
                                     has_input.set(false);
                                     state.update_search_results(0, 0);
@@ -232,7 +318,7 @@ pub fn SearchBar() -> Element {
                 button {
                     class: "search-nav-button",
                     disabled: match_count == 0,
-                    title: "Previous match (Shift+Enter)",
+                    title: t("search.previous"),
                     onclick: move |_| navigate("prev"),
                     Icon { name: IconName::ChevronUp, size: 16 }
                 }
@@ -240,7 +326,7 @@ pub fn SearchBar() -> Element {
                 button {
                     class: "search-nav-button",
                     disabled: match_count == 0,
-                    title: "Next match (Enter)",
+                    title: t("search.next"),
                     onclick: move |_| navigate("next"),
                     Icon { name: IconName::ChevronDown, size: 16 }
                 }
@@ -252,7 +338,7 @@ pub fn SearchBar() -> Element {
 
                 button {
                     class: "search-close-button",
-                    title: "Close (Escape)",
+                    title: t("search.close"),
                     onclick: move |_| state.toggle_search(),
                     Icon { name: IconName::Close, size: 16 }
                 }
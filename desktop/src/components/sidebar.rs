@@ -1,6 +1,10 @@
+pub mod book_outline;
 pub mod context_menu;
 pub mod file_explorer;
 pub mod quick_access;
+pub mod recent_files;
+pub mod tag_index;
+pub mod watcher_notice;
 
 use dioxus::document;
 use dioxus::prelude::*;
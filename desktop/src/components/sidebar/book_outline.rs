@@ -0,0 +1,109 @@
+//! Book view for the sidebar.
+//!
+//! Lists the chapters of an mdBook or MkDocs book in the order declared by
+//! its navigation file, as an alternative to the alphabetical file tree.
+
+use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
+
+use crate::book::BookItem;
+use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
+use crate::state::AppState;
+
+/// Chapter outline of the book detected in the sidebar root directory
+#[component]
+pub fn BookOutline() -> Element {
+    let mut state = use_context::<AppState>();
+    let Some(book) = state.book.read().clone() else {
+        return rsx! {};
+    };
+
+    // Highlight the chapter open in the current tab
+    let current_index = state
+        .current_tab()
+        .and_then(|tab| tab.file().map(Path::to_path_buf))
+        .and_then(|file| book.chapter_index(&file));
+    let root = book.root.clone();
+
+    rsx! {
+        div {
+            class: "left-sidebar-book",
+
+            div {
+                class: "left-sidebar-book-header",
+                Icon {
+                    name: IconName::Book,
+                    size: 14,
+                    class: "left-sidebar-book-header-icon",
+                }
+                span { class: "left-sidebar-book-title", "{book.title}" }
+                button {
+                    class: "left-sidebar-book-read-all",
+                    title: t("book.read_all_description"),
+                    onclick: move |_| state.open_book(root.clone()),
+                    {t("book.read_all")}
+                }
+            }
+
+            div {
+                class: "left-sidebar-book-list",
+                for (index, item) in book.items.iter().cloned().enumerate() {
+                    BookOutlineItem {
+                        key: "{index}",
+                        item,
+                        is_current: current_index == Some(index),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BookOutlineItem(item: BookItem, is_current: bool) -> Element {
+    let mut state = use_context::<AppState>();
+    let indent = format!("padding-left: {}px;", 8 + item.depth() * 12);
+
+    match item {
+        BookItem::Section { title, .. } => rsx! {
+            div {
+                class: "left-sidebar-book-section",
+                style: "{indent}",
+                "{title}"
+            }
+        },
+        BookItem::Chapter { title, path, .. } => {
+            let is_draft = path.is_none();
+            rsx! {
+                div {
+                    class: "left-sidebar-book-chapter",
+                    class: if is_current { "current" },
+                    class: if is_draft { "draft" },
+                    style: "{indent}",
+                    title: path.as_deref().map(|p| p.to_string_lossy().into_owned()).unwrap_or_else(|| t("book.draft_chapter").to_string()),
+                    onclick: move |_| {
+                        if let Some(path) = path.clone() {
+                            open_chapter(&mut state, path);
+                        }
+                    },
+                    Icon {
+                        name: IconName::File,
+                        size: 14,
+                        class: "left-sidebar-book-chapter-icon",
+                    }
+                    span { class: "left-sidebar-book-chapter-title", "{title}" }
+                }
+            }
+        }
+    }
+}
+
+/// Open a chapter, reporting files listed in the navigation that do not exist
+fn open_chapter(state: &mut AppState, path: PathBuf) {
+    if path.exists() {
+        state.open_file(path);
+    } else {
+        tracing::warn!(?path, "Book chapter does not exist");
+    }
+}
@@ -3,8 +3,9 @@ use std::path::PathBuf;
 use dioxus::desktop::tao::window::WindowId;
 use dioxus::prelude::*;
 
-use crate::bookmarks::BOOKMARKS;
+use crate::bookmarks::{BookmarkFolder, BookmarkFolderId, BOOKMARKS};
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum SidebarItemKind {
@@ -19,9 +20,11 @@ pub fn SidebarContextMenu(
     kind: SidebarItemKind,
     on_close: EventHandler<()>,
     on_open: EventHandler<()>,
+    on_open_as_document: EventHandler<()>,
     on_open_in_new_window: EventHandler<()>,
     on_move_to_window: EventHandler<WindowId>,
     on_toggle_bookmark: EventHandler<()>,
+    on_edit: EventHandler<()>,
     on_copy_path: EventHandler<()>,
     on_reveal_in_finder: EventHandler<()>,
     on_reload: EventHandler<()>,
@@ -34,14 +37,14 @@ pub fn SidebarContextMenu(
 
     // Dynamic labels based on item kind
     let open_label = if is_file {
-        "Open File"
+        t("context_menu.open_file")
     } else {
-        "Open Directory"
+        t("context_menu.open_directory")
     };
     let copy_path_label = if is_file {
-        "Copy File Path"
+        t("context_menu.copy_file_path")
     } else {
-        "Copy Directory Path"
+        t("context_menu.copy_directory_path")
     };
 
     rsx! {
@@ -64,8 +67,16 @@ pub fn SidebarContextMenu(
                 on_click: move |_| on_open.call(()),
             }
 
+            if !is_file {
+                ContextMenuItem {
+                    label: t("context_menu.open_folder_as_document"),
+                    icon: Some(IconName::Book),
+                    on_click: move |_| on_open_as_document.call(()),
+                }
+            }
+
             ContextMenuItem {
-                label: "Open in New Window",
+                label: t("context_menu.open_in_new_window"),
                 on_click: move |_| on_open_in_new_window.call(()),
             }
 
@@ -75,7 +86,7 @@ pub fn SidebarContextMenu(
                 onmouseenter: move |_| show_submenu.set(true),
                 onmouseleave: move |_| show_submenu.set(false),
 
-                span { class: "context-menu-label", "Open in Window" }
+                span { class: "context-menu-label", {t("context_menu.open_in_window")} }
                 span { class: "submenu-arrow", "›" }
 
                 if *show_submenu.read() {
@@ -85,7 +96,7 @@ pub fn SidebarContextMenu(
                         if other_windows.is_empty() {
                             div {
                                 class: "context-menu-item disabled",
-                                "No other windows"
+                                {t("context_menu.no_other_windows")}
                             }
                         } else {
                             for (window_id, title) in other_windows.iter() {
@@ -122,13 +133,25 @@ pub fn SidebarContextMenu(
 
                 span {
                     class: "context-menu-label",
-                    if is_bookmarked { "Remove from Quick Access" } else { "Add to Quick Access" }
+                    if is_bookmarked {
+                        {t("context_menu.remove_from_quick_access")}
+                    } else {
+                        {t("context_menu.add_to_quick_access")}
+                    }
                 }
             }
 
             // === Section 3: File operations ===
             ContextMenuSeparator {}
 
+            if is_file {
+                ContextMenuItem {
+                    label: t("context_menu.edit_here"),
+                    icon: Some(IconName::Pencil),
+                    on_click: move |_| on_edit.call(()),
+                }
+            }
+
             ContextMenuItem {
                 label: copy_path_label,
                 icon: Some(IconName::Copy),
@@ -136,7 +159,7 @@ pub fn SidebarContextMenu(
             }
 
             ContextMenuItem {
-                label: "Reveal in Finder",
+                label: t("context_menu.reveal_in_finder"),
                 icon: Some(IconName::Folder),
                 on_click: move |_| on_reveal_in_finder.call(()),
             }
@@ -145,7 +168,7 @@ pub fn SidebarContextMenu(
             ContextMenuSeparator {}
 
             ContextMenuItem {
-                label: "Reload",
+                label: t("context_menu.reload"),
                 icon: Some(IconName::Refresh),
                 on_click: move |_| on_reload.call(()),
             }
@@ -153,6 +176,180 @@ pub fn SidebarContextMenu(
     }
 }
 
+/// Context menu for a bookmark in Quick Access
+#[component]
+pub fn QuickAccessContextMenu(
+    position: (i32, i32),
+    /// Folder the bookmark currently belongs to
+    folder: Option<BookmarkFolderId>,
+    folders: Vec<BookmarkFolder>,
+    /// Possible new locations of a missing bookmark (None if the bookmark exists)
+    relink_candidates: Option<Vec<PathBuf>>,
+    on_close: EventHandler<()>,
+    on_move_to_folder: EventHandler<Option<BookmarkFolderId>>,
+    on_relink: EventHandler<PathBuf>,
+    on_choose_relink: EventHandler<()>,
+    on_remove: EventHandler<()>,
+) -> Element {
+    let mut show_folders = use_signal(|| false);
+    let mut show_relink = use_signal(|| false);
+
+    rsx! {
+        // Backdrop to close menu on outside click
+        div {
+            class: "context-menu-backdrop",
+            onclick: move |_| on_close.call(()),
+        }
+
+        div {
+            class: "context-menu",
+            style: "left: {position.0}px; top: {position.1}px;",
+            onclick: move |evt| evt.stop_propagation(),
+
+            // === Section 1: Relink (missing bookmarks only) ===
+            if let Some(candidates) = relink_candidates {
+                div {
+                    class: "context-menu-item has-submenu",
+                    onmouseenter: move |_| show_relink.set(true),
+                    onmouseleave: move |_| show_relink.set(false),
+
+                    Icon {
+                        name: IconName::AlertTriangle,
+                        size: 14,
+                        class: "context-menu-icon",
+                    }
+                    span { class: "context-menu-label", {t("context_menu.relink")} }
+                    span { class: "submenu-arrow", "›" }
+
+                    if *show_relink.read() {
+                        div {
+                            class: "context-submenu",
+
+                            if candidates.is_empty() {
+                                div {
+                                    class: "context-menu-item disabled",
+                                    {t("context_menu.no_relink_candidates")}
+                                }
+                            }
+                            for candidate in candidates {
+                                div {
+                                    key: "{candidate.display()}",
+                                    class: "context-menu-item",
+                                    onclick: {
+                                        let candidate = candidate.clone();
+                                        move |_| on_relink.call(candidate.clone())
+                                    },
+                                    "{candidate.display()}"
+                                }
+                            }
+
+                            ContextMenuSeparator {}
+
+                            ContextMenuItem {
+                                label: t("context_menu.choose_file"),
+                                on_click: move |_| on_choose_relink.call(()),
+                            }
+                        }
+                    }
+                }
+
+                ContextMenuSeparator {}
+            }
+
+            // === Section 2: Folders ===
+            div {
+                class: "context-menu-item has-submenu",
+                onmouseenter: move |_| show_folders.set(true),
+                onmouseleave: move |_| show_folders.set(false),
+
+                Icon {
+                    name: IconName::Folder,
+                    size: 14,
+                    class: "context-menu-icon",
+                }
+                span { class: "context-menu-label", {t("context_menu.move_to_folder")} }
+                span { class: "submenu-arrow", "›" }
+
+                if *show_folders.read() {
+                    div {
+                        class: "context-submenu",
+
+                        ContextMenuItem {
+                            label: t("context_menu.no_folder"),
+                            disabled: folder.is_none(),
+                            on_click: move |_| on_move_to_folder.call(None),
+                        }
+
+                        if !folders.is_empty() {
+                            ContextMenuSeparator {}
+                        }
+
+                        for target in folders {
+                            div {
+                                key: "{target.id}",
+                                class: if folder.as_ref() == Some(&target.id) { "context-menu-item disabled" } else { "context-menu-item" },
+                                onclick: {
+                                    let id = target.id.clone();
+                                    let is_current = folder.as_ref() == Some(&id);
+                                    move |_| {
+                                        if !is_current {
+                                            on_move_to_folder.call(Some(id.clone()));
+                                        }
+                                    }
+                                },
+                                "{target.name}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // === Section 3: Remove ===
+            ContextMenuSeparator {}
+
+            ContextMenuItem {
+                label: t("context_menu.remove_from_quick_access"),
+                icon: Some(IconName::Trash),
+                on_click: move |_| on_remove.call(()),
+            }
+        }
+    }
+}
+
+/// Context menu for a bookmark folder in Quick Access
+#[component]
+pub fn QuickAccessFolderContextMenu(
+    position: (i32, i32),
+    on_close: EventHandler<()>,
+    on_rename: EventHandler<()>,
+    on_delete: EventHandler<()>,
+) -> Element {
+    rsx! {
+        // Backdrop to close menu on outside click
+        div {
+            class: "context-menu-backdrop",
+            onclick: move |_| on_close.call(()),
+        }
+
+        div {
+            class: "context-menu",
+            style: "left: {position.0}px; top: {position.1}px;",
+            onclick: move |evt| evt.stop_propagation(),
+
+            ContextMenuItem {
+                label: t("context_menu.rename_folder"),
+                on_click: move |_| on_rename.call(()),
+            }
+
+            ContextMenuItem {
+                label: t("context_menu.delete_folder"),
+                icon: Some(IconName::Trash),
+                on_click: move |_| on_delete.call(()),
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Helper Components
 // ============================================================================
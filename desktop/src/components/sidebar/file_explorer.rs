@@ -1,13 +1,23 @@
 use dioxus::desktop::window;
+use dioxus::document;
 use dioxus::prelude::*;
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use super::book_outline::BookOutline;
 use super::context_menu::{SidebarContextMenu, SidebarItemKind};
 use super::quick_access::QuickAccess;
+use super::recent_files::RecentFiles;
+use super::tag_index::TagIndex;
+use super::watcher_notice::WatcherNotice;
+use crate::book::Book;
 use crate::components::bookmark_button::BookmarkButton;
 use crate::components::icon::{Icon, IconName};
+use crate::config::CONFIG;
+use crate::editor::open_in_editor;
+use crate::i18n::t;
 use crate::state::AppState;
 use crate::utils::{file::is_markdown_file, file_operations};
 use crate::watcher::FILE_WATCHER;
@@ -41,6 +51,134 @@ fn read_sorted_entries(path: &PathBuf) -> Vec<PathBuf> {
     }
 }
 
+// Whether an entry is shown in the tree
+fn is_visible_entry(path: &Path, show_all_files: bool) -> bool {
+    show_all_files || path.is_dir() || is_markdown_file(path)
+}
+
+// Entries currently shown in the tree, in display order (depth-first through expanded dirs)
+fn visible_entries(
+    dir: &PathBuf,
+    expanded_dirs: &HashSet<PathBuf>,
+    show_all_files: bool,
+) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    for entry in read_sorted_entries(dir) {
+        if !is_visible_entry(&entry, show_all_files) {
+            continue;
+        }
+        let expanded = entry.is_dir() && expanded_dirs.contains(&entry);
+        result.push(entry.clone());
+        if expanded {
+            result.extend(visible_entries(&entry, expanded_dirs, show_all_files));
+        }
+    }
+    result
+}
+
+/// Keyboard action in the file tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TreeKey {
+    Next,
+    Prev,
+    Expand,
+    Collapse,
+    Open,
+}
+
+impl TreeKey {
+    fn from_key(key: &Key, vim_navigation: bool) -> Option<Self> {
+        match key {
+            Key::ArrowDown => Some(Self::Next),
+            Key::ArrowUp => Some(Self::Prev),
+            Key::ArrowRight => Some(Self::Expand),
+            Key::ArrowLeft => Some(Self::Collapse),
+            Key::Enter => Some(Self::Open),
+            Key::Character(c) if vim_navigation => match c.as_str() {
+                "j" => Some(Self::Next),
+                "k" => Some(Self::Prev),
+                "l" => Some(Self::Expand),
+                "h" => Some(Self::Collapse),
+                "o" => Some(Self::Open),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Apply a keyboard action to the file tree of the sidebar
+fn handle_tree_key(mut state: AppState, root: &PathBuf, action: TreeKey) {
+    let (focused, expanded_dirs, show_all_files) = {
+        let sidebar = state.sidebar.read();
+        (
+            sidebar.focused_path.clone(),
+            sidebar.expanded_dirs.clone(),
+            sidebar.show_all_files,
+        )
+    };
+    let entries = visible_entries(root, &expanded_dirs, show_all_files);
+    if entries.is_empty() {
+        return;
+    }
+    let index = focused
+        .as_ref()
+        .and_then(|f| entries.iter().position(|e| e == f));
+
+    let mut new_focus = None;
+    match (action, index) {
+        (TreeKey::Next, Some(i)) => new_focus = entries.get(i + 1).cloned(),
+        (TreeKey::Prev, Some(i)) => new_focus = i.checked_sub(1).map(|i| entries[i].clone()),
+        (TreeKey::Next, None) => new_focus = entries.first().cloned(),
+        (TreeKey::Prev, None) => new_focus = entries.last().cloned(),
+        (_, None) => {}
+        (TreeKey::Expand, Some(i)) => {
+            let path = &entries[i];
+            if path.is_dir() {
+                if !expanded_dirs.contains(path) {
+                    state.sidebar.write().toggle_expansion(path);
+                } else {
+                    // Already expanded: step into the first child
+                    new_focus = entries
+                        .get(i + 1)
+                        .filter(|child| child.parent() == Some(path.as_path()))
+                        .cloned();
+                }
+            }
+        }
+        (TreeKey::Collapse, Some(i)) => {
+            let path = &entries[i];
+            if path.is_dir() && expanded_dirs.contains(path) {
+                state.sidebar.write().toggle_expansion(path);
+            } else {
+                // Step out to the parent directory (unless it is the root)
+                new_focus = path
+                    .parent()
+                    .filter(|parent| *parent != root.as_path())
+                    .map(Path::to_path_buf);
+            }
+        }
+        (TreeKey::Open, Some(i)) => {
+            let path = entries[i].clone();
+            if path.is_dir() {
+                state.set_root_directory(&path);
+            } else {
+                state.open_file(&path);
+            }
+        }
+    }
+
+    if let Some(path) = new_focus {
+        state.sidebar.write().focused_path = Some(path);
+        spawn(async move {
+            let _ = document::eval(
+                "document.querySelector('.left-sidebar-tree-node-content.focused')?.scrollIntoView({ block: 'nearest' });",
+            )
+            .await;
+        });
+    }
+}
+
 #[component]
 pub fn FileExplorer() -> Element {
     let state = use_context::<AppState>();
@@ -51,6 +189,10 @@ pub fn FileExplorer() -> Element {
 
     // Watch directory for file system changes
     use_directory_watcher(root_directory.clone(), refresh_counter);
+    use_book_detection(root_directory.clone(), refresh_counter, state);
+
+    let show_book = state.sidebar.read().book_view && state.book.read().is_some();
+    let tags_root = root_directory.clone();
 
     rsx! {
         div {
@@ -59,16 +201,25 @@ pub fn FileExplorer() -> Element {
 
             if let Some(root) = root_directory {
                 DirectoryNavigation { current_dir: root.clone(), refresh_counter }
-                DirectoryTree { path: root, refresh_counter }
+                WatcherNotice {}
+                if show_book {
+                    BookOutline {}
+                } else {
+                    DirectoryTree { path: root, refresh_counter }
+                }
             } else {
                 div {
                     class: "left-sidebar-explorer-empty",
-                    "No directory open"
+                    {t("sidebar.no_directory")}
                 }
             }
 
-            // Quick Access section (fixed at bottom)
+            // Quick Access, Recent and Tags sections (fixed at bottom)
             QuickAccess {}
+            RecentFiles {}
+            if let Some(root) = tags_root {
+                TagIndex { root }
+            }
         }
     }
 }
@@ -78,6 +229,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
     let mut state = use_context::<AppState>();
     let sidebar = state.sidebar.read();
     let show_all_files = sidebar.show_all_files;
+    let book_view = sidebar.book_view;
     let can_go_back = sidebar.can_go_back();
     let can_go_forward = sidebar.can_go_forward();
     drop(sidebar);
@@ -132,7 +284,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                     class: "left-sidebar-header-history-button",
                     class: if !can_go_back { "disabled" },
                     disabled: !can_go_back,
-                    title: "Go back",
+                    title: t("sidebar.go_back"),
                     onclick: move |_| {
                         state.go_back_directory();
                     },
@@ -147,7 +299,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                     class: "left-sidebar-header-history-button",
                     class: if !can_go_forward { "disabled" },
                     disabled: !can_go_forward,
-                    title: "Go forward",
+                    title: t("sidebar.go_forward"),
                     onclick: move |_| {
                         state.go_forward_directory();
                     },
@@ -184,7 +336,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                             button {
                                 class: "left-sidebar-action-button copy-button",
                                 class: if *is_copied.read() { "copied" },
-                                title: "Copy directory path",
+                                title: t("sidebar.copy_directory_path"),
                                 onclick: {
                                     let current_dir = current_dir.clone();
                                     move |evt: Event<MouseData>| {
@@ -207,7 +359,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                             button {
                                 class: "left-sidebar-action-button reload-button",
                                 class: if *is_reloading.read() { "reloading" },
-                                title: "Reload file explorer",
+                                title: t("sidebar.reload"),
                                 onclick: on_reload,
                                 Icon {
                                     name: IconName::Refresh,
@@ -245,7 +397,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                             button {
                                 class: "left-sidebar-action-button copy-button",
                                 class: if *is_copied.read() { "copied" },
-                                title: "Copy directory path",
+                                title: t("sidebar.copy_directory_path"),
                                 onclick: {
                                     let current_dir = current_dir.clone();
                                     move |evt: Event<MouseData>| {
@@ -268,7 +420,7 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                             button {
                                 class: "left-sidebar-action-button reload-button",
                                 class: if *is_reloading.read() { "reloading" },
-                                title: "Reload file explorer",
+                                title: t("sidebar.reload"),
                                 onclick: on_reload,
                                 Icon {
                                     name: IconName::Refresh,
@@ -280,14 +432,30 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
                 }
             }
 
-            // Toolbar buttons container (visibility toggle only)
+            // Toolbar buttons container (book view and visibility toggles)
             div {
                 class: "left-sidebar-header-toolbar",
 
+                // Book view toggle (only when the directory is an mdBook/MkDocs book)
+                if state.book.read().is_some() {
+                    button {
+                        class: "left-sidebar-header-toolbar-button",
+                        class: if book_view { "active" },
+                        title: if book_view { t("sidebar.show_file_tree") } else { t("sidebar.show_book_chapters") },
+                        onclick: move |_| {
+                            state.sidebar.write().book_view = !book_view;
+                        },
+                        Icon {
+                            name: IconName::Book,
+                            size: 20,
+                        }
+                    }
+                }
+
                 // File visibility toggle button
                 button {
                     class: "left-sidebar-header-toolbar-button",
-                    title: if show_all_files { "Hide non-markdown files" } else { "Show all files" },
+                    title: if show_all_files { t("sidebar.show_markdown_only") } else { t("sidebar.show_all_files") },
                     onclick: move |_| {
                         state.sidebar.write().show_all_files = !show_all_files;
                     },
@@ -303,12 +471,24 @@ fn DirectoryNavigation(current_dir: PathBuf, mut refresh_counter: Signal<u32>) -
 
 #[component]
 fn DirectoryTree(path: PathBuf, refresh_counter: Signal<u32>) -> Element {
+    let state = use_context::<AppState>();
     let entries = read_sorted_entries(&path);
 
     rsx! {
         div {
             class: "left-sidebar-tree",
             key: "{refresh_counter}",
+            tabindex: "0",
+            onkeydown: move |evt: Event<KeyboardData>| {
+                if evt.modifiers().meta() || evt.modifiers().ctrl() || evt.modifiers().alt() {
+                    return;
+                }
+                let vim_navigation = CONFIG.read().keymap.vim_navigation;
+                if let Some(action) = TreeKey::from_key(&evt.key(), vim_navigation) {
+                    evt.prevent_default();
+                    handle_tree_key(state, &path, action);
+                }
+            },
             for entry in entries {
                 FileTreeNode { path: entry, depth: 0, refresh_counter }
             }
@@ -322,6 +502,7 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
 
     let is_dir = path.is_dir();
     let is_expanded = state.sidebar.read().expanded_dirs.contains(&path);
+    let is_focused = state.sidebar.read().focused_path.as_ref() == Some(&path);
     let show_all_files = state.sidebar.read().show_all_files;
 
     let name = path
@@ -333,7 +514,7 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
     let is_markdown = !is_dir && is_markdown_file(&path);
 
     // Hide non-markdown files if show_all_files is disabled
-    if !show_all_files && !is_dir && !is_markdown {
+    if !is_visible_entry(&path, show_all_files) {
         return rsx! {};
     }
 
@@ -393,6 +574,15 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
         }
     };
 
+    // Handler for "Open Folder as Document"
+    let handle_open_as_document = {
+        let path = path.clone();
+        move |_| {
+            state.open_folder_document(path.clone());
+            show_context_menu.set(false);
+        }
+    };
+
     // Handler for "Open in New Window"
     let handle_open_in_new_window = {
         let path = path.clone();
@@ -453,6 +643,15 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
         }
     };
 
+    // Handler for "Edit Here"
+    let handle_edit = {
+        let path = path.clone();
+        move |_| {
+            open_in_editor(&path, 1, 1);
+            show_context_menu.set(false);
+        }
+    };
+
     // Handler for "Reveal in Finder"
     let handle_reveal_in_finder = {
         let path = path.clone();
@@ -490,8 +689,17 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
             // click areas for different actions.
             div {
                 class: "left-sidebar-tree-node-content",
+                class: if is_focused { "focused" },
                 style: "{indent_style}",
                 oncontextmenu: handle_context_menu,
+                // Keyboard navigation continues from the clicked entry
+                // (mousedown, since the links below stop click propagation)
+                onmousedown: {
+                    let path = path.clone();
+                    move |_| {
+                        state.sidebar.write().focused_path = Some(path.clone());
+                    }
+                },
                 onclick: {
                     let path = path.clone();
                     move |_| {
@@ -575,7 +783,7 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
                 button {
                     class: "left-sidebar-tree-copy-button",
                     class: if *is_copied.read() { "copied" },
-                    title: "Copy full path",
+                    title: t("sidebar.copy_full_path"),
                     onclick: move |evt| {
                         evt.stop_propagation();
                         crate::utils::clipboard::copy_text(path.to_string_lossy());
@@ -617,9 +825,11 @@ fn FileTreeNode(path: PathBuf, depth: usize, mut refresh_counter: Signal<u32>) -
                 kind: if is_dir { SidebarItemKind::Directory } else { SidebarItemKind::File },
                 on_close: move |_| show_context_menu.set(false),
                 on_open: handle_open,
+                on_open_as_document: handle_open_as_document,
                 on_open_in_new_window: handle_open_in_new_window,
                 on_move_to_window: handle_open_in_window,
                 on_toggle_bookmark: handle_toggle_bookmark,
+                on_edit: handle_edit,
                 on_copy_path: handle_copy_path,
                 on_reveal_in_finder: handle_reveal_in_finder,
                 on_reload: handle_reload,
@@ -656,3 +866,84 @@ fn use_directory_watcher(directory: Option<PathBuf>, mut refresh_counter: Signal
         });
     }));
 }
+
+/// Hook to detect an mdBook or MkDocs book in the root directory
+///
+/// Re-runs on directory changes so edits to the navigation file are picked up.
+fn use_book_detection(directory: Option<PathBuf>, refresh_counter: Signal<u32>, state: AppState) {
+    use_effect(use_reactive!(|directory| {
+        let mut state = state;
+        let _ = refresh_counter();
+        let book = directory.as_deref().and_then(Book::detect);
+        if *state.book.peek() != book {
+            state.book.set(book);
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_tree() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("docs/nested")).unwrap();
+        fs::write(root.join("docs/guide.md"), "").unwrap();
+        fs::write(root.join("docs/nested/deep.md"), "").unwrap();
+        fs::write(root.join("README.md"), "").unwrap();
+        fs::write(root.join("image.png"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_visible_entries_follows_expansion() {
+        let dir = create_tree();
+        let root = dir.path().to_path_buf();
+        let names = |entries: Vec<PathBuf>| -> Vec<String> {
+            entries
+                .iter()
+                .map(|p| p.strip_prefix(&root).unwrap().display().to_string())
+                .collect()
+        };
+
+        let mut expanded = HashSet::new();
+        assert_eq!(
+            names(visible_entries(&root, &expanded, false)),
+            vec!["docs", "README.md"]
+        );
+
+        expanded.insert(root.join("docs"));
+        assert_eq!(
+            names(visible_entries(&root, &expanded, true)),
+            vec![
+                "docs",
+                "docs/nested",
+                "docs/guide.md",
+                "README.md",
+                "image.png"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tree_key_mapping() {
+        let key = |c: &str| Key::Character(c.to_string());
+
+        assert_eq!(
+            TreeKey::from_key(&Key::ArrowDown, false),
+            Some(TreeKey::Next)
+        );
+        assert_eq!(
+            TreeKey::from_key(&Key::ArrowLeft, false),
+            Some(TreeKey::Collapse)
+        );
+        assert_eq!(TreeKey::from_key(&Key::Enter, false), Some(TreeKey::Open));
+        // Letter keys only with vim-style navigation enabled
+        assert_eq!(TreeKey::from_key(&key("j"), false), None);
+        assert_eq!(TreeKey::from_key(&key("j"), true), Some(TreeKey::Next));
+        assert_eq!(TreeKey::from_key(&key("l"), true), Some(TreeKey::Expand));
+        assert_eq!(TreeKey::from_key(&key("x"), true), None);
+    }
+}
@@ -1,17 +1,30 @@
 //! Quick Access section component for the sidebar.
 //!
 //! Displays bookmarked files and directories for quick navigation.
-//! Supports drag-and-drop reordering of bookmarks.
+//! Bookmarks can be grouped into collapsible folders, reordered with
+//! drag-and-drop, and relinked when the bookmarked file has moved.
 
 use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
 
-use crate::bookmarks::{reorder_bookmark, Bookmark, BOOKMARKS, BOOKMARKS_CHANGED};
+use crate::bookmarks::{
+    add_bookmark_folder, find_relink_candidates, relink_bookmarks, remove_bookmark_at,
+    remove_bookmark_folder, rename_bookmark_folder, reorder_bookmark, set_bookmark_folder,
+    toggle_bookmark_folder_collapsed, Bookmark, BookmarkFolder, BookmarkFolderId, BookmarkTarget,
+    BOOKMARKS, BOOKMARKS_CHANGED,
+};
 use crate::components::bookmark_button::BookmarkButton;
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 use crate::state::AppState;
 
+use super::context_menu::{QuickAccessContextMenu, QuickAccessFolderContextMenu};
+
+/// Maximum number of relink candidates offered for a missing bookmark
+const MAX_RELINK_CANDIDATES: usize = 8;
+
 /// Bookmark with cached filesystem status to avoid filesystem calls during render
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 struct CachedBookmark {
     bookmark: Bookmark,
     exists: bool,
@@ -30,14 +43,68 @@ impl CachedBookmark {
     }
 }
 
+/// Bookmarks with cached status and the folders they are grouped in
+#[derive(Clone, Default)]
+struct QuickAccessData {
+    items: Vec<CachedBookmark>,
+    folders: Vec<BookmarkFolder>,
+}
+
 /// Load bookmarks with cached exists status
-fn load_cached_bookmarks() -> Vec<CachedBookmark> {
-    BOOKMARKS
-        .read()
-        .items
-        .iter()
-        .map(|b| CachedBookmark::from_bookmark(b.clone()))
-        .collect()
+fn load_cached_bookmarks() -> QuickAccessData {
+    let bookmarks = BOOKMARKS.read();
+    QuickAccessData {
+        items: bookmarks
+            .items
+            .iter()
+            .map(|b| CachedBookmark::from_bookmark(b.clone()))
+            .collect(),
+        folders: bookmarks.folders.clone(),
+    }
+}
+
+/// Context menu shown in Quick Access
+#[derive(Clone, PartialEq)]
+enum QuickAccessMenu {
+    Item {
+        index: usize,
+        position: (i32, i32),
+        relink_candidates: Option<Vec<PathBuf>>,
+    },
+    Folder {
+        id: BookmarkFolderId,
+        position: (i32, i32),
+    },
+}
+
+/// Directory searched for the new location of a missing bookmark
+///
+/// The sidebar root is preferred; otherwise the closest existing ancestor is
+/// used unless it is too broad to walk (the home or filesystem root).
+fn relink_search_root(state: &AppState, missing: &Path) -> Option<PathBuf> {
+    if let Some(root) = state.sidebar.read().root_directory.clone() {
+        return Some(root);
+    }
+    let ancestor = missing.ancestors().skip(1).find(|dir| dir.is_dir())?;
+    let too_broad = ancestor.parent().is_none() || Some(ancestor) == dirs::home_dir().as_deref();
+    (!too_broad).then(|| ancestor.to_path_buf())
+}
+
+/// Show a file picker to choose the new location of a missing bookmark
+fn pick_relink_target(missing: &Path) -> Option<PathBuf> {
+    use rfd::FileDialog;
+
+    let dialog = FileDialog::new();
+    let dialog = match missing.ancestors().skip(1).find(|dir| dir.is_dir()) {
+        Some(dir) => dialog.set_directory(dir),
+        None => dialog,
+    };
+    // Paths without an extension are most likely directories
+    if missing.extension().is_some() {
+        dialog.pick_file()
+    } else {
+        dialog.pick_folder()
+    }
 }
 
 /// Quick Access section in the sidebar
@@ -52,6 +119,10 @@ pub fn QuickAccess() -> Element {
     let mut dragging_index = use_signal(|| None::<usize>);
     let mut drop_target_index = use_signal(|| None::<usize>);
 
+    // Context menu and inline folder renaming
+    let mut menu = use_signal(|| None::<QuickAccessMenu>);
+    let renaming_folder = use_signal(|| None::<BookmarkFolderId>);
+
     // Subscribe to bookmark changes and refresh exists status
     use_future(move || async move {
         let mut rx = BOOKMARKS_CHANGED.subscribe();
@@ -60,13 +131,112 @@ pub fn QuickAccess() -> Element {
         }
     });
 
-    let items = bookmarks.read();
+    let data = bookmarks.read();
 
     // Don't render if no bookmarks
-    if items.is_empty() {
+    if data.items.is_empty() && data.folders.is_empty() {
         return rsx! {};
     }
 
+    // Bookmarks referring to a folder that no longer exists are shown ungrouped
+    let in_folder = |item: &CachedBookmark, folder: Option<&BookmarkFolderId>| {
+        let known = item
+            .bookmark
+            .folder
+            .as_ref()
+            .filter(|id| data.folders.iter().any(|f| f.id == **id));
+        known == folder
+    };
+    let ungrouped: Vec<(usize, CachedBookmark)> = data
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| in_folder(item, None))
+        .map(|(index, item)| (index, item.clone()))
+        .collect();
+    let groups: Vec<(BookmarkFolder, Vec<(usize, CachedBookmark)>)> = data
+        .folders
+        .iter()
+        .map(|folder| {
+            let items = data
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| in_folder(item, Some(&folder.id)))
+                .map(|(index, item)| (index, item.clone()))
+                .collect();
+            (folder.clone(), items)
+        })
+        .collect();
+
+    let render_item = move |(index, cached): (usize, CachedBookmark)| {
+        rsx! {
+            QuickAccessItem {
+                key: "{cached.bookmark.key()}",
+                index,
+                bookmark: cached.bookmark.clone(),
+                exists: cached.exists,
+                item_is_directory: cached.is_dir,
+                is_dragging: *dragging_index.read() == Some(index),
+                is_drop_target: *drop_target_index.read() == Some(index),
+                on_click: move |(bookmark, is_directory): (Bookmark, bool)| {
+                    if is_directory {
+                        state.set_root_directory(&bookmark.path);
+                    } else {
+                        state.open_bookmark(&bookmark);
+                    }
+                },
+                on_context_menu: move |(index, position): (usize, (i32, i32))| {
+                    let item = bookmarks.peek().items.get(index).cloned();
+                    let Some(item) = item else {
+                        return;
+                    };
+                    // Missing bookmarks are offered files with the same name to relink to
+                    let relink_candidates = (!item.exists).then(|| {
+                        relink_search_root(&state, &item.bookmark.path)
+                            .map(|root| {
+                                find_relink_candidates(
+                                    &item.bookmark.path,
+                                    &root,
+                                    MAX_RELINK_CANDIDATES,
+                                )
+                            })
+                            .unwrap_or_default()
+                    });
+                    menu.set(Some(QuickAccessMenu::Item {
+                        index,
+                        position,
+                        relink_candidates,
+                    }));
+                },
+                on_drag_start: move |idx| {
+                    dragging_index.set(Some(idx));
+                },
+                on_drag_over: move |idx| {
+                    if dragging_index.read().is_some() {
+                        drop_target_index.set(Some(idx));
+                    }
+                },
+                on_drag_leave: move |_| {
+                    drop_target_index.set(None);
+                },
+                on_drag_end: move |_| {
+                    // Perform the reorder if we have valid indices
+                    if let (Some(from), Some(to)) = (*dragging_index.read(), *drop_target_index.read()) {
+                        if from != to {
+                            reorder_bookmark(from, to);
+                        }
+                    }
+                    dragging_index.set(None);
+                    drop_target_index.set(None);
+                },
+            }
+        }
+    };
+
+    let mut renaming = renaming_folder;
+    let current_menu = menu.read().clone();
+
     rsx! {
         div {
             class: "left-sidebar-quick-access",
@@ -79,7 +249,16 @@ pub fn QuickAccess() -> Element {
                     size: 14,
                     class: "left-sidebar-quick-access-header-icon",
                 }
-                span { class: "left-sidebar-quick-access-title", "QUICK ACCESS" }
+                span { class: "left-sidebar-quick-access-title", {t("sidebar.quick_access")} }
+                button {
+                    class: "left-sidebar-quick-access-header-button",
+                    title: t("bookmarks.new_folder"),
+                    onclick: move |_| {
+                        let id = add_bookmark_folder(t("bookmarks.new_folder"));
+                        renaming.set(Some(id));
+                    },
+                    Icon { name: IconName::Add, size: 12 }
+                }
             }
 
             // Bookmark items
@@ -89,45 +268,188 @@ pub fn QuickAccess() -> Element {
                     evt.stop_propagation();
                     evt.prevent_default();
                 },
-                for (index, cached) in items.iter().enumerate() {
-                    QuickAccessItem {
-                        key: "{cached.bookmark.path.display()}",
-                        index,
-                        bookmark: cached.bookmark.clone(),
-                        exists: cached.exists,
-                        item_is_directory: cached.is_dir,
-                        is_dragging: *dragging_index.read() == Some(index),
-                        is_drop_target: *drop_target_index.read() == Some(index),
-                        on_click: move |(bookmark, is_directory): (Bookmark, bool)| {
-                            if is_directory {
-                                state.set_root_directory(&bookmark.path);
-                            } else {
-                                state.open_file(&bookmark.path);
+                for entry in ungrouped {
+                    {render_item(entry)}
+                }
+                for (folder, items) in groups {
+                    QuickAccessFolder {
+                        key: "{folder.id}",
+                        folder: folder.clone(),
+                        count: items.len(),
+                        renaming_folder,
+                        on_context_menu: {
+                            let id = folder.id.clone();
+                            move |position| {
+                                menu.set(Some(QuickAccessMenu::Folder {
+                                    id: id.clone(),
+                                    position,
+                                }));
                             }
                         },
-                        on_drag_start: move |idx| {
-                            dragging_index.set(Some(idx));
-                        },
-                        on_drag_over: move |idx| {
-                            if dragging_index.read().is_some() {
-                                drop_target_index.set(Some(idx));
+                    }
+                    if !folder.collapsed {
+                        div {
+                            key: "{folder.id}-items",
+                            class: "left-sidebar-quick-access-folder-items",
+                            for entry in items {
+                                {render_item(entry)}
                             }
-                        },
-                        on_drag_leave: move |_| {
-                            drop_target_index.set(None);
-                        },
-                        on_drag_end: move |_| {
-                            // Perform the reorder if we have valid indices
-                            if let (Some(from), Some(to)) = (*dragging_index.read(), *drop_target_index.read()) {
-                                if from != to {
-                                    reorder_bookmark(from, to);
+                        }
+                    }
+                }
+            }
+
+            match current_menu {
+                Some(QuickAccessMenu::Item { index, position, relink_candidates }) => {
+                    let missing = data.items.get(index).map(|item| item.bookmark.path.clone());
+                    rsx! {
+                        QuickAccessContextMenu {
+                            position,
+                            folder: data.items.get(index).and_then(|item| item.bookmark.folder.clone()),
+                            folders: data.folders.clone(),
+                            relink_candidates,
+                            on_close: move |_| menu.set(None),
+                            on_move_to_folder: move |folder| {
+                                set_bookmark_folder(index, folder);
+                                menu.set(None);
+                            },
+                            on_relink: {
+                                let missing = missing.clone();
+                                move |to: PathBuf| {
+                                    if let Some(from) = &missing {
+                                        relink_bookmarks(from, &to);
+                                    }
+                                    menu.set(None);
+                                }
+                            },
+                            on_choose_relink: move |_| {
+                                menu.set(None);
+                                if let Some(from) = &missing {
+                                    if let Some(to) = pick_relink_target(from) {
+                                        relink_bookmarks(from, &to);
+                                    }
                                 }
+                            },
+                            on_remove: move |_| {
+                                remove_bookmark_at(index);
+                                menu.set(None);
+                            },
+                        }
+                    }
+                }
+                Some(QuickAccessMenu::Folder { id, position }) => rsx! {
+                    QuickAccessFolderContextMenu {
+                        position,
+                        on_close: move |_| menu.set(None),
+                        on_rename: {
+                            let id = id.clone();
+                            move |_| {
+                                renaming.set(Some(id.clone()));
+                                menu.set(None);
                             }
-                            dragging_index.set(None);
-                            drop_target_index.set(None);
                         },
+                        on_delete: move |_| {
+                            remove_bookmark_folder(&id);
+                            menu.set(None);
+                        },
+                    }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
+
+/// Collapsible folder header in the Quick Access list
+#[component]
+fn QuickAccessFolder(
+    folder: BookmarkFolder,
+    /// Number of bookmarks in the folder
+    count: usize,
+    /// Folder whose name is being edited inline
+    renaming_folder: Signal<Option<BookmarkFolderId>>,
+    on_context_menu: EventHandler<(i32, i32)>,
+) -> Element {
+    let is_renaming = renaming_folder.read().as_ref() == Some(&folder.id);
+    let mut draft = use_signal(|| folder.name.clone());
+
+    let chevron = if folder.collapsed {
+        IconName::ChevronRight
+    } else {
+        IconName::ChevronDown
+    };
+    let icon = if folder.collapsed {
+        IconName::Folder
+    } else {
+        IconName::FolderOpen
+    };
+
+    let id = folder.id.clone();
+    let commit = use_callback(move |save: bool| {
+        let mut renaming_folder = renaming_folder;
+        let name = draft.read().trim().to_string();
+        if save && !name.is_empty() {
+            rename_bookmark_folder(&id, name);
+        }
+        renaming_folder.set(None);
+    });
+
+    rsx! {
+        div {
+            class: "left-sidebar-quick-access-folder",
+            onclick: {
+                let id = folder.id.clone();
+                move |_| {
+                    if !is_renaming {
+                        toggle_bookmark_folder_collapsed(&id);
                     }
                 }
+            },
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                evt.stop_propagation();
+                let point = evt.data().client_coordinates();
+                on_context_menu.call((point.x as i32, point.y as i32));
+            },
+
+            Icon {
+                name: chevron,
+                size: 12,
+                class: "left-sidebar-quick-access-folder-chevron",
+            }
+            Icon {
+                name: icon,
+                size: 14,
+                class: "left-sidebar-quick-access-item-icon",
+            }
+
+            if is_renaming {
+                input {
+                    class: "left-sidebar-quick-access-folder-input",
+                    r#type: "text",
+                    value: "{draft}",
+                    autofocus: true,
+                    onmounted: move |evt| async move {
+                        let _ = evt.set_focus(true).await;
+                    },
+                    onclick: move |evt| evt.stop_propagation(),
+                    oninput: move |evt| draft.set(evt.value()),
+                    onkeydown: move |evt| match evt.key() {
+                        Key::Enter => commit.call(true),
+                        Key::Escape => commit.call(false),
+                        _ => {}
+                    },
+                    onblur: move |_| commit.call(true),
+                }
+            } else {
+                span {
+                    class: "left-sidebar-quick-access-folder-name",
+                    "{folder.name}"
+                }
+                span {
+                    class: "left-sidebar-quick-access-folder-count",
+                    "{count}"
+                }
             }
         }
     }
@@ -145,6 +467,7 @@ fn QuickAccessItem(
     is_dragging: bool,
     is_drop_target: bool,
     on_click: EventHandler<(Bookmark, bool)>,
+    on_context_menu: EventHandler<(usize, (i32, i32))>,
     on_drag_start: EventHandler<usize>,
     on_drag_over: EventHandler<usize>,
     on_drag_leave: EventHandler<()>,
@@ -153,10 +476,11 @@ fn QuickAccessItem(
     let path = bookmark.path.clone();
     let display_name = bookmark.display_name().to_string();
 
-    let icon_name = if item_is_directory {
-        IconName::Folder
-    } else {
-        IconName::File
+    let icon_name = match &bookmark.target {
+        Some(BookmarkTarget::Heading { .. }) => IconName::List,
+        Some(BookmarkTarget::Scroll { .. }) => IconName::Pin,
+        None if item_is_directory => IconName::Folder,
+        None => IconName::File,
     };
 
     let mut classes = vec!["left-sidebar-quick-access-item"];
@@ -172,9 +496,14 @@ fn QuickAccessItem(
     let class_str = classes.join(" ");
 
     let title = if exists {
-        path.to_string_lossy().to_string()
+        bookmark.key()
     } else {
-        format!("{} (not found)", path.to_string_lossy())
+        format!("{} (not found, click to relink)", bookmark.key())
+    };
+
+    let open_menu = move |evt: Event<MouseData>| {
+        let point = evt.data().client_coordinates();
+        on_context_menu.call((index, (point.x as i32, point.y as i32)));
     };
 
     rsx! {
@@ -201,12 +530,20 @@ fn QuickAccessItem(
             },
             onclick: {
                 let bookmark = bookmark.clone();
-                move |_| {
+                move |evt| {
                     if exists {
                         on_click.call((bookmark.clone(), item_is_directory));
+                    } else {
+                        // Offer relinking instead of opening a missing file
+                        open_menu(evt);
                     }
                 }
             },
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                evt.stop_propagation();
+                open_menu(evt);
+            },
 
             // Drag handle indicator (visual only, drag is on parent)
             span {
@@ -232,7 +569,20 @@ fn QuickAccessItem(
             }
 
             // Remove button
-            BookmarkButton { path: path.clone(), size: 12 }
+            if bookmark.target.is_some() {
+                button {
+                    class: "bookmark-button bookmarked",
+                    title: t("context_menu.remove_from_quick_access"),
+                    draggable: false,
+                    onclick: move |evt| {
+                        evt.stop_propagation();
+                        remove_bookmark_at(index);
+                    },
+                    Icon { name: IconName::StarFilled, size: 12 }
+                }
+            } else {
+                BookmarkButton { path: path.clone(), size: 12 }
+            }
         }
     }
 }
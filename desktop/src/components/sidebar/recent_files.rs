@@ -0,0 +1,156 @@
+//! Recent section component for the sidebar.
+//!
+//! Lists recently opened files and directories (most recent first), shared
+//! with the "Open Recent" menu.
+
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
+use crate::recent::{record_recent, remove_recent, RECENT_ITEMS, RECENT_ITEMS_CHANGED};
+use crate::state::AppState;
+
+/// Recent item with cached filesystem status to avoid filesystem calls during render
+#[derive(Clone, PartialEq)]
+struct CachedRecentItem {
+    path: PathBuf,
+    exists: bool,
+    is_dir: bool,
+}
+
+/// Load recent items with cached exists status
+fn load_cached_recent_items() -> Vec<CachedRecentItem> {
+    RECENT_ITEMS
+        .read()
+        .items
+        .iter()
+        .map(|path| CachedRecentItem {
+            path: path.clone(),
+            exists: path.exists(),
+            is_dir: path.is_dir(),
+        })
+        .collect()
+}
+
+/// Recent section in the sidebar
+#[component]
+pub fn RecentFiles() -> Element {
+    let mut state = use_context::<AppState>();
+
+    let mut items = use_signal(load_cached_recent_items);
+    let mut collapsed = use_signal(|| false);
+
+    // Subscribe to recent item changes and refresh exists status
+    use_future(move || async move {
+        let mut rx = RECENT_ITEMS_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            items.set(load_cached_recent_items());
+        }
+    });
+
+    let items = items.read();
+
+    // Don't render if nothing was opened yet
+    if items.is_empty() {
+        return rsx! {};
+    }
+
+    let is_collapsed = collapsed();
+
+    rsx! {
+        div {
+            class: "left-sidebar-recent",
+
+            // Header (click to collapse)
+            div {
+                class: "left-sidebar-recent-header",
+                onclick: move |_| collapsed.toggle(),
+                Icon {
+                    name: IconName::History,
+                    size: 14,
+                    class: "left-sidebar-recent-header-icon",
+                }
+                span { class: "left-sidebar-recent-title", {t("sidebar.recent")} }
+                span { class: "left-sidebar-recent-count", "{items.len()}" }
+                Icon {
+                    name: if is_collapsed { IconName::ChevronRight } else { IconName::ChevronDown },
+                    size: 12,
+                    class: "left-sidebar-recent-header-icon",
+                }
+            }
+
+            if !is_collapsed {
+                div {
+                    class: "left-sidebar-recent-list",
+                    for item in items.iter().cloned() {
+                        RecentItem {
+                            key: "{item.path.display()}",
+                            path: item.path.clone(),
+                            exists: item.exists,
+                            item_is_directory: item.is_dir,
+                            on_click: move |(path, is_directory): (PathBuf, bool)| {
+                                if is_directory {
+                                    record_recent(&path);
+                                    state.set_root_directory(path);
+                                } else {
+                                    state.open_file(path);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RecentItem(
+    path: PathBuf,
+    exists: bool,
+    item_is_directory: bool,
+    on_click: EventHandler<(PathBuf, bool)>,
+) -> Element {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string());
+    let title = path.to_string_lossy().to_string();
+
+    let icon = if item_is_directory {
+        IconName::Folder
+    } else {
+        IconName::File
+    };
+
+    let remove_path = path.clone();
+
+    rsx! {
+        div {
+            class: "left-sidebar-recent-item",
+            class: if !exists { "missing" },
+            title: "{title}",
+            onclick: move |_| {
+                if exists {
+                    on_click.call((path.clone(), item_is_directory));
+                }
+            },
+
+            Icon {
+                name: icon,
+                size: 14,
+                class: "left-sidebar-recent-item-icon",
+            }
+            span { class: "left-sidebar-recent-item-name", "{name}" }
+            button {
+                class: "left-sidebar-recent-item-remove",
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    remove_recent(&remove_path);
+                },
+                Icon { name: IconName::Close, size: 12 }
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Tags section component for the sidebar.
+//!
+//! Groups the Markdown files under the root directory by the tags declared
+//! in their frontmatter, so every document tagged e.g. `adr` can be browsed.
+
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::components::icon::{Icon, IconName};
+use crate::frontmatter::{build_tag_index, TaggedDocument};
+use crate::i18n::t;
+use crate::state::AppState;
+
+/// Tags section in the sidebar
+#[component]
+pub fn TagIndex(root: PathBuf) -> Element {
+    let mut state = use_context::<AppState>();
+    let mut collapsed = use_signal(|| false);
+
+    // Walking the tree reads every Markdown file, so keep it off the UI thread
+    let index = use_resource(use_reactive!(|root| async move {
+        tokio::task::spawn_blocking(move || build_tag_index(&root))
+            .await
+            .unwrap_or_default()
+    }));
+
+    let index: BTreeMap<String, Vec<TaggedDocument>> = match &*index.read() {
+        Some(index) if !index.is_empty() => index.clone(),
+        _ => return rsx! {},
+    };
+
+    let is_collapsed = collapsed();
+    let expanded_tags = state.sidebar.read().expanded_tags.clone();
+
+    rsx! {
+        div {
+            class: "left-sidebar-tags",
+
+            // Header (click to collapse)
+            div {
+                class: "left-sidebar-tags-header",
+                onclick: move |_| collapsed.toggle(),
+                Icon {
+                    name: IconName::Tag,
+                    size: 14,
+                    class: "left-sidebar-tags-header-icon",
+                }
+                span { class: "left-sidebar-tags-title", {t("sidebar.tags")} }
+                span { class: "left-sidebar-tags-count", "{index.len()}" }
+                Icon {
+                    name: if is_collapsed { IconName::ChevronRight } else { IconName::ChevronDown },
+                    size: 12,
+                    class: "left-sidebar-tags-header-icon",
+                }
+            }
+
+            if !is_collapsed {
+                div {
+                    class: "left-sidebar-tags-list",
+                    for (tag, documents) in index {
+                        {
+                            let is_expanded = expanded_tags.contains(&tag);
+                            let toggle_tag = tag.clone();
+                            rsx! {
+                                div {
+                                    key: "{tag}",
+                                    class: "left-sidebar-tag",
+                                    class: if is_expanded { "expanded" },
+                                    onclick: move |_| {
+                                        let mut sidebar = state.sidebar.write();
+                                        if !sidebar.expanded_tags.remove(&toggle_tag) {
+                                            sidebar.expanded_tags.insert(toggle_tag.clone());
+                                        }
+                                    },
+                                    Icon {
+                                        name: if is_expanded { IconName::ChevronDown } else { IconName::ChevronRight },
+                                        size: 12,
+                                        class: "left-sidebar-tag-chevron",
+                                    }
+                                    span { class: "left-sidebar-tag-name", "#{tag}" }
+                                    span { class: "left-sidebar-tag-count", "{documents.len()}" }
+                                }
+                                if is_expanded {
+                                    for document in documents {
+                                        TaggedDocumentItem {
+                                            key: "{tag}-{document.path.display()}",
+                                            document,
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TaggedDocumentItem(document: TaggedDocument) -> Element {
+    let mut state = use_context::<AppState>();
+    let label = document.label();
+    let title = document.path.to_string_lossy().to_string();
+
+    rsx! {
+        div {
+            class: "left-sidebar-tag-document",
+            title: "{title}",
+            onclick: move |_| state.open_file(&document.path),
+            Icon {
+                name: IconName::File,
+                size: 14,
+                class: "left-sidebar-tag-document-icon",
+            }
+            span { class: "left-sidebar-tag-document-name", "{label}" }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+
+use crate::components::content::set_preferences_tab_to_watcher;
+use crate::components::icon::{Icon, IconName};
+use crate::i18n::{t, t_args};
+use crate::state::AppState;
+use crate::watcher::{WatcherStatus, FILE_WATCHER};
+
+/// Warning shown while some paths are polled or not watched at all
+#[component]
+pub fn WatcherNotice() -> Element {
+    let mut state = use_context::<AppState>();
+    let mut status = use_signal(|| FILE_WATCHER.status().borrow().clone());
+
+    use_future(move || async move {
+        let mut rx = FILE_WATCHER.status();
+        while rx.changed().await.is_ok() {
+            let current = rx.borrow_and_update().clone();
+            status.set(current);
+        }
+    });
+
+    let status = status();
+    if !status.is_degraded() {
+        return rsx! {};
+    }
+
+    let (label, details) = describe(&status);
+
+    rsx! {
+        div {
+            class: "left-sidebar-watcher-notice",
+            class: if !status.failed.is_empty() { "failed" },
+            title: "{details}",
+            onclick: move |_| {
+                set_preferences_tab_to_watcher();
+                state.open_preferences();
+            },
+            Icon { name: IconName::AlertTriangle, size: 14 }
+            span { "{label}" }
+        }
+    }
+}
+
+/// Short label and a tooltip listing the affected paths
+fn describe(status: &WatcherStatus) -> (&'static str, String) {
+    let label = if status.failed.is_empty() {
+        t("watcher.polling")
+    } else {
+        t("watcher.unavailable")
+    };
+    let details = status
+        .polled
+        .iter()
+        .map(|path| {
+            t_args(
+                "watcher.polled_path",
+                &[("path", &path.display().to_string())],
+            )
+        })
+        .chain(status.failed.iter().map(|(path, reason)| {
+            t_args(
+                "watcher.failed_path",
+                &[("path", &path.display().to_string()), ("reason", reason)],
+            )
+        }))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (label, details)
+}
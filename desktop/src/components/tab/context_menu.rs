@@ -4,6 +4,7 @@ use dioxus::desktop::tao::window::WindowId;
 use dioxus::prelude::*;
 
 use crate::components::icon::{Icon, IconName};
+use crate::i18n::t;
 
 #[component]
 pub fn TabContextMenu(
@@ -37,7 +38,7 @@ pub fn TabContextMenu(
 
             // === Section 1: Window operations ===
             ContextMenuItem {
-                label: "Open in New Window",
+                label: t("context_menu.open_in_new_window"),
                 disabled: disabled,
                 on_click: move |_| on_open_in_new_window.call(()),
             }
@@ -52,7 +53,7 @@ pub fn TabContextMenu(
                 },
                 onmouseleave: move |_| show_submenu.set(false),
 
-                span { class: "context-menu-label", "Move to Window" }
+                span { class: "context-menu-label", {t("context_menu.move_to_window")} }
                 span { class: "submenu-arrow", "›" }
 
                 if *show_submenu.read() {
@@ -62,7 +63,7 @@ pub fn TabContextMenu(
                         if other_windows.is_empty() {
                             div {
                                 class: "context-menu-item disabled",
-                                "No other windows"
+                                {t("context_menu.no_other_windows")}
                             }
                         } else {
                             for (window_id, title) in other_windows.iter() {
@@ -88,14 +89,14 @@ pub fn TabContextMenu(
             ContextMenuSeparator {}
 
             ContextMenuItem {
-                label: "Copy File Path",
+                label: t("context_menu.copy_file_path"),
                 icon: Some(IconName::Copy),
                 disabled: !has_file,
                 on_click: move |_| on_copy_path.call(()),
             }
 
             ContextMenuItem {
-                label: "Reveal in Finder",
+                label: t("context_menu.reveal_in_finder"),
                 icon: Some(IconName::Folder),
                 disabled: !has_file,
                 on_click: move |_| on_reveal_in_finder.call(()),
@@ -105,14 +106,14 @@ pub fn TabContextMenu(
             ContextMenuSeparator {}
 
             ContextMenuItem {
-                label: "Set Parent as Root",
+                label: t("context_menu.set_parent_as_root"),
                 icon: Some(IconName::FolderOpen),
                 disabled: !has_file,
                 on_click: move |_| on_set_parent_as_root.call(()),
             }
 
             ContextMenuItem {
-                label: "Reload",
+                label: t("context_menu.reload"),
                 icon: Some(IconName::Refresh),
                 disabled: !has_file,
                 on_click: move |_| on_reload.call(()),
@@ -10,6 +10,7 @@ use super::tab_item::TabItem;
 use crate::components::icon::{Icon, IconName};
 use crate::drag::{self, GlobalActiveDrag};
 use crate::events::ACTIVE_DRAG_UPDATE;
+use crate::i18n::t;
 use crate::state::AppState;
 use crate::window::Offset;
 
@@ -457,7 +458,7 @@ fn PreferencesButton() -> Element {
         button {
             class: "tab-preferences",
             class: if is_preferences_active { "active" },
-            title: "Preferences",
+            title: t("header.preferences"),
             onclick: move |_| {
                 state.toggle_preferences();
             },
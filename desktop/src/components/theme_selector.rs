@@ -4,15 +4,41 @@ use dioxus_sdk_window::theme::use_system_theme;
 
 use crate::components::icon::{Icon, IconName};
 use crate::state::LAST_FOCUSED_STATE;
-use crate::theme::{DioxusTheme, Theme};
+use crate::theme::{
+    start_watching_user_styles, DioxusTheme, Theme, USER_STYLES, USER_STYLES_CHANGED,
+};
+
+/// Icon shown for a theme in the selector and preferences
+pub fn theme_icon(theme: &Theme) -> IconName {
+    match theme {
+        Theme::Auto => IconName::SunMoon,
+        Theme::Light => IconName::Sun,
+        Theme::Dark => IconName::Moon,
+        Theme::HighContrast => IconName::Contrast,
+        _ => IconName::Palette,
+    }
+}
 
 #[component]
 pub fn ThemeSelector(current_theme: Signal<Theme>) -> Element {
     let system_theme = use_system_theme();
-    let resolved_theme = use_memo(move || match current_theme() {
-        Theme::Auto => system_theme().unwrap_or(DioxusTheme::Light),
-        Theme::Light => DioxusTheme::Light,
-        Theme::Dark => DioxusTheme::Dark,
+
+    // Bumped whenever user stylesheets are reloaded from disk
+    let mut styles_version = use_signal(|| 0_u64);
+    use_hook(start_watching_user_styles);
+    use_future(move || async move {
+        let mut rx = USER_STYLES_CHANGED.subscribe();
+        while rx.recv().await.is_ok() {
+            styles_version += 1;
+        }
+    });
+
+    let resolved_theme = use_memo(move || {
+        // User themes declare their base in CSS
+        let _ = styles_version();
+        current_theme()
+            .base()
+            .unwrap_or_else(|| system_theme().unwrap_or(DioxusTheme::Light))
     });
 
     // Dispatch custom event when resolved theme changes
@@ -34,6 +60,52 @@ pub fn ThemeSelector(current_theme: Signal<Theme>) -> Element {
         });
     });
 
+    // Apply the palette of the reading theme and the user stylesheets
+    use_effect(move || {
+        let _ = styles_version();
+        let theme = current_theme();
+        let (theme_css, user_css) = {
+            let styles = USER_STYLES.read();
+            let theme_css = match &theme {
+                Theme::User(name) => styles.theme(name).map(|theme| theme.css.clone()),
+                _ => None,
+            };
+            (theme_css, styles.user_css.clone())
+        };
+        let reading_theme = serde_json::to_string(&theme.reading_theme()).unwrap_or_default();
+        let theme_css = serde_json::to_string(&theme_css).unwrap_or_default();
+        let user_css = serde_json::to_string(&user_css).unwrap_or_default();
+        spawn(async move {
+            // Style elements are moved to the end of <head> so they apply after MAIN_STYLE
+            let js = indoc::formatdoc! {r#"
+                (() => {{
+                    const readingTheme = {reading_theme};
+                    if (readingTheme) {{
+                        document.body.dataset.readingTheme = readingTheme;
+                    }} else {{
+                        delete document.body.dataset.readingTheme;
+                    }}
+                    const apply = (id, css) => {{
+                        let element = document.getElementById(id);
+                        if (css === null) {{
+                            element?.remove();
+                            return;
+                        }}
+                        if (!element) {{
+                            element = document.createElement("style");
+                            element.id = id;
+                        }}
+                        element.textContent = css;
+                        document.head.append(element);
+                    }};
+                    apply("arto-user-theme", {theme_css});
+                    apply("arto-user-css", {user_css});
+                }})();
+            "#};
+            let _ = document::eval(&js).await;
+        });
+    });
+
     // Save last selected theme in memory (persisted on window close)
     use_effect(move || {
         let theme = current_theme();
@@ -74,36 +146,16 @@ pub fn ThemeSelector(current_theme: Signal<Theme>) -> Element {
         });
     });
 
-    // Get current theme icon and title
-    let (current_icon, current_title) = match current_theme() {
-        Theme::Light => (IconName::Sun, "Light theme"),
-        Theme::Dark => (IconName::Moon, "Dark theme"),
-        Theme::Auto => (IconName::SunMoon, "Auto theme (follows system)"),
-    };
-
-    // Get other theme options (remaining 2 themes)
-    let other_themes = match current_theme() {
-        Theme::Light => [
-            (Theme::Dark, IconName::Moon, "Dark theme"),
-            (
-                Theme::Auto,
-                IconName::SunMoon,
-                "Auto theme (follows system)",
-            ),
-        ],
-        Theme::Dark => [
-            (Theme::Light, IconName::Sun, "Light theme"),
-            (
-                Theme::Auto,
-                IconName::SunMoon,
-                "Auto theme (follows system)",
-            ),
-        ],
-        Theme::Auto => [
-            (Theme::Light, IconName::Sun, "Light theme"),
-            (Theme::Dark, IconName::Moon, "Dark theme"),
-        ],
-    };
+    let theme = current_theme();
+    let current_icon = theme_icon(&theme);
+    let current_title = theme.label();
+
+    // Other theme options (built-in themes followed by user themes)
+    let _ = styles_version();
+    let other_themes: Vec<Theme> = Theme::available()
+        .into_iter()
+        .filter(|other| *other != theme)
+        .collect();
 
     rsx! {
         div {
@@ -125,7 +177,7 @@ pub fn ThemeSelector(current_theme: Signal<Theme>) -> Element {
                 Icon { name: current_icon, size: 18 }
             }
 
-            // Dropdown menu (remaining 2 themes)
+            // Dropdown menu (remaining themes)
             div {
                 class: "theme-selector-dropdown",
                 class: if is_expanded() { "theme-selector-dropdown--expanded" },
@@ -134,21 +186,25 @@ pub fn ThemeSelector(current_theme: Signal<Theme>) -> Element {
                     evt.stop_propagation();
                 },
 
-                for (theme, icon, title) in other_themes {
+                for theme in other_themes {
                     button {
+                        key: "{theme.id()}",
                         class: "theme-option",
                         role: "menuitem",
-                        title: title,
                         onmousedown: move |evt| {
                             evt.stop_propagation();
                         },
-                        onclick: move |evt| {
-                            evt.stop_propagation();
-                            let mut current_theme = current_theme;
-                            current_theme.set(theme);
-                            is_expanded.set(false);
+                        onclick: {
+                            let theme = theme.clone();
+                            move |evt: Event<MouseData>| {
+                                evt.stop_propagation();
+                                let mut current_theme = current_theme;
+                                current_theme.set(theme.clone());
+                                is_expanded.set(false);
+                            }
                         },
-                        Icon { name: icon, size: 18 }
+                        Icon { name: theme_icon(&theme), size: 18 }
+                        span { class: "theme-option-label", "{theme.label()}" }
                     }
                 }
             }
@@ -2,6 +2,13 @@
 mod app_config;
 pub use app_config::*;
 
+// Schema versions of config.json and the migrations between them
+mod migration;
+
 // Configuration persistence (load/save methods + CONFIG global)
 mod persistence;
-pub use persistence::CONFIG;
+pub use persistence::{CONFIG, CONFIG_CHANGED, CONFIG_ERROR};
+
+// Applying settings to the running app, and following edits to config.json
+mod reload;
+pub use reload::{apply_config, watch_config_file};
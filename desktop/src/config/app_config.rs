@@ -1,19 +1,28 @@
+use crate::i18n::Language;
 use serde::{Deserialize, Serialize};
 
 mod behavior;
 mod directory_config;
+mod editing_config;
+mod keymap_config;
+mod recent_config;
 mod right_sidebar_config;
 mod sidebar_config;
 mod theme_config;
+mod watcher_config;
 mod window_dimension;
 mod window_position_config;
 mod window_size_config;
 
 pub use behavior::{NewWindowBehavior, StartupBehavior};
 pub use directory_config::DirectoryConfig;
+pub use editing_config::EditingConfig;
+pub use keymap_config::KeymapConfig;
+pub use recent_config::RecentConfig;
 pub use right_sidebar_config::{RightSidebarConfig, DEFAULT_RIGHT_SIDEBAR_WIDTH};
 pub use sidebar_config::SidebarConfig;
 pub use theme_config::ThemeConfig;
+pub use watcher_config::{WatcherBackend, WatcherConfig};
 pub use window_dimension::{WindowDimension, WindowDimensionUnit};
 pub use window_position_config::{
     WindowPosition, WindowPositionConfig, WindowPositionMode, WindowPositionOffset,
@@ -30,10 +39,16 @@ pub struct Config {
     pub right_sidebar: RightSidebarConfig,
     pub window_position: WindowPositionConfig,
     pub window_size: WindowSizeConfig,
+    pub keymap: KeymapConfig,
+    pub language: Language,
+    pub recent: RecentConfig,
+    pub editing: EditingConfig,
+    pub watcher: WatcherConfig,
 }
 
 #[cfg(test)]
 mod tests {
+    use super::recent_config::DEFAULT_RECENT_MAX_ITEMS;
     use super::window_position_config::WindowPositionOffset;
     use super::*;
     use crate::theme::Theme;
@@ -63,6 +78,8 @@ mod tests {
         // Right sidebar defaults
         assert!(!config.right_sidebar.default_open);
         assert_eq!(config.right_sidebar.default_width, 220.0);
+        assert_eq!(config.right_sidebar.toc_max_depth, 6);
+        assert!(!config.right_sidebar.toc_numbering);
         assert_eq!(config.right_sidebar.on_startup, StartupBehavior::Default);
         assert_eq!(
             config.right_sidebar.on_new_window,
@@ -105,6 +122,22 @@ mod tests {
             config.window_position.on_new_window,
             NewWindowBehavior::Default
         );
+
+        // Language defaults
+        assert_eq!(config.language, Language::Auto);
+
+        // Recent items defaults
+        assert_eq!(config.recent.max_items, DEFAULT_RECENT_MAX_ITEMS);
+
+        // Editing defaults
+        assert!(!config.editing.interactive_tasks);
+        assert_eq!(config.editing.editor_command, None);
+
+        // Watcher defaults
+        assert_eq!(config.watcher.backend, WatcherBackend::Auto);
+        assert_eq!(config.watcher.poll_interval_ms, 2000);
+        assert_eq!(config.watcher.debounce_ms, 500);
+        assert!(config.watcher.ignore.is_empty());
     }
 
     #[test]
@@ -133,6 +166,8 @@ mod tests {
                 default_tab: Default::default(),
                 on_startup: StartupBehavior::LastClosed,
                 on_new_window: NewWindowBehavior::LastFocused,
+                toc_max_depth: 3,
+                toc_numbering: true,
             },
             window_position: WindowPositionConfig {
                 default_position: WindowPosition {
@@ -164,12 +199,32 @@ mod tests {
                 on_startup: StartupBehavior::LastClosed,
                 on_new_window: NewWindowBehavior::LastFocused,
             },
+            keymap: Default::default(),
+            language: Language::Ja,
+            recent: RecentConfig { max_items: 5 },
+            editing: EditingConfig {
+                interactive_tasks: true,
+                editor_command: Some("code -g {file}:{line}:{column}".to_string()),
+            },
+            watcher: WatcherConfig {
+                backend: WatcherBackend::Polling,
+                poll_interval_ms: 5000,
+                debounce_ms: 250,
+                ignore: vec!["node_modules".to_string(), "*.tmp".to_string()],
+            },
         };
 
         let json = serde_json::to_string_pretty(&config).unwrap();
         let parsed: Config = serde_json::from_str(&json).unwrap();
 
         assert_eq!(parsed.theme.default_theme, Theme::Dark);
+        assert_eq!(parsed.language, Language::Ja);
+        assert_eq!(parsed.recent.max_items, 5);
+        assert!(parsed.editing.interactive_tasks);
+        assert_eq!(
+            parsed.editing.editor_command.as_deref(),
+            Some("code -g {file}:{line}:{column}")
+        );
         assert_eq!(parsed.theme.on_startup, StartupBehavior::LastClosed);
         assert_eq!(
             parsed.directory.default_directory,
@@ -178,6 +233,8 @@ mod tests {
         assert!(!parsed.sidebar.default_open);
         assert_eq!(parsed.sidebar.default_width, 320.0);
         assert!(parsed.right_sidebar.default_open);
+        assert_eq!(parsed.right_sidebar.toc_max_depth, 3);
+        assert!(parsed.right_sidebar.toc_numbering);
         assert_eq!(parsed.right_sidebar.default_width, 250.0);
         assert_eq!(parsed.window_position.default_position.x.value, 10.0);
         assert_eq!(
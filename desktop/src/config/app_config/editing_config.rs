@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for changes made to documents from the viewer
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EditingConfig {
+    /// Clicking a task list checkbox toggles `[ ]`/`[x]` in the source file
+    pub interactive_tasks: bool,
+    /// Shell command opening a file in an editor, with `{file}`, `{line}` and
+    /// `{column}` placeholders (e.g. `code -g {file}:{line}:{column}`).
+    /// Falls back to `$VISUAL`/`$EDITOR`, run in a Terminal window, when unset.
+    pub editor_command: Option<String>,
+}
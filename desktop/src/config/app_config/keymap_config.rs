@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Configuration for keyboard shortcuts
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct KeymapConfig {
+    /// Shortcut overrides keyed by command id (e.g. "edit.find" => "Cmd+Shift+F").
+    /// An empty string removes the default shortcut of the command.
+    pub bindings: BTreeMap<String, String>,
+    /// Enable modal vim-style keys (j/k, gg/G, ]]/[[, n/N, f for link hints)
+    pub vim_navigation: bool,
+}
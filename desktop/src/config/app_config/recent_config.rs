@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Default number of entries kept in "Open Recent"
+pub const DEFAULT_RECENT_MAX_ITEMS: usize = 20;
+
+/// Configuration for the recently opened files and directories list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecentConfig {
+    /// Maximum number of entries kept (0 disables the list)
+    pub max_items: usize,
+}
+
+impl Default for RecentConfig {
+    fn default() -> Self {
+        Self {
+            max_items: DEFAULT_RECENT_MAX_ITEMS,
+        }
+    }
+}
@@ -9,6 +9,13 @@ fn default_right_sidebar_width() -> f64 {
     DEFAULT_RIGHT_SIDEBAR_WIDTH
 }
 
+/// Default number of heading levels shown in the table of contents
+pub const DEFAULT_TOC_MAX_DEPTH: usize = 6;
+
+fn default_toc_max_depth() -> usize {
+    DEFAULT_TOC_MAX_DEPTH
+}
+
 /// Configuration for right sidebar panel settings
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,6 +31,12 @@ pub struct RightSidebarConfig {
     pub on_startup: StartupBehavior,
     /// Behavior when opening a new window: "default" or "last_focused"
     pub on_new_window: NewWindowBehavior,
+    /// Number of nested heading levels shown in the table of contents
+    #[serde(default = "default_toc_max_depth")]
+    pub toc_max_depth: usize,
+    /// Whether to prefix table of contents entries with section numbers (1.2.3)
+    #[serde(default)]
+    pub toc_numbering: bool,
 }
 
 impl Default for RightSidebarConfig {
@@ -34,6 +47,8 @@ impl Default for RightSidebarConfig {
             default_tab: RightSidebarTab::default(),
             on_startup: StartupBehavior::Default,
             on_new_window: NewWindowBehavior::Default,
+            toc_max_depth: default_toc_max_depth(),
+            toc_numbering: false,
         }
     }
 }
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// How changes to open files and directories are detected
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatcherBackend {
+    /// Native notifications, polling the paths the OS refuses to watch
+    #[default]
+    Auto,
+    /// Native notifications only (FSEvents, inotify, ...)
+    Native,
+    /// Periodically scan watched paths, for network drives and mounted volumes
+    Polling,
+}
+
+/// Configuration for the file watcher behind live reload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WatcherConfig {
+    pub backend: WatcherBackend,
+    /// Delay between scans of the polling backend
+    pub poll_interval_ms: u64,
+    /// Quiet period before a burst of changes is reported
+    pub debounce_ms: u64,
+    /// Glob patterns of paths whose changes never refresh directory views
+    pub ignore: Vec<String>,
+}
+
+impl Default for WatcherConfig {
+    fn default() -> Self {
+        Self {
+            backend: WatcherBackend::default(),
+            poll_interval_ms: 2000,
+            debounce_ms: 500,
+            ignore: Vec::new(),
+        }
+    }
+}
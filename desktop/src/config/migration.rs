@@ -0,0 +1,92 @@
+use serde_json::{Map, Value};
+
+/// Schema version written to config.json
+///
+/// Bump it together with a new entry in [`MIGRATIONS`] whenever a field is
+/// renamed or restructured, so existing files keep their values.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Key holding the schema version in config.json
+const VERSION_KEY: &str = "version";
+
+/// Rewrites a config object from one schema version to the next
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[n]` upgrades version `n` to `n + 1`
+const MIGRATIONS: [Migration; CONFIG_VERSION as usize] = [from_unversioned];
+
+/// Files written before versioning already have the version 1 layout
+fn from_unversioned(_config: &mut Map<String, Value>) {}
+
+/// Upgrade a parsed config.json to the current schema
+///
+/// Returns the version the file was written with. Files from a newer
+/// version are left alone; their unknown fields are ignored when loading.
+pub fn migrate(value: &mut Value) -> u32 {
+    migrate_with(value, &MIGRATIONS)
+}
+
+fn migrate_with(value: &mut Value, migrations: &[Migration]) -> u32 {
+    let Some(config) = value.as_object_mut() else {
+        return 0;
+    };
+    let version = config.get(VERSION_KEY).and_then(Value::as_u64).unwrap_or(0) as u32;
+
+    for (from, migration) in migrations.iter().enumerate().skip(version as usize) {
+        tracing::info!(from, to = from + 1, "Migrating configuration");
+        migration(config);
+    }
+    if (version as usize) < migrations.len() {
+        config.insert(VERSION_KEY.to_string(), Value::from(migrations.len()));
+    }
+    version
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rename_default_open(config: &mut Map<String, Value>) {
+        if let Some(Value::Object(sidebar)) = config.get_mut("sidebar") {
+            if let Some(open) = sidebar.remove("open") {
+                sidebar.insert("defaultOpen".to_string(), open);
+            }
+        }
+    }
+
+    #[test]
+    fn test_migrate_unversioned() {
+        let mut value = json!({ "language": "ja" });
+        assert_eq!(migrate(&mut value), 0);
+        assert_eq!(
+            value,
+            json!({ "language": "ja", "version": CONFIG_VERSION })
+        );
+    }
+
+    #[test]
+    fn test_migrate_runs_pending_migrations() {
+        let migrations: [Migration; 2] = [from_unversioned, rename_default_open];
+
+        let mut value = json!({ "version": 1, "sidebar": { "open": true } });
+        assert_eq!(migrate_with(&mut value, &migrations), 1);
+        assert_eq!(
+            value,
+            json!({ "version": 2, "sidebar": { "defaultOpen": true } })
+        );
+
+        // Already migrated files are untouched
+        let mut current = value.clone();
+        assert_eq!(migrate_with(&mut current, &migrations), 2);
+        assert_eq!(current, value);
+    }
+
+    #[test]
+    fn test_migrate_newer_version() {
+        let mut value = json!({ "version": CONFIG_VERSION + 1, "futureField": 1 });
+        let original = value.clone();
+        assert_eq!(migrate(&mut value), CONFIG_VERSION + 1);
+        assert_eq!(value, original);
+    }
+}
@@ -1,46 +1,144 @@
 use super::app_config::Config;
-use anyhow::Result;
+use super::migration::{self, CONFIG_VERSION};
+use crate::utils::file::write_atomic;
+use anyhow::{bail, Result};
 use parking_lot::RwLock;
+use serde::Serialize;
+use serde_json::Value;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::LazyLock;
+use thiserror::Error;
+use tokio::sync::broadcast;
 
-impl Config {
-    /// Get the configuration file path based on the platform
-    pub fn path() -> PathBuf {
-        const FILENAME: &str = "config.json";
-        if let Some(mut path) = dirs::config_dir() {
-            path.push("arto");
-            path.push(FILENAME);
-            return path;
-        }
+/// Why config.json could not be applied, shown in the Preferences view
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConfigError {
+    #[error("Failed to read the file: {0}")]
+    Read(String),
+    #[error("Line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[error("{0}")]
+    Invalid(String),
+}
 
-        // Fallback to home directory
-        if let Some(mut path) = dirs::home_dir() {
-            path.push(".arto");
-            path.push(FILENAME);
-            return path;
+impl From<serde_json::Error> for ConfigError {
+    fn from(error: serde_json::Error) -> Self {
+        let message = error.to_string();
+        if error.line() == 0 {
+            return Self::Invalid(message);
         }
+        // The position is reported separately
+        let position = format!(" at line {} column {}", error.line(), error.column());
+        Self::Parse {
+            line: error.line(),
+            column: error.column(),
+            message: message
+                .strip_suffix(&position)
+                .unwrap_or(&message)
+                .to_string(),
+        }
+    }
+}
 
-        PathBuf::from(FILENAME)
+/// Layout of config.json: the settings tagged with their schema version
+#[derive(Serialize)]
+struct VersionedConfig<'a> {
+    version: u32,
+    #[serde(flatten)]
+    config: &'a Config,
+}
+
+impl Config {
+    /// Get the configuration file path (see `crate::storage`)
+    pub fn path() -> PathBuf {
+        crate::storage::config_file("config.json")
     }
 
     /// Load configuration from file or return default configuration
-    pub fn load() -> Result<Self> {
+    pub fn load() -> Result<Self, ConfigError> {
         let path = Self::path();
 
         if !path.exists() {
             return Ok(Config::default());
         }
 
-        let content = fs::read_to_string(&path)?;
-        let config: Config = serde_json::from_str(&content)?;
+        let content = fs::read_to_string(&path).map_err(|e| ConfigError::Read(e.to_string()))?;
+        let config = Self::parse(&content)?;
 
         tracing::debug!(path = %path.display(), "Configuration loaded");
 
         Ok(config)
     }
 
+    /// Schema version of config.json if it was written by a newer version of Arto
+    ///
+    /// Such a file may hold settings this version does not know about, so it
+    /// is never overwritten.
+    pub fn newer_file_version() -> Option<u32> {
+        let content = fs::read_to_string(Self::path()).ok()?;
+        newer_version(&content)
+    }
+
+    /// Parse and validate the contents of config.json, migrating older schemas
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        let mut value: Value = serde_json::from_str(content)?;
+        let version = migration::migrate(&mut value);
+        if version > CONFIG_VERSION {
+            tracing::warn!(version, "Configuration written by a newer version");
+        }
+
+        let config: Config = match serde_json::from_value(value) {
+            Ok(config) => config,
+            // Re-read the text so the error points into the file, unless
+            // the problem only arose from migrating it
+            Err(e) => {
+                return Err(serde_json::from_str::<Config>(content)
+                    .err()
+                    .unwrap_or(e)
+                    .into())
+            }
+        };
+        config.validate().map_err(ConfigError::Invalid)?;
+        Ok(config)
+    }
+
+    /// Reject values that parse but cannot be used
+    fn validate(&self) -> Result<(), String> {
+        if self.sidebar.default_width <= 0.0 {
+            return Err("sidebar.defaultWidth must be positive".to_string());
+        }
+        if self.right_sidebar.default_width <= 0.0 {
+            return Err("rightSidebar.defaultWidth must be positive".to_string());
+        }
+        if !(1..=6).contains(&self.right_sidebar.toc_max_depth) {
+            return Err("rightSidebar.tocMaxDepth must be between 1 and 6".to_string());
+        }
+        let size = &self.window_size.default_size;
+        if size.width.value <= 0.0 || size.height.value <= 0.0 {
+            return Err("windowSize.defaultSize must be positive".to_string());
+        }
+        if self.watcher.poll_interval_ms < 100 {
+            return Err("watcher.pollIntervalMs must be at least 100".to_string());
+        }
+        if self.watcher.debounce_ms == 0 {
+            return Err("watcher.debounceMs must be positive".to_string());
+        }
+        Ok(())
+    }
+
+    /// The settings in the layout of config.json, tagged with the schema version
+    pub fn to_versioned_value(&self) -> serde_json::Result<Value> {
+        serde_json::to_value(VersionedConfig {
+            version: CONFIG_VERSION,
+            config: self,
+        })
+    }
+
     /// Save configuration to file
     pub fn save(&self) -> Result<()> {
         let path = Self::path();
@@ -50,8 +148,18 @@ impl Config {
             fs::create_dir_all(parent)?;
         }
 
-        let content = serde_json::to_string_pretty(&self)?;
-        fs::write(&path, content)?;
+        if let Some(version) = Self::newer_file_version() {
+            bail!(
+                "{} was written by a newer version of Arto (version {version})",
+                path.display()
+            );
+        }
+
+        let content = serde_json::to_string_pretty(&VersionedConfig {
+            version: CONFIG_VERSION,
+            config: self,
+        })?;
+        write_atomic(&path, &content)?;
 
         tracing::debug!(path = %path.display(), "Configuration saved");
 
@@ -59,8 +167,110 @@ impl Config {
     }
 }
 
+/// Schema version of a config.json written by a newer version of Arto
+fn newer_version(content: &str) -> Option<u32> {
+    let mut value: Value = serde_json::from_str(content).ok()?;
+    let version = migration::migrate(&mut value);
+    (version > CONFIG_VERSION).then_some(version)
+}
+
 /// Global configuration instance
 pub static CONFIG: LazyLock<RwLock<Config>> = LazyLock::new(|| {
-    let config = Config::load().unwrap_or_default();
+    let config = Config::load().unwrap_or_else(|e| {
+        tracing::error!(%e, "Invalid configuration, using defaults");
+        *CONFIG_ERROR.write() = Some(e);
+        Config::default()
+    });
     RwLock::new(config)
 });
+
+/// Problem with config.json that kept it from being applied, if any
+pub static CONFIG_ERROR: LazyLock<RwLock<Option<ConfigError>>> =
+    LazyLock::new(|| RwLock::new(None));
+
+/// Broadcast channel notifying all windows that CONFIG or CONFIG_ERROR was replaced.
+///
+/// Most settings are only read when a window or tab is created; subscribers
+/// are the few places that apply settings to already open windows.
+pub static CONFIG_CHANGED: LazyLock<broadcast::Sender<()>> =
+    LazyLock::new(|| broadcast::channel(10).0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_reports_position() {
+        let content = indoc! {r#"
+            {
+              "language": "ja",
+              "recent": { "maxItems": "many" }
+            }
+        "#};
+        let Err(ConfigError::Parse {
+            line,
+            column,
+            message,
+        }) = Config::parse(content)
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(line, 3);
+        assert_eq!(column, 32);
+        assert!(message.starts_with("invalid type"), "{message}");
+    }
+
+    #[test]
+    fn test_parse_reports_syntax_error() {
+        let error = Config::parse("{ \"language\": \"ja\",, }").unwrap_err();
+        assert!(
+            matches!(error, ConfigError::Parse { line: 1, .. }),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_values() {
+        let mut value = serde_json::to_value(Config::default()).unwrap();
+        value["rightSidebar"]["tocMaxDepth"] = Value::from(9);
+        let error = Config::parse(&value.to_string()).unwrap_err();
+        assert_eq!(
+            error,
+            ConfigError::Invalid("rightSidebar.tocMaxDepth must be between 1 and 6".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_unversioned() {
+        let config = Config::parse(r#"{ "language": "ja" }"#).unwrap();
+        assert_eq!(config.language, crate::i18n::Language::Ja);
+    }
+
+    #[test]
+    fn test_saved_layout_roundtrip() {
+        let config = Config::default();
+        let content = serde_json::to_string_pretty(&VersionedConfig {
+            version: CONFIG_VERSION,
+            config: &config,
+        })
+        .unwrap();
+        assert!(content.starts_with("{\n  \"version\": 1,"), "{content}");
+        assert_eq!(Config::parse(&content).unwrap(), config);
+    }
+
+    #[test]
+    fn test_newer_version() {
+        let newer = CONFIG_VERSION + 1;
+        assert_eq!(
+            newer_version(&format!(r#"{{ "version": {newer}, "language": "ja" }}"#)),
+            Some(newer)
+        );
+        assert_eq!(
+            newer_version(&format!(r#"{{ "version": {CONFIG_VERSION} }}"#)),
+            None
+        );
+        assert_eq!(newer_version(r#"{ "language": "ja" }"#), None);
+        assert_eq!(newer_version("not json"), None);
+    }
+}
@@ -0,0 +1,68 @@
+use super::app_config::Config;
+use super::persistence::{CONFIG, CONFIG_CHANGED, CONFIG_ERROR};
+use crate::i18n;
+use crate::watcher::{FileEvent, FILE_WATCHER};
+use std::fs;
+
+/// Make `config` the live configuration of every window
+///
+/// Settings held outside of CONFIG (menus, locale, recent list, file watcher)
+/// are updated here; windows pick up the rest through CONFIG_CHANGED.
+pub async fn apply_config(config: Config) {
+    crate::menu::apply_keymap(&config.keymap);
+    i18n::set_locale(config.language.resolve());
+    crate::menu::apply_language();
+    crate::recent::apply_recent_limit(config.recent.max_items);
+    FILE_WATCHER.configure(config.watcher.clone()).await.ok();
+    *CONFIG.write() = config;
+    *CONFIG_ERROR.write() = None;
+    CONFIG_CHANGED.send(()).ok();
+}
+
+/// Apply edits made to config.json outside of the Preferences view
+///
+/// Runs for the lifetime of the app. Invalid edits keep the current settings
+/// in place and are reported through CONFIG_ERROR.
+pub async fn watch_config_file() {
+    let path = Config::path();
+
+    // Files are watched through their directory, which may not exist yet
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            tracing::error!(%e, ?parent, "Failed to create configuration directory");
+            return;
+        }
+    }
+
+    loop {
+        let Ok(mut rx) = FILE_WATCHER.watch(&path).await else {
+            tracing::error!(?path, "Failed to watch configuration file");
+            return;
+        };
+        while let Some(event) = rx.recv().await {
+            // A removed file keeps the current settings until it is recreated
+            if event == FileEvent::Modified {
+                reload().await;
+            }
+        }
+        // The watch ends when the file is renamed away; keep following the path
+    }
+}
+
+async fn reload() {
+    match Config::load() {
+        Ok(config) => {
+            // Saving from the Preferences view comes back here unchanged
+            if *CONFIG.read() == config && CONFIG_ERROR.read().is_none() {
+                return;
+            }
+            tracing::info!("Configuration file changed, applying");
+            apply_config(config).await;
+        }
+        Err(e) => {
+            tracing::error!(%e, "Invalid configuration file, keeping current settings");
+            *CONFIG_ERROR.write() = Some(e);
+            CONFIG_CHANGED.send(()).ok();
+        }
+    }
+}
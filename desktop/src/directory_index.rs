@@ -0,0 +1,309 @@
+//! Landing page for directories opened in a tab.
+//!
+//! This module provides:
+//! - `find_index_file`: The `README.md` or `index.md` shown for a directory
+//! - `list_entries`: Subdirectories and Markdown files with their metadata
+//! - `render_listing`: Generated index for directories without an index file
+
+use chrono::{DateTime, Local};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::folder_document::{folder_files, natural_cmp};
+use crate::frontmatter::DocumentMetadata;
+use crate::markdown::first_heading;
+
+/// Index file names (lowercase), in order of preference
+const INDEX_FILE_NAMES: &[&str] = &["readme.md", "readme.markdown", "index.md", "index.markdown"];
+
+/// Entry of a generated directory listing
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexEntry {
+    pub path: PathBuf,
+    pub is_dir: bool,
+    /// Frontmatter `title`, or the first heading of the file
+    pub title: Option<String>,
+    /// Frontmatter `description` (or `summary`)
+    pub description: Option<String>,
+    pub modified: Option<DateTime<Local>>,
+    /// File size in bytes (0 for directories)
+    pub size: u64,
+}
+
+impl IndexEntry {
+    pub fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+/// `README.md` or `index.md` of a directory, matched case-insensitively
+pub fn find_index_file(dir: &Path) -> Option<PathBuf> {
+    let files: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| path.is_file())
+        .collect();
+
+    INDEX_FILE_NAMES.iter().find_map(|index_name| {
+        files
+            .iter()
+            .find(|path| {
+                path.file_name()
+                    .is_some_and(|name| name.to_string_lossy().to_lowercase() == *index_name)
+            })
+            .cloned()
+    })
+}
+
+/// Visible subdirectories followed by the Markdown files of a directory
+///
+/// Files are listed in the same order as when the folder is read as one document.
+pub fn list_entries(dir: &Path) -> Vec<IndexEntry> {
+    let mut subdirectories: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|path| path.is_dir() && !is_hidden(path))
+                .collect()
+        })
+        .unwrap_or_default();
+    subdirectories.sort_by(|a, b| {
+        natural_cmp(
+            &a.file_name().unwrap_or_default().to_string_lossy(),
+            &b.file_name().unwrap_or_default().to_string_lossy(),
+        )
+    });
+
+    let directories = subdirectories.into_iter().map(|path| IndexEntry {
+        modified: modified_time(&path),
+        path,
+        is_dir: true,
+        title: None,
+        description: None,
+        size: 0,
+    });
+    let files = folder_files(dir).into_iter().map(|path| {
+        let (title, description) = fs::read_to_string(&path)
+            .map(|content| file_summary(&content))
+            .unwrap_or_default();
+        IndexEntry {
+            modified: modified_time(&path),
+            size: fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+            path,
+            is_dir: false,
+            title,
+            description,
+        }
+    });
+
+    directories.chain(files).collect()
+}
+
+/// Render a generated listing of the directory entries as HTML
+pub fn render_listing(dir: &Path, entries: &[IndexEntry]) -> String {
+    let dir_name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| dir.to_string_lossy().into_owned());
+
+    let mut html = format!(
+        "<h1 class=\"directory-index-title\">{}</h1>\n",
+        html_escape::encode_text(&dir_name)
+    );
+    if entries.is_empty() {
+        html.push_str(
+            "<p class=\"directory-index-empty\">No Markdown files in this directory</p>\n",
+        );
+        return html;
+    }
+
+    html.push_str("<table class=\"directory-index\">\n<thead><tr><th>Name</th><th>Title</th><th>Modified</th><th>Size</th></tr></thead>\n<tbody>\n");
+    for entry in entries {
+        let name = entry.name();
+        let onclick = format!(
+            "if (event.button === 0 || event.button === 1) {{ event.preventDefault(); window.handleMarkdownLinkClick('{}', event.button); }}",
+            name.replace('\\', "\\\\").replace('\'', "\\'")
+        );
+        let class = if entry.is_dir {
+            "md-link md-link-directory"
+        } else {
+            "md-link"
+        };
+        let label = if entry.is_dir {
+            format!("{name}/")
+        } else {
+            name
+        };
+        let mut summary = entry
+            .title
+            .as_deref()
+            .map(|title| html_escape::encode_text(title).into_owned())
+            .unwrap_or_default();
+        if let Some(description) = &entry.description {
+            summary.push_str(&format!(
+                "<div class=\"directory-index-description\">{}</div>",
+                html_escape::encode_text(description)
+            ));
+        }
+        let modified = entry
+            .modified
+            .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        let size = if entry.is_dir {
+            String::new()
+        } else {
+            format_size(entry.size)
+        };
+
+        html.push_str(&format!(
+            "<tr><td><span class=\"{}\" onmousedown=\"{}\">{}</span></td><td>{}</td><td class=\"directory-index-modified\">{}</td><td class=\"directory-index-size\">{}</td></tr>\n",
+            class,
+            html_escape::encode_double_quoted_attribute(&onclick),
+            html_escape::encode_text(&label),
+            summary,
+            modified,
+            size
+        ));
+    }
+    html.push_str("</tbody>\n</table>\n");
+    html
+}
+
+/// Title and description of a Markdown file for the listing
+fn file_summary(markdown: &str) -> (Option<String>, Option<String>) {
+    let metadata = DocumentMetadata::parse(markdown).unwrap_or_default();
+    let title = metadata.title.or_else(|| first_heading(markdown));
+    (title, metadata.description)
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
+
+fn modified_time(path: &Path) -> Option<DateTime<Local>> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(DateTime::<Local>::from)
+}
+
+/// Human-readable file size
+fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_index_file() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        assert_eq!(find_index_file(root), None);
+
+        fs::write(root.join("index.md"), "# Index").unwrap();
+        assert_eq!(find_index_file(root), Some(root.join("index.md")));
+
+        // README takes precedence over index
+        fs::write(root.join("Readme.md"), "# Readme").unwrap();
+        assert_eq!(find_index_file(root), Some(root.join("Readme.md")));
+    }
+
+    #[test]
+    fn test_list_entries() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join("drafts")).unwrap();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(
+            root.join("2-meeting.md"),
+            indoc! {"
+                ---
+                title: Weekly Sync
+                description: Notes from the weekly sync
+                ---
+                # Agenda
+            "},
+        )
+        .unwrap();
+        fs::write(root.join("10-retro.md"), "Intro\n\n## Retrospective\n").unwrap();
+        fs::write(root.join("plain.md"), "No headings").unwrap();
+
+        let entries = list_entries(root);
+        let summary: Vec<(String, bool, Option<&str>, Option<&str>)> = entries
+            .iter()
+            .map(|e| {
+                (
+                    e.name(),
+                    e.is_dir,
+                    e.title.as_deref(),
+                    e.description.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("drafts".to_string(), true, None, None),
+                (
+                    "2-meeting.md".to_string(),
+                    false,
+                    Some("Weekly Sync"),
+                    Some("Notes from the weekly sync")
+                ),
+                (
+                    "10-retro.md".to_string(),
+                    false,
+                    Some("Retrospective"),
+                    None
+                ),
+                ("plain.md".to_string(), false, None, None),
+            ]
+        );
+        assert_eq!(entries[3].size, 11);
+        assert!(entries.iter().all(|e| e.modified.is_some()));
+    }
+
+    #[test]
+    fn test_render_listing() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join("archive")).unwrap();
+        fs::write(root.join("it's.md"), "# Q&A").unwrap();
+
+        let html = render_listing(root, &list_entries(root));
+        assert!(html.contains(r#"class="md-link md-link-directory""#));
+        assert!(html.contains(">archive/</span>"));
+        assert!(html.contains(r"handleMarkdownLinkClick('it\'s.md', event.button)"));
+        assert!(html.contains("Q&amp;A"));
+
+        let empty = TempDir::new().unwrap();
+        assert!(render_listing(empty.path(), &[]).contains("No Markdown files"));
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
+}
@@ -0,0 +1,146 @@
+//! Opening documents in an external editor at a source position
+//!
+//! The command comes from `editing.editorCommand` with `{file}`, `{line}` and
+//! `{column}` placeholders, and is run through `sh -c` so editors can be
+//! given as users would type them. Without one, `$VISUAL` or `$EDITOR` is
+//! started with the widely supported `+LINE` argument, in a new Terminal
+//! window since those usually name terminal editors.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::CONFIG;
+use crate::markdown::heading_source_line;
+
+/// Open `file` in the configured editor at `line` and `column` (1-based)
+///
+/// Falls back to the default application for the file when no editor is
+/// configured.
+pub fn open_in_editor(file: &Path, line: usize, column: usize) {
+    let configured = CONFIG
+        .read()
+        .editing
+        .editor_command
+        .clone()
+        .filter(|command| !command.trim().is_empty());
+    let command = match configured {
+        Some(template) => expand_command(&template, file, line, column),
+        None => {
+            let Some(template) =
+                default_command(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok())
+            else {
+                tracing::warn!(
+                    ?file,
+                    "No editor configured, opening with the default application"
+                );
+                if let Err(e) = open::that(file) {
+                    tracing::error!(%e, ?file, "Failed to open file");
+                }
+                return;
+            };
+            in_terminal(&expand_command(&template, file, line, column))
+        }
+    };
+
+    tracing::info!(%command, "Opening file in editor");
+    match Command::new("sh").arg("-c").arg(&command).spawn() {
+        // Reap the process once the editor exits
+        Ok(mut child) => {
+            std::thread::spawn(move || child.wait());
+        }
+        Err(e) => tracing::error!(%e, %command, "Failed to start editor"),
+    }
+}
+
+/// Open `file` in the configured editor at the heading with this anchor ID
+pub fn open_heading_in_editor(file: &Path, heading_id: &str) {
+    let line = std::fs::read_to_string(file)
+        .ok()
+        .and_then(|content| heading_source_line(&content, heading_id))
+        .unwrap_or(1);
+    open_in_editor(file, line, 1);
+}
+
+/// Command template for `$VISUAL`, or else `$EDITOR`
+fn default_command(visual: Option<String>, editor: Option<String>) -> Option<String> {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|editor| editor.trim().to_string())
+        .find(|editor| !editor.is_empty())
+        .map(|editor| format!("{editor} +{{line}} {{file}}"))
+}
+
+/// Command running `command` in a new Terminal window
+///
+/// Arto has no terminal of its own for editors like `vim` to run in.
+fn in_terminal(command: &str) -> String {
+    let script = format!(
+        r#"tell application "Terminal" to do script "{}""#,
+        command.replace('\\', r"\\").replace('"', r#"\""#)
+    );
+    format!(
+        "osascript -e {} -e {}",
+        shell_quote(&script),
+        shell_quote(r#"tell application "Terminal" to activate"#)
+    )
+}
+
+/// Fill in the placeholders of an editor command template
+///
+/// The file is appended when the template does not mention it.
+fn expand_command(template: &str, file: &Path, line: usize, column: usize) -> String {
+    let file = shell_quote(&file.to_string_lossy());
+    let command = template
+        .replace("{line}", &line.to_string())
+        .replace("{column}", &column.to_string());
+    if command.contains("{file}") {
+        command.replace("{file}", &file)
+    } else {
+        format!("{command} {file}")
+    }
+}
+
+/// Quote a string as a single `sh` word
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r#"'\''"#))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_command() {
+        let file = Path::new("/notes/it's here.md");
+        assert_eq!(
+            expand_command("code -g {file}:{line}:{column}", file, 12, 3),
+            r#"code -g '/notes/it'\''s here.md':12:3"#
+        );
+        assert_eq!(
+            expand_command("subl", Path::new("/notes/a.md"), 1, 1),
+            "subl '/notes/a.md'"
+        );
+    }
+
+    #[test]
+    fn test_default_command() {
+        assert_eq!(
+            default_command(Some("nvim".to_string()), Some("vi".to_string())),
+            Some("nvim +{line} {file}".to_string())
+        );
+        assert_eq!(
+            default_command(Some(" ".to_string()), Some("emacs -nw".to_string())),
+            Some("emacs -nw +{line} {file}".to_string())
+        );
+        assert_eq!(default_command(None, None), None);
+    }
+
+    #[test]
+    fn test_in_terminal() {
+        assert_eq!(
+            in_terminal(r#"vim +3 '/notes/"a".md'"#),
+            r#"osascript -e 'tell application "Terminal" to do script "vim +3 '\''/notes/\"a\".md'\''"' -e 'tell application "Terminal" to activate'"#
+        );
+    }
+}
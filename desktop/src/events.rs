@@ -4,8 +4,11 @@
 //! - Tab transfers (drag-and-drop, context menu "Move to Window")
 //! - Drag state updates (visual feedback across windows)
 //! - Cross-window file/directory opening (context menu "Open in Window")
+//! - Remote control commands and document change notifications
 
+use crate::menu::MenuId;
 use crate::state::Tab;
+use crate::theme::Theme;
 use dioxus::desktop::tao::window::WindowId;
 use std::path::PathBuf;
 use tokio::sync::broadcast;
@@ -62,3 +65,44 @@ pub static OPEN_FILE_IN_WINDOW: std::sync::LazyLock<broadcast::Sender<(WindowId,
 /// this event targets a specific window by its WindowId.
 pub static OPEN_DIRECTORY_IN_WINDOW: std::sync::LazyLock<broadcast::Sender<(WindowId, PathBuf)>> =
     std::sync::LazyLock::new(|| broadcast::channel(10).0);
+
+// ============================================================================
+// Remote Control Events (via the remote control socket)
+// ============================================================================
+
+/// Action requested by a remote control client for a specific window
+#[derive(Debug, Clone)]
+pub enum WindowCommand {
+    /// Scroll the heading with this anchor ID into view
+    ScrollToHeading(String),
+    /// Re-read the document shown in the active tab
+    Reload,
+    /// Switch the window to another theme
+    SetTheme(Theme),
+    /// Run a menu command as if chosen in that window
+    RunCommand(MenuId),
+    /// Show the live preview with this ID
+    OpenLivePreview(String),
+}
+
+/// Run a remote control command in a specific window
+///
+/// Like OPEN_FILE_IN_WINDOW, each window only handles commands targeting its WindowId.
+pub static WINDOW_COMMAND: std::sync::LazyLock<broadcast::Sender<(WindowId, WindowCommand)>> =
+    std::sync::LazyLock::new(|| broadcast::channel(10).0);
+
+/// Change of the document shown in a window, reported to remote control clients
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentEvent {
+    /// The active tab now shows another file (None for tabs without a file)
+    Navigated {
+        window_id: WindowId,
+        path: Option<PathBuf>,
+    },
+    /// The file shown in the window was reloaded after changing on disk
+    Reloaded { window_id: WindowId, path: PathBuf },
+}
+
+/// Global broadcast sender for document changes in any window
+pub static DOCUMENT_EVENT: std::sync::LazyLock<broadcast::Sender<DocumentEvent>> =
+    std::sync::LazyLock::new(|| broadcast::channel(100).0);
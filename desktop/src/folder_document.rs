@@ -0,0 +1,261 @@
+//! Folder documents: all Markdown files of a directory read as one document.
+//!
+//! This module provides:
+//! - `folder_files`: Markdown files of a directory in reading order
+//! - `render_to_html`: The files rendered one after another, each under a sticky header
+
+use serde_yaml::Value as YamlValue;
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::frontmatter::split_frontmatter;
+use crate::markdown::{render_to_html_with_slugs, HeadingInfo, HeadingSlugs};
+use crate::utils::file::is_markdown_file;
+
+/// Frontmatter keys that set the position of a file, in order of precedence
+const ORDER_KEYS: &[&str] = &["order", "weight", "nav_order", "sidebar_position"];
+
+/// Markdown files directly inside `dir`, in reading order
+///
+/// Files with a numeric `order` (or `weight`, `nav_order`, `sidebar_position`)
+/// frontmatter key come first, by that value; the rest follow in natural
+/// file name order (`2-notes.md` before `10-notes.md`).
+pub fn folder_files(dir: &Path) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            tracing::error!("Failed to read directory {:?}: {}", dir, err);
+            return vec![];
+        }
+    };
+
+    let mut files: Vec<(Option<f64>, String, PathBuf)> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| path.is_file() && is_markdown_file(path))
+        .map(|path| {
+            let order = fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| frontmatter_order(&content));
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            (order, name, path)
+        })
+        .collect();
+
+    files.sort_by(|(a_order, a_name, _), (b_order, b_name, _)| {
+        let by_order = match (a_order, b_order) {
+            (Some(a), Some(b)) => a.total_cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        by_order.then_with(|| natural_cmp(a_name, b_name))
+    });
+
+    files.into_iter().map(|(_, _, path)| path).collect()
+}
+
+/// Render the Markdown files of `dir` as one document
+///
+/// Each file is wrapped in a `<section id="folder-document-file-{index}">`
+/// starting with a sticky header that links back to the file. The returned
+/// headings contain a level 0 entry per file, pointing at its section, followed
+/// by the headings of that file, so the table of contents groups them by file.
+pub fn render_to_html(dir: &Path) -> (String, Vec<HeadingInfo>) {
+    let mut slugs = HeadingSlugs::default();
+    let mut html = String::new();
+    let mut headings = Vec::new();
+
+    for (index, path) in folder_files(dir).iter().enumerate() {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let section_id = format!("folder-document-file-{index}");
+
+        headings.push(HeadingInfo {
+            level: 0,
+            text: name.clone(),
+            id: section_id.clone(),
+        });
+
+        let rendered = fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| render_to_html_with_slugs(&content, path, &mut slugs));
+        let body = match rendered {
+            Ok((body, file_headings)) => {
+                headings.extend(file_headings);
+                body
+            }
+            Err(e) => {
+                tracing::warn!(?path, %e, "Failed to render folder document file");
+                format!(
+                    r#"<p class="error">Failed to load {}: {}</p>"#,
+                    html_escape::encode_text(&path.to_string_lossy()),
+                    html_escape::encode_text(&e.to_string())
+                )
+            }
+        };
+
+        // The third argument asks the link handler to open the file itself
+        // instead of scrolling to its section
+        let onclick = format!(
+            "if (event.button === 0 || event.button === 1) {{ event.preventDefault(); window.handleMarkdownLinkClick('{}', event.button, true); }}",
+            name.replace('\\', "\\\\").replace('\'', "\\'")
+        );
+        html.push_str(&format!(
+            "<section class=\"folder-document-file\" id=\"{}\" data-chapter-dir=\"{}\">\n<header class=\"folder-document-file-header\"><span class=\"folder-document-file-name\">{}</span><span class=\"md-link folder-document-file-open\" onmousedown=\"{}\">Open File</span></header>\n{}\n</section>\n",
+            section_id,
+            html_escape::encode_double_quoted_attribute(&dir.to_string_lossy()),
+            html_escape::encode_text(&name),
+            html_escape::encode_double_quoted_attribute(&onclick),
+            body
+        ));
+    }
+
+    (html, headings)
+}
+
+/// Numeric position declared in the frontmatter of a Markdown file
+fn frontmatter_order(markdown: &str) -> Option<f64> {
+    let (yaml, _) = split_frontmatter(markdown)?;
+
+    ORDER_KEYS.iter().find_map(|key| match yaml.get(*key)? {
+        YamlValue::Number(number) => number.as_f64(),
+        YamlValue::String(text) => text.trim().parse().ok(),
+        _ => None,
+    })
+}
+
+/// Compare file names case-insensitively, treating digit runs as numbers
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(c);
+                    }
+                    digits.trim_start_matches('0').to_string()
+                };
+                let x_number = take_number(&mut a_chars);
+                let y_number = take_number(&mut b_chars);
+                let ordering = x_number
+                    .len()
+                    .cmp(&y_number.len())
+                    .then_with(|| x_number.cmp(&y_number));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use tempfile::TempDir;
+
+    fn names(files: &[PathBuf]) -> Vec<String> {
+        files
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut items = vec!["10-b.md", "2-a.md", "Notes.md", "1-c.md", "agenda.md"];
+        items.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            items,
+            vec!["1-c.md", "2-a.md", "10-b.md", "agenda.md", "Notes.md"]
+        );
+        assert_eq!(
+            natural_cmp("adr-007.md", "adr-7.md"),
+            "adr-007.md".cmp("adr-7.md")
+        );
+    }
+
+    #[test]
+    fn test_frontmatter_order() {
+        assert_eq!(frontmatter_order("---\norder: 3\n---\n# A"), Some(3.0));
+        assert_eq!(frontmatter_order("---\nweight: \"1.5\"\n---\n"), Some(1.5));
+        assert_eq!(frontmatter_order("---\ntitle: A\n---\n"), None);
+        assert_eq!(frontmatter_order("# No frontmatter"), None);
+    }
+
+    #[test]
+    fn test_folder_files_order() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::write(root.join("10-later.md"), "# Later").unwrap();
+        fs::write(root.join("2-early.md"), "# Early").unwrap();
+        fs::write(root.join("index.md"), "---\norder: 1\n---\n# Index").unwrap();
+        fs::write(root.join("zz-first.md"), "---\norder: 0\n---\n# First").unwrap();
+        fs::write(root.join("image.png"), "").unwrap();
+        fs::create_dir(root.join("nested.md")).unwrap();
+
+        assert_eq!(
+            names(&folder_files(root)),
+            vec!["zz-first.md", "index.md", "2-early.md", "10-later.md"]
+        );
+    }
+
+    #[test]
+    fn test_render_to_html_groups_headings_by_file() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("1-intro.md"),
+            indoc! {"
+                # Overview
+
+                ## Context
+            "},
+        )
+        .unwrap();
+        fs::write(root.join("2-decision.md"), "# Overview\n").unwrap();
+
+        let (html, headings) = render_to_html(root);
+
+        let summary: Vec<(u8, &str, &str)> = headings
+            .iter()
+            .map(|h| (h.level, h.text.as_str(), h.id.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "1-intro.md", "folder-document-file-0"),
+                (1, "Overview", "overview"),
+                (2, "Context", "context"),
+                (0, "2-decision.md", "folder-document-file-1"),
+                (1, "Overview", "overview-1"),
+            ]
+        );
+        assert!(html.contains(r#"id="folder-document-file-1""#));
+        assert!(html.contains("handleMarkdownLinkClick('2-decision.md', event.button, true)"));
+        assert_eq!(html.matches("folder-document-file-header").count(), 2);
+    }
+}
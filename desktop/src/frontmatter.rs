@@ -0,0 +1,342 @@
+//! Frontmatter of Markdown documents.
+//!
+//! This module provides:
+//! - `split_frontmatter`: YAML (`---`), TOML (`+++`) or JSON (`{ ... }`) frontmatter and the content after it
+//! - `DocumentMetadata`: Title, description, tags and other fields declared in frontmatter
+//! - `build_tag_index`: Markdown files under a directory grouped by their tags
+
+use serde_yaml::Value as YamlValue;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::file::find_markdown_files;
+
+/// Upper bound of files read when building the tag index, to keep huge trees responsive
+const MAX_TAG_INDEX_FILES: usize = 10_000;
+
+/// Syntax of a frontmatter block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrontmatterFormat {
+    #[default]
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl FrontmatterFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Yaml => "YAML",
+            Self::Toml => "TOML",
+            Self::Json => "JSON",
+        }
+    }
+}
+
+/// Structured metadata read from the frontmatter of a document
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentMetadata {
+    pub format: FrontmatterFormat,
+    pub title: Option<String>,
+    /// `description` (or `summary`) field
+    pub description: Option<String>,
+    /// `tags` (or `tag`), given as a list or a comma-separated string
+    pub tags: Vec<String>,
+    /// All top-level fields as text, in declared order
+    pub fields: Vec<(String, String)>,
+}
+
+impl DocumentMetadata {
+    /// Metadata of a document, or `None` when it has no valid frontmatter
+    pub fn parse(markdown: &str) -> Option<Self> {
+        let (format, value, _) = parse_frontmatter(markdown)?;
+        let YamlValue::Mapping(mapping) = &value else {
+            return None;
+        };
+
+        let text = |keys: &[&str]| {
+            keys.iter().find_map(|key| {
+                value
+                    .get(*key)
+                    .and_then(YamlValue::as_str)
+                    .map(|text| text.trim().to_string())
+                    .filter(|text| !text.is_empty())
+            })
+        };
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in ["tags", "tag"]
+            .iter()
+            .filter_map(|key| value.get(*key))
+            .flat_map(tag_values)
+        {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+                tags.push(tag);
+            }
+        }
+
+        Some(Self {
+            format,
+            title: text(&["title"]),
+            description: text(&["description", "summary"]),
+            tags,
+            fields: mapping
+                .iter()
+                .map(|(key, value)| (yaml_to_string(key), yaml_to_string(value)))
+                .collect(),
+        })
+    }
+}
+
+/// Document listed in the tag index
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedDocument {
+    pub path: PathBuf,
+    /// Frontmatter `title`, if any
+    pub title: Option<String>,
+}
+
+impl TaggedDocument {
+    /// Title, falling back to the file name
+    pub fn label(&self) -> String {
+        self.title.clone().unwrap_or_else(|| {
+            self.path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Split frontmatter from markdown, returning its fields and the content after it
+///
+/// TOML and JSON frontmatter is converted to the equivalent YAML value.
+/// Returns `None` when there is no frontmatter or it cannot be parsed.
+pub fn split_frontmatter(markdown: &str) -> Option<(YamlValue, &str)> {
+    parse_frontmatter(markdown).map(|(_, value, content)| (value, content))
+}
+
+/// Markdown content with any frontmatter block removed, even one that fails to parse
+pub fn strip_frontmatter(markdown: &str) -> &str {
+    split_raw(markdown)
+        .map(|(_, _, content)| content)
+        .unwrap_or(markdown)
+}
+
+/// Convert a YAML value to a string representation
+pub fn yaml_to_string(value: &YamlValue) -> String {
+    match value {
+        YamlValue::Null => "null".to_string(),
+        YamlValue::Bool(b) => b.to_string(),
+        YamlValue::Number(n) => n.to_string(),
+        YamlValue::String(s) => s.clone(),
+        YamlValue::Sequence(seq) => seq
+            .iter()
+            .map(yaml_to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        YamlValue::Mapping(_) => "[object]".to_string(),
+        YamlValue::Tagged(tagged) => yaml_to_string(&tagged.value),
+    }
+}
+
+/// Markdown files under `root` grouped by lowercase tag
+///
+/// Hidden directories are skipped. Documents are sorted by path within each tag.
+pub fn build_tag_index(root: &Path) -> BTreeMap<String, Vec<TaggedDocument>> {
+    let mut index: BTreeMap<String, Vec<TaggedDocument>> = BTreeMap::new();
+
+    for path in find_markdown_files(root, MAX_TAG_INDEX_FILES) {
+        let Some(metadata) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| DocumentMetadata::parse(&content))
+        else {
+            continue;
+        };
+        for tag in &metadata.tags {
+            index
+                .entry(tag.to_lowercase())
+                .or_default()
+                .push(TaggedDocument {
+                    path: path.clone(),
+                    title: metadata.title.clone(),
+                });
+        }
+    }
+
+    index
+}
+
+/// Tags declared by a `tags` value (list or comma/space-separated string)
+fn tag_values(value: &YamlValue) -> Vec<String> {
+    let raw: Vec<String> = match value {
+        YamlValue::Sequence(items) => items.iter().map(yaml_to_string).collect(),
+        YamlValue::String(text) if text.contains(',') => {
+            text.split(',').map(str::to_string).collect()
+        }
+        YamlValue::String(text) => text.split_whitespace().map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    raw.into_iter()
+        .map(|tag| tag.trim().trim_start_matches('#').to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Parse frontmatter into its format, fields and the content after it
+fn parse_frontmatter(markdown: &str) -> Option<(FrontmatterFormat, YamlValue, &str)> {
+    let (format, raw, content) = split_raw(markdown)?;
+    let value = match format {
+        FrontmatterFormat::Yaml => serde_yaml::from_str::<YamlValue>(raw.trim()).ok()?,
+        FrontmatterFormat::Toml => toml_to_yaml(toml::from_str::<toml::Value>(raw).ok()?),
+        FrontmatterFormat::Json => {
+            serde_yaml::to_value(serde_json::from_str::<serde_json::Value>(raw).ok()?).ok()?
+        }
+    };
+    Some((format, value, content))
+}
+
+/// Locate the frontmatter block, returning its format, raw text and the content after it
+fn split_raw(markdown: &str) -> Option<(FrontmatterFormat, &str, &str)> {
+    if let Some(rest) = markdown.strip_prefix("---") {
+        let end_pos = rest.find("\n---")?;
+        return Some((
+            FrontmatterFormat::Yaml,
+            &rest[..end_pos],
+            rest[end_pos + 4..].trim_start(),
+        ));
+    }
+
+    if let Some(rest) = markdown.strip_prefix("+++") {
+        let end_pos = rest.find("\n+++")?;
+        return Some((
+            FrontmatterFormat::Toml,
+            &rest[..end_pos],
+            rest[end_pos + 4..].trim_start(),
+        ));
+    }
+
+    // JSON frontmatter is an object whose closing brace starts a line;
+    // only treat it as frontmatter when it parses, since `{` may start regular text
+    if markdown.starts_with('{') {
+        let end_pos = markdown.find("\n}")? + 2;
+        let raw = &markdown[..end_pos];
+        serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(raw).ok()?;
+        return Some((
+            FrontmatterFormat::Json,
+            raw,
+            markdown[end_pos..].trim_start(),
+        ));
+    }
+
+    None
+}
+
+/// Convert a TOML value to YAML, keeping dates as their string form
+fn toml_to_yaml(value: toml::Value) -> YamlValue {
+    match value {
+        toml::Value::String(s) => YamlValue::String(s),
+        toml::Value::Integer(i) => YamlValue::Number(i.into()),
+        toml::Value::Float(f) => YamlValue::Number(f.into()),
+        toml::Value::Boolean(b) => YamlValue::Bool(b),
+        toml::Value::Datetime(dt) => YamlValue::String(dt.to_string()),
+        toml::Value::Array(items) => {
+            YamlValue::Sequence(items.into_iter().map(toml_to_yaml).collect())
+        }
+        toml::Value::Table(table) => YamlValue::Mapping(
+            table
+                .into_iter()
+                .map(|(key, value)| (YamlValue::String(key), toml_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_yaml_metadata() {
+        let markdown = indoc! {"
+            ---
+            title: Use Rust
+            summary: Decision record
+            tags: [adr, Backend]
+            status: accepted
+            ---
+            # Body
+        "};
+        let metadata = DocumentMetadata::parse(markdown).unwrap();
+        assert_eq!(metadata.format, FrontmatterFormat::Yaml);
+        assert_eq!(metadata.title.as_deref(), Some("Use Rust"));
+        assert_eq!(metadata.description.as_deref(), Some("Decision record"));
+        assert_eq!(metadata.tags, vec!["adr", "Backend"]);
+        assert_eq!(metadata.fields[3], ("status".into(), "accepted".into()));
+    }
+
+    #[test]
+    fn test_parse_toml_metadata() {
+        let markdown = indoc! {r#"
+            +++
+            title = "Restart the queue"
+            tags = "runbook, ops"
+            date = 2024-05-01
+            +++
+            # Steps
+        "#};
+        let metadata = DocumentMetadata::parse(markdown).unwrap();
+        assert_eq!(metadata.format, FrontmatterFormat::Toml);
+        assert_eq!(metadata.title.as_deref(), Some("Restart the queue"));
+        assert_eq!(metadata.tags, vec!["runbook", "ops"]);
+        assert!(metadata
+            .fields
+            .contains(&("date".to_string(), "2024-05-01".to_string())));
+        assert_eq!(strip_frontmatter(markdown), "# Steps\n");
+    }
+
+    #[test]
+    fn test_parse_json_metadata() {
+        let markdown = indoc! {r##"
+            {
+              "title": "Notes",
+              "tags": ["#meeting", "meeting"]
+            }
+
+            # Notes
+        "##};
+        let metadata = DocumentMetadata::parse(markdown).unwrap();
+        assert_eq!(metadata.format, FrontmatterFormat::Json);
+        assert_eq!(metadata.tags, vec!["meeting"]);
+        assert_eq!(strip_frontmatter(markdown), "# Notes\n");
+
+        // Text starting with a brace is not frontmatter
+        let text = "{not json}\n}\n# Heading";
+        assert_eq!(DocumentMetadata::parse(text), None);
+        assert_eq!(strip_frontmatter(text), text);
+    }
+
+    #[test]
+    fn test_build_tag_index() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("adr")).unwrap();
+        fs::create_dir_all(root.join(".hidden")).unwrap();
+        fs::write(
+            root.join("adr/0001.md"),
+            "---\ntitle: First\ntags: [ADR]\n---\n",
+        )
+        .unwrap();
+        fs::write(root.join("runbook.md"), "---\ntags: runbook adr\n---\n").unwrap();
+        fs::write(root.join(".hidden/skip.md"), "---\ntags: [adr]\n---\n").unwrap();
+        fs::write(root.join("plain.md"), "# No frontmatter").unwrap();
+
+        let index = build_tag_index(root);
+        assert_eq!(index.keys().collect::<Vec<_>>(), vec!["adr", "runbook"]);
+        let labels: Vec<String> = index["adr"].iter().map(TaggedDocument::label).collect();
+        assert_eq!(labels, vec!["First", "runbook.md"]);
+    }
+}
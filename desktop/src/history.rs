@@ -97,6 +97,13 @@ impl HistoryManager {
         None
     }
 
+    /// Point the entries of a renamed file at its new path
+    pub fn rename(&mut self, from: &Path, to: &Path) {
+        for entry in self.history.iter_mut().filter(|entry| entry.path == from) {
+            entry.path = to.to_path_buf();
+        }
+    }
+
     /// Get the current history entry
     pub fn current(&self) -> Option<&HistoryEntry> {
         self.history.get(self.current_index)
@@ -246,4 +253,17 @@ mod tests {
         let back = manager.go_back().unwrap();
         assert_eq!(back.scroll_position, 100.0);
     }
+
+    #[test]
+    fn test_rename() {
+        let mut manager = HistoryManager::new();
+        manager.push("/test/draft.md");
+        manager.save_scroll_position(100.0);
+        manager.push("/test/other.md");
+        manager.rename(Path::new("/test/draft.md"), Path::new("/test/final.md"));
+
+        let back = manager.go_back().unwrap();
+        assert_eq!(back.path, PathBuf::from("/test/final.md"));
+        assert_eq!(back.scroll_position, 100.0);
+    }
 }
@@ -0,0 +1,467 @@
+//! Localisation of user interface strings.
+//!
+//! Messages live in flat JSON catalogs (`src/i18n/<locale>.json`) keyed by
+//! dotted ids such as `"menu.file"`. Placeholders are written as `{name}` and
+//! filled in with [`t_args`]. English is the reference catalog: missing keys
+//! fall back to it, and tests fail when another catalog drifts from it.
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Language preference stored in the config
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// Follow the environment (LC_ALL/LC_MESSAGES/LANG, then the system)
+    #[default]
+    Auto,
+    En,
+    Ja,
+}
+
+impl Language {
+    /// Resolve the preference to the locale to use
+    pub fn resolve(self) -> Locale {
+        match self {
+            Self::Auto => detect_locale(),
+            Self::En => Locale::En,
+            Self::Ja => Locale::Ja,
+        }
+    }
+}
+
+/// A locale with a message catalog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    En,
+    Ja,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Self::En, Self::Ja];
+
+    /// BCP 47 language code (also used as the `lang` attribute)
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Ja => "ja",
+        }
+    }
+
+    /// Parse a locale tag such as "ja_JP.UTF-8", "ja-JP" or "en"
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Self::En),
+            "ja" => Some(Self::Ja),
+            _ => None,
+        }
+    }
+
+    fn catalog_source(self) -> &'static str {
+        match self {
+            Self::En => include_str!("i18n/en.json"),
+            Self::Ja => include_str!("i18n/ja.json"),
+        }
+    }
+}
+
+type Catalog = HashMap<String, String>;
+
+static CATALOGS: LazyLock<HashMap<Locale, Catalog>> = LazyLock::new(|| {
+    Locale::ALL
+        .into_iter()
+        .map(|locale| {
+            let catalog = serde_json::from_str(locale.catalog_source())
+                .unwrap_or_else(|e| panic!("Invalid {} catalog: {e}", locale.code()));
+            (locale, catalog)
+        })
+        .collect()
+});
+
+static LOCALE: LazyLock<RwLock<Locale>> = LazyLock::new(|| RwLock::new(Locale::En));
+
+/// Get the locale used for new strings
+pub fn current_locale() -> Locale {
+    *LOCALE.read()
+}
+
+/// Switch the locale used for new strings
+pub fn set_locale(locale: Locale) {
+    tracing::debug!(?locale, "Setting UI locale");
+    *LOCALE.write() = locale;
+}
+
+/// Detect the locale from the environment, falling back to English
+pub fn detect_locale() -> Locale {
+    // POSIX precedence: the first non-empty variable wins
+    let env_tag = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty());
+    if let Some(locale) = env_tag.as_deref().and_then(Locale::from_tag) {
+        return locale;
+    }
+    system_locale().unwrap_or(Locale::En)
+}
+
+/// First supported language in the macOS language preferences
+#[cfg(target_os = "macos")]
+fn system_locale() -> Option<Locale> {
+    use objc2_foundation::NSLocale;
+
+    NSLocale::preferredLanguages()
+        .iter()
+        .find_map(|language| Locale::from_tag(&language.to_string()))
+}
+
+#[cfg(not(target_os = "macos"))]
+fn system_locale() -> Option<Locale> {
+    None
+}
+
+fn lookup(locale: Locale, key: &str) -> Option<&'static str> {
+    CATALOGS
+        .get(&locale)
+        .and_then(|catalog| catalog.get(key))
+        .map(String::as_str)
+}
+
+fn translate(locale: Locale, key: &'static str) -> &'static str {
+    lookup(locale, key)
+        .or_else(|| lookup(Locale::En, key))
+        .unwrap_or_else(|| {
+            tracing::warn!(key, "Missing translation");
+            key
+        })
+}
+
+/// Translate a message in the current locale
+pub fn t(key: &'static str) -> &'static str {
+    translate(current_locale(), key)
+}
+
+/// Translate a message and fill in its `{name}` placeholders
+pub fn t_args(key: &'static str, args: &[(&str, &str)]) -> String {
+    interpolate(t(key), args)
+}
+
+fn interpolate(message: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(message.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    fn keys(locale: Locale) -> BTreeSet<&'static str> {
+        CATALOGS[&locale].keys().map(String::as_str).collect()
+    }
+
+    fn placeholders(message: &str) -> BTreeSet<&str> {
+        message
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogs_have_same_keys() {
+        let reference = keys(Locale::En);
+        for locale in Locale::ALL {
+            let catalog = keys(locale);
+            let missing: Vec<_> = reference.difference(&catalog).collect();
+            let extra: Vec<_> = catalog.difference(&reference).collect();
+            assert!(
+                missing.is_empty(),
+                "{} catalog is missing keys: {missing:?}",
+                locale.code()
+            );
+            assert!(
+                extra.is_empty(),
+                "{} catalog has unknown keys: {extra:?}",
+                locale.code()
+            );
+        }
+    }
+
+    #[test]
+    fn test_catalogs_have_same_placeholders() {
+        for locale in Locale::ALL {
+            for (key, message) in &CATALOGS[&locale] {
+                let reference = &CATALOGS[&Locale::En][key];
+                assert_eq!(
+                    placeholders(message),
+                    placeholders(reference),
+                    "{} catalog has different placeholders for {key}",
+                    locale.code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalogs_have_no_empty_messages() {
+        for locale in Locale::ALL {
+            for (key, message) in &CATALOGS[&locale] {
+                assert!(
+                    !message.trim().is_empty(),
+                    "{} catalog has an empty message for {key}",
+                    locale.code()
+                );
+            }
+        }
+    }
+
+    /// Collect the literal keys passed to `t(...)` / `t_args(...)` in a source file
+    fn used_keys(source: &str, found: &mut BTreeSet<String>) {
+        for function in ["t", "t_args"] {
+            let pattern = format!("{function}(");
+            for (pos, _) in source.match_indices(&pattern) {
+                let preceded_by_ident = source[..pos]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric() || c == '_');
+                if preceded_by_ident {
+                    continue;
+                }
+                let rest = source[pos + pattern.len()..].trim_start();
+                if let Some((key, _)) = rest.strip_prefix('"').and_then(|r| r.split_once('"')) {
+                    found.insert(key.to_string());
+                }
+            }
+        }
+    }
+
+    fn collect_used_keys(dir: &Path, found: &mut BTreeSet<String>) {
+        for entry in std::fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_used_keys(&path, found);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                used_keys(&std::fs::read_to_string(&path).unwrap(), found);
+            }
+        }
+    }
+
+    #[test]
+    fn test_used_keys_exist_in_catalog() {
+        let mut found = BTreeSet::new();
+        collect_used_keys(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut found,
+        );
+        let reference = keys(Locale::En);
+        let missing: Vec<_> = found
+            .iter()
+            .filter(|key| !reference.contains(key.as_str()))
+            .collect();
+        assert!(missing.is_empty(), "Keys missing from catalog: {missing:?}");
+    }
+
+    /// Literals shown as-is in every language (names, key caps, examples)
+    const UNTRANSLATED: [&str; 7] = [
+        "Arto",
+        "English",
+        "px",
+        "Cmd+O",
+        "Copyright © 2025 lambdalisue",
+        "code -g {{file}}:{{line}}:{{column}}",
+        "node_modules\\n*.tmp",
+    ];
+
+    /// Attributes whose value is shown to the user
+    const TEXT_ATTRIBUTES: [&str; 4] = ["title", "placeholder", "aria-label", "alt"];
+
+    /// Name of the attribute a `rsx!` line starts with (`title: ...`)
+    fn attribute_name(line: &str) -> Option<String> {
+        let line = line.strip_prefix('"').unwrap_or(line);
+        let end = line
+            .find(|c: char| !(c.is_ascii_lowercase() || c == '_' || c == '-'))
+            .unwrap_or(line.len());
+        let rest = line[end..].strip_prefix('"').unwrap_or(&line[end..]);
+        (end > 0 && rest.trim_start().starts_with(':')).then(|| line[..end].replace('_', "-"))
+    }
+
+    /// Whether a literal contains words once its `{...}` interpolations are removed
+    fn has_words(literal: &str) -> bool {
+        let mut text = String::new();
+        let mut depth = 0;
+        for c in literal.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ if depth == 0 => text.push(c),
+                _ => {}
+            }
+        }
+        text.as_bytes()
+            .windows(2)
+            .any(|pair| pair.iter().all(u8::is_ascii_alphabetic))
+    }
+
+    /// Find user-facing string literals in the `rsx!` blocks of a source file
+    ///
+    /// Text nodes and the values of `TEXT_ATTRIBUTES` are reported; literals
+    /// inside calls (such as the message keys passed to `t`) and other attributes are not.
+    fn bare_literals(source: &str) -> Vec<String> {
+        let bytes = source.as_bytes();
+        let mut found = Vec::new();
+        let mut pos = 0;
+        while let Some(offset) = source[pos..].find("rsx! {") {
+            pos += offset + "rsx! {".len();
+            let (mut braces, mut parens) = (1, 0);
+            while braces > 0 && pos < bytes.len() {
+                if source[pos..].starts_with("r#\"") {
+                    pos += source[pos..].find("\"#").unwrap() + 2;
+                    continue;
+                }
+                match bytes[pos] {
+                    b'"' => {
+                        let mut end = pos + 1;
+                        while bytes[end] != b'"' {
+                            end += if bytes[end] == b'\\' { 2 } else { 1 };
+                        }
+                        let literal = &source[pos + 1..end];
+                        let line_start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+                        let before = source[line_start..pos].trim();
+                        let is_user_facing = if parens > 0
+                            || source[end + 1..].trim_start().starts_with(':')
+                            || before.contains('=')
+                        {
+                            false
+                        } else if let Some(name) = attribute_name(before) {
+                            TEXT_ATTRIBUTES.contains(&name.as_str())
+                        } else {
+                            before.is_empty() || before.ends_with(['{', ','])
+                        };
+                        if is_user_facing && !UNTRANSLATED.contains(&literal) && has_words(literal)
+                        {
+                            let line_end =
+                                source[end..].find('\n').map_or(source.len(), |i| end + i);
+                            found.push(source[line_start..line_end].trim().to_string());
+                        }
+                        pos = end;
+                    }
+                    b'{' => braces += 1,
+                    b'}' => braces -= 1,
+                    b'(' | b'[' => parens += 1,
+                    b')' | b']' => parens -= 1,
+                    _ => {}
+                }
+                pos += 1;
+            }
+        }
+        found
+    }
+
+    fn collect_bare_literals(dir: &Path, found: &mut Vec<String>) {
+        for entry in std::fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_bare_literals(&path, found);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                let source = std::fs::read_to_string(&path).unwrap();
+                // Test modules hold fixtures, not UI
+                let source = source.split("#[cfg(test)]").next().unwrap();
+                for line in bare_literals(source) {
+                    found.push(format!("{}: {line}", path.display()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bare_literals() {
+        let source = indoc::indoc! {r#"
+            rsx! {
+                div {
+                    class: "note",
+                    title: "Hello there",
+                    placeholder: t("search.placeholder"),
+                    "Plain text"
+                    "{count}"
+                    {t("menu.file")}
+                    span { "Nested text" }
+                    kbd { "Cmd+O" }
+                    "aria-label": "Labelled",
+                    "data-id": "not shown",
+                }
+            }
+        "#};
+        assert_eq!(
+            bare_literals(source),
+            vec![
+                r#"title: "Hello there","#,
+                r#""Plain text""#,
+                r#"span { "Nested text" }"#,
+                r#""aria-label": "Labelled","#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_bare_literals_in_rsx() {
+        let mut found = Vec::new();
+        collect_bare_literals(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut found,
+        );
+        assert!(
+            found.is_empty(),
+            "User-facing literals should go through t():\n{}",
+            found.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_menu_labels_exist_in_catalog() {
+        for id in crate::menu::MenuId::ALL {
+            assert!(!id.label().starts_with("command."), "{id:?}");
+            assert!(!id.category().starts_with("menu."), "{id:?}");
+        }
+    }
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("ja_JP.UTF-8"), Some(Locale::Ja));
+        assert_eq!(Locale::from_tag("ja-JP"), Some(Locale::Ja));
+        assert_eq!(Locale::from_tag("en_US"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("EN"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("C"), None);
+        assert_eq!(Locale::from_tag("fr_FR.UTF-8"), None);
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn test_translate_falls_back() {
+        assert_eq!(translate(Locale::En, "menu.file"), "File");
+        assert_eq!(translate(Locale::Ja, "menu.file"), "ファイル");
+        assert_eq!(translate(Locale::Ja, "no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(
+            interpolate("Version {version}", &[("version", "1.2.3")]),
+            "Version 1.2.3"
+        );
+        assert_eq!(
+            interpolate("{a} and {b}", &[("a", "x"), ("b", "y")]),
+            "x and y"
+        );
+        assert_eq!(interpolate("No args", &[]), "No args");
+    }
+}
@@ -0,0 +1,235 @@
+//! Keyboard shortcut resolution for menu commands.
+//!
+//! Shortcuts are written as `+`-separated modifiers followed by a single key,
+//! e.g. `CmdOrCtrl+Shift+P`, `Ctrl+Alt+BracketLeft` or `Cmd+[`. Keys are physical
+//! key codes, so a binding refers to the same key position regardless of the
+//! active keyboard layout. An empty string in the keymap config removes the
+//! built-in shortcut of a command.
+
+use dioxus_desktop::muda::accelerator::{Accelerator, Code, Modifiers};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use crate::config::KeymapConfig;
+use crate::menu::MenuId;
+
+/// Shortcuts owned by predefined (system) menu items that cannot be rebound.
+pub const RESERVED_SHORTCUTS: [(&str, &str); 5] = [
+    ("Cut", "CmdOrCtrl+X"),
+    ("Copy", "CmdOrCtrl+C"),
+    ("Paste", "CmdOrCtrl+V"),
+    ("Select All", "CmdOrCtrl+A"),
+    ("Quit", "CmdOrCtrl+Q"),
+];
+
+/// Parse a shortcut string. Returns `Ok(None)` for an empty (unbound) shortcut.
+pub fn parse_shortcut(shortcut: &str) -> Result<Option<Accelerator>, String> {
+    let shortcut = shortcut.trim();
+    if shortcut.is_empty() {
+        return Ok(None);
+    }
+    let accelerator = Accelerator::from_str(shortcut).map_err(|e| e.to_string())?;
+
+    // muda parses `Cmd` as META but `CmdOrCtrl` as SUPER; both are the Command
+    // key on macOS, so normalize to SUPER to make equal shortcuts compare equal.
+    let mut modifiers = accelerator.modifiers();
+    if modifiers.contains(Modifiers::META) {
+        modifiers.remove(Modifiers::META);
+        modifiers.insert(Modifiers::SUPER);
+    }
+    Ok(Some(Accelerator::new(Some(modifiers), accelerator.key())))
+}
+
+/// Resolve the effective shortcut of a command.
+///
+/// A user override wins over the built-in default. Invalid overrides are
+/// ignored (with a warning) so a typo never leaves a command unreachable.
+pub fn resolve_shortcut(id: MenuId, config: &KeymapConfig) -> Option<Accelerator> {
+    if let Some(shortcut) = config.bindings.get(id.as_str()) {
+        match parse_shortcut(shortcut) {
+            Ok(accelerator) => return accelerator,
+            Err(e) => {
+                tracing::warn!(command = id.as_str(), %shortcut, %e, "Invalid shortcut in keymap");
+            }
+        }
+    }
+    id.default_shortcut()
+        .and_then(|s| parse_shortcut(s).ok().flatten())
+}
+
+/// Find commands sharing a shortcut with another command or a reserved shortcut.
+///
+/// Returns a map from each conflicting command to the labels it clashes with.
+pub fn find_conflicts(config: &KeymapConfig) -> BTreeMap<MenuId, Vec<String>> {
+    let mut by_shortcut: HashMap<Accelerator, Vec<String>> = HashMap::new();
+    for (label, shortcut) in RESERVED_SHORTCUTS {
+        if let Ok(Some(accelerator)) = parse_shortcut(shortcut) {
+            by_shortcut
+                .entry(accelerator)
+                .or_default()
+                .push(label.to_string());
+        }
+    }
+
+    let bound: Vec<(MenuId, Accelerator)> = MenuId::ALL
+        .into_iter()
+        .filter_map(|id| resolve_shortcut(id, config).map(|a| (id, a)))
+        .collect();
+    for (id, accelerator) in &bound {
+        by_shortcut
+            .entry(*accelerator)
+            .or_default()
+            .push(id.label().to_string());
+    }
+
+    let mut conflicts = BTreeMap::new();
+    for (id, accelerator) in bound {
+        let others: Vec<String> = by_shortcut[&accelerator]
+            .iter()
+            .filter(|label| label.as_str() != id.label())
+            .cloned()
+            .collect();
+        if !others.is_empty() {
+            conflicts.insert(id, others);
+        }
+    }
+    conflicts
+}
+
+/// Format a shortcut for display using macOS modifier symbols (e.g. `⇧⌘P`).
+pub fn format_shortcut(accelerator: &Accelerator) -> String {
+    let modifiers = accelerator.modifiers();
+    let mut text = String::new();
+    for (modifier, symbol) in [
+        (Modifiers::CONTROL, "⌃"),
+        (Modifiers::ALT, "⌥"),
+        (Modifiers::SHIFT, "⇧"),
+        (Modifiers::SUPER, "⌘"),
+    ] {
+        if modifiers.contains(modifier) {
+            text.push_str(symbol);
+        }
+    }
+    text.push_str(&format_key(accelerator.key()));
+    text
+}
+
+fn format_key(code: Code) -> String {
+    let name = code.to_string();
+    if let Some(letter) = name.strip_prefix("Key") {
+        return letter.to_string();
+    }
+    if let Some(digit) = name.strip_prefix("Digit") {
+        return digit.to_string();
+    }
+    let symbol = match code {
+        Code::Backquote => "`",
+        Code::Backslash => "\\",
+        Code::BracketLeft => "[",
+        Code::BracketRight => "]",
+        Code::Comma => ",",
+        Code::Equal => "=",
+        Code::Minus => "-",
+        Code::Period => ".",
+        Code::Quote => "'",
+        Code::Semicolon => ";",
+        Code::Slash => "/",
+        Code::ArrowUp => "↑",
+        Code::ArrowDown => "↓",
+        Code::ArrowLeft => "←",
+        Code::ArrowRight => "→",
+        Code::Enter => "↩",
+        Code::Escape => "⎋",
+        Code::Backspace => "⌫",
+        Code::Tab => "⇥",
+        Code::Space => "Space",
+        _ => return name,
+    };
+    symbol.to_string()
+}
+
+/// Format the effective shortcut of a command, if any.
+pub fn shortcut_label(id: MenuId, config: &KeymapConfig) -> Option<String> {
+    resolve_shortcut(id, config).map(|a| format_shortcut(&a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keymap(bindings: &[(&str, &str)]) -> KeymapConfig {
+        KeymapConfig {
+            bindings: bindings
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_shortcut() {
+        let accelerator = parse_shortcut("Cmd+Shift+P").unwrap().unwrap();
+        assert_eq!(accelerator.key(), Code::KeyP);
+        assert_eq!(accelerator.modifiers(), Modifiers::SUPER | Modifiers::SHIFT);
+        assert_eq!(
+            parse_shortcut("Cmd+[").unwrap().unwrap().key(),
+            Code::BracketLeft
+        );
+        assert_eq!(parse_shortcut("  ").unwrap(), None);
+        assert!(parse_shortcut("Cmd+Shift").is_err());
+        assert!(parse_shortcut("Cmd+Nope").is_err());
+    }
+
+    #[test]
+    fn test_default_shortcuts_are_valid_and_conflict_free() {
+        for id in MenuId::ALL {
+            if let Some(shortcut) = id.default_shortcut() {
+                assert!(parse_shortcut(shortcut).is_ok(), "{shortcut}");
+            }
+        }
+        assert!(find_conflicts(&KeymapConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_shortcut_override() {
+        let config = keymap(&[
+            ("edit.find", "Cmd+Alt+F"),
+            ("view.toggle_sidebar", ""),
+            ("file.open", "Cmd+Bogus"),
+        ]);
+
+        let find = resolve_shortcut(MenuId::Find, &config).unwrap();
+        assert_eq!(find.modifiers(), Modifiers::SUPER | Modifiers::ALT);
+        // Empty string unbinds the default
+        assert_eq!(resolve_shortcut(MenuId::ToggleSidebar, &config), None);
+        // Invalid override falls back to the default
+        assert_eq!(
+            resolve_shortcut(MenuId::Open, &config).unwrap().key(),
+            Code::KeyO
+        );
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let config = keymap(&[
+            ("view.zoom_in", "CmdOrCtrl+B"),
+            ("help.homepage", "CmdOrCtrl+C"),
+        ]);
+        let conflicts = find_conflicts(&config);
+
+        assert_eq!(conflicts[&MenuId::ZoomIn], vec!["Toggle Sidebar"]);
+        assert_eq!(conflicts[&MenuId::ToggleSidebar], vec!["Zoom In"]);
+        assert_eq!(conflicts[&MenuId::GoToHomepage], vec!["Copy"]);
+        assert_eq!(conflicts.len(), 3);
+    }
+
+    #[test]
+    fn test_format_shortcut() {
+        let format = |s: &str| format_shortcut(&parse_shortcut(s).unwrap().unwrap());
+        assert_eq!(format("Cmd+Shift+P"), "⇧⌘P");
+        assert_eq!(format("Ctrl+Alt+BracketLeft"), "⌃⌥[");
+        assert_eq!(format("Cmd+0"), "⌘0");
+        assert_eq!(format("F5"), "F5");
+    }
+}
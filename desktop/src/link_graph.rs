@@ -0,0 +1,414 @@
+//! Graph of the links between the Markdown files of a directory tree
+//!
+//! Files are the nodes; relative links (`[text](other.md)`) and wiki links
+//! (`[[Other]]`) that resolve to another file of the tree are the edges.
+//! Links are cached per file and only re-read when the file's modification
+//! time changes, so the graph can be refreshed cheaply on every watcher event.
+
+use percent_encoding::percent_decode_str;
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::frontmatter::{strip_frontmatter, DocumentMetadata};
+use crate::markdown::first_heading;
+use crate::utils::file::{find_markdown_files, is_markdown_file};
+
+/// Upper bound of files in the graph, to keep huge trees responsive
+const MAX_LINK_GRAPH_FILES: usize = 5_000;
+
+/// Destination of a link as written in the source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LinkTarget {
+    /// Relative link, already resolved against the linking file's directory
+    Path(PathBuf),
+    /// Wiki link by page name, resolved against the files of the graph
+    Wiki(String),
+}
+
+#[derive(Debug, Clone)]
+struct FileEntry {
+    modified: Option<SystemTime>,
+    title: Option<String>,
+    links: Vec<LinkTarget>,
+}
+
+/// Links between the Markdown files under a root directory
+#[derive(Debug, Clone)]
+pub struct LinkGraph {
+    root: PathBuf,
+    files: BTreeMap<PathBuf, FileEntry>,
+}
+
+/// A file of the graph, as sent to the graph window
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphNode {
+    pub path: PathBuf,
+    pub label: String,
+    pub incoming: usize,
+    pub outgoing: usize,
+    /// No other file links to this one
+    pub orphan: bool,
+}
+
+/// Nodes and edges (pairs of node indices, source first) of the graph
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GraphData {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl GraphData {
+    pub fn orphan_count(&self) -> usize {
+        self.nodes.iter().filter(|node| node.orphan).count()
+    }
+}
+
+impl LinkGraph {
+    /// Read the links of every Markdown file under `root`
+    pub fn build(root: &Path) -> Self {
+        let mut graph = Self {
+            root: normalize(root),
+            files: BTreeMap::new(),
+        };
+        graph.refresh();
+        graph
+    }
+
+    /// Pick up added, removed and modified files; returns whether anything changed
+    pub fn refresh(&mut self) -> bool {
+        let paths = find_markdown_files(&self.root, MAX_LINK_GRAPH_FILES);
+        let present: BTreeSet<&PathBuf> = paths.iter().collect();
+
+        let before = self.files.len();
+        self.files.retain(|path, _| present.contains(path));
+        let mut changed = self.files.len() != before;
+
+        for path in &paths {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            let unchanged = self
+                .files
+                .get(path)
+                .is_some_and(|entry| modified.is_some() && entry.modified == modified);
+            if unchanged {
+                continue;
+            }
+
+            let content = fs::read_to_string(path).unwrap_or_default();
+            let title = DocumentMetadata::parse(&content)
+                .and_then(|metadata| metadata.title)
+                .or_else(|| first_heading(&content));
+            self.files.insert(
+                path.clone(),
+                FileEntry {
+                    modified,
+                    title,
+                    links: extract_links(path, &content),
+                },
+            );
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Nodes with their link counts and the resolved edges
+    pub fn data(&self) -> GraphData {
+        let indices: HashMap<&Path, usize> = self
+            .files
+            .keys()
+            .enumerate()
+            .map(|(index, path)| (path.as_path(), index))
+            .collect();
+
+        let mut edges = BTreeSet::new();
+        for (source, entry) in &self.files {
+            for link in &entry.links {
+                let Some(target) = self.resolve(source, link) else {
+                    continue;
+                };
+                if target != source.as_path() {
+                    edges.insert((indices[source.as_path()], indices[target]));
+                }
+            }
+        }
+
+        let mut nodes: Vec<GraphNode> = self
+            .files
+            .iter()
+            .map(|(path, entry)| GraphNode {
+                path: path.clone(),
+                label: entry
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| self.relative_name(path)),
+                incoming: 0,
+                outgoing: 0,
+                orphan: false,
+            })
+            .collect();
+        for &(source, target) in &edges {
+            nodes[source].outgoing += 1;
+            nodes[target].incoming += 1;
+        }
+        for node in &mut nodes {
+            node.orphan = node.incoming == 0;
+        }
+
+        GraphData {
+            nodes,
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    /// Graphviz DOT representation, with orphans drawn dashed
+    pub fn to_dot(&self) -> String {
+        let data = self.data();
+        let mut dot = String::from("digraph links {\n    node [shape=box];\n");
+        for node in &data.nodes {
+            let id = dot_escape(&self.relative_name(&node.path));
+            let label = dot_escape(&node.label);
+            let style = if node.orphan { ", style=dashed" } else { "" };
+            dot.push_str(&format!("    \"{id}\" [label=\"{label}\"{style}];\n"));
+        }
+        for &(source, target) in &data.edges {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\";\n",
+                dot_escape(&self.relative_name(&data.nodes[source].path)),
+                dot_escape(&self.relative_name(&data.nodes[target].path)),
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Path of a file relative to the root, for labels and DOT node IDs
+    fn relative_name(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// The file a link points to, if it is part of the graph
+    fn resolve(&self, source: &Path, link: &LinkTarget) -> Option<&Path> {
+        let known = |path: PathBuf| {
+            self.files
+                .get_key_value(&path)
+                .map(|(path, _)| path.as_path())
+        };
+
+        match link {
+            LinkTarget::Path(path) => known(path.clone()),
+            LinkTarget::Wiki(name) => {
+                // Relative to the linking file or the root, with or without extension
+                let dir = source.parent().unwrap_or(&self.root);
+                for base in [dir.join(name), self.root.join(name)] {
+                    for extension in ["", ".md", ".markdown"] {
+                        let mut candidate = base.clone().into_os_string();
+                        candidate.push(extension);
+                        if let Some(path) = known(normalize(Path::new(&candidate))) {
+                            return Some(path);
+                        }
+                    }
+                }
+
+                // Otherwise any file of that name, ignoring case like most wikis do
+                let name = Path::new(name)
+                    .file_name()?
+                    .to_string_lossy()
+                    .to_lowercase();
+                self.files
+                    .keys()
+                    .find(|path| {
+                        path.file_stem()
+                            .is_some_and(|stem| stem.to_string_lossy().to_lowercase() == name)
+                    })
+                    .map(PathBuf::as_path)
+            }
+        }
+    }
+}
+
+/// Local Markdown links of a document, without duplicates
+fn extract_links(source: &Path, markdown: &str) -> Vec<LinkTarget> {
+    let dir = source.parent().unwrap_or(Path::new(""));
+    let mut links = Vec::new();
+
+    for event in Parser::new_ext(strip_frontmatter(markdown), Options::all()) {
+        let Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            ..
+        }) = event
+        else {
+            continue;
+        };
+
+        let link = if matches!(link_type, LinkType::WikiLink { .. }) {
+            let name = dest_url.split('#').next().unwrap_or_default().trim();
+            if name.is_empty() {
+                continue;
+            }
+            LinkTarget::Wiki(name.to_string())
+        } else {
+            let Some(path) = local_markdown_path(&dest_url) else {
+                continue;
+            };
+            LinkTarget::Path(normalize(&dir.join(path)))
+        };
+
+        if !links.contains(&link) {
+            links.push(link);
+        }
+    }
+
+    links
+}
+
+/// Path of a link to a local Markdown file, without fragment or query
+fn local_markdown_path(href: &str) -> Option<PathBuf> {
+    if href.is_empty() || href.starts_with('#') || href.contains(':') {
+        return None;
+    }
+    let path = href.split(['#', '?']).next()?;
+    let path = PathBuf::from(percent_decode_str(path).decode_utf8().ok()?.as_ref());
+    is_markdown_file(&path).then_some(path)
+}
+
+/// Resolve `.` and `..` components without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn edge_names(graph: &LinkGraph) -> Vec<(String, String)> {
+        let data = graph.data();
+        data.edges
+            .iter()
+            .map(|&(source, target)| {
+                (
+                    graph.relative_name(&data.nodes[source].path),
+                    graph.relative_name(&data.nodes[target].path),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build_link_graph() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("guide")).unwrap();
+        fs::write(
+            root.join("index.md"),
+            "# Home\n\n[Intro](guide/intro.md) [again](./guide/intro.md#part) \
+             [[Setup Notes|setup]] [web](https://example.com) [top](#top) [gone](missing.md)\n",
+        )
+        .unwrap();
+        fs::write(root.join("guide/intro.md"), "[Home](../index.md)\n").unwrap();
+        fs::write(
+            root.join("setup notes.md"),
+            "---\ntitle: Setup\n---\n[Self](setup%20notes.md)\n",
+        )
+        .unwrap();
+        fs::write(root.join("lonely.md"), "Nobody links here\n").unwrap();
+
+        let graph = LinkGraph::build(root);
+        assert_eq!(
+            edge_names(&graph),
+            vec![
+                ("guide/intro.md".to_string(), "index.md".to_string()),
+                ("index.md".to_string(), "guide/intro.md".to_string()),
+                ("index.md".to_string(), "setup notes.md".to_string()),
+            ]
+        );
+
+        let data = graph.data();
+        let labels: Vec<(&str, bool)> = data
+            .nodes
+            .iter()
+            .map(|node| (node.label.as_str(), node.orphan))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("guide/intro.md", false),
+                ("Home", false),
+                ("lonely.md", true),
+                ("Setup", false),
+            ]
+        );
+        assert_eq!(data.orphan_count(), 1);
+    }
+
+    #[test]
+    fn test_refresh_link_graph() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("a.md"), "No links yet\n").unwrap();
+        fs::write(root.join("b.md"), "[A](a.md)\n").unwrap();
+
+        let mut graph = LinkGraph::build(root);
+        assert_eq!(edge_names(&graph).len(), 1);
+        assert!(!graph.refresh());
+
+        // Only files with a new modification time are read again
+        fs::write(root.join("a.md"), "[B](b.md)\n").unwrap();
+        let file = fs::File::options()
+            .write(true)
+            .open(root.join("a.md"))
+            .unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        assert!(graph.refresh());
+        assert_eq!(edge_names(&graph).len(), 2);
+
+        fs::remove_file(root.join("b.md")).unwrap();
+        assert!(graph.refresh());
+        assert_eq!(edge_names(&graph), Vec::<(String, String)>::new());
+        assert_eq!(graph.data().nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("a.md"), "---\ntitle: Say \"hi\"\n---\n[[b]]\n").unwrap();
+        fs::write(root.join("b.md"), "").unwrap();
+
+        let dot = LinkGraph::build(root).to_dot();
+        assert_eq!(
+            dot,
+            concat!(
+                "digraph links {\n",
+                "    node [shape=box];\n",
+                "    \"a.md\" [label=\"Say \\\"hi\\\"\", style=dashed];\n",
+                "    \"b.md\" [label=\"b.md\"];\n",
+                "    \"a.md\" -> \"b.md\";\n",
+                "}\n",
+            )
+        );
+    }
+}
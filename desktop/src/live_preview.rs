@@ -0,0 +1,202 @@
+//! Live preview of unsaved editor buffers
+//!
+//! Editors push the Markdown of a buffer instead of saving it, and tabs showing
+//! the preview re-render on every update. Buffers arrive over stdin
+//! (`arto --stdin`) or the remote control socket (`preview.update`). On stdin,
+//! each line is one JSON update like `preview.update` params; input that does
+//! not start with one is read as plain Markdown until EOF.
+
+use parking_lot::RwLock;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tokio::sync::broadcast;
+
+/// Command line flag making the first window preview stdin
+const STDIN_FLAG: &str = "--stdin";
+
+/// ID of the preview fed from stdin
+pub const STDIN_PREVIEW: &str = "stdin";
+
+/// Latest content pushed for a preview
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewBuffer {
+    pub markdown: String,
+    /// File the buffer belongs to, or the directory relative paths start from
+    #[serde(default)]
+    pub base_path: Option<PathBuf>,
+    /// Line under the cursor (1-based), scrolled into view after rendering
+    #[serde(default)]
+    pub cursor_line: Option<usize>,
+}
+
+impl PreviewBuffer {
+    /// Path to render the buffer as, so relative images resolve from its directory
+    ///
+    /// Buffers without a base path resolve against the working directory.
+    pub fn document_path(&self) -> PathBuf {
+        let base = self
+            .base_path
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_else(|| PathBuf::from("."));
+        if base.is_dir() {
+            // Relative paths resolve against the parent of the document
+            base.join("preview.md")
+        } else {
+            base
+        }
+    }
+
+    /// Directory links in the buffer are resolved against
+    pub fn base_dir(&self) -> PathBuf {
+        self.document_path()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+}
+
+static LIVE_PREVIEWS: LazyLock<RwLock<HashMap<String, PreviewBuffer>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Notification carrying the ID of a preview that was updated
+pub static LIVE_PREVIEW_UPDATED: LazyLock<broadcast::Sender<String>> =
+    LazyLock::new(|| broadcast::channel(100).0);
+
+/// Replace the content of a preview and re-render the tabs showing it
+pub fn update_preview(id: &str, buffer: PreviewBuffer) {
+    LIVE_PREVIEWS.write().insert(id.to_string(), buffer);
+    LIVE_PREVIEW_UPDATED.send(id.to_string()).ok();
+}
+
+/// Latest content of a preview, if anything was pushed yet
+pub fn get_preview(id: &str) -> Option<PreviewBuffer> {
+    LIVE_PREVIEWS.read().get(id).cloned()
+}
+
+/// Whether Arto was started with `--stdin`
+pub fn stdin_requested() -> bool {
+    std::env::args_os().skip(1).any(|arg| arg == STDIN_FLAG)
+}
+
+/// Feed the stdin preview from a background thread until stdin closes
+pub fn spawn_stdin_reader() {
+    std::thread::spawn(|| {
+        let stdin = io::stdin().lock();
+        match read_updates(stdin, |buffer| update_preview(STDIN_PREVIEW, buffer)) {
+            Ok(()) => tracing::info!("Live preview input closed"),
+            Err(e) => tracing::error!(%e, "Failed to read live preview input"),
+        }
+    });
+}
+
+/// Read preview updates from `reader`, calling `on_update` for each
+fn read_updates(
+    mut reader: impl BufRead,
+    mut on_update: impl FnMut(PreviewBuffer),
+) -> io::Result<()> {
+    let mut first = String::new();
+    reader.read_line(&mut first)?;
+    let Ok(buffer) = serde_json::from_str::<PreviewBuffer>(&first) else {
+        // Plain Markdown: render once everything has arrived
+        let mut markdown = first;
+        reader.read_to_string(&mut markdown)?;
+        on_update(PreviewBuffer {
+            markdown,
+            ..Default::default()
+        });
+        return Ok(());
+    };
+    on_update(buffer);
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(buffer) => on_update(buffer),
+            Err(e) => tracing::warn!(%e, "Ignoring invalid live preview update"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn collect(input: &str) -> Vec<PreviewBuffer> {
+        let mut updates = Vec::new();
+        read_updates(input.as_bytes(), |buffer| updates.push(buffer)).unwrap();
+        updates
+    }
+
+    #[test]
+    fn test_read_json_updates() {
+        let updates = collect(indoc! {r##"
+            {"markdown": "# One", "basePath": "/notes/a.md"}
+
+            not json
+            {"markdown": "# Two", "cursorLine": 3}
+        "##});
+
+        assert_eq!(
+            updates,
+            vec![
+                PreviewBuffer {
+                    markdown: "# One".to_string(),
+                    base_path: Some(PathBuf::from("/notes/a.md")),
+                    cursor_line: None,
+                },
+                PreviewBuffer {
+                    markdown: "# Two".to_string(),
+                    base_path: None,
+                    cursor_line: Some(3),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_plain_markdown() {
+        let markdown = indoc! {"
+            # Title
+
+            {\"not\": \"an update\"}
+        "};
+        assert_eq!(
+            collect(markdown),
+            vec![PreviewBuffer {
+                markdown: markdown.to_string(),
+                ..Default::default()
+            }]
+        );
+        assert_eq!(collect(""), vec![PreviewBuffer::default()]);
+    }
+
+    #[test]
+    fn test_document_path() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("notes.md");
+
+        // A file need not exist yet, e.g. for a new buffer
+        let buffer = PreviewBuffer {
+            base_path: Some(file.clone()),
+            ..Default::default()
+        };
+        assert_eq!(buffer.document_path(), file);
+        assert_eq!(buffer.base_dir(), dir.path());
+
+        let buffer = PreviewBuffer {
+            base_path: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        assert_eq!(buffer.base_dir(), dir.path());
+    }
+}
@@ -1,14 +1,29 @@
+mod annotations;
 mod assets;
+mod book;
 mod bookmarks;
 mod components;
 mod config;
+mod directory_index;
 mod drag;
+mod editor;
 mod events;
+mod folder_document;
+mod frontmatter;
 mod history;
+mod i18n;
+mod keymap;
+mod link_graph;
+mod live_preview;
 mod markdown;
 mod menu;
 mod pinned_search;
+mod recent;
+mod remote;
+mod settings_bundle;
 mod state;
+mod storage;
+mod tasks;
 mod theme;
 mod utils;
 mod watcher;
@@ -31,6 +46,7 @@ fn main() {
         println!("Loaded .env file from: {}", dotenv.display());
     }
     init_tracing();
+    tracing::info!(storage = ?*storage::STORAGE, "Resolved storage directories");
 
     // Create event channel and store receiver for MainApp
     let (tx, rx) = channel::<components::main_app::OpenEvent>(10);
@@ -39,6 +55,12 @@ fn main() {
         .expect("Failed to lock OPEN_EVENT_RECEIVER")
         .replace(rx);
 
+    // Preview what an editor pipes in (shown in the first window)
+    if live_preview::stdin_requested() {
+        live_preview::spawn_stdin_reader();
+    }
+
+    i18n::set_locale(config::CONFIG.read().language.resolve());
     let menu = menu::build_menu();
 
     // Get window parameters for first window from preferences
@@ -1,14 +1,24 @@
 use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
-use lol_html::{element, HtmlRewriter, Settings};
+use lol_html::{doc_comments, element, EndTagHandler, HtmlRewriter, Settings};
 use pulldown_cmark::{html, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 use serde_yaml::Value as YamlValue;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::frontmatter::{split_frontmatter, strip_frontmatter, yaml_to_string};
+use crate::tasks::extract_tasks;
+
+/// Prefix of the comments carrying the source position of a block while rendering
+const SOURCE_POSITION_MARKER: &str = "arto-source:";
 
 /// Information about a heading extracted from markdown
 #[derive(Debug, Clone, PartialEq)]
 pub struct HeadingInfo {
-    /// Heading level (1-6)
+    /// Heading level (1-6), or 0 for an entry grouping the headings of a file
     pub level: u8,
     /// Heading text content
     pub text: String,
@@ -16,6 +26,108 @@ pub struct HeadingInfo {
     pub id: String,
 }
 
+/// Rendered document with the local files it was rendered from
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub headings: Vec<HeadingInfo>,
+    /// Files read besides the document itself (e.g. embedded images), to watch for changes
+    pub dependencies: BTreeSet<PathBuf>,
+}
+
+/// A heading with its nested subsections, used for the table of contents
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadingNode {
+    pub heading: HeadingInfo,
+    /// Section number within the outline (e.g. `[1, 2, 3]` for "1.2.3")
+    pub number: Vec<usize>,
+    pub children: Vec<HeadingNode>,
+}
+
+impl HeadingNode {
+    /// Section number formatted as "1.2.3"
+    pub fn number_label(&self) -> String {
+        self.number
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Whether this node or one of its descendants has the given anchor ID
+    pub fn contains(&self, id: &str) -> bool {
+        self.heading.id == id || self.children.iter().any(|child| child.contains(id))
+    }
+}
+
+/// Build the heading outline from a flat heading list
+///
+/// A heading becomes a child of the closest preceding heading with a lower
+/// level, so skipped levels (`#` followed by `###`) still nest. Headings
+/// nested deeper than `max_depth` are left out.
+pub fn build_heading_tree(headings: &[HeadingInfo], max_depth: usize) -> Vec<HeadingNode> {
+    fn insert(nodes: &mut Vec<HeadingNode>, heading: &HeadingInfo, depth: usize, max_depth: usize) {
+        // Descend into the last node while it is an ancestor of the heading
+        if let Some(last) = nodes.last_mut() {
+            if last.heading.level < heading.level {
+                if depth < max_depth {
+                    insert(&mut last.children, heading, depth + 1, max_depth);
+                }
+                return;
+            }
+        }
+        nodes.push(HeadingNode {
+            heading: heading.clone(),
+            number: Vec::new(),
+            children: Vec::new(),
+        });
+    }
+
+    fn assign_numbers(nodes: &mut [HeadingNode], prefix: &[usize]) {
+        for (index, node) in nodes.iter_mut().enumerate() {
+            node.number = prefix.to_vec();
+            node.number.push(index + 1);
+            let number = node.number.clone();
+            assign_numbers(&mut node.children, &number);
+        }
+    }
+
+    let mut roots = Vec::new();
+    if max_depth == 0 {
+        return roots;
+    }
+    for heading in headings {
+        insert(&mut roots, heading, 1, max_depth);
+    }
+    assign_numbers(&mut roots, &[]);
+    roots
+}
+
+/// Keep only headings matching `query` (case-insensitive) and their ancestors
+pub fn filter_heading_tree(nodes: &[HeadingNode], query: &str) -> Vec<HeadingNode> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return nodes.to_vec();
+    }
+
+    fn filter(nodes: &[HeadingNode], query: &str) -> Vec<HeadingNode> {
+        nodes
+            .iter()
+            .filter_map(|node| {
+                let children = filter(&node.children, query);
+                let matches = node.heading.text.to_lowercase().contains(query);
+                (matches || !children.is_empty()).then(|| HeadingNode {
+                    heading: node.heading.clone(),
+                    number: node.number.clone(),
+                    children,
+                })
+            })
+            .collect()
+    }
+
+    filter(nodes, &query)
+}
+
 /// Generate a URL-safe slug from heading text
 fn generate_slug(text: &str) -> String {
     text.to_lowercase()
@@ -38,72 +150,92 @@ fn generate_slug(text: &str) -> String {
         .join("-")
 }
 
-/// Extract headings from markdown content
-pub fn extract_headings(markdown: &str) -> Vec<HeadingInfo> {
-    let options = Options::all();
+/// Anchor IDs already handed out, used to keep heading IDs unique
+///
+/// Sharing one instance across several documents (e.g. the chapters of a
+/// book rendered as one page) keeps IDs unique across all of them.
+#[derive(Debug, Default)]
+pub struct HeadingSlugs {
+    counts: std::collections::HashMap<String, usize>,
+}
 
-    // Skip frontmatter if present
-    let content = if let Some(after_start) = markdown.strip_prefix("---") {
-        if let Some(end_pos) = after_start.find("\n---") {
-            after_start[end_pos + 4..].trim_start()
+impl HeadingSlugs {
+    /// Reserve an ID for the heading text, appending a number for duplicates
+    fn unique(&mut self, text: &str) -> String {
+        let base_slug = generate_slug(text);
+        if let Some(count) = self.counts.get_mut(&base_slug) {
+            *count += 1;
+            format!("{}-{}", base_slug, count)
         } else {
-            markdown
+            self.counts.insert(base_slug.clone(), 0);
+            base_slug
         }
-    } else {
-        markdown
-    };
+    }
+}
+
+/// Extract headings from markdown content, reserving their anchor IDs in `slugs`
+fn extract_headings(markdown: &str, slugs: &mut HeadingSlugs) -> Vec<HeadingInfo> {
+    // Skip frontmatter if present
+    let content = strip_frontmatter(markdown);
 
     // Process GitHub alerts (they contain their own parsing)
-    let processed = process_github_alerts(content);
-    let parser = Parser::new_ext(&processed, options);
+    let processed = ProcessedMarkdown::new(content);
+    scan_headings(&processed, slugs)
+        .into_iter()
+        .map(|(heading, _)| heading)
+        .collect()
+}
+
+/// Headings of processed content with the source line (1-based) each starts at
+///
+/// Every lookup of headings by anchor ID goes through here, so IDs always
+/// match the ones given to the rendered document.
+fn scan_headings(
+    processed: &ProcessedMarkdown,
+    slugs: &mut HeadingSlugs,
+) -> Vec<(HeadingInfo, usize)> {
+    let options = Options::all();
+    let parser = Parser::new_ext(&processed.text, options).into_offset_iter();
 
     let mut headings = Vec::new();
-    let mut current_level: Option<u8> = None;
+    let mut current: Option<(u8, usize)> = None;
     let mut current_text = String::new();
-    let mut slug_counts: std::collections::HashMap<String, usize> =
-        std::collections::HashMap::new();
 
-    for event in parser {
+    for (event, range) in parser {
         match event {
             Event::Start(Tag::Heading { level, .. }) => {
-                current_level = Some(match level {
+                let level = match level {
                     HeadingLevel::H1 => 1,
                     HeadingLevel::H2 => 2,
                     HeadingLevel::H3 => 3,
                     HeadingLevel::H4 => 4,
                     HeadingLevel::H5 => 5,
                     HeadingLevel::H6 => 6,
-                });
+                };
+                current = Some((level, processed.source_position(range.start).0));
                 current_text.clear();
             }
-            Event::Text(text) if current_level.is_some() => {
+            Event::Text(text) if current.is_some() => {
                 current_text.push_str(&text);
             }
-            Event::Code(code) if current_level.is_some() => {
+            Event::Code(code) if current.is_some() => {
                 current_text.push_str(&code);
             }
-            Event::SoftBreak | Event::HardBreak if current_level.is_some() => {
+            Event::SoftBreak | Event::HardBreak if current.is_some() => {
                 current_text.push(' ');
             }
-            Event::End(TagEnd::Heading(_)) if current_level.is_some() => {
-                let level = current_level.take().unwrap();
-                let base_slug = generate_slug(&current_text);
-
-                // Handle duplicate slugs by appending a number
-                let id = if let Some(count) = slug_counts.get(&base_slug) {
-                    let new_count = count + 1;
-                    slug_counts.insert(base_slug.clone(), new_count);
-                    format!("{}-{}", base_slug, new_count)
-                } else {
-                    slug_counts.insert(base_slug.clone(), 0);
-                    base_slug
-                };
-
-                headings.push(HeadingInfo {
-                    level,
-                    text: current_text.trim().to_string(),
-                    id,
-                });
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, line)) = current.take() {
+                    // Handle duplicate slugs by appending a number
+                    let id = slugs.unique(&current_text);
+
+                    let heading = HeadingInfo {
+                        level,
+                        text: current_text.trim().to_string(),
+                        id,
+                    };
+                    headings.push((heading, line));
+                }
             }
             _ => {}
         }
@@ -155,24 +287,78 @@ pub fn render_to_html(markdown: impl AsRef<str>, base_path: impl AsRef<Path>) ->
     Ok(final_output)
 }
 
-/// Extract frontmatter from markdown and render it as an HTML table
-fn extract_and_render_frontmatter(markdown: &str) -> (String, String) {
-    // Check if markdown starts with frontmatter delimiter
-    if !markdown.starts_with("---") {
-        return (String::new(), markdown.to_string());
-    }
+/// Text of the first heading in markdown content
+pub fn first_heading(markdown: &str) -> Option<String> {
+    extract_headings(markdown, &mut HeadingSlugs::default())
+        .into_iter()
+        .next()
+        .map(|heading| heading.text)
+}
 
-    // Find the closing delimiter
-    let rest = &markdown[3..];
-    let Some(end_pos) = rest.find("\n---") else {
-        return (String::new(), markdown.to_string());
-    };
+/// Number of lines before the content of markdown, i.e. taken by frontmatter
+fn content_first_line(markdown: &str) -> usize {
+    let content = strip_frontmatter(markdown);
+    markdown[..markdown.len() - content.len()]
+        .matches('\n')
+        .count()
+}
 
-    let frontmatter_str = rest[..end_pos].trim();
-    let content = rest[end_pos + 4..].trim_start();
+/// 1-based line and column of a byte offset
+///
+/// The column counts characters, as editors do.
+fn source_position(markdown: &str, offset: usize) -> (usize, usize) {
+    let before = &markdown[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Source line (1-based) of the heading with this anchor ID
+pub fn heading_source_line(markdown: &str, heading_id: &str) -> Option<usize> {
+    let processed = ProcessedMarkdown::new(strip_frontmatter(markdown));
+    scan_headings(&processed, &mut HeadingSlugs::default())
+        .into_iter()
+        .find(|(heading, _)| heading.id == heading_id)
+        .map(|(_, line)| content_first_line(markdown) + line)
+}
+
+/// Source of the section starting at the heading with this anchor ID
+///
+/// The section runs up to the next heading of the same or a higher level,
+/// so it includes its subsections.
+pub fn section_source<'a>(markdown: &'a str, heading_id: &str) -> Option<&'a str> {
+    let content = strip_frontmatter(markdown);
+    let headings = scan_headings(
+        &ProcessedMarkdown::new(content),
+        &mut HeadingSlugs::default(),
+    );
+
+    let index = headings
+        .iter()
+        .position(|(heading, _)| heading.id == heading_id)?;
+    let (heading, start_line) = &headings[index];
+    let end = headings[index + 1..]
+        .iter()
+        .find(|(other, _)| other.level <= heading.level)
+        .map(|(_, line)| line_offset(content, *line))
+        .unwrap_or(content.len());
+    Some(content[line_offset(content, *start_line)..end].trim_end())
+}
+
+/// Byte offset of the start of a line (1-based)
+fn line_offset(content: &str, line: usize) -> usize {
+    content
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum()
+}
 
-    // Parse YAML
-    let Ok(yaml) = serde_yaml::from_str::<YamlValue>(frontmatter_str) else {
+/// Extract frontmatter from markdown and render it as an HTML table
+fn extract_and_render_frontmatter(markdown: &str) -> (String, String) {
+    let Some((yaml, content)) = split_frontmatter(markdown) else {
         return (String::new(), markdown.to_string());
     };
 
@@ -216,23 +402,6 @@ fn render_frontmatter_table(yaml: &YamlValue) -> String {
     )
 }
 
-/// Convert a YAML value to a string representation
-fn yaml_to_string(value: &YamlValue) -> String {
-    match value {
-        YamlValue::Null => "null".to_string(),
-        YamlValue::Bool(b) => b.to_string(),
-        YamlValue::Number(n) => n.to_string(),
-        YamlValue::String(s) => s.clone(),
-        YamlValue::Sequence(seq) => seq
-            .iter()
-            .map(yaml_to_string)
-            .collect::<Vec<_>>()
-            .join(", "),
-        YamlValue::Mapping(_) => "[object]".to_string(),
-        YamlValue::Tagged(tagged) => yaml_to_string(&tagged.value),
-    }
-}
-
 /// Render a YAML value as HTML (with special handling for arrays and objects)
 fn render_yaml_value(value: &YamlValue) -> String {
     match value {
@@ -353,27 +522,122 @@ fn process_alert_block(
     (html_lines, i)
 }
 
+/// Markdown content with GitHub alerts turned into HTML
+///
+/// Remembers the source line each of its lines came from, so positions found
+/// by parsing it can be reported in the original content.
+struct ProcessedMarkdown {
+    text: String,
+    /// 0-based source line of each line of `text`, and whether it was copied verbatim
+    lines: Vec<(usize, bool)>,
+}
+
+impl ProcessedMarkdown {
+    fn new(content: &str) -> Self {
+        let source_lines: Vec<&str> = content.lines().collect();
+        let mut parts = Vec::new();
+        let mut lines = Vec::new();
+        let mut i = 0;
+
+        while i < source_lines.len() {
+            let line = source_lines[i];
+
+            if let Some((alert_name, alert_class, rest)) = parse_alert_start(line) {
+                let (alert_html, next_index) =
+                    process_alert_block(&source_lines, i, alert_name, alert_class, rest);
+                let alert_html = alert_html.join("\n");
+                lines.extend(std::iter::repeat_n(
+                    (i, false),
+                    alert_html.split('\n').count(),
+                ));
+                parts.push(alert_html);
+                i = next_index;
+            } else {
+                parts.push(line.to_string());
+                lines.push((i, true));
+                i += 1;
+            }
+        }
+
+        Self {
+            text: parts.join("\n"),
+            lines,
+        }
+    }
+
+    /// 1-based source line and column of a byte offset into `text`
+    ///
+    /// Lines generated for an alert report the start of the alert.
+    fn source_position(&self, offset: usize) -> (usize, usize) {
+        let (line, column) = source_position(&self.text, offset);
+        match self.lines.get(line - 1) {
+            Some(&(source, true)) => (source + 1, column),
+            Some(&(source, false)) => (source + 1, 1),
+            None => (self.lines.last().map_or(1, |&(source, _)| source + 1), 1),
+        }
+    }
+}
+
 /// Process GitHub alert format
 fn process_github_alerts(markdown: &str) -> String {
-    let lines: Vec<&str> = markdown.lines().collect();
-    let mut result = Vec::new();
-    let mut i = 0;
-
-    while i < lines.len() {
-        let line = lines[i];
-
-        if let Some((alert_name, alert_class, rest)) = parse_alert_start(line) {
-            let (alert_html, next_index) =
-                process_alert_block(&lines, i, alert_name, alert_class, rest);
-            result.extend(alert_html);
-            i = next_index;
-        } else {
-            result.push(line.to_string());
-            i += 1;
+    ProcessedMarkdown::new(markdown).text
+}
+
+/// Precede each top-level block with a comment carrying its source position
+///
+/// `post_process_html_with_headings` moves each position onto the first
+/// top-level element that follows, so blocks rendering no element (like an
+/// HTML comment) or several (like raw HTML) do not shift later positions.
+fn mark_source_positions<'a>(
+    events: impl Iterator<Item = (Event<'a>, Range<usize>)>,
+    processed: &'a ProcessedMarkdown,
+    first_line: usize,
+) -> impl Iterator<Item = Event<'a>> {
+    let mut depth = 0usize;
+    events.flat_map(move |(event, range)| {
+        let marker = (depth == 0 && !matches!(event, Event::End(_))).then(|| {
+            let (line, column) = processed.source_position(range.start);
+            Event::Html(
+                format!(
+                    "<!--{SOURCE_POSITION_MARKER}{}:{column}-->",
+                    first_line + line
+                )
+                .into(),
+            )
+        });
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth = depth.saturating_sub(1),
+            _ => {}
         }
-    }
+        marker.into_iter().chain(std::iter::once(event))
+    })
+}
 
-    result.join("\n")
+/// Tag task list checkboxes with the index of their task in `extract_tasks`
+///
+/// Tasks are matched by source line, so checkboxes written as raw HTML or
+/// rendered inside GitHub alerts are left untagged instead of shifting the
+/// indices of the tasks after them.
+fn mark_task_checkboxes<'a>(
+    events: impl Iterator<Item = (Event<'a>, Range<usize>)>,
+    processed: &'a ProcessedMarkdown,
+    first_line: usize,
+    task_indices: HashMap<usize, usize>,
+) -> impl Iterator<Item = (Event<'a>, Range<usize>)> {
+    events.map(move |(event, range)| {
+        let Event::TaskListMarker(checked) = event else {
+            return (event, range);
+        };
+        let (line, _) = processed.source_position(range.start);
+        let Some(index) = task_indices.get(&(first_line + line)) else {
+            return (event, range);
+        };
+        let checked = if checked { r#" checked="""# } else { "" };
+        let html =
+            format!(r#"<input disabled="" type="checkbox" data-task-index="{index}"{checked}/>"#);
+        (Event::InlineHtml(format!("{html}\n").into()), range)
+    })
 }
 
 /// Process Code blocks
@@ -459,11 +723,38 @@ pub fn render_to_html_with_toc(
     markdown: impl AsRef<str>,
     base_path: impl AsRef<Path>,
 ) -> Result<(String, Vec<HeadingInfo>)> {
-    let markdown = markdown.as_ref();
-    let base_path = base_path.as_ref();
+    render_to_html_with_slugs(markdown, base_path, &mut HeadingSlugs::default())
+}
+
+/// Render Markdown to HTML with TOC headings whose IDs are unique within `slugs`
+pub fn render_to_html_with_slugs(
+    markdown: impl AsRef<str>,
+    base_path: impl AsRef<Path>,
+    slugs: &mut HeadingSlugs,
+) -> Result<(String, Vec<HeadingInfo>)> {
+    render_markdown(markdown.as_ref(), base_path.as_ref(), slugs)
+        .map(|rendered| (rendered.html, rendered.headings))
+}
 
+/// Render Markdown to HTML with TOC information and the local files it depends on
+pub fn render_to_html_with_dependencies(
+    markdown: impl AsRef<str>,
+    base_path: impl AsRef<Path>,
+) -> Result<RenderedMarkdown> {
+    render_markdown(
+        markdown.as_ref(),
+        base_path.as_ref(),
+        &mut HeadingSlugs::default(),
+    )
+}
+
+fn render_markdown(
+    markdown: &str,
+    base_path: &Path,
+    slugs: &mut HeadingSlugs,
+) -> Result<RenderedMarkdown> {
     // Extract headings first
-    let headings = extract_headings(markdown);
+    let headings = extract_headings(markdown, slugs);
 
     // Enable GitHub Flavored Markdown options
     let options = Options::all();
@@ -478,10 +769,17 @@ pub fn render_to_html_with_toc(
     let (frontmatter_html, content) = extract_and_render_frontmatter(markdown);
 
     // Process GitHub alerts
-    let processed_markdown = process_github_alerts(&content);
+    let processed_markdown = ProcessedMarkdown::new(&content);
 
     // Parse Markdown and process blocks
-    let parser = Parser::new_ext(&processed_markdown, options);
+    let first_line = content_first_line(markdown);
+    let task_indices = extract_tasks(markdown)
+        .into_iter()
+        .map(|task| (task.line, task.index))
+        .collect();
+    let parser = Parser::new_ext(&processed_markdown.text, options).into_offset_iter();
+    let parser = mark_task_checkboxes(parser, &processed_markdown, first_line, task_indices);
+    let parser = mark_source_positions(parser, &processed_markdown, first_line);
     let parser = process_code_blocks(parser, "mermaid");
     let parser = process_code_blocks(parser, "math");
     let parser = process_math_expressions(parser);
@@ -491,7 +789,8 @@ pub fn render_to_html_with_toc(
     html::push_html(&mut html_output, parser);
 
     // Post-process HTML with heading IDs
-    let html_output = post_process_html_with_headings(&html_output, base_dir.as_path(), &headings);
+    let (html_output, dependencies) =
+        post_process_html_with_headings(&html_output, base_dir.as_path(), &headings);
 
     // Prepend frontmatter table if present
     let final_output = if frontmatter_html.is_empty() {
@@ -500,19 +799,67 @@ pub fn render_to_html_with_toc(
         format!("{}\n{}", frontmatter_html, html_output)
     };
 
-    Ok((final_output, headings))
+    Ok(RenderedMarkdown {
+        html: final_output,
+        headings,
+        dependencies,
+    })
+}
+
+/// Data URL of a local image, with the file it was read from
+fn local_image_data_url(src: &str, base_dir: &Path) -> Option<(PathBuf, String)> {
+    if src.starts_with("http://") || src.starts_with("https://") || src.starts_with("data:") {
+        return None;
+    }
+    let canonical_path = base_dir.join(src).canonicalize().ok()?;
+    let image_data = std::fs::read(&canonical_path).ok()?;
+    let mime_type = get_mime_type(&canonical_path);
+    let base64_data = general_purpose::STANDARD.encode(&image_data);
+    let data_url = format!("data:{};base64,{}", mime_type, base64_data);
+    Some((canonical_path, data_url))
+}
+
+/// Class of the span replacing a local link, or `None` to keep the anchor as is
+///
+/// Links with an extension point at files (only Markdown files can be opened);
+/// links without one are kept unless they resolve to a directory.
+fn local_link_class(href: &str, base_dir: &Path) -> Option<&'static str> {
+    if href.is_empty()
+        || href.starts_with('#')
+        || href.starts_with("http://")
+        || href.starts_with("https://")
+    {
+        return None;
+    }
+    match Path::new(href).extension().and_then(|e| e.to_str()) {
+        Some("md" | "markdown") => Some("md-link"),
+        Some(_) => Some("md-link md-link-invalid"),
+        None if base_dir.join(href).is_dir() => Some("md-link md-link-directory"),
+        None => None,
+    }
 }
 
 /// Post-process HTML to handle img, anchor tags, and add heading IDs using lol_html
+///
+/// The source position comments of `mark_source_positions` become
+/// `data-source-line`/`data-source-column` of the top-level element after
+/// them, so the source of a block can be found from the rendered document.
+/// Also returns the local images that were inlined.
 fn post_process_html_with_headings(
     html_str: &str,
     base_dir: &Path,
     headings: &[HeadingInfo],
-) -> String {
+) -> (String, BTreeSet<PathBuf>) {
     let base_dir = base_dir.to_path_buf();
+    let link_base_dir = base_dir.clone();
     let mut output = Vec::new();
-    let heading_index = std::cell::RefCell::new(0usize);
+    let dependencies = RefCell::new(BTreeSet::new());
+    let heading_index = RefCell::new(0usize);
     let headings = headings.to_vec();
+    // Nesting depth, decremented by end tag handlers that must be 'static
+    let depth = Rc::new(Cell::new(0usize));
+    // Position of the block whose first element is still to come
+    let source_position = Cell::new(None::<(usize, usize)>);
 
     let mut rewriter = HtmlRewriter::new(
         Settings {
@@ -526,57 +873,70 @@ fn post_process_html_with_headings(
                     *idx += 1;
                     Ok(())
                 }),
+                // Tag top-level blocks with their source position
+                element!("*", |el| {
+                    if depth.get() == 0 {
+                        if let Some((line, column)) = source_position.take() {
+                            el.set_attribute("data-source-line", &line.to_string())?;
+                            el.set_attribute("data-source-column", &column.to_string())?;
+                        }
+                    }
+                    // Void elements have no end tag and never contain others
+                    if let Some(handlers) = el.end_tag_handlers() {
+                        depth.set(depth.get() + 1);
+                        let depth = depth.clone();
+                        let on_end: EndTagHandler<'static> = Box::new(move |_| {
+                            depth.set(depth.get().saturating_sub(1));
+                            Ok(())
+                        });
+                        handlers.push(on_end);
+                    }
+                    Ok(())
+                }),
                 // Process img tags: convert relative paths to data URLs
-                element!("img[src]", move |el| {
+                element!("img[src]", |el| {
                     if let Some(src) = el.get_attribute("src") {
-                        if !src.starts_with("http://")
-                            && !src.starts_with("https://")
-                            && !src.starts_with("data:")
-                        {
-                            let absolute_path = base_dir.join(&src);
-                            if let Ok(canonical_path) = absolute_path.canonicalize() {
-                                if let Ok(image_data) = std::fs::read(&canonical_path) {
-                                    let mime_type = get_mime_type(&canonical_path);
-                                    let base64_data = general_purpose::STANDARD.encode(&image_data);
-                                    let data_url =
-                                        format!("data:{};base64,{}", mime_type, base64_data);
-                                    el.set_attribute("src", &data_url)?;
-                                }
-                            }
+                        if let Some((path, data_url)) = local_image_data_url(&src, &base_dir) {
+                            el.set_attribute("src", &data_url)?;
+                            dependencies.borrow_mut().insert(path);
                         }
                     }
                     Ok(())
                 }),
-                // Process anchor tags: convert markdown links to spans
-                element!("a[href]", |el| {
+                // Process anchor tags: convert markdown and directory links to spans
+                element!("a[href]", move |el| {
                     if let Some(href) = el.get_attribute("href") {
-                        if !href.starts_with("http://") && !href.starts_with("https://") {
-                            if let Some(ext) = std::path::Path::new(&href)
-                                .extension()
-                                .and_then(|e| e.to_str())
-                            {
-                                // Replace with span element
-                                let escaped_href = href.replace('\'', "\\'");
-                                let onclick = indoc::formatdoc! {r#"
-                                        if (event.button === 0 || event.button === 1) {{
-                                            event.preventDefault();
-                                            window.handleMarkdownLinkClick('{escaped_href}', event.button);
-                                        }}"#
-                                };
-                                el.set_tag_name("span")?;
-                                el.remove_attribute("href");
-                                if ext != "md" && ext != "markdown" {
-                                    el.set_attribute("class", "md-link md-link-invalid")?;
-                                } else {
-                                    el.set_attribute("class", "md-link")?;
-                                }
-                                el.set_attribute("onmousedown", &onclick)?;
-                            }
+                        if let Some(class) = local_link_class(&href, &link_base_dir) {
+                            // Replace with span element
+                            let escaped_href = href.replace('\'', "\\'");
+                            let onclick = indoc::formatdoc! {r#"
+                                    if (event.button === 0 || event.button === 1) {{
+                                        event.preventDefault();
+                                        window.handleMarkdownLinkClick('{escaped_href}', event.button);
+                                    }}"#
+                            };
+                            el.set_tag_name("span")?;
+                            el.remove_attribute("href");
+                            el.set_attribute("class", class)?;
+                            el.set_attribute("onmousedown", &onclick)?;
                         }
                     }
                     Ok(())
                 }),
             ],
+            document_content_handlers: vec![
+                // Take the position of the next block out of its marker comment
+                doc_comments!(|comment| {
+                    let text = comment.text();
+                    if let Some(position) = text.strip_prefix(SOURCE_POSITION_MARKER) {
+                        source_position.set(position.split_once(':').and_then(|(line, column)| {
+                            Some((line.parse().ok()?, column.parse().ok()?))
+                        }));
+                        comment.remove();
+                    }
+                    Ok(())
+                }),
+            ],
             ..Settings::default()
         },
         |chunk: &[u8]| {
@@ -586,12 +946,14 @@ fn post_process_html_with_headings(
 
     let _ = rewriter.write(html_str.as_bytes());
     let _ = rewriter.end();
-    String::from_utf8(output).unwrap_or_else(|_| html_str.to_string())
+    let html = String::from_utf8(output).unwrap_or_else(|_| html_str.to_string());
+    (html, dependencies.into_inner())
 }
 
 /// Post-process HTML to handle img and anchor tags using lol_html
 fn post_process_html_tags(html_str: &str, base_dir: &Path) -> String {
     let base_dir = base_dir.to_path_buf();
+    let link_base_dir = base_dir.clone();
     let mut output = Vec::new();
 
     let mut rewriter = HtmlRewriter::new(
@@ -600,49 +962,28 @@ fn post_process_html_tags(html_str: &str, base_dir: &Path) -> String {
                 // Process img tags: convert relative paths to data URLs
                 element!("img[src]", move |el| {
                     if let Some(src) = el.get_attribute("src") {
-                        if !src.starts_with("http://")
-                            && !src.starts_with("https://")
-                            && !src.starts_with("data:")
-                        {
-                            let absolute_path = base_dir.join(&src);
-                            if let Ok(canonical_path) = absolute_path.canonicalize() {
-                                if let Ok(image_data) = std::fs::read(&canonical_path) {
-                                    let mime_type = get_mime_type(&canonical_path);
-                                    let base64_data = general_purpose::STANDARD.encode(&image_data);
-                                    let data_url =
-                                        format!("data:{};base64,{}", mime_type, base64_data);
-                                    el.set_attribute("src", &data_url)?;
-                                }
-                            }
+                        if let Some((_, data_url)) = local_image_data_url(&src, &base_dir) {
+                            el.set_attribute("src", &data_url)?;
                         }
                     }
                     Ok(())
                 }),
-                // Process anchor tags: convert markdown links to spans
-                element!("a[href]", |el| {
+                // Process anchor tags: convert markdown and directory links to spans
+                element!("a[href]", move |el| {
                     if let Some(href) = el.get_attribute("href") {
-                        if !href.starts_with("http://") && !href.starts_with("https://") {
-                            if let Some(ext) = std::path::Path::new(&href)
-                                .extension()
-                                .and_then(|e| e.to_str())
-                            {
-                                // Replace with span element
-                                let escaped_href = href.replace('\'', "\\'");
-                                let onclick = indoc::formatdoc! {r#"
-                                        if (event.button === 0 || event.button === 1) {{
-                                            event.preventDefault();
-                                            window.handleMarkdownLinkClick('{escaped_href}', event.button); 
-                                        }}"#
-                                };
-                                el.set_tag_name("span")?;
-                                el.remove_attribute("href");
-                                if ext != "md" && ext != "markdown" {
-                                    el.set_attribute("class", "md-link md-link-invalid")?;
-                                } else {
-                                    el.set_attribute("class", "md-link")?;
-                                }
-                                el.set_attribute("onmousedown", &onclick)?;
-                            }
+                        if let Some(class) = local_link_class(&href, &link_base_dir) {
+                            // Replace with span element
+                            let escaped_href = href.replace('\'', "\\'");
+                            let onclick = indoc::formatdoc! {r#"
+                                    if (event.button === 0 || event.button === 1) {{
+                                        event.preventDefault();
+                                        window.handleMarkdownLinkClick('{escaped_href}', event.button); 
+                                    }}"#
+                            };
+                            el.set_tag_name("span")?;
+                            el.remove_attribute("href");
+                            el.set_attribute("class", class)?;
+                            el.set_attribute("onmousedown", &onclick)?;
                         }
                     }
                     Ok(())
@@ -901,6 +1242,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_post_process_html_tags_directory_links() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("notes")).unwrap();
+        let html =
+            r##"<a href="notes">Notes</a><a href="missing">Missing</a><a href="#top">Top</a>"##;
+        let result = post_process_html_tags(html, temp_dir.path());
+
+        assert!(result.contains(r#"class="md-link md-link-directory""#));
+        assert!(result.contains("handleMarkdownLinkClick('notes'"));
+        // Plain anchors and links to nothing are left untouched
+        assert!(result.contains(r#"<a href="missing">"#));
+        assert!(result.contains(r##"<a href="#top">"##));
+    }
+
     #[test]
     fn test_render_to_html_basic() {
         let markdown = "# Hello\n\nThis is a test.";
@@ -1323,7 +1679,7 @@ mod tests {
             ## Section 2
         "};
 
-        let headings = extract_headings(markdown);
+        let headings = extract_headings(markdown, &mut HeadingSlugs::default());
 
         assert_eq!(headings.len(), 4);
         assert_eq!(
@@ -1376,7 +1732,7 @@ mod tests {
             ## Overview
         "};
 
-        let headings = extract_headings(markdown);
+        let headings = extract_headings(markdown, &mut HeadingSlugs::default());
 
         assert_eq!(headings.len(), 4);
         assert_eq!(headings[0].id, "introduction");
@@ -1385,6 +1741,23 @@ mod tests {
         assert_eq!(headings[3].id, "overview-2");
     }
 
+    #[test]
+    fn test_render_to_html_with_shared_slugs() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut slugs = HeadingSlugs::default();
+
+        let (_, first) =
+            render_to_html_with_slugs("# Overview", temp_dir.path().join("a.md"), &mut slugs)
+                .unwrap();
+        let (html, second) =
+            render_to_html_with_slugs("# Overview", temp_dir.path().join("b.md"), &mut slugs)
+                .unwrap();
+
+        assert_eq!(first[0].id, "overview");
+        assert_eq!(second[0].id, "overview-1");
+        assert!(html.contains(r#"id="overview-1""#));
+    }
+
     #[test]
     fn test_extract_headings_with_frontmatter() {
         let markdown = indoc! {"
@@ -1397,12 +1770,111 @@ mod tests {
             Content
         "};
 
-        let headings = extract_headings(markdown);
+        let headings = extract_headings(markdown, &mut HeadingSlugs::default());
 
         assert_eq!(headings.len(), 1);
         assert_eq!(headings[0].text, "Heading After Frontmatter");
     }
 
+    fn heading(level: u8, id: &str) -> HeadingInfo {
+        HeadingInfo {
+            level,
+            text: id.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    fn outline(nodes: &[HeadingNode]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for node in nodes {
+            lines.push(format!("{} {}", node.number_label(), node.heading.id));
+            lines.extend(
+                outline(&node.children)
+                    .into_iter()
+                    .map(|l| format!("  {l}")),
+            );
+        }
+        lines
+    }
+
+    #[test]
+    fn test_build_heading_tree() {
+        let headings = vec![
+            heading(1, "title"),
+            heading(2, "intro"),
+            heading(3, "scope"),
+            heading(2, "usage"),
+            heading(4, "skipped-level"),
+            heading(1, "appendix"),
+        ];
+
+        let tree = build_heading_tree(&headings, 6);
+
+        assert_eq!(
+            outline(&tree),
+            vec![
+                "1 title",
+                "  1.1 intro",
+                "    1.1.1 scope",
+                "  1.2 usage",
+                "    1.2.1 skipped-level",
+                "2 appendix",
+            ]
+        );
+        assert!(tree[0].contains("scope"));
+        assert!(!tree[1].contains("scope"));
+    }
+
+    #[test]
+    fn test_build_heading_tree_without_top_level() {
+        // Documents starting at `##` (or going up a level) have several roots
+        let headings = vec![heading(2, "a"), heading(3, "b"), heading(1, "c")];
+
+        let tree = build_heading_tree(&headings, 6);
+
+        assert_eq!(outline(&tree), vec!["1 a", "  1.1 b", "2 c"]);
+    }
+
+    #[test]
+    fn test_build_heading_tree_depth_limit() {
+        let headings = vec![
+            heading(1, "title"),
+            heading(2, "section"),
+            heading(3, "hidden"),
+            heading(4, "also-hidden"),
+            heading(2, "next"),
+        ];
+
+        let tree = build_heading_tree(&headings, 2);
+
+        assert_eq!(
+            outline(&tree),
+            vec!["1 title", "  1.1 section", "  1.2 next"]
+        );
+        assert!(build_heading_tree(&headings, 0).is_empty());
+    }
+
+    #[test]
+    fn test_filter_heading_tree() {
+        let headings = vec![
+            heading(1, "title"),
+            heading(2, "install"),
+            heading(3, "linux"),
+            heading(2, "usage"),
+        ];
+        let tree = build_heading_tree(&headings, 6);
+
+        // Ancestors of matches are kept and numbering is preserved
+        let filtered = filter_heading_tree(&tree, "LIN");
+        assert_eq!(
+            outline(&filtered),
+            vec!["1 title", "  1.1 install", "    1.1.1 linux"]
+        );
+
+        assert_eq!(filter_heading_tree(&tree, "  "), tree);
+        assert!(filter_heading_tree(&tree, "missing").is_empty());
+    }
+
     #[test]
     fn test_render_to_html_with_toc() {
         let markdown = indoc! {"
@@ -1427,12 +1899,242 @@ mod tests {
 
         // Check IDs were added to HTML
         assert!(
-            html.contains(r#"<h1 id="title">"#),
+            html.contains(r#"<h1 id="title" "#),
             "H1 should have id attribute"
         );
         assert!(
-            html.contains(r#"<h2 id="section-1">"#),
+            html.contains(r#"<h2 id="section-1" "#),
             "H2 should have id attribute"
         );
     }
+
+    #[test]
+    fn test_render_to_html_with_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("images")).unwrap();
+        fs::write(temp_dir.path().join("images/chart.png"), [0x89, 0x50]).unwrap();
+        fs::write(temp_dir.path().join("logo.svg"), "<svg/>").unwrap();
+        let md_path = temp_dir.path().join("test.md");
+
+        let markdown = indoc! {r#"
+            ![Chart](images/chart.png)
+
+            <img src="logo.svg"> ![Missing](missing.png) ![Remote](https://example.com/a.png)
+        "#};
+        let rendered = render_to_html_with_dependencies(markdown, &md_path).unwrap();
+
+        // Only local images that were actually read are dependencies
+        let root = temp_dir.path().canonicalize().unwrap();
+        assert_eq!(
+            rendered.dependencies.into_iter().collect::<Vec<_>>(),
+            vec![root.join("images/chart.png"), root.join("logo.svg")]
+        );
+        assert_eq!(rendered.html.matches("data:image/").count(), 2);
+    }
+
+    #[test]
+    fn test_render_to_html_with_source_positions() {
+        let markdown = indoc! {"
+            # Title
+
+            - item
+            - item
+
+            ---
+            Text
+        "};
+        let (html, _) = render_to_html_with_toc(markdown, "/tmp/test.md").unwrap();
+
+        assert!(html.contains(r#"<h1 id="title" data-source-line="1" data-source-column="1">"#));
+        assert!(html.contains(r#"<ul data-source-line="3" data-source-column="1">"#));
+        // Nested and void elements do not shift the count
+        assert!(html.contains("<li>item</li>"));
+        assert!(html.contains(r#"<hr data-source-line="6" data-source-column="1" />"#));
+        assert!(html.contains(r#"<p data-source-line="7" data-source-column="1">Text</p>"#));
+    }
+
+    #[test]
+    fn test_render_to_html_with_source_positions_and_frontmatter() {
+        let markdown = indoc! {"
+            ---
+            title: Notes
+            ---
+
+            # Notes
+
+              ```rust
+              fn main() {}
+              ```
+
+            > [!NOTE]
+            > Body
+
+            Last
+        "};
+        let (html, _) = render_to_html_with_toc(markdown, "/tmp/test.md").unwrap();
+
+        assert!(html.contains(r#"<h1 id="notes" data-source-line="5" data-source-column="1">"#));
+        assert!(html.contains(r#"<pre data-source-line="7" data-source-column="3">"#));
+        // Alerts are replaced by HTML, reported at their first line
+        assert!(html.contains(
+            r#"<div class="markdown-alert markdown-alert-note" dir="auto" data-source-line="11" data-source-column="1">"#
+        ));
+        assert!(html.contains(r#"<p data-source-line="14" data-source-column="1">Last</p>"#));
+        assert!(!html.contains(SOURCE_POSITION_MARKER));
+    }
+
+    #[test]
+    fn test_render_to_html_with_source_positions_and_html_blocks() {
+        let markdown = indoc! {r#"
+            <!-- hidden note -->
+
+            # Title
+
+            <details>
+            <summary>More</summary>
+
+            Hidden
+
+            </details>
+
+            <p align="center">One</p>
+            <p align="center">Two</p>
+
+            Text
+        "#};
+        let (html, _) = render_to_html_with_toc(markdown, "/tmp/test.md").unwrap();
+
+        // A comment renders no element and does not shift later blocks
+        assert!(html.contains("<!-- hidden note -->"));
+        assert!(html.contains(r#"<h1 id="title" data-source-line="3" data-source-column="1">"#));
+        assert!(html.contains(r#"<details data-source-line="5" data-source-column="1">"#));
+        // Blocks inside raw HTML elements are not top-level
+        assert!(html.contains("<p>Hidden</p>"));
+        // Only the first element of an HTML block gets its position
+        assert!(html
+            .contains(r#"<p align="center" data-source-line="12" data-source-column="1">One</p>"#));
+        assert!(html.contains(r#"<p align="center">Two</p>"#));
+        assert!(html.contains(r#"<p data-source-line="15" data-source-column="1">Text</p>"#));
+    }
+
+    #[test]
+    fn test_render_to_html_with_task_indices() {
+        let markdown = indoc! {r#"
+            ---
+            title: Todo
+            ---
+
+            <input disabled="" type="checkbox"> Raw HTML
+
+            > [!NOTE]
+            > - [ ] In alert
+
+            - [ ] First
+            - [x] Second
+        "#};
+        let (html, _) = render_to_html_with_toc(markdown, "/tmp/test.md").unwrap();
+
+        // Checkboxes are numbered like `extract_tasks`, skipping raw HTML ones
+        let tasks = extract_tasks(markdown);
+        assert_eq!(tasks.len(), 3);
+        assert!(html.contains(r#"<input disabled="" type="checkbox"> Raw HTML"#));
+        assert!(html.contains(r#"<input disabled="" type="checkbox"/>"#));
+        assert!(html.contains(r#"<input disabled="" type="checkbox" data-task-index="1"/>"#));
+        assert!(
+            html.contains(r#"<input disabled="" type="checkbox" data-task-index="2" checked=""/>"#)
+        );
+        assert!(!html.contains(r#"data-task-index="0""#));
+    }
+
+    #[test]
+    fn test_heading_source_line() {
+        let markdown = indoc! {"
+            ---
+            title: Guide
+            ---
+
+            # Guide
+
+            ## Usage
+
+            ## Usage
+        "};
+
+        assert_eq!(heading_source_line(markdown, "guide"), Some(5));
+        assert_eq!(heading_source_line(markdown, "usage-1"), Some(9));
+        assert_eq!(heading_source_line(markdown, "missing"), None);
+    }
+
+    #[test]
+    fn test_heading_source_line_after_alert() {
+        let markdown = indoc! {"
+            # Setup
+
+            > [!NOTE]
+            > # Setup
+            > Inside the alert
+
+            ## Setup
+        "};
+
+        // IDs match extract_headings, which does not see headings inside alerts
+        let ids: Vec<String> = extract_headings(markdown, &mut HeadingSlugs::default())
+            .into_iter()
+            .map(|heading| heading.id)
+            .collect();
+        assert_eq!(ids, vec!["setup", "setup-1"]);
+        assert_eq!(heading_source_line(markdown, "setup-1"), Some(7));
+        assert_eq!(section_source(markdown, "setup-1"), Some("## Setup"));
+    }
+
+    #[test]
+    fn test_section_source() {
+        let markdown = indoc! {"
+            # Guide
+
+            Intro
+
+            ## Install
+
+            Run it.
+
+            ### Linux
+
+            Use apt.
+
+            ## Usage
+
+            Open a file.
+        "};
+
+        assert_eq!(
+            section_source(markdown, "install"),
+            Some("## Install\n\nRun it.\n\n### Linux\n\nUse apt.")
+        );
+        assert_eq!(
+            section_source(markdown, "usage"),
+            Some("## Usage\n\nOpen a file.")
+        );
+        assert_eq!(section_source(markdown, "guide"), Some(markdown.trim_end()));
+        assert_eq!(section_source(markdown, "missing"), None);
+    }
+
+    #[test]
+    fn test_section_source_with_duplicate_headings() {
+        let markdown = indoc! {"
+            ## Example
+
+            First
+
+            ## Example
+
+            Second
+        "};
+
+        // IDs match the ones given to the rendered headings
+        assert_eq!(
+            section_source(markdown, "example-1"),
+            Some("## Example\n\nSecond")
+        );
+    }
 }
@@ -1,21 +1,35 @@
 use dioxus::prelude::{spawn, ReadableExt, WritableExt};
-use dioxus_desktop::muda::accelerator::{Accelerator, Code, Modifiers};
 use dioxus_desktop::muda::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
 use dioxus_desktop::window;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::components::content::set_preferences_tab_to_about;
-use crate::state::AppState;
+use crate::config::{KeymapConfig, CONFIG};
+use crate::i18n::t;
+use crate::keymap;
+use crate::recent::{clear_recent, record_recent, remove_recent, RECENT_ITEMS};
+use crate::state::{AppState, ViewMode};
 use crate::window::{self, CreateMainWindowConfigParams};
 
 /// Menu identifier enum
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum MenuId {
+///
+/// Every variant is also a command that can be rebound in the keymap config
+/// and run from the command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MenuId {
     About,
     NewWindow,
     NewTab,
     Open,
     OpenDirectory,
+    ReopenClosedTab,
+    ClearRecent,
+    ImportBookmarks,
+    ExportBookmarks,
+    ImportSettings,
+    ExportSettings,
     RevealInFinder,
     CopyFilePath,
     CloseTab,
@@ -25,7 +39,12 @@ enum MenuId {
     CloseAllWindows,
     Preferences,
     Find,
+    CommandPalette,
     ToggleSidebar,
+    ShowLinkGraph,
+    ViewRendered,
+    ViewSource,
+    ViewSideBySide,
     ActualSize,
     ZoomIn,
     ZoomOut,
@@ -35,14 +54,56 @@ enum MenuId {
 }
 
 impl MenuId {
+    /// All menu commands in menu order
+    pub const ALL: [MenuId; 32] = [
+        Self::About,
+        Self::Preferences,
+        Self::NewWindow,
+        Self::NewTab,
+        Self::Open,
+        Self::OpenDirectory,
+        Self::ReopenClosedTab,
+        Self::ClearRecent,
+        Self::ImportBookmarks,
+        Self::ExportBookmarks,
+        Self::ImportSettings,
+        Self::ExportSettings,
+        Self::CopyFilePath,
+        Self::RevealInFinder,
+        Self::CloseTab,
+        Self::CloseAllTabs,
+        Self::CloseWindow,
+        Self::Find,
+        Self::CommandPalette,
+        Self::ToggleSidebar,
+        Self::ShowLinkGraph,
+        Self::ViewRendered,
+        Self::ViewSource,
+        Self::ViewSideBySide,
+        Self::ActualSize,
+        Self::ZoomIn,
+        Self::ZoomOut,
+        Self::GoBack,
+        Self::GoForward,
+        Self::CloseAllChildWindows,
+        Self::CloseAllWindows,
+        Self::GoToHomepage,
+    ];
+
     /// Convert menu ID string to enum variant
-    fn from_str(s: &str) -> Option<Self> {
+    pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "app.about" => Some(Self::About),
             "file.new_window" => Some(Self::NewWindow),
             "file.new_tab" => Some(Self::NewTab),
             "file.open" => Some(Self::Open),
             "file.open_directory" => Some(Self::OpenDirectory),
+            "file.reopen_closed_tab" => Some(Self::ReopenClosedTab),
+            "file.clear_recent" => Some(Self::ClearRecent),
+            "file.import_bookmarks" => Some(Self::ImportBookmarks),
+            "file.export_bookmarks" => Some(Self::ExportBookmarks),
+            "file.import_settings" => Some(Self::ImportSettings),
+            "file.export_settings" => Some(Self::ExportSettings),
             "file.reveal_in_finder" => Some(Self::RevealInFinder),
             "file.copy_file_path" => Some(Self::CopyFilePath),
             "file.close_tab" => Some(Self::CloseTab),
@@ -52,7 +113,12 @@ impl MenuId {
             "window.close_all_windows" => Some(Self::CloseAllWindows),
             "app.preferences" => Some(Self::Preferences),
             "edit.find" => Some(Self::Find),
+            "view.command_palette" => Some(Self::CommandPalette),
             "view.toggle_sidebar" => Some(Self::ToggleSidebar),
+            "view.link_graph" => Some(Self::ShowLinkGraph),
+            "view.rendered" => Some(Self::ViewRendered),
+            "view.source" => Some(Self::ViewSource),
+            "view.side_by_side" => Some(Self::ViewSideBySide),
             "view.actual_size" => Some(Self::ActualSize),
             "view.zoom_in" => Some(Self::ZoomIn),
             "view.zoom_out" => Some(Self::ZoomOut),
@@ -64,13 +130,19 @@ impl MenuId {
     }
 
     /// Get the string ID for this menu item
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::About => "app.about",
             Self::NewWindow => "file.new_window",
             Self::NewTab => "file.new_tab",
             Self::Open => "file.open",
             Self::OpenDirectory => "file.open_directory",
+            Self::ReopenClosedTab => "file.reopen_closed_tab",
+            Self::ClearRecent => "file.clear_recent",
+            Self::ImportBookmarks => "file.import_bookmarks",
+            Self::ExportBookmarks => "file.export_bookmarks",
+            Self::ImportSettings => "file.import_settings",
+            Self::ExportSettings => "file.export_settings",
             Self::RevealInFinder => "file.reveal_in_finder",
             Self::CopyFilePath => "file.copy_file_path",
             Self::CloseTab => "file.close_tab",
@@ -80,7 +152,12 @@ impl MenuId {
             Self::CloseAllWindows => "window.close_all_windows",
             Self::Preferences => "app.preferences",
             Self::Find => "edit.find",
+            Self::CommandPalette => "view.command_palette",
             Self::ToggleSidebar => "view.toggle_sidebar",
+            Self::ShowLinkGraph => "view.link_graph",
+            Self::ViewRendered => "view.rendered",
+            Self::ViewSource => "view.source",
+            Self::ViewSideBySide => "view.side_by_side",
             Self::ActualSize => "view.actual_size",
             Self::ZoomIn => "view.zoom_in",
             Self::ZoomOut => "view.zoom_out",
@@ -89,17 +166,216 @@ impl MenuId {
             Self::GoToHomepage => "help.homepage",
         }
     }
+
+    /// Get the menu label for this menu item in the current locale
+    pub fn label(self) -> &'static str {
+        t(match self {
+            Self::About => "command.about",
+            Self::NewWindow => "command.new_window",
+            Self::NewTab => "command.new_tab",
+            Self::Open => "command.open",
+            Self::OpenDirectory => "command.open_directory",
+            Self::ReopenClosedTab => "command.reopen_closed_tab",
+            Self::ClearRecent => "command.clear_recent",
+            Self::ImportBookmarks => "command.import_bookmarks",
+            Self::ExportBookmarks => "command.export_bookmarks",
+            Self::ImportSettings => "command.import_settings",
+            Self::ExportSettings => "command.export_settings",
+            Self::RevealInFinder => "command.reveal_in_finder",
+            Self::CopyFilePath => "command.copy_file_path",
+            Self::CloseTab => "command.close_tab",
+            Self::CloseAllTabs => "command.close_all_tabs",
+            Self::CloseWindow => "command.close_window",
+            Self::CloseAllChildWindows => "command.close_all_child_windows",
+            Self::CloseAllWindows => "command.close_all_windows",
+            Self::Preferences => "command.preferences",
+            Self::Find => "command.find",
+            Self::CommandPalette => "command.command_palette",
+            Self::ToggleSidebar => "command.toggle_sidebar",
+            Self::ShowLinkGraph => "command.show_link_graph",
+            Self::ViewRendered => "command.view_rendered",
+            Self::ViewSource => "command.view_source",
+            Self::ViewSideBySide => "command.view_side_by_side",
+            Self::ActualSize => "command.actual_size",
+            Self::ZoomIn => "command.zoom_in",
+            Self::ZoomOut => "command.zoom_out",
+            Self::GoBack => "command.go_back",
+            Self::GoForward => "command.go_forward",
+            Self::GoToHomepage => "command.go_to_homepage",
+        })
+    }
+
+    /// Get the menu (category) name this item belongs to in the current locale
+    pub fn category(self) -> &'static str {
+        t(match self.as_str().split('.').next() {
+            Some("app") => "menu.app",
+            Some("file") => "menu.file",
+            Some("edit") => "menu.edit",
+            Some("view") => "menu.view",
+            Some("history") => "menu.history",
+            Some("window") => "menu.window",
+            _ => "menu.help",
+        })
+    }
+
+    /// Get the built-in keyboard shortcut (see `crate::keymap` for the syntax)
+    pub fn default_shortcut(self) -> Option<&'static str> {
+        match self {
+            Self::Preferences => Some("CmdOrCtrl+Comma"),
+            Self::NewWindow => Some("CmdOrCtrl+N"),
+            Self::NewTab => Some("CmdOrCtrl+T"),
+            Self::Open => Some("CmdOrCtrl+O"),
+            Self::OpenDirectory => Some("CmdOrCtrl+Shift+O"),
+            Self::ReopenClosedTab => Some("CmdOrCtrl+Shift+T"),
+            Self::RevealInFinder => Some("CmdOrCtrl+Shift+R"),
+            Self::CloseTab => Some("CmdOrCtrl+W"),
+            Self::CloseWindow => Some("CmdOrCtrl+Shift+W"),
+            Self::Find => Some("CmdOrCtrl+F"),
+            Self::CommandPalette => Some("CmdOrCtrl+Shift+P"),
+            Self::ToggleSidebar => Some("CmdOrCtrl+B"),
+            Self::ViewSource => Some("CmdOrCtrl+Alt+U"),
+            Self::ActualSize => Some("CmdOrCtrl+0"),
+            Self::ZoomIn => Some("CmdOrCtrl+Equal"),
+            Self::ZoomOut => Some("CmdOrCtrl+Minus"),
+            Self::GoBack => Some("CmdOrCtrl+BracketLeft"),
+            Self::GoForward => Some("CmdOrCtrl+BracketRight"),
+            _ => None,
+        }
+    }
+}
+
+/// Menu entries without a command whose text is still localised
+#[derive(Clone)]
+enum LabeledItem {
+    Submenu(Submenu),
+    Predefined(PredefinedMenuItem),
+}
+
+thread_local! {
+    /// Menu items by ID, kept to update accelerators and labels when the config changes
+    static MENU_ITEMS: RefCell<HashMap<MenuId, MenuItem>> = RefCell::new(HashMap::new());
+    /// Submenus and predefined items with their message keys
+    static LABELED_ITEMS: RefCell<Vec<(&'static str, LabeledItem)>> = const { RefCell::new(Vec::new()) };
+    /// "Open Recent" submenu, rebuilt whenever the recent items change
+    static RECENT_MENU: RefCell<Option<Submenu>> = const { RefCell::new(None) };
 }
 
-/// Helper to create a menu item with optional keyboard shortcut
-fn create_menu_item(
-    id: MenuId,
-    label: &str,
-    code: Option<Code>,
-    additional_modifiers: Option<Modifiers>,
-) -> MenuItem {
-    let accelerator = code.map(|c| get_cmd_or_ctrl(c, additional_modifiers));
-    MenuItem::with_id(id.as_str(), label, true, accelerator)
+/// Menu ID prefix of "Open Recent" entries (followed by the index in RECENT_ITEMS)
+const RECENT_ITEM_PREFIX: &str = "file.open_recent.";
+
+/// Helper to create a menu item with its keyboard shortcut from the keymap
+fn create_menu_item(id: MenuId) -> MenuItem {
+    let accelerator = keymap::resolve_shortcut(id, &CONFIG.read().keymap);
+    let item = MenuItem::with_id(id.as_str(), id.label(), true, accelerator);
+    MENU_ITEMS.with(|items| items.borrow_mut().insert(id, item.clone()));
+    item
+}
+
+/// Re-apply keyboard shortcuts to the menu bar after the keymap changed
+pub fn apply_keymap(config: &KeymapConfig) {
+    MENU_ITEMS.with(|items| {
+        for (id, item) in items.borrow().iter() {
+            let accelerator = keymap::resolve_shortcut(*id, config);
+            if let Err(e) = item.set_accelerator(accelerator) {
+                tracing::warn!(?id, ?e, "Failed to update menu accelerator");
+            }
+        }
+    });
+}
+
+/// Helper to create a submenu titled with a localised message
+fn create_submenu(key: &'static str) -> Submenu {
+    let submenu = Submenu::new(t(key), true);
+    LABELED_ITEMS.with(|items| {
+        items
+            .borrow_mut()
+            .push((key, LabeledItem::Submenu(submenu.clone())))
+    });
+    submenu
+}
+
+/// Helper to create a predefined menu item with a localised label
+fn create_predefined_item(
+    key: &'static str,
+    create: fn(Option<&str>) -> PredefinedMenuItem,
+) -> PredefinedMenuItem {
+    let item = create(Some(t(key)));
+    LABELED_ITEMS.with(|items| {
+        items
+            .borrow_mut()
+            .push((key, LabeledItem::Predefined(item.clone())))
+    });
+    item
+}
+
+/// Re-apply labels to the menu bar after the UI language changed
+pub fn apply_language() {
+    MENU_ITEMS.with(|items| {
+        for (id, item) in items.borrow().iter() {
+            item.set_text(id.label());
+        }
+    });
+    LABELED_ITEMS.with(|items| {
+        for (key, item) in items.borrow().iter() {
+            match item {
+                LabeledItem::Submenu(submenu) => submenu.set_text(t(key)),
+                LabeledItem::Predefined(item) => item.set_text(t(key)),
+            }
+        }
+    });
+}
+
+/// Rebuild the "Open Recent" submenu from the recent items
+pub fn apply_recent_items() {
+    RECENT_MENU.with(|menu| {
+        if let Some(submenu) = menu.borrow().as_ref() {
+            populate_recent_menu(submenu);
+        }
+    });
+}
+
+fn populate_recent_menu(submenu: &Submenu) {
+    while submenu.remove_at(0).is_some() {}
+
+    let items = RECENT_ITEMS.read().items.clone();
+    let home = dirs::home_dir();
+    for (index, path) in items.iter().enumerate() {
+        // Abbreviate the home directory like Finder does
+        let label = match home.as_ref().and_then(|home| path.strip_prefix(home).ok()) {
+            Some(relative) => format!("~/{}", relative.display()),
+            None => path.display().to_string(),
+        };
+        let item = MenuItem::with_id(format!("{RECENT_ITEM_PREFIX}{index}"), label, true, None);
+        submenu.append(&item).unwrap();
+    }
+    if !items.is_empty() {
+        submenu.append(&PredefinedMenuItem::separator()).unwrap();
+    }
+    let clear = create_menu_item(MenuId::ClearRecent);
+    clear.set_enabled(!items.is_empty());
+    submenu.append(&clear).unwrap();
+}
+
+/// Resolve an "Open Recent" menu ID to its path
+fn recent_item_path(menu_id: &str) -> Option<PathBuf> {
+    let index: usize = menu_id.strip_prefix(RECENT_ITEM_PREFIX)?.parse().ok()?;
+    RECENT_ITEMS.read().items.get(index).cloned()
+}
+
+/// Open a recent file or directory in the current window
+fn open_recent_item(path: PathBuf, state: &mut AppState) {
+    if !path.exists() {
+        tracing::warn!(?path, "Recent item no longer exists");
+        remove_recent(&path);
+    } else if path.is_dir() {
+        record_recent(&path);
+        state.set_root_directory(path);
+        if !state.sidebar.read().open {
+            state.toggle_sidebar();
+        }
+    } else {
+        state.open_file(path);
+    }
 }
 
 /// Build the application menu bar
@@ -120,20 +396,15 @@ pub fn build_menu() -> Menu {
 }
 
 fn add_app_menu(menu: &Menu) {
-    let arto_menu = Submenu::new("Arto", true);
+    let arto_menu = create_submenu("menu.app");
 
     arto_menu
         .append_items(&[
-            &create_menu_item(MenuId::About, "About Arto", None, None),
+            &create_menu_item(MenuId::About),
             &PredefinedMenuItem::separator(),
-            &create_menu_item(
-                MenuId::Preferences,
-                "Preferences...",
-                Some(Code::Comma),
-                None,
-            ),
+            &create_menu_item(MenuId::Preferences),
             &PredefinedMenuItem::separator(),
-            &PredefinedMenuItem::quit(Some("Quit")),
+            &create_predefined_item("menu.quit", PredefinedMenuItem::quit),
         ])
         .unwrap();
 
@@ -141,37 +412,33 @@ fn add_app_menu(menu: &Menu) {
 }
 
 fn add_file_menu(menu: &Menu) {
-    let file_menu = Submenu::new("File", true);
+    let file_menu = create_submenu("menu.file");
+
+    let recent_menu = create_submenu("menu.open_recent");
+    populate_recent_menu(&recent_menu);
+    RECENT_MENU.with(|menu| menu.borrow_mut().replace(recent_menu.clone()));
 
     file_menu
         .append_items(&[
-            &create_menu_item(MenuId::NewWindow, "New Window", Some(Code::KeyN), None),
-            &create_menu_item(MenuId::NewTab, "New Tab", Some(Code::KeyT), None),
+            &create_menu_item(MenuId::NewWindow),
+            &create_menu_item(MenuId::NewTab),
+            &PredefinedMenuItem::separator(),
+            &create_menu_item(MenuId::Open),
+            &create_menu_item(MenuId::OpenDirectory),
+            &recent_menu,
+            &create_menu_item(MenuId::ReopenClosedTab),
             &PredefinedMenuItem::separator(),
-            &create_menu_item(MenuId::Open, "Open File...", Some(Code::KeyO), None),
-            &create_menu_item(
-                MenuId::OpenDirectory,
-                "Open Directory...",
-                Some(Code::KeyO),
-                Some(Modifiers::SHIFT),
-            ),
+            &create_menu_item(MenuId::ImportBookmarks),
+            &create_menu_item(MenuId::ExportBookmarks),
+            &create_menu_item(MenuId::ImportSettings),
+            &create_menu_item(MenuId::ExportSettings),
             &PredefinedMenuItem::separator(),
-            &create_menu_item(MenuId::CopyFilePath, "Copy File Path", None, None),
-            &create_menu_item(
-                MenuId::RevealInFinder,
-                "Reveal in Finder",
-                Some(Code::KeyR),
-                Some(Modifiers::SHIFT),
-            ),
+            &create_menu_item(MenuId::CopyFilePath),
+            &create_menu_item(MenuId::RevealInFinder),
             &PredefinedMenuItem::separator(),
-            &create_menu_item(MenuId::CloseTab, "Close Tab", Some(Code::KeyW), None),
-            &create_menu_item(MenuId::CloseAllTabs, "Close All Tabs", None, None),
-            &create_menu_item(
-                MenuId::CloseWindow,
-                "Close Window",
-                Some(Code::KeyW),
-                Some(Modifiers::SHIFT),
-            ),
+            &create_menu_item(MenuId::CloseTab),
+            &create_menu_item(MenuId::CloseAllTabs),
+            &create_menu_item(MenuId::CloseWindow),
         ])
         .unwrap();
 
@@ -179,17 +446,17 @@ fn add_file_menu(menu: &Menu) {
 }
 
 fn add_edit_menu(menu: &Menu) {
-    let edit_menu = Submenu::new("Edit", true);
+    let edit_menu = create_submenu("menu.edit");
 
     edit_menu
         .append_items(&[
-            &PredefinedMenuItem::cut(Some("Cut")),
-            &PredefinedMenuItem::copy(Some("Copy")),
-            &PredefinedMenuItem::paste(Some("Paste")),
+            &create_predefined_item("menu.cut", PredefinedMenuItem::cut),
+            &create_predefined_item("menu.copy", PredefinedMenuItem::copy),
+            &create_predefined_item("menu.paste", PredefinedMenuItem::paste),
             &PredefinedMenuItem::separator(),
-            &PredefinedMenuItem::select_all(Some("Select All")),
+            &create_predefined_item("menu.select_all", PredefinedMenuItem::select_all),
             &PredefinedMenuItem::separator(),
-            &create_menu_item(MenuId::Find, "Find...", Some(Code::KeyF), None),
+            &create_menu_item(MenuId::Find),
         ])
         .unwrap();
 
@@ -197,20 +464,22 @@ fn add_edit_menu(menu: &Menu) {
 }
 
 fn add_view_menu(menu: &Menu) {
-    let view_menu = Submenu::new("View", true);
+    let view_menu = create_submenu("menu.view");
 
     view_menu
         .append_items(&[
-            &create_menu_item(
-                MenuId::ToggleSidebar,
-                "Toggle Sidebar",
-                Some(Code::KeyB),
-                None,
-            ),
+            &create_menu_item(MenuId::CommandPalette),
             &PredefinedMenuItem::separator(),
-            &create_menu_item(MenuId::ActualSize, "Actual Size", Some(Code::Digit0), None),
-            &create_menu_item(MenuId::ZoomIn, "Zoom In", Some(Code::Equal), None),
-            &create_menu_item(MenuId::ZoomOut, "Zoom Out", Some(Code::Minus), None),
+            &create_menu_item(MenuId::ToggleSidebar),
+            &create_menu_item(MenuId::ShowLinkGraph),
+            &PredefinedMenuItem::separator(),
+            &create_menu_item(MenuId::ViewRendered),
+            &create_menu_item(MenuId::ViewSource),
+            &create_menu_item(MenuId::ViewSideBySide),
+            &PredefinedMenuItem::separator(),
+            &create_menu_item(MenuId::ActualSize),
+            &create_menu_item(MenuId::ZoomIn),
+            &create_menu_item(MenuId::ZoomOut),
         ])
         .unwrap();
 
@@ -218,17 +487,12 @@ fn add_view_menu(menu: &Menu) {
 }
 
 fn add_history_menu(menu: &Menu) {
-    let history_menu = Submenu::new("History", true);
+    let history_menu = create_submenu("menu.history");
 
     history_menu
         .append_items(&[
-            &create_menu_item(MenuId::GoBack, "Go Back", Some(Code::BracketLeft), None),
-            &create_menu_item(
-                MenuId::GoForward,
-                "Go Forward",
-                Some(Code::BracketRight),
-                None,
-            ),
+            &create_menu_item(MenuId::GoBack),
+            &create_menu_item(MenuId::GoForward),
         ])
         .unwrap();
 
@@ -236,17 +500,12 @@ fn add_history_menu(menu: &Menu) {
 }
 
 fn add_window_menu(menu: &Menu) {
-    let window_menu = Submenu::new("Window", true);
+    let window_menu = create_submenu("menu.window");
 
     window_menu
         .append_items(&[
-            &create_menu_item(
-                MenuId::CloseAllChildWindows,
-                "Close All Child Windows",
-                None,
-                None,
-            ),
-            &create_menu_item(MenuId::CloseAllWindows, "Close All Windows", None, None),
+            &create_menu_item(MenuId::CloseAllChildWindows),
+            &create_menu_item(MenuId::CloseAllWindows),
         ])
         .unwrap();
 
@@ -254,43 +513,47 @@ fn add_window_menu(menu: &Menu) {
 }
 
 fn add_help_menu(menu: &Menu) {
-    let help_menu = Submenu::new("Help", true);
+    let help_menu = create_submenu("menu.help");
 
     help_menu
-        .append(&create_menu_item(
-            MenuId::GoToHomepage,
-            "Go to Homepage",
-            None,
-            None,
-        ))
+        .append(&create_menu_item(MenuId::GoToHomepage))
         .unwrap();
 
     menu.append(&help_menu).unwrap();
 }
 
-/// Get Cmd modifier with optional additional modifiers
-fn get_cmd_or_ctrl(code: Code, additional: Option<Modifiers>) -> Accelerator {
-    let base_modifier = Modifiers::SUPER;
-
-    let modifiers = if let Some(additional_mods) = additional {
-        base_modifier | additional_mods
-    } else {
-        base_modifier
-    };
-
-    Accelerator::new(Some(modifiers), code)
-}
-
 /// Handle menu events that don't require app state
 pub fn handle_menu_event_global(event: &MenuEvent) -> bool {
     let menu_id = event.id().0.as_ref();
     tracing::info!("Global menu event: {}", menu_id);
 
-    let id = match MenuId::from_str(menu_id) {
-        Some(id) => id,
-        None => return false,
-    };
+    // Open recent items in a new window when no window can handle them
+    if let Some(path) = recent_item_path(menu_id) {
+        if window::has_any_main_windows() {
+            return false;
+        }
+        spawn(async move {
+            if path.is_dir() {
+                let params = CreateMainWindowConfigParams {
+                    directory: Some(path),
+                    ..Default::default()
+                };
+                window::create_new_main_window_with_empty(params).await;
+            } else {
+                window::create_new_main_window_with_file(path, Default::default()).await;
+            }
+        });
+        return true;
+    }
 
+    match MenuId::from_str(menu_id) {
+        Some(id) => execute_global(id),
+        None => false,
+    }
+}
+
+/// Run a menu command that doesn't require app state
+fn execute_global(id: MenuId) -> bool {
     match id {
         MenuId::NewWindow => {
             spawn(async move {
@@ -323,6 +586,41 @@ pub fn handle_menu_event_global(event: &MenuEvent) -> bool {
         MenuId::GoToHomepage => {
             let _ = open::that("https://github.com/arto-app/Arto");
         }
+        MenuId::ClearRecent => {
+            clear_recent();
+        }
+        MenuId::ImportBookmarks => {
+            if let Some(file) = pick_bookmarks_file() {
+                match crate::bookmarks::import_bookmarks(&file) {
+                    Ok(added) => tracing::info!(added, ?file, "Imported bookmarks"),
+                    Err(e) => tracing::error!(?e, ?file, "Failed to import bookmarks"),
+                }
+            }
+        }
+        MenuId::ExportBookmarks => {
+            if let Some(file) = pick_bookmarks_export_file() {
+                if let Err(e) = crate::bookmarks::export_bookmarks(&file) {
+                    tracing::error!(?e, ?file, "Failed to export bookmarks");
+                }
+            }
+        }
+        MenuId::ImportSettings => {
+            if let Some(file) = pick_settings_file() {
+                spawn(async move {
+                    match crate::settings_bundle::import_settings(&file).await {
+                        Ok(summary) => tracing::info!(?summary, ?file, "Imported settings"),
+                        Err(e) => tracing::error!(?e, ?file, "Failed to import settings"),
+                    }
+                });
+            }
+        }
+        MenuId::ExportSettings => {
+            if let Some(file) = pick_settings_export_file() {
+                if let Err(e) = crate::settings_bundle::export_settings(&file) {
+                    tracing::error!(?e, ?file, "Failed to export settings");
+                }
+            }
+        }
         _ => return false,
     }
 
@@ -340,11 +638,27 @@ pub fn handle_menu_event_with_state(event: &MenuEvent, state: &mut AppState) ->
     let menu_id = event.id().0.as_ref();
     tracing::debug!("State menu event (focused window): {}", menu_id);
 
-    let id = match MenuId::from_str(menu_id) {
-        Some(id) => id,
-        None => return false,
-    };
+    if let Some(path) = recent_item_path(menu_id) {
+        open_recent_item(path, state);
+        return true;
+    }
+
+    match MenuId::from_str(menu_id) {
+        Some(id) => execute_with_state(id, state),
+        None => false,
+    }
+}
+
+/// Run a menu command from the command palette of the current window
+pub fn run_command(id: MenuId, state: &mut AppState) {
+    tracing::debug!(?id, "Running command");
+    if !execute_with_state(id, state) {
+        execute_global(id);
+    }
+}
 
+/// Run a menu command that requires app state
+fn execute_with_state(id: MenuId, state: &mut AppState) -> bool {
     match id {
         MenuId::About => {
             // Set the preferences tab to About before opening
@@ -364,6 +678,7 @@ pub fn handle_menu_event_with_state(event: &MenuEvent, state: &mut AppState) ->
         }
         MenuId::OpenDirectory => {
             if let Some(dir) = pick_directory() {
+                record_recent(&dir);
                 state.set_root_directory(dir);
             }
         }
@@ -371,11 +686,16 @@ pub fn handle_menu_event_with_state(event: &MenuEvent, state: &mut AppState) ->
             let active_tab = *state.active_tab.read();
             state.close_tab(active_tab);
         }
+        MenuId::ReopenClosedTab => {
+            state.reopen_closed_tab();
+        }
         MenuId::CloseAllTabs => {
             // Close all tabs except one, then clear it
-            let mut tabs = state.tabs.write();
-            tabs.clear();
-            tabs.push(crate::state::Tab::default());
+            let closed: Vec<_> = state.tabs.write().drain(..).enumerate().collect();
+            for (index, tab) in closed {
+                state.remember_closed_tab(index, tab);
+            }
+            state.tabs.write().push(crate::state::Tab::default());
             state.active_tab.set(0);
         }
         MenuId::CloseWindow => {
@@ -384,6 +704,22 @@ pub fn handle_menu_event_with_state(event: &MenuEvent, state: &mut AppState) ->
         MenuId::ToggleSidebar => {
             state.toggle_sidebar();
         }
+        MenuId::ShowLinkGraph => {
+            let root = state.sidebar.read().root_directory.clone();
+            if let Some(root) = root {
+                let theme = state.current_theme.read().clone();
+                crate::window::open_or_focus_link_graph_window(root, theme);
+            }
+        }
+        MenuId::ViewRendered => {
+            state.set_view_mode(ViewMode::Rendered);
+        }
+        MenuId::ViewSource => {
+            state.set_view_mode(ViewMode::Source);
+        }
+        MenuId::ViewSideBySide => {
+            state.set_view_mode(ViewMode::SideBySide);
+        }
         MenuId::ActualSize => {
             state.zoom_level.set(1.0);
         }
@@ -417,6 +753,9 @@ pub fn handle_menu_event_with_state(event: &MenuEvent, state: &mut AppState) ->
             // None = get selected text from JavaScript
             state.open_search_with_text(None);
         }
+        MenuId::CommandPalette => {
+            state.toggle_command_palette();
+        }
         _ => return false,
     }
 
@@ -469,6 +808,45 @@ fn pick_directory() -> Option<PathBuf> {
     dir
 }
 
+/// Show file picker dialog for a bookmarks file to import
+fn pick_bookmarks_file() -> Option<PathBuf> {
+    use rfd::FileDialog;
+
+    FileDialog::new()
+        .add_filter("Bookmarks", &["md", "markdown", "json"])
+        .pick_file()
+}
+
+/// Show save dialog for exporting bookmarks (format follows the extension)
+fn pick_bookmarks_export_file() -> Option<PathBuf> {
+    use rfd::FileDialog;
+
+    FileDialog::new()
+        .add_filter("Markdown", &["md"])
+        .add_filter("JSON", &["json"])
+        .set_file_name("bookmarks.md")
+        .save_file()
+}
+
+/// Show open dialog for importing a settings file
+fn pick_settings_file() -> Option<PathBuf> {
+    use rfd::FileDialog;
+
+    FileDialog::new()
+        .add_filter("Arto Settings", &["json"])
+        .pick_file()
+}
+
+/// Show save dialog for exporting settings
+fn pick_settings_export_file() -> Option<PathBuf> {
+    use rfd::FileDialog;
+
+    FileDialog::new()
+        .add_filter("Arto Settings", &["json"])
+        .set_file_name("arto-settings.json")
+        .save_file()
+}
+
 fn disable_automatic_window_tabbing() {
     use objc2::MainThreadMarker;
     use objc2_app_kit::NSWindow;
@@ -1,7 +1,7 @@
 //! Pinned Search feature for persistent keyword highlighting.
 //!
 //! This module provides:
-//! - `PinnedSearch`: A pinned search query with color
+//! - `PinnedSearch`: A pinned search query with color, pattern kind and scope
 //! - `PinnedSearches`: Collection of pinned searches with persistence
 //! - `PINNED_SEARCHES`: Global static for app-wide access
 //! - `PINNED_SEARCHES_CHANGED`: Broadcast channel for cross-window sync
@@ -11,11 +11,21 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
+use thiserror::Error;
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
+use crate::i18n::{t, t_args};
+use crate::utils::glob::glob_match;
+
+/// Current version of the pinned searches file format.
+///
+/// - 1: plain text patterns
+/// - 2: adds `kind` and `scope` to each pinned search
+const CURRENT_VERSION: u32 = 2;
+
 /// Unique identifier for a pinned search.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -101,14 +111,213 @@ impl HighlightColor {
     }
 }
 
+/// How the pattern of a pinned search is matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternKind {
+    /// Plain text (substring match).
+    #[default]
+    Text,
+    /// Plain text matched as a whole word.
+    Word,
+    /// Regular expression.
+    Regex,
+}
+
+impl PatternKind {
+    /// All pattern kinds in display order.
+    pub const ALL: [PatternKind; 3] = [PatternKind::Text, PatternKind::Word, PatternKind::Regex];
+
+    /// Get the kind name for JavaScript.
+    pub fn to_js_name(self) -> &'static str {
+        match self {
+            PatternKind::Text => "text",
+            PatternKind::Word => "word",
+            PatternKind::Regex => "regex",
+        }
+    }
+
+    /// Short label shown on toggles and chips.
+    pub fn label(self) -> &'static str {
+        match self {
+            PatternKind::Text => t("search.kind.text"),
+            PatternKind::Word => t("search.kind.word"),
+            PatternKind::Regex => t("search.kind.regex"),
+        }
+    }
+}
+
+/// Files a pinned search applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SearchScope {
+    /// Every file.
+    #[default]
+    All,
+    /// Files whose path matches a glob (`*`, `**` and `?`).
+    ///
+    /// Patterns starting with `/` or `~` match absolute paths; other
+    /// patterns may match at any directory depth.
+    Glob { pattern: String },
+    /// Only the listed files.
+    Files { paths: Vec<PathBuf> },
+}
+
+impl SearchScope {
+    /// Scope covering a directory and everything below it.
+    pub fn directory(dir: &Path) -> Self {
+        Self::Glob {
+            pattern: format!("{}/**", dir.display()),
+        }
+    }
+
+    /// Check whether a file is in scope (`None` means no file is open).
+    pub fn contains(&self, file: Option<&Path>) -> bool {
+        match (self, file) {
+            (SearchScope::All, _) => true,
+            (_, None) => false,
+            (SearchScope::Glob { pattern }, Some(file)) => {
+                glob_match(&expand_home(pattern), &file.to_string_lossy())
+            }
+            (SearchScope::Files { paths }, Some(file)) => paths.iter().any(|p| p == file),
+        }
+    }
+
+    /// Short description shown in tooltips and the Search tab.
+    pub fn describe(&self) -> String {
+        match self {
+            SearchScope::All => t("search.scope.all").to_string(),
+            SearchScope::Glob { pattern } => pattern.clone(),
+            SearchScope::Files { paths } => match paths.as_slice() {
+                [path] => path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.display().to_string()),
+                paths => t_args(
+                    "search.scope.file_count",
+                    &[("count", &paths.len().to_string())],
+                ),
+            },
+        }
+    }
+}
+
+/// Expand a leading `~` to the home directory and anchor relative patterns.
+fn expand_home(pattern: &str) -> String {
+    if let Some(rest) = pattern.strip_prefix('~') {
+        if let Some(home) = dirs::home_dir() {
+            return format!("{}{rest}", home.display());
+        }
+    }
+    if pattern.starts_with('/') {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    }
+}
+
+/// Error of a pinned search pattern, shown in the Search tab.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PatternError {
+    #[error("Invalid regular expression: {0}")]
+    InvalidRegex(String),
+    #[error("Not supported by JavaScript regular expressions: {0}")]
+    UnsupportedSyntax(String),
+    #[error("Pattern matches empty text")]
+    MatchesEmpty,
+}
+
+/// Validate a search pattern of the given kind.
+///
+/// Regular expressions are matched by the renderer's `RegExp`, so patterns
+/// are limited to the syntax that it shares with the `regex` crate: syntax
+/// errors, syntax JavaScript lacks or reads differently, and patterns that
+/// match empty text (which would highlight nothing) are rejected.
+pub fn validate_pattern(
+    pattern: &str,
+    kind: PatternKind,
+    case_sensitive: bool,
+) -> Result<(), PatternError> {
+    if kind != PatternKind::Regex {
+        return Ok(());
+    }
+    let hir = regex_syntax::ParserBuilder::new()
+        .case_insensitive(!case_sensitive)
+        .build()
+        .parse(pattern)
+        .map_err(|e| PatternError::InvalidRegex(e.to_string()))?;
+    check_shared_syntax(pattern)?;
+    if hir.properties().minimum_len() == Some(0) {
+        return Err(PatternError::MatchesEmpty);
+    }
+    Ok(())
+}
+
+/// Reject syntax of a valid `regex` pattern that `RegExp` (without the `u`
+/// flag) rejects or matches differently.
+fn check_shared_syntax(pattern: &str) -> Result<(), PatternError> {
+    let unsupported = |syntax: &str| Err(PatternError::UnsupportedSyntax(syntax.to_string()));
+    let mut chars = pattern.chars().peekable();
+    let mut in_class = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('d' | 'D' | 'w' | 'W' | 's' | 'S' | 'n' | 'r' | 't' | 'f' | 'v') => {}
+                Some(escape @ ('b' | 'B')) if chars.peek() == Some(&'{') => {
+                    return unsupported(&format!("\\{escape}{{...}}"));
+                }
+                Some('b' | 'B') => {}
+                // `\xHH` and `\uHHHH` are shared, the braced forms are not
+                Some(escape @ ('x' | 'u')) if chars.peek() == Some(&'{') => {
+                    return unsupported(&format!("\\{escape}{{...}}"));
+                }
+                Some('x' | 'u') => {}
+                Some(escape) if escape.is_ascii_alphanumeric() || "<>".contains(escape) => {
+                    return unsupported(&format!("\\{escape}"));
+                }
+                _ => {}
+            },
+            // Nested classes, POSIX classes and set operations
+            '[' if in_class => return unsupported("["),
+            '&' | '-' | '~' if in_class && chars.peek() == Some(&c) => {
+                return unsupported(&format!("{c}{c}"));
+            }
+            '[' => {
+                in_class = true;
+                chars.next_if_eq(&'^');
+                // A leading `]` is a literal in `regex` but closes an empty class in `RegExp`
+                if chars.peek() == Some(&']') {
+                    return unsupported("[]");
+                }
+            }
+            ']' if in_class => in_class = false,
+            // Only non-capturing and named groups; inline flags are not shared
+            '(' if !in_class
+                && chars.next_if_eq(&'?').is_some()
+                && !matches!(chars.peek(), Some(':' | '<')) =>
+            {
+                return unsupported("(?flags)");
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 /// A pinned search entry.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PinnedSearch {
     /// Unique identifier.
     pub id: PinnedSearchId,
-    /// Search pattern (plain text, not regex).
+    /// Search pattern (interpreted according to `kind`).
     pub pattern: String,
+    /// How the pattern is matched.
+    #[serde(default)]
+    pub kind: PatternKind,
+    /// Files the pinned search applies to.
+    #[serde(default)]
+    pub scope: SearchScope,
     /// Highlight color.
     pub color: HighlightColor,
     /// Case-sensitive matching.
@@ -126,12 +335,24 @@ impl PinnedSearch {
         Self {
             id: PinnedSearchId::new(),
             pattern: pattern.into(),
+            kind: PatternKind::default(),
+            scope: SearchScope::default(),
             color,
             case_sensitive: false,
             disabled: false,
             created_at: Utc::now(),
         }
     }
+
+    /// Validate the pattern.
+    pub fn validate(&self) -> Result<(), PatternError> {
+        validate_pattern(&self.pattern, self.kind, self.case_sensitive)
+    }
+
+    /// Check whether the pinned search should be applied to a file.
+    pub fn applies_to(&self, file: Option<&Path>) -> bool {
+        self.scope.contains(file) && self.validate().is_ok()
+    }
 }
 
 /// Pinned searches storage (saved to pinned-searches.json).
@@ -146,6 +367,7 @@ pub struct PinnedSearches {
     pub pinned_searches: Vec<PinnedSearch>,
 }
 
+/// Files written before the version field was introduced are version 1.
 fn default_version() -> u32 {
     1
 }
@@ -153,33 +375,55 @@ fn default_version() -> u32 {
 impl Default for PinnedSearches {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: CURRENT_VERSION,
             pinned_searches: Vec::new(),
         }
     }
 }
 
-impl PinnedSearches {
-    /// Get the pinned searches file path.
-    fn path() -> PathBuf {
-        const FILENAME: &str = "pinned-searches.json";
-        if let Some(mut path) = dirs::data_local_dir() {
-            path.push("arto");
-            path.push(FILENAME);
-            return path;
-        }
+/// Upgrade a pinned searches document to the current version.
+///
+/// Returns `true` if the document was changed.
+fn migrate(value: &mut serde_json::Value) -> bool {
+    let Some(object) = value.as_object_mut() else {
+        return false;
+    };
+    let version = object
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(1);
+    if version >= u64::from(CURRENT_VERSION) {
+        return false;
+    }
 
-        // Fallback to home directory
-        if let Some(mut path) = dirs::home_dir() {
-            path.push(".arto");
-            path.push(FILENAME);
-            return path;
+    // 1 -> 2: plain text patterns applied to every file
+    if let Some(searches) = object
+        .get_mut("pinnedSearches")
+        .and_then(serde_json::Value::as_array_mut)
+    {
+        for search in searches.iter_mut().filter_map(|s| s.as_object_mut()) {
+            search
+                .entry("kind")
+                .or_insert_with(|| serde_json::json!("text"));
+            search
+                .entry("scope")
+                .or_insert_with(|| serde_json::json!({ "type": "all" }));
         }
+    }
 
-        PathBuf::from(FILENAME)
+    object.insert("version".to_string(), CURRENT_VERSION.into());
+    true
+}
+
+impl PinnedSearches {
+    /// Get the pinned searches file path.
+    fn path() -> PathBuf {
+        crate::storage::data_file("pinned-searches.json")
     }
 
     /// Load pinned searches from file or return empty.
+    ///
+    /// Files written by older versions are migrated and saved back.
     pub fn load() -> Self {
         let path = Self::path();
 
@@ -188,11 +432,30 @@ impl PinnedSearches {
         }
 
         match fs::read_to_string(&path) {
-            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Ok(content) => {
+                let (searches, migrated) = Self::parse(&content);
+                if migrated {
+                    tracing::info!(version = CURRENT_VERSION, "Migrated pinned searches");
+                    searches.save();
+                }
+                searches
+            }
             Err(_) => Self::default(),
         }
     }
 
+    /// Parse and migrate the file content, returning whether it was migrated.
+    pub fn parse(content: &str) -> (Self, bool) {
+        let Ok(mut value) = serde_json::from_str::<serde_json::Value>(content) else {
+            return (Self::default(), false);
+        };
+        let migrated = migrate(&mut value);
+        match serde_json::from_value(value) {
+            Ok(searches) => (searches, migrated),
+            Err(_) => (Self::default(), false),
+        }
+    }
+
     /// Save pinned searches to file.
     pub fn save(&self) {
         let path = Self::path();
@@ -229,9 +492,12 @@ impl PinnedSearches {
     }
 
     /// Add a new pinned search.
-    pub fn add(&mut self, pattern: impl Into<String>) -> &PinnedSearch {
+    pub fn add(&mut self, pattern: impl Into<String>, kind: PatternKind) -> &PinnedSearch {
         let color = self.next_color();
-        let pinned = PinnedSearch::new(pattern, color);
+        let pinned = PinnedSearch {
+            kind,
+            ..PinnedSearch::new(pattern, color)
+        };
         self.pinned_searches.push(pinned);
         self.pinned_searches.last().unwrap()
     }
@@ -263,6 +529,49 @@ impl PinnedSearches {
         }
     }
 
+    /// Update the pattern kind of a pinned search.
+    pub fn set_kind(&mut self, id: &PinnedSearchId, kind: PatternKind) -> bool {
+        if let Some(pinned) = self.pinned_searches.iter_mut().find(|p| &p.id == id) {
+            pinned.kind = kind;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Update the scope of a pinned search.
+    pub fn set_scope(&mut self, id: &PinnedSearchId, scope: SearchScope) -> bool {
+        if let Some(pinned) = self.pinned_searches.iter_mut().find(|p| &p.id == id) {
+            pinned.scope = scope;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Add pinned searches from another collection, e.g. an imported settings bundle.
+    ///
+    /// Searches with a pattern and kind already pinned are skipped. Returns the
+    /// number of searches added.
+    pub fn merge(&mut self, other: PinnedSearches) -> usize {
+        let mut added = 0;
+        for search in other.pinned_searches {
+            if self
+                .pinned_searches
+                .iter()
+                .any(|p| p.pattern == search.pattern && p.kind == search.kind)
+            {
+                continue;
+            }
+            self.pinned_searches.push(PinnedSearch {
+                id: PinnedSearchId::new(),
+                ..search
+            });
+            added += 1;
+        }
+        added
+    }
+
     /// Check if a pattern is already pinned.
     #[cfg(test)]
     pub fn contains_pattern(&self, pattern: &str) -> bool {
@@ -298,10 +607,10 @@ pub static PINNED_SEARCHES_CHANGED: LazyLock<broadcast::Sender<()>> =
 /// Add a pinned search and broadcast the change.
 ///
 /// Returns the ID of the newly created pinned search.
-pub fn add_pinned_search(pattern: impl Into<String>) -> PinnedSearchId {
+pub fn add_pinned_search(pattern: impl Into<String>, kind: PatternKind) -> PinnedSearchId {
     let id = {
         let mut pinned = PINNED_SEARCHES.write();
-        let search = pinned.add(pattern);
+        let search = pinned.add(pattern, kind);
         let id = search.id.clone();
         pinned.save();
         id
@@ -360,9 +669,60 @@ pub fn toggle_pinned_search_disabled(id: &PinnedSearchId) -> bool {
     result
 }
 
+/// Update the pattern kind of a pinned search and broadcast the change.
+pub fn set_pinned_search_kind(id: &PinnedSearchId, kind: PatternKind) -> bool {
+    let result = {
+        let mut pinned = PINNED_SEARCHES.write();
+        let result = pinned.set_kind(id, kind);
+        if result {
+            pinned.save();
+        }
+        result
+    };
+    if result {
+        PINNED_SEARCHES_CHANGED.send(()).ok();
+    }
+    result
+}
+
+/// Update the scope of a pinned search and broadcast the change.
+pub fn set_pinned_search_scope(id: &PinnedSearchId, scope: SearchScope) -> bool {
+    let result = {
+        let mut pinned = PINNED_SEARCHES.write();
+        let result = pinned.set_scope(id, scope);
+        if result {
+            pinned.save();
+        }
+        result
+    };
+    if result {
+        PINNED_SEARCHES_CHANGED.send(()).ok();
+    }
+    result
+}
+
+/// Merge imported pinned searches and broadcast the change.
+///
+/// Returns the number of searches added.
+pub fn merge_pinned_searches(imported: PinnedSearches) -> usize {
+    let added = {
+        let mut pinned = PINNED_SEARCHES.write();
+        let added = pinned.merge(imported);
+        if added > 0 {
+            pinned.save();
+        }
+        added
+    };
+    if added > 0 {
+        PINNED_SEARCHES_CHANGED.send(()).ok();
+    }
+    added
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use indoc::indoc;
 
     #[test]
     fn test_pinned_search_id_generation() {
@@ -394,7 +754,7 @@ mod tests {
     fn test_pinned_searches_add_remove() {
         let mut searches = PinnedSearches::default();
 
-        let search = searches.add("TODO");
+        let search = searches.add("TODO", PatternKind::Text);
         let id = search.id.clone();
         assert_eq!(searches.pinned_searches.len(), 1);
         assert!(searches.contains_pattern("TODO"));
@@ -409,11 +769,11 @@ mod tests {
         let mut searches = PinnedSearches::default();
 
         // Add 5 pinned searches - should use all 5 colors
-        searches.add("A");
-        searches.add("B");
-        searches.add("C");
-        searches.add("D");
-        searches.add("E");
+        searches.add("A", PatternKind::Text);
+        searches.add("B", PatternKind::Text);
+        searches.add("C", PatternKind::Text);
+        searches.add("D", PatternKind::Text);
+        searches.add("E", PatternKind::Text);
 
         let colors: Vec<_> = searches.pinned_searches.iter().map(|p| p.color).collect();
         assert!(colors.contains(&HighlightColor::Green));
@@ -423,10 +783,31 @@ mod tests {
         assert!(colors.contains(&HighlightColor::Purple));
     }
 
+    #[test]
+    fn test_pinned_searches_merge() {
+        let mut searches = PinnedSearches::default();
+        searches.add("TODO", PatternKind::Text);
+
+        let mut imported = PinnedSearches::default();
+        imported.add("TODO", PatternKind::Text);
+        imported.add("TODO", PatternKind::Regex);
+        imported.add("FIXME", PatternKind::Text);
+
+        assert_eq!(searches.merge(imported.clone()), 2);
+        assert_eq!(searches.pinned_searches.len(), 3);
+        assert!(searches
+            .pinned_searches
+            .iter()
+            .all(|p| imported.pinned_searches.iter().all(|i| i.id != p.id)));
+
+        // Merging again adds nothing
+        assert_eq!(searches.merge(imported), 0);
+    }
+
     #[test]
     fn test_pinned_searches_toggle_disabled() {
         let mut searches = PinnedSearches::default();
-        let search = searches.add("TODO");
+        let search = searches.add("TODO", PatternKind::Text);
         let id = search.id.clone();
 
         assert!(!searches.pinned_searches[0].disabled);
@@ -441,7 +822,7 @@ mod tests {
     #[test]
     fn test_pinned_searches_set_color() {
         let mut searches = PinnedSearches::default();
-        let search = searches.add("TODO");
+        let search = searches.add("TODO", PatternKind::Text);
         let id = search.id.clone();
 
         assert!(searches.set_color(&id, HighlightColor::Pink));
@@ -451,15 +832,154 @@ mod tests {
     #[test]
     fn test_pinned_searches_serialization() {
         let mut searches = PinnedSearches::default();
-        searches.add("TODO");
+        searches.add("TODO", PatternKind::Text);
         searches.pinned_searches[0].disabled = true;
 
         let json = serde_json::to_string_pretty(&searches).unwrap();
         let parsed: PinnedSearches = serde_json::from_str(&json).unwrap();
 
-        assert_eq!(parsed.version, 1);
+        assert_eq!(parsed.version, CURRENT_VERSION);
         assert_eq!(parsed.pinned_searches.len(), 1);
         assert_eq!(parsed.pinned_searches[0].pattern, "TODO");
         assert!(parsed.pinned_searches[0].disabled);
     }
+
+    #[test]
+    fn test_migrate_v1() {
+        let content = indoc! {r#"
+            {
+              "version": 1,
+              "pinnedSearches": [
+                {
+                  "id": "ps_1",
+                  "pattern": "TODO",
+                  "color": "green",
+                  "caseSensitive": false,
+                  "createdAt": "2025-01-01T00:00:00Z"
+                }
+              ]
+            }
+        "#};
+
+        let (parsed, migrated) = PinnedSearches::parse(content);
+
+        assert!(migrated);
+        assert_eq!(parsed.version, CURRENT_VERSION);
+        assert_eq!(parsed.pinned_searches[0].pattern, "TODO");
+        assert_eq!(parsed.pinned_searches[0].kind, PatternKind::Text);
+        assert_eq!(parsed.pinned_searches[0].scope, SearchScope::All);
+    }
+
+    #[test]
+    fn test_parse_current_version_is_not_migrated() {
+        let mut searches = PinnedSearches::default();
+        searches.add(r"PROJ-\d+", PatternKind::Regex);
+        searches.pinned_searches[0].scope = SearchScope::Files {
+            paths: vec![PathBuf::from("/notes/a.md")],
+        };
+        let json = serde_json::to_string(&searches).unwrap();
+
+        let (parsed, migrated) = PinnedSearches::parse(&json);
+
+        assert!(!migrated);
+        assert_eq!(parsed, searches);
+    }
+
+    #[test]
+    fn test_validate_pattern() {
+        let mut pinned = PinnedSearch::new(r"PROJ-\d+", HighlightColor::Green);
+        assert!(pinned.validate().is_ok());
+
+        // Plain text is never validated as a regex
+        pinned.pattern = "(".to_string();
+        assert!(pinned.validate().is_ok());
+
+        pinned.kind = PatternKind::Regex;
+        assert!(matches!(
+            pinned.validate(),
+            Err(PatternError::InvalidRegex(_))
+        ));
+
+        pinned.pattern = "a*".to_string();
+        assert_eq!(pinned.validate(), Err(PatternError::MatchesEmpty));
+
+        pinned.pattern = r"PROJ-\d+".to_string();
+        assert!(pinned.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_pattern_shared_syntax() {
+        let validate = |pattern| validate_pattern(pattern, PatternKind::Regex, false);
+
+        for pattern in [
+            r"(?:TODO|FIXME)\b",
+            r"(?<year>\d{4})-\d{2}",
+            r"[\w.-]+@[^\s\]]+",
+            r"\x41\u00e9\.",
+            r"a{2,}",
+        ] {
+            assert_eq!(validate(pattern), Ok(()), "{pattern}");
+        }
+
+        for pattern in [
+            r"(?i)foo",
+            r"(?x) a b",
+            r"(?P<name>a)",
+            r"foo\z",
+            r"\Afoo",
+            r"\p{Greek}",
+            r"\x{41}",
+            r"\<word\>",
+            r"[[:alpha:]]+",
+            r"[a-z&&[^aeiou]]",
+            r"[]a]",
+        ] {
+            assert!(
+                matches!(validate(pattern), Err(PatternError::UnsupportedSyntax(_))),
+                "{pattern}"
+            );
+        }
+
+        // Lookarounds are JavaScript-only and rejected by the `regex` parser
+        assert!(matches!(
+            validate(r"(?<=\$)\d+"),
+            Err(PatternError::InvalidRegex(_))
+        ));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("/notes/**", "/notes/a.md"));
+        assert!(glob_match("/notes/**", "/notes/work/a.md"));
+        assert!(!glob_match("/notes/**", "/other/a.md"));
+        assert!(glob_match("/notes/*.md", "/notes/a.md"));
+        assert!(!glob_match("/notes/*.md", "/notes/work/a.md"));
+        assert!(glob_match("/notes/**/*.md", "/notes/a.md"));
+        assert!(glob_match("/notes/**/*.md", "/notes/work/a.md"));
+        assert!(glob_match("/notes/?.md", "/notes/a.md"));
+        assert!(!glob_match("/notes/?.md", "/notes/ab.md"));
+    }
+
+    #[test]
+    fn test_scope_contains() {
+        let file = Path::new("/notes/work/a.md");
+
+        assert!(SearchScope::All.contains(Some(file)));
+        assert!(SearchScope::All.contains(None));
+        assert!(SearchScope::directory(Path::new("/notes")).contains(Some(file)));
+        assert!(!SearchScope::directory(Path::new("/other")).contains(Some(file)));
+        assert!(!SearchScope::directory(Path::new("/notes")).contains(None));
+
+        // Relative patterns match at any depth
+        let relative = SearchScope::Glob {
+            pattern: "work/*.md".to_string(),
+        };
+        assert!(relative.contains(Some(file)));
+
+        let files = SearchScope::Files {
+            paths: vec![PathBuf::from("/notes/work/a.md")],
+        };
+        assert!(files.contains(Some(file)));
+        assert!(!files.contains(Some(Path::new("/notes/work/b.md"))));
+    }
 }
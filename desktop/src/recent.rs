@@ -0,0 +1,218 @@
+//! Recently opened files and directories ("Open Recent").
+//!
+//! This module provides:
+//! - `RecentItems`: Most-recently-used list of paths with persistence
+//! - `RECENT_ITEMS`: Global static for app-wide access
+//! - `RECENT_ITEMS_CHANGED`: Broadcast channel for cross-window sync (and the menu bar)
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tokio::sync::broadcast;
+
+use crate::config::CONFIG;
+
+/// Recently opened paths, most recent first (saved to recent.json)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecentItems {
+    /// Opened files and directories, most recent first
+    pub items: Vec<PathBuf>,
+}
+
+impl RecentItems {
+    /// Get the recent items file path
+    fn path() -> PathBuf {
+        crate::storage::data_file("recent.json")
+    }
+
+    /// Load recent items from file or return empty
+    pub fn load() -> Self {
+        let path = Self::path();
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save recent items to file
+    pub fn save(&self) {
+        let path = Self::path();
+
+        tracing::debug!(path = %path.display(), count = self.items.len(), "Saving recent items");
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                tracing::error!(?e, "Failed to create recent items directory");
+                return;
+            }
+        }
+
+        match serde_json::to_string_pretty(self) {
+            Ok(content) => {
+                if let Err(e) = fs::write(&path, content) {
+                    tracing::error!(?e, "Failed to save recent items");
+                }
+            }
+            Err(e) => {
+                tracing::error!(?e, "Failed to serialize recent items");
+            }
+        }
+    }
+
+    /// Move (or add) a path to the front, keeping at most `limit` entries
+    ///
+    /// Returns `true` if the list changed.
+    pub fn push(&mut self, path: impl Into<PathBuf>, limit: usize) -> bool {
+        let path = path.into();
+        let before = self.items.clone();
+        self.items.retain(|p| *p != path);
+        self.items.insert(0, path);
+        self.items.truncate(limit);
+        self.items != before
+    }
+
+    /// Remove a path from the list
+    ///
+    /// Returns `true` if the path was listed.
+    pub fn remove(&mut self, path: &Path) -> bool {
+        let len = self.items.len();
+        self.items.retain(|p| p != path);
+        self.items.len() != len
+    }
+
+    /// Remove all entries
+    ///
+    /// Returns `true` if the list was not empty.
+    pub fn clear(&mut self) -> bool {
+        let changed = !self.items.is_empty();
+        self.items.clear();
+        changed
+    }
+
+    /// Keep at most `limit` entries
+    ///
+    /// Returns `true` if entries were dropped.
+    pub fn truncate(&mut self, limit: usize) -> bool {
+        let changed = self.items.len() > limit;
+        self.items.truncate(limit);
+        changed
+    }
+}
+
+/// Global recent items instance
+pub static RECENT_ITEMS: LazyLock<RwLock<RecentItems>> =
+    LazyLock::new(|| RwLock::new(RecentItems::load()));
+
+/// Broadcast channel for recent item changes
+///
+/// Windows and the "Open Recent" menu subscribe to this to refresh.
+/// The payload is empty since subscribers should read from RECENT_ITEMS directly.
+pub static RECENT_ITEMS_CHANGED: LazyLock<broadcast::Sender<()>> =
+    LazyLock::new(|| broadcast::channel(10).0);
+
+/// Apply a change to the global recent items, then save and broadcast it
+fn update_recent_items(f: impl FnOnce(&mut RecentItems) -> bool) -> bool {
+    let changed = {
+        let mut recent = RECENT_ITEMS.write();
+        let changed = f(&mut recent);
+        if changed {
+            recent.save();
+        }
+        changed
+    };
+    if changed {
+        RECENT_ITEMS_CHANGED.send(()).ok();
+    }
+    changed
+}
+
+/// Record that a file or directory was opened
+pub fn record_recent(path: impl Into<PathBuf>) {
+    let limit = CONFIG.read().recent.max_items;
+    let path = path.into();
+    update_recent_items(|recent| recent.push(path, limit));
+}
+
+/// Remove a path from the recent items (e.g. when it no longer exists)
+pub fn remove_recent(path: &Path) {
+    update_recent_items(|recent| recent.remove(path));
+}
+
+/// Clear all recent items
+pub fn clear_recent() {
+    update_recent_items(RecentItems::clear);
+}
+
+/// Drop entries beyond the configured list size
+pub fn apply_recent_limit(limit: usize) {
+    update_recent_items(|recent| recent.truncate(limit));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(recent: &RecentItems) -> Vec<&str> {
+        recent.items.iter().map(|p| p.to_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_recent_push_moves_to_front() {
+        let mut recent = RecentItems::default();
+        assert!(recent.push("/a.md", 10));
+        assert!(recent.push("/b.md", 10));
+        assert!(recent.push("/a.md", 10));
+        assert_eq!(paths(&recent), vec!["/a.md", "/b.md"]);
+
+        // Re-opening the most recent entry is not a change
+        assert!(!recent.push("/a.md", 10));
+    }
+
+    #[test]
+    fn test_recent_push_respects_limit() {
+        let mut recent = RecentItems::default();
+        recent.push("/a.md", 2);
+        recent.push("/b.md", 2);
+        recent.push("/c.md", 2);
+        assert_eq!(paths(&recent), vec!["/c.md", "/b.md"]);
+
+        // A zero limit disables the list
+        assert!(recent.push("/d.md", 0));
+        assert!(recent.items.is_empty());
+        assert!(!recent.push("/e.md", 0));
+    }
+
+    #[test]
+    fn test_recent_remove_and_clear() {
+        let mut recent = RecentItems::default();
+        recent.push("/a.md", 10);
+        recent.push("/dir", 10);
+
+        assert!(recent.remove(Path::new("/a.md")));
+        assert!(!recent.remove(Path::new("/a.md")));
+        assert_eq!(paths(&recent), vec!["/dir"]);
+
+        assert!(recent.clear());
+        assert!(!recent.clear());
+    }
+
+    #[test]
+    fn test_recent_truncate() {
+        let mut recent = RecentItems::default();
+        recent.push("/a.md", 10);
+        recent.push("/b.md", 10);
+        recent.push("/c.md", 10);
+
+        assert!(!recent.truncate(3));
+        assert!(recent.truncate(1));
+        assert_eq!(paths(&recent), vec!["/c.md"]);
+    }
+}
@@ -0,0 +1,73 @@
+//! Remote control of Arto from editors and scripts
+//!
+//! Arto listens on a Unix domain socket (`arto.sock` in the data directory,
+//! or `$ARTO_SOCKET`). Clients send one JSON-RPC 2.0 request per line and
+//! receive one response per line, interleaved with notifications:
+//!
+//! - `windows.list`, `tabs.list`, `toc.get`, `commands.list`
+//! - `file.open` (`path`, optional `newWindow`)
+//! - `document.scrollToHeading` (`id`), `document.reload`
+//! - `theme.set` (`theme`), `command.run` (`command`, a menu command ID)
+//! - `preview.update` (`id`, `markdown`, optional `basePath` and `cursorLine`),
+//!   opening a live preview tab unless one already shows it
+//! - notifications `document.navigated` and `document.reloaded`
+//!
+//! Requests acting on a window take an optional `window` (an ID from
+//! `windows.list`) and default to the last focused window.
+
+mod methods;
+mod protocol;
+mod server;
+mod windows;
+
+pub use windows::{unregister_window, update_window, TabInfo};
+
+use std::path::PathBuf;
+use tokio::sync::broadcast;
+
+use crate::events::DOCUMENT_EVENT;
+
+/// Environment variable overriding the socket location
+pub const SOCKET_ENV: &str = "ARTO_SOCKET";
+
+/// Location of the remote control socket
+pub fn socket_path() -> PathBuf {
+    std::env::var_os(SOCKET_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| crate::storage::data_file("arto.sock"))
+}
+
+/// Serve remote control clients
+///
+/// Runs for the lifetime of the app. Requests are answered one at a time on
+/// the UI thread, where window state can be reached.
+pub async fn serve() {
+    let path = socket_path();
+    let notifications = broadcast::channel(100).0;
+    let mut calls = match server::listen(&path, notifications.clone()) {
+        Ok(calls) => calls,
+        Err(e) => {
+            tracing::warn!(%e, ?path, "Remote control is not available");
+            return;
+        }
+    };
+    tracing::info!(?path, "Remote control listening");
+
+    let mut events = DOCUMENT_EVENT.subscribe();
+    loop {
+        tokio::select! {
+            Some(mut call) = calls.recv() => {
+                let params = std::mem::take(&mut call.params);
+                let result = methods::dispatch(&call.method, params).await;
+                call.reply(result);
+            }
+            Ok(event) = events.recv() => {
+                if let Some(notification) = methods::notification(event) {
+                    notifications.send(notification).ok();
+                }
+            }
+            else => break,
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use dioxus::desktop::tao::window::WindowId;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+use super::protocol::{parse_params, Notification, RpcError};
+use super::windows::{list_windows, remote_id, RemoteWindow};
+use crate::events::{
+    DocumentEvent, WindowCommand, OPEN_DIRECTORY_IN_WINDOW, OPEN_FILE_IN_WINDOW, WINDOW_COMMAND,
+};
+use crate::live_preview::{self, PreviewBuffer};
+use crate::menu::MenuId;
+use crate::theme::Theme;
+use crate::window::{self, CreateMainWindowConfigParams};
+
+/// Params of requests that act on one window (the last focused one by default)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WindowParams {
+    #[serde(default)]
+    window: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenParams {
+    path: PathBuf,
+    #[serde(default)]
+    window: Option<u64>,
+    #[serde(default)]
+    new_window: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HeadingParams {
+    id: String,
+    #[serde(default)]
+    window: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThemeParams {
+    theme: String,
+    #[serde(default)]
+    window: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PreviewParams {
+    id: String,
+    #[serde(flatten)]
+    buffer: PreviewBuffer,
+    #[serde(default)]
+    window: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommandParams {
+    command: String,
+    #[serde(default)]
+    window: Option<u64>,
+}
+
+/// Answer a request from a remote control client
+pub async fn dispatch(method: &str, params: Value) -> Result<Value, RpcError> {
+    match method {
+        "windows.list" => Ok(list()),
+        "tabs.list" => tabs(parse_params(params)?),
+        "toc.get" => toc(parse_params(params)?),
+        "commands.list" => Ok(commands()),
+        "file.open" => open(parse_params(params)?).await,
+        "document.scrollToHeading" => scroll_to_heading(parse_params(params)?),
+        "document.reload" => {
+            let WindowParams { window } = parse_params(params)?;
+            send(target(window)?, WindowCommand::Reload)
+        }
+        "theme.set" => set_theme(parse_params(params)?),
+        "command.run" => run_command(parse_params(params)?),
+        "preview.update" => update_preview(parse_params(params)?),
+        _ => Err(RpcError::method_not_found(method)),
+    }
+}
+
+/// Notification announcing a document change to clients
+pub fn notification(event: DocumentEvent) -> Option<Notification> {
+    let (method, window_id, path) = match event {
+        DocumentEvent::Navigated { window_id, path } => ("document.navigated", window_id, path),
+        DocumentEvent::Reloaded { window_id, path } => ("document.reloaded", window_id, Some(path)),
+    };
+    let window = remote_id(window_id)?;
+    Some(Notification::new(
+        method,
+        json!({ "window": window, "path": path }),
+    ))
+}
+
+/// Windows on screen; the first window stays registered while hidden
+fn visible_windows() -> Vec<RemoteWindow> {
+    let visible: Vec<WindowId> = window::main::list_visible_main_windows()
+        .iter()
+        .map(|ctx| ctx.window.id())
+        .collect();
+    list_windows()
+        .into_iter()
+        .filter(|w| visible.contains(&w.window_id))
+        .collect()
+}
+
+fn focused_window_id() -> Option<WindowId> {
+    window::main::get_last_focused_window().map(window::child::resolve_to_parent_window)
+}
+
+/// Resolve the `window` param, defaulting to the last focused window
+fn target(id: Option<u64>) -> Result<RemoteWindow, RpcError> {
+    let windows = visible_windows();
+    let found = match id {
+        Some(id) => windows.into_iter().find(|w| w.id == id),
+        None => {
+            let focused = focused_window_id();
+            let index = windows
+                .iter()
+                .position(|w| Some(w.window_id) == focused)
+                .unwrap_or(0);
+            windows.into_iter().nth(index)
+        }
+    };
+    found.ok_or_else(|| match id {
+        Some(id) => RpcError::new(RpcError::WINDOW_NOT_FOUND, format!("No window {id}")),
+        None => RpcError::new(RpcError::WINDOW_NOT_FOUND, "No window is open"),
+    })
+}
+
+fn send(target: RemoteWindow, command: WindowCommand) -> Result<Value, RpcError> {
+    WINDOW_COMMAND
+        .send((target.window_id, command))
+        .map_err(|_| RpcError::internal("Window is not listening"))?;
+    Ok(json!({ "window": target.id }))
+}
+
+fn tabs_json(window: &RemoteWindow) -> Value {
+    let tabs: Vec<Value> = window
+        .tabs
+        .iter()
+        .enumerate()
+        .map(|(index, tab)| {
+            json!({ "index": index, "title": tab.title, "path": tab.path, "preview": tab.preview })
+        })
+        .collect();
+    json!(tabs)
+}
+
+fn list() -> Value {
+    let focused = focused_window_id();
+    let windows: Vec<Value> = visible_windows()
+        .iter()
+        .map(|w| {
+            json!({
+                "id": w.id,
+                "focused": Some(w.window_id) == focused,
+                "activeTab": w.active_tab,
+                "theme": w.theme,
+                "tabs": tabs_json(w),
+            })
+        })
+        .collect();
+    json!(windows)
+}
+
+fn tabs(params: WindowParams) -> Result<Value, RpcError> {
+    let window = target(params.window)?;
+    Ok(json!({
+        "window": window.id,
+        "activeTab": window.active_tab,
+        "tabs": tabs_json(&window),
+    }))
+}
+
+fn toc(params: WindowParams) -> Result<Value, RpcError> {
+    let window = target(params.window)?;
+    let headings: Vec<Value> = window
+        .headings
+        .iter()
+        .map(|h| json!({ "level": h.level, "text": h.text, "id": h.id }))
+        .collect();
+    Ok(json!({
+        "window": window.id,
+        "path": window.active_path(),
+        "headings": headings,
+    }))
+}
+
+fn commands() -> Value {
+    let commands: Vec<Value> = MenuId::ALL
+        .iter()
+        .map(|id| json!({ "id": id.as_str(), "label": id.label() }))
+        .collect();
+    json!(commands)
+}
+
+async fn open(params: OpenParams) -> Result<Value, RpcError> {
+    if !params.path.is_absolute() {
+        return Err(RpcError::invalid_params("path must be absolute"));
+    }
+    if !params.path.exists() {
+        return Err(RpcError::invalid_params(format!(
+            "{} does not exist",
+            params.path.display()
+        )));
+    }
+
+    if params.new_window || (params.window.is_none() && visible_windows().is_empty()) {
+        window::create_new_main_window_with_file(
+            params.path,
+            CreateMainWindowConfigParams::default(),
+        )
+        .await;
+        // The new window registers itself once it renders
+        return Ok(json!({ "window": null }));
+    }
+
+    let target = target(params.window)?;
+    let channel = if params.path.is_dir() {
+        &OPEN_DIRECTORY_IN_WINDOW
+    } else {
+        &OPEN_FILE_IN_WINDOW
+    };
+    channel
+        .send((target.window_id, params.path))
+        .map_err(|_| RpcError::internal("Window is not listening"))?;
+    Ok(json!({ "window": target.id }))
+}
+
+fn scroll_to_heading(params: HeadingParams) -> Result<Value, RpcError> {
+    let target = target(params.window)?;
+    if !target.headings.iter().any(|h| h.id == params.id) {
+        return Err(RpcError::invalid_params(format!(
+            "No heading {} in the current document",
+            params.id
+        )));
+    }
+    send(target, WindowCommand::ScrollToHeading(params.id))
+}
+
+fn set_theme(params: ThemeParams) -> Result<Value, RpcError> {
+    let theme = Theme::from(params.theme);
+    if !Theme::available().contains(&theme) {
+        return Err(RpcError::invalid_params(format!(
+            "Unknown theme {}",
+            String::from(theme)
+        )));
+    }
+    send(target(params.window)?, WindowCommand::SetTheme(theme))
+}
+
+fn run_command(params: CommandParams) -> Result<Value, RpcError> {
+    let id = MenuId::from_str(&params.command)
+        .ok_or_else(|| RpcError::invalid_params(format!("Unknown command {}", params.command)))?;
+    send(target(params.window)?, WindowCommand::RunCommand(id))
+}
+
+fn update_preview(params: PreviewParams) -> Result<Value, RpcError> {
+    live_preview::update_preview(&params.id, params.buffer);
+
+    // Tabs already showing the preview re-render on their own
+    let showing = visible_windows().into_iter().find(|w| {
+        params.window.is_none_or(|id| w.id == id)
+            && w.tabs
+                .iter()
+                .any(|tab| tab.preview.as_deref() == Some(params.id.as_str()))
+    });
+    match showing {
+        Some(window) => Ok(json!({ "window": window.id })),
+        None => send(
+            target(params.window)?,
+            WindowCommand::OpenLivePreview(params.id),
+        ),
+    }
+}
@@ -0,0 +1,219 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+/// JSON-RPC version tag sent with every message
+const JSONRPC_VERSION: &str = "2.0";
+
+/// A call from a client; requests without an `id` get no response
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Request {
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl Request {
+    /// Parse one line sent by a client
+    ///
+    /// Malformed input is answered right away with the error response.
+    pub fn parse(line: &str) -> Result<Self, Response> {
+        let value: Value = serde_json::from_str(line)
+            .map_err(|e| Response::error(Value::Null, RpcError::parse_error(e.to_string())))?;
+        let id = value.get("id").cloned().unwrap_or(Value::Null);
+        let request: Self = serde_json::from_value(value)
+            .map_err(|e| Response::error(id.clone(), RpcError::invalid_request(e.to_string())))?;
+        if request.jsonrpc != JSONRPC_VERSION {
+            return Err(Response::error(
+                id,
+                RpcError::invalid_request(format!(
+                    "Unsupported jsonrpc version {}",
+                    request.jsonrpc
+                )),
+            ));
+        }
+        Ok(request)
+    }
+}
+
+/// Reply to a request, carrying either a result or an error
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Response {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+impl Response {
+    pub fn new(id: Value, result: Result<Value, RpcError>) -> Self {
+        match result {
+            Ok(result) => Self {
+                jsonrpc: JSONRPC_VERSION,
+                id,
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => Self::error(id, error),
+        }
+    }
+
+    pub fn error(id: Value, error: RpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Message pushed to every client without being asked for
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Notification {
+    jsonrpc: &'static str,
+    pub method: String,
+    pub params: Value,
+}
+
+impl Notification {
+    pub fn new(method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+/// Error object of a failed request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Error)]
+#[error("{message} ({code})")]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// The requested window is not open
+    pub const WINDOW_NOT_FOUND: i64 = -32001;
+
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn parse_error(message: impl Into<String>) -> Self {
+        Self::new(Self::PARSE_ERROR, message)
+    }
+
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(Self::INVALID_REQUEST, message)
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(Self::METHOD_NOT_FOUND, format!("Unknown method {method}"))
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(Self::INVALID_PARAMS, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(Self::INTERNAL_ERROR, message)
+    }
+}
+
+/// Deserialize the `params` of a request; omitted params read as an empty object
+pub fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() {
+        Value::Object(Default::default())
+    } else {
+        params
+    };
+    serde_json::from_value(params).map_err(|e| RpcError::invalid_params(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_request() {
+        let request = Request::parse(
+            r#"{"jsonrpc":"2.0","id":1,"method":"tabs.list","params":{"window":2}}"#,
+        )
+        .unwrap();
+        assert_eq!(request.id, Some(json!(1)));
+        assert_eq!(request.method, "tabs.list");
+        assert_eq!(request.params, json!({ "window": 2 }));
+
+        // Notifications from the client have no id
+        let request = Request::parse(r#"{"jsonrpc":"2.0","method":"document.reload"}"#).unwrap();
+        assert_eq!(request.id, None);
+        assert_eq!(request.params, Value::Null);
+    }
+
+    #[test]
+    fn test_parse_request_errors() {
+        let response = Request::parse("{not json").unwrap_err();
+        assert_eq!(response.id, Value::Null);
+        assert_eq!(response.error.unwrap().code, RpcError::PARSE_ERROR);
+
+        let response = Request::parse(r#"{"jsonrpc":"2.0","id":"a"}"#).unwrap_err();
+        assert_eq!(response.id, json!("a"));
+        assert_eq!(response.error.unwrap().code, RpcError::INVALID_REQUEST);
+
+        let response = Request::parse(r#"{"jsonrpc":"1.0","id":3,"method":"x"}"#).unwrap_err();
+        assert_eq!(response.error.unwrap().code, RpcError::INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_response_serialization() {
+        let response = Response::new(json!(7), Ok(json!({ "ok": true })));
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({ "jsonrpc": "2.0", "id": 7, "result": { "ok": true } })
+        );
+
+        let response = Response::new(json!(8), Err(RpcError::method_not_found("nope")));
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 8,
+                "error": { "code": -32601, "message": "Unknown method nope" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_params() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Params {
+            #[serde(default)]
+            window: Option<u64>,
+        }
+
+        assert_eq!(
+            parse_params::<Params>(Value::Null).unwrap(),
+            Params { window: None }
+        );
+        let error = parse_params::<Params>(json!({ "window": "x" })).unwrap_err();
+        assert_eq!(error.code, RpcError::INVALID_PARAMS);
+    }
+}
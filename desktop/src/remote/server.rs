@@ -0,0 +1,320 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::io;
+use std::path::Path;
+use tokio::io::{AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+use super::protocol::{Notification, Request, Response, RpcError};
+
+/// A request waiting to be answered by the app
+#[derive(Debug)]
+pub struct Call {
+    pub method: String,
+    pub params: Value,
+    reply: oneshot::Sender<Result<Value, RpcError>>,
+}
+
+impl Call {
+    /// Answer the client; a client that went away is ignored
+    pub fn reply(self, result: Result<Value, RpcError>) {
+        self.reply.send(result).ok();
+    }
+}
+
+/// Accept clients on a Unix domain socket at `path`
+///
+/// Each line a client sends is one JSON-RPC request, and each line it
+/// receives is a response or one of `notifications`. Requests are handed out
+/// through the returned channel in the order they arrive. Must be called
+/// from within a tokio runtime.
+pub fn listen(
+    path: &Path,
+    notifications: broadcast::Sender<Notification>,
+) -> io::Result<mpsc::Receiver<Call>> {
+    let listener = bind(path)?;
+    let (tx, rx) = mpsc::channel(32);
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tracing::debug!("Remote control client connected");
+                    tokio::spawn(handle_client(stream, tx.clone(), notifications.subscribe()));
+                }
+                Err(e) => tracing::error!(%e, "Failed to accept remote control client"),
+            }
+        }
+    });
+    Ok(rx)
+}
+
+/// Bind the socket, replacing a stale one left behind by a crashed instance
+///
+/// Anything at `path` that is not a socket is left alone. The socket is bound
+/// in a private directory and moved into place once restricted to the
+/// current user, so it is never reachable with looser permissions.
+fn bind(path: &Path) -> io::Result<UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if !metadata.file_type().is_socket() => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "path exists and is not a socket",
+            ));
+        }
+        Ok(_) => {
+            if std::os::unix::net::UnixStream::connect(path).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    "another instance is listening",
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    std::fs::create_dir_all(parent)?;
+
+    let staging = parent.join(format!(".arto-sock-{}", std::process::id()));
+    std::fs::remove_dir_all(&staging).ok();
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join("sock");
+    let result = UnixListener::bind(&staged).and_then(|listener| {
+        // Only the current user may drive the app
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    std::fs::remove_dir_all(&staging).ok();
+    result
+}
+
+async fn handle_client(
+    stream: UnixStream,
+    calls: mpsc::Sender<Call>,
+    mut notifications: broadcast::Receiver<Notification>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Ok(Some(line)) = line else {
+                    break;
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if let Some(response) = handle_line(&line, &calls).await {
+                    if write_message(&mut writer, &response).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            notification = notifications.recv() => match notification {
+                Ok(notification) => {
+                    if write_message(&mut writer, &notification).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Remote control client fell behind on notifications");
+                }
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+    tracing::debug!("Remote control client disconnected");
+}
+
+async fn handle_line(line: &str, calls: &mpsc::Sender<Call>) -> Option<Response> {
+    let request = match Request::parse(line) {
+        Ok(request) => request,
+        Err(response) => return Some(response),
+    };
+
+    let (reply, result) = oneshot::channel();
+    let call = Call {
+        method: request.method,
+        params: request.params,
+        reply,
+    };
+    let result = match calls.send(call).await {
+        Ok(()) => result
+            .await
+            .unwrap_or_else(|_| Err(RpcError::internal("Request was dropped"))),
+        Err(_) => Err(RpcError::internal("Arto is shutting down")),
+    };
+    request.id.map(|id| Response::new(id, result))
+}
+
+async fn write_message(
+    writer: &mut (impl AsyncWrite + Unpin),
+    message: &impl Serialize,
+) -> io::Result<()> {
+    let mut line = serde_json::to_vec(message)?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+    writer.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::{BufRead, Write};
+    use std::os::unix::net::UnixStream as StdUnixStream;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    /// Answer calls like the app does, echoing the params of `echo`
+    fn spawn_dispatcher(mut calls: mpsc::Receiver<Call>) {
+        tokio::spawn(async move {
+            while let Some(call) = calls.recv().await {
+                let result = match call.method.as_str() {
+                    "echo" => Ok(call.params.clone()),
+                    method => Err(RpcError::method_not_found(method)),
+                };
+                call.reply(result);
+            }
+        });
+    }
+
+    /// Send lines from a plain blocking socket client and collect `count` replies
+    async fn exchange(path: PathBuf, lines: &'static [&'static str], count: usize) -> Vec<Value> {
+        tokio::task::spawn_blocking(move || {
+            let mut stream = StdUnixStream::connect(&path).unwrap();
+            for line in lines {
+                writeln!(stream, "{line}").unwrap();
+            }
+            let reader = std::io::BufReader::new(stream);
+            reader
+                .lines()
+                .take(count)
+                .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+                .collect()
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_requests_over_socket() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("arto.sock");
+        let (notifications, _) = broadcast::channel(8);
+        spawn_dispatcher(listen(&path, notifications).unwrap());
+
+        let replies = exchange(
+            path.clone(),
+            &[
+                r#"{"jsonrpc":"2.0","id":1,"method":"echo","params":{"a":1}}"#,
+                r#"{"jsonrpc":"2.0","method":"echo"}"#,
+                r#"{"jsonrpc":"2.0","id":2,"method":"missing"}"#,
+                "garbage",
+            ],
+            3,
+        )
+        .await;
+
+        assert_eq!(
+            replies,
+            vec![
+                json!({ "jsonrpc": "2.0", "id": 1, "result": { "a": 1 } }),
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 2,
+                    "error": { "code": -32601, "message": "Unknown method missing" }
+                }),
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": null,
+                    "error": { "code": -32700, "message": "expected value at line 1 column 1" }
+                }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notifications_reach_clients() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("arto.sock");
+        let (notifications, _) = broadcast::channel(8);
+        spawn_dispatcher(listen(&path, notifications.clone()).unwrap());
+
+        // Wait until the client is subscribed before notifying
+        let client = tokio::spawn(exchange(
+            path.clone(),
+            &[r#"{"jsonrpc":"2.0","id":1,"method":"echo"}"#],
+            2,
+        ));
+        while notifications.receiver_count() == 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        notifications
+            .send(Notification::new(
+                "document.reloaded",
+                json!({ "window": 1, "path": "/a.md" }),
+            ))
+            .unwrap();
+
+        let mut replies = client.await.unwrap();
+        replies.sort_by_key(|reply| reply.get("id").is_some());
+        assert_eq!(
+            replies,
+            vec![
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "document.reloaded",
+                    "params": { "window": 1, "path": "/a.md" }
+                }),
+                json!({ "jsonrpc": "2.0", "id": 1, "result": null }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stale_socket_is_replaced() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("arto.sock");
+        // A crashed instance leaves its socket behind
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+        let (notifications, _) = broadcast::channel(8);
+        spawn_dispatcher(listen(&path, notifications.clone()).unwrap());
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let entries: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, ["arto.sock"]);
+
+        // A live instance keeps its socket
+        let error = listen(&path, notifications).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+    }
+
+    #[tokio::test]
+    async fn test_non_socket_is_kept() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("arto.sock");
+        std::fs::write(&path, "notes").unwrap();
+
+        let (notifications, _) = broadcast::channel(8);
+        let error = listen(&path, notifications).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "notes");
+    }
+}
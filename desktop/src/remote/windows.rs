@@ -0,0 +1,89 @@
+use dioxus::desktop::tao::window::WindowId;
+use serde::Serialize;
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+
+use crate::markdown::HeadingInfo;
+use crate::theme::Theme;
+
+/// What remote control clients can see of a main window
+///
+/// Windows keep their entry up to date from their own state, so requests can
+/// be answered without reaching into another window's signals.
+#[derive(Debug, Clone)]
+pub struct RemoteWindow {
+    /// Number identifying the window to clients while it is open
+    pub id: u64,
+    pub window_id: WindowId,
+    pub tabs: Vec<TabInfo>,
+    pub active_tab: usize,
+    pub headings: Vec<HeadingInfo>,
+    pub theme: Theme,
+}
+
+impl RemoteWindow {
+    /// File shown in the active tab, if any
+    pub fn active_path(&self) -> Option<PathBuf> {
+        self.tabs
+            .get(self.active_tab)
+            .and_then(|tab| tab.path.clone())
+    }
+}
+
+/// A tab as reported to remote control clients
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TabInfo {
+    pub title: String,
+    pub path: Option<PathBuf>,
+    /// ID of the live preview shown in the tab
+    pub preview: Option<String>,
+}
+
+thread_local! {
+    static REMOTE_WINDOWS: RefCell<Vec<RemoteWindow>> = const { RefCell::new(Vec::new()) };
+    static NEXT_WINDOW_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+/// Update the entry of a window, creating it on first use
+pub fn update_window<R>(window_id: WindowId, f: impl FnOnce(&mut RemoteWindow) -> R) -> R {
+    REMOTE_WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        let index = match windows.iter().position(|w| w.window_id == window_id) {
+            Some(index) => index,
+            None => {
+                let id = NEXT_WINDOW_ID.with(|next| next.replace(next.get() + 1));
+                windows.push(RemoteWindow {
+                    id,
+                    window_id,
+                    tabs: Vec::new(),
+                    active_tab: 0,
+                    headings: Vec::new(),
+                    theme: Theme::default(),
+                });
+                windows.len() - 1
+            }
+        };
+        f(&mut windows[index])
+    })
+}
+
+/// Forget a window when it closes
+pub fn unregister_window(window_id: WindowId) {
+    REMOTE_WINDOWS.with(|windows| windows.borrow_mut().retain(|w| w.window_id != window_id));
+}
+
+/// All windows that published their state, in the order they opened
+pub fn list_windows() -> Vec<RemoteWindow> {
+    REMOTE_WINDOWS.with(|windows| windows.borrow().clone())
+}
+
+/// Client-facing number of a window
+pub fn remote_id(window_id: WindowId) -> Option<u64> {
+    REMOTE_WINDOWS.with(|windows| {
+        windows
+            .borrow()
+            .iter()
+            .find(|w| w.window_id == window_id)
+            .map(|w| w.id)
+    })
+}
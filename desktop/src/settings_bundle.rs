@@ -0,0 +1,222 @@
+//! Settings bundles for sharing a setup between machines
+//!
+//! A bundle is one JSON file holding the configuration, bookmarks and pinned
+//! searches. Importing it replaces the configuration and merges bookmarks and
+//! pinned searches into the existing ones, so a team can hand out a baseline
+//! without wiping anyone's own entries.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::bookmarks::{merge_bookmarks, BookmarkFormat, Bookmarks, BOOKMARKS};
+use crate::config::{apply_config, Config, CONFIG};
+use crate::pinned_search::{merge_pinned_searches, PinnedSearches, PINNED_SEARCHES};
+
+/// Format version of settings bundles
+const BUNDLE_VERSION: u32 = 1;
+
+/// Contents of an exported settings file
+///
+/// Each part keeps the layout of its own file so it goes through the same
+/// migrations when imported by a newer version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsBundle {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bookmarks: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_searches: Option<Value>,
+}
+
+/// What importing a bundle changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub config: bool,
+    pub bookmarks: usize,
+    pub pinned_searches: usize,
+}
+
+impl SettingsBundle {
+    /// Bundle the given settings
+    ///
+    /// Bookmarks inside `base_dir` are stored relative to it, like
+    /// [`Bookmarks::export`].
+    pub fn capture(
+        config: &Config,
+        bookmarks: &Bookmarks,
+        pinned_searches: &PinnedSearches,
+        base_dir: &Path,
+    ) -> Result<Self> {
+        let bookmarks = bookmarks.export(BookmarkFormat::Json, base_dir)?;
+        Ok(Self {
+            version: BUNDLE_VERSION,
+            config: Some(config.to_versioned_value()?),
+            bookmarks: Some(serde_json::from_str(&bookmarks)?),
+            pinned_searches: Some(serde_json::to_value(pinned_searches)?),
+        })
+    }
+
+    /// Parse a bundle written by [`SettingsBundle::capture`]
+    pub fn parse(content: &str) -> Result<Self> {
+        let bundle: Self = serde_json::from_str(content)?;
+        if bundle.version > BUNDLE_VERSION {
+            bail!(
+                "Settings were exported by a newer version of Arto (format {})",
+                bundle.version
+            );
+        }
+        Ok(bundle)
+    }
+
+    /// The bundled configuration, migrated and validated
+    pub fn config(&self) -> Result<Option<Config>> {
+        self.config
+            .as_ref()
+            .map(|value| Config::parse(&value.to_string()))
+            .transpose()
+            .context("Invalid configuration in settings")
+    }
+
+    /// The bundled bookmarks, with relative paths resolved against `base_dir`
+    pub fn bookmarks(&self, base_dir: &Path) -> Result<Option<Bookmarks>> {
+        self.bookmarks
+            .as_ref()
+            .map(|value| Bookmarks::import(&value.to_string(), BookmarkFormat::Json, base_dir))
+            .transpose()
+            .context("Invalid bookmarks in settings")
+    }
+
+    /// The bundled pinned searches, migrated to the current format
+    pub fn pinned_searches(&self) -> Option<PinnedSearches> {
+        self.pinned_searches
+            .as_ref()
+            .map(|value| PinnedSearches::parse(&value.to_string()).0)
+    }
+}
+
+/// Export the configuration, bookmarks and pinned searches to `path`
+pub fn export_settings(path: &Path) -> Result<()> {
+    let base_dir = path.parent().unwrap_or(Path::new("/"));
+    let bundle = SettingsBundle::capture(
+        &CONFIG.read(),
+        &BOOKMARKS.read(),
+        &PINNED_SEARCHES.read(),
+        base_dir,
+    )?;
+    let content = serde_json::to_string_pretty(&bundle)?;
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Import settings exported by [`export_settings`] and apply them to all windows
+///
+/// Nothing is changed unless every part of the file is valid.
+pub async fn import_settings(path: &Path) -> Result<ImportSummary> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let base_dir = path.parent().unwrap_or(Path::new("/"));
+    let bundle = SettingsBundle::parse(&content)?;
+    let config = bundle.config()?;
+    let bookmarks = bundle.bookmarks(base_dir)?;
+    let pinned_searches = bundle.pinned_searches();
+
+    let mut summary = ImportSummary {
+        config: false,
+        bookmarks: 0,
+        pinned_searches: 0,
+    };
+    if let Some(config) = config {
+        config.save()?;
+        apply_config(config).await;
+        summary.config = true;
+    }
+    if let Some(bookmarks) = bookmarks {
+        summary.bookmarks = merge_bookmarks(bookmarks);
+    }
+    if let Some(pinned_searches) = pinned_searches {
+        summary.pinned_searches = merge_pinned_searches(pinned_searches);
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinned_search::PatternKind;
+    use indoc::indoc;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let mut config = Config::default();
+        config.recent.max_items = 3;
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.add("/team/docs/README.md");
+        bookmarks.add("/elsewhere/notes.md");
+        let mut pinned_searches = PinnedSearches::default();
+        pinned_searches.add("TODO", PatternKind::Word);
+
+        let bundle =
+            SettingsBundle::capture(&config, &bookmarks, &pinned_searches, Path::new("/team"))
+                .unwrap();
+        let content = serde_json::to_string_pretty(&bundle).unwrap();
+        let parsed = SettingsBundle::parse(&content).unwrap();
+
+        assert_eq!(parsed.config().unwrap(), Some(config));
+        assert_eq!(parsed.pinned_searches(), Some(pinned_searches));
+
+        // Paths inside the export directory follow the bundle
+        let imported = parsed
+            .bookmarks(Path::new("/home/me/team"))
+            .unwrap()
+            .unwrap();
+        let paths: Vec<_> = imported.items.iter().map(|b| b.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/home/me/team/docs/README.md"),
+                PathBuf::from("/elsewhere/notes.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bundle_parts_are_optional() {
+        let bundle = SettingsBundle::parse(indoc! {r#"
+            {
+              "version": 1,
+              "config": { "language": "ja" }
+            }
+        "#})
+        .unwrap();
+
+        let config = bundle.config().unwrap().unwrap();
+        assert_eq!(config.language, crate::i18n::Language::Ja);
+        assert!(bundle.bookmarks(Path::new("/")).unwrap().is_none());
+        assert!(bundle.pinned_searches().is_none());
+    }
+
+    #[test]
+    fn test_bundle_rejects_invalid_config() {
+        let mut config = Config::default().to_versioned_value().unwrap();
+        config["rightSidebar"]["tocMaxDepth"] = Value::from(9);
+        let bundle = SettingsBundle {
+            version: BUNDLE_VERSION,
+            config: Some(config),
+            bookmarks: None,
+            pinned_searches: None,
+        };
+        assert!(bundle.config().is_err());
+    }
+
+    #[test]
+    fn test_bundle_rejects_newer_version() {
+        let content = format!(r#"{{ "version": {} }}"#, BUNDLE_VERSION + 1);
+        assert!(SettingsBundle::parse(&content).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 // State module - manages application state
 
 mod app_state;
-pub use app_state::{AppState, SearchMatch, Sidebar, Tab, TabContent};
+pub use app_state::{AppState, SearchMatch, Sidebar, Tab, TabContent, ViewMode};
 
 mod persistence;
 pub use persistence::{PersistedState, Position, Size, LAST_FOCUSED_STATE};
@@ -1,10 +1,13 @@
 use dioxus::desktop::tao::dpi::{LogicalPosition, LogicalSize};
 use dioxus::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use super::persistence::LAST_FOCUSED_STATE;
-use crate::components::right_sidebar::RightSidebarTab;
+use crate::annotations::AnnotationId;
+use crate::book::Book;
+use crate::components::right_sidebar::{RightSidebarTab, TaskScope};
+use crate::frontmatter::DocumentMetadata;
 use crate::markdown::HeadingInfo;
 use crate::pinned_search::PinnedSearchId;
 use crate::theme::Theme;
@@ -13,7 +16,7 @@ mod sidebar;
 mod tabs;
 
 pub use sidebar::Sidebar;
-pub use tabs::{Tab, TabContent};
+pub use tabs::{Tab, TabContent, ViewMode};
 
 /// Information about a single search match for display in the Search tab.
 #[derive(Debug, Clone, PartialEq)]
@@ -62,6 +65,14 @@ pub struct AppState {
     pub right_sidebar_width: Signal<f64>,
     pub right_sidebar_tab: Signal<RightSidebarTab>,
     pub toc_headings: Signal<Vec<HeadingInfo>>,
+    /// Frontmatter metadata of the document shown in the current tab (not persisted)
+    pub document_metadata: Signal<Option<DocumentMetadata>>,
+    /// Collapsed table of contents entries (heading anchor IDs) per document (not persisted)
+    pub toc_collapsed: Signal<HashMap<PathBuf, HashSet<String>>>,
+    /// Whether the Tasks tab lists the current document or the root directory (not persisted)
+    pub task_scope: Signal<TaskScope>,
+    /// Book (mdBook or MkDocs) detected in the sidebar root directory (not persisted)
+    pub book: Signal<Option<Book>>,
     pub position: Signal<LogicalPosition<i32>>,
     pub size: Signal<LogicalSize<u32>>,
     // Search state (not persisted, managed via JavaScript for IME compatibility)
@@ -72,16 +83,31 @@ pub struct AppState {
     pub search_initial_text: Signal<Option<String>>,
     /// Current search query string (for display in Search tab)
     pub search_query: Signal<Option<String>>,
+    /// Error of compiling the search query in the renderer (for display in the search bar)
+    pub search_error: Signal<Option<String>>,
     /// All search matches with context (for Search tab display)
     pub search_matches: Signal<Vec<SearchMatch>>,
     /// Pinned search matches by ID (for Search tab display)
     pub pinned_matches: Signal<HashMap<PinnedSearchId, Vec<SearchMatch>>>,
+    /// Annotations of the current file whose quote could not be found in the rendered content
+    pub detached_annotations: Signal<HashSet<AnnotationId>>,
+    /// Whether the command palette overlay is shown (not persisted)
+    pub command_palette_open: Signal<bool>,
     /// Pending scroll position to restore after navigation (for back/forward).
     /// When Some, FileViewer will scroll to this position instead of resetting to top.
     pub pending_scroll_position: Signal<Option<f64>>,
+    /// Pending heading anchor to scroll to after navigation (for heading bookmarks).
+    /// Takes precedence over pending_scroll_position.
+    pub pending_heading: Signal<Option<String>>,
+    /// Pending task list item (by position in the document) to scroll to after
+    /// navigation (from the Tasks tab). Takes precedence over pending_scroll_position.
+    pub pending_task: Signal<Option<usize>>,
     /// Current scroll position of the content area.
     /// Updated by scroll events, used to save position before back/forward navigation.
     pub current_scroll_position: Signal<f64>,
+    /// Recently closed tabs of this window with the index they were closed at,
+    /// most recent last (not persisted)
+    pub closed_tabs: Signal<Vec<(usize, Tab)>>,
 }
 
 impl Default for AppState {
@@ -90,13 +116,17 @@ impl Default for AppState {
         Self {
             tabs: Signal::new(vec![Tab::default()]),
             active_tab: Signal::new(0),
-            current_theme: Signal::new(persisted.theme),
+            current_theme: Signal::new(persisted.theme.clone()),
             zoom_level: Signal::new(1.0),
             sidebar: Signal::new(Sidebar::default()),
             right_sidebar_open: Signal::new(persisted.right_sidebar_open),
             right_sidebar_width: Signal::new(persisted.right_sidebar_width),
             right_sidebar_tab: Signal::new(persisted.right_sidebar_tab),
             toc_headings: Signal::new(Vec::new()),
+            document_metadata: Signal::new(None),
+            toc_collapsed: Signal::new(HashMap::new()),
+            task_scope: Signal::new(TaskScope::default()),
+            book: Signal::new(None),
             position: Signal::new(Default::default()),
             size: Signal::new(Default::default()),
             // Search state
@@ -105,10 +135,16 @@ impl Default for AppState {
             search_current_index: Signal::new(0),
             search_initial_text: Signal::new(None),
             search_query: Signal::new(None),
+            search_error: Signal::new(None),
             search_matches: Signal::new(Vec::new()),
             pinned_matches: Signal::new(HashMap::new()),
+            detached_annotations: Signal::new(HashSet::new()),
+            command_palette_open: Signal::new(false),
             pending_scroll_position: Signal::new(None),
+            pending_heading: Signal::new(None),
+            pending_task: Signal::new(None),
             current_scroll_position: Signal::new(0.0),
+            closed_tabs: Signal::new(Vec::new()),
         }
     }
 }
@@ -192,6 +228,12 @@ impl AppState {
         self.search_open.set(new_state);
     }
 
+    /// Toggle command palette visibility
+    pub fn toggle_command_palette(&mut self) {
+        let new_state = !*self.command_palette_open.read();
+        self.command_palette_open.set(new_state);
+    }
+
     /// Update search results from JavaScript callback (basic count/current only)
     pub fn update_search_results(&mut self, count: usize, current: usize) {
         self.search_match_count.set(count);
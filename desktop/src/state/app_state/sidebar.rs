@@ -13,6 +13,12 @@ pub struct Sidebar {
     pub expanded_dirs: HashSet<PathBuf>,
     pub width: f64,
     pub show_all_files: bool,
+    /// Entry highlighted by keyboard navigation in the file explorer (not persisted)
+    pub focused_path: Option<PathBuf>,
+    /// Show the chapter outline instead of the file tree when the root is a book (not persisted)
+    pub book_view: bool,
+    /// Tags expanded in the Tags section (not persisted)
+    pub expanded_tags: HashSet<String>,
     /// History of root directory navigation.
     ///
     /// This history is intentionally kept in-memory only and is not persisted
@@ -30,6 +36,9 @@ impl Default for Sidebar {
             expanded_dirs: HashSet::new(),
             width: 280.0,
             show_all_files: false,
+            focused_path: None,
+            book_view: false,
+            expanded_tags: HashSet::new(),
             dir_history: HistoryManager::new(),
         }
     }
@@ -85,6 +94,16 @@ impl AppState {
         let mut sidebar = self.sidebar.write();
         sidebar.toggle_expansion(path);
     }
+
+    /// Show the documents tagged `tag` in the sidebar's Tags section
+    pub fn reveal_tag(&mut self, tag: &str) {
+        let mut sidebar = self.sidebar.write();
+        sidebar.expanded_tags.insert(tag.to_lowercase());
+        if !sidebar.open {
+            sidebar.open = true;
+            LAST_FOCUSED_STATE.write().sidebar_open = true;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -6,11 +6,14 @@
 //!
 //! - [`TabContent`] - Enum representing the content type of a tab
 //! - [`Tab`] - Struct representing a single tab with content and navigation history
+//! - [`ViewMode`] - Whether a tab shows the rendered document, its source, or both
 //! - `impl AppState` - Extension methods for tab management on AppState
 
 mod content;
 mod state_ext;
 mod tab;
+mod view_mode;
 
 pub use content::TabContent;
 pub use tab::Tab;
+pub use view_mode::ViewMode;
@@ -10,10 +10,30 @@ pub enum TabContent {
     File(PathBuf),
     /// Inline markdown content (for welcome screen)
     Inline(String),
+    /// Unsaved editor buffer pushed by ID, re-rendered on every update
+    LivePreview(String),
     /// File that cannot be opened (binary or error)
     FileError(PathBuf, String),
     /// Preferences page (browser-style settings)
     Preferences,
+    /// All chapters of the book in a directory rendered as one document
+    Book(PathBuf),
+    /// All Markdown files directly inside a directory rendered as one document
+    Folder(PathBuf),
+    /// Landing page of a directory (its README/index file or a generated listing)
+    Directory(PathBuf),
+}
+
+impl TabContent {
+    /// Content showing a path opened from a link or history: a directory or a file
+    pub fn for_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if path.is_dir() {
+            Self::Directory(path)
+        } else {
+            Self::File(path)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -31,4 +51,17 @@ mod tests {
         assert_eq!(TabContent::File(path.clone()), TabContent::File(path));
         assert_ne!(TabContent::None, TabContent::Preferences);
     }
+
+    #[test]
+    fn test_tab_content_for_path() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("notes.md");
+        std::fs::write(&file, "# Notes").unwrap();
+
+        assert_eq!(
+            TabContent::for_path(dir.path()),
+            TabContent::Directory(dir.path().to_path_buf())
+        );
+        assert_eq!(TabContent::for_path(&file), TabContent::File(file));
+    }
 }
@@ -16,11 +16,17 @@
 
 use super::content::TabContent;
 use super::tab::Tab;
+use super::view_mode::ViewMode;
+use crate::bookmarks::{Bookmark, BookmarkTarget};
 use crate::history::HistoryManager;
 use crate::state::AppState;
+use dioxus::document;
 use dioxus::prelude::*;
 use std::path::{Path, PathBuf};
 
+/// Maximum number of closed tabs kept per window
+const MAX_CLOSED_TABS: usize = 20;
+
 impl AppState {
     /// Get a tab by index (returns a clone)
     ///
@@ -60,7 +66,8 @@ impl AppState {
     /// The caller cannot distinguish between "tab closed" and "window closed"
     /// from the return value alone.
     pub fn close_tab(&mut self, index: usize) -> bool {
-        if self.take_tab(index).is_some() {
+        if let Some(tab) = self.take_tab(index) {
+            self.remember_closed_tab(index, tab);
             // Close window if no tabs remain
             if self.tabs.read().is_empty() {
                 dioxus::desktop::window().close();
@@ -71,6 +78,36 @@ impl AppState {
         }
     }
 
+    /// Keep a closed tab so it can be restored with `reopen_closed_tab`.
+    /// Tabs without a file (new tabs, welcome page, preferences) are not kept.
+    pub fn remember_closed_tab(&mut self, index: usize, tab: Tab) {
+        if tab.file().is_none() {
+            return;
+        }
+        let mut closed_tabs = self.closed_tabs.write();
+        closed_tabs.push((index, tab));
+        if closed_tabs.len() > MAX_CLOSED_TABS {
+            closed_tabs.remove(0);
+        }
+    }
+
+    /// Restore the most recently closed tab (with its history) at its old position.
+    ///
+    /// Returns `false` if no closed tab is left.
+    pub fn reopen_closed_tab(&mut self) -> bool {
+        let Some((index, tab)) = self.closed_tabs.write().pop() else {
+            return false;
+        };
+        if self.is_current_tab_no_file() && self.tabs.read().len() == 1 {
+            // Replace the only empty tab instead of keeping it next to the restored one
+            self.update_current_tab(|current| *current = tab);
+        } else {
+            let index = self.insert_tab(tab, index);
+            self.switch_to_tab(index);
+        }
+        true
+    }
+
     /// Remove a tab at index and return it.
     /// Unlike close_tab, does NOT close the window if no tabs remain.
     /// Used for drag operations where the tab may be re-inserted.
@@ -146,6 +183,13 @@ impl AppState {
             .unwrap_or(false)
     }
 
+    /// Follow a file renamed on disk in every tab of the window
+    pub fn follow_rename(&mut self, from: &Path, to: &Path) {
+        for tab in self.tabs.write().iter_mut() {
+            tab.follow_rename(from, to);
+        }
+    }
+
     /// Find the index of a tab that has the specified file open
     pub fn find_tab_with_file(&self, file: impl AsRef<Path>) -> Option<usize> {
         let file = file.as_ref();
@@ -173,6 +217,61 @@ impl AppState {
         }
     }
 
+    /// Open a bookmarked file, scrolling to its heading or offset once rendered
+    ///
+    /// When the file is already shown in the current tab, it is not re-rendered,
+    /// so the content is scrolled right away instead.
+    pub fn open_bookmark(&mut self, bookmark: &Bookmark) {
+        let Some(target) = &bookmark.target else {
+            self.open_file(&bookmark.path);
+            return;
+        };
+
+        let is_current = self
+            .current_tab()
+            .and_then(|tab| tab.file().map(|f| f == bookmark.path))
+            .unwrap_or(false);
+        if is_current {
+            let js = match target {
+                BookmarkTarget::Heading { slug } => format!(
+                    "document.getElementById({})?.scrollIntoView({{ behavior: 'smooth', block: 'start' }});",
+                    serde_json::to_string(slug).unwrap_or_default()
+                ),
+                BookmarkTarget::Scroll { offset } => format!(
+                    "document.querySelector('.content')?.scrollTo({{ top: {offset}, behavior: 'smooth' }});"
+                ),
+            };
+            let _ = document::eval(&js);
+            return;
+        }
+
+        match target {
+            BookmarkTarget::Heading { slug } => self.pending_heading.set(Some(slug.clone())),
+            BookmarkTarget::Scroll { offset } => self.pending_scroll_position.set(Some(*offset)),
+        }
+        self.open_file(&bookmark.path);
+    }
+
+    /// Open a file and scroll to its `index`-th task list item once rendered
+    ///
+    /// Like `open_bookmark`, a file already shown in the current tab is scrolled right away.
+    pub fn open_task(&mut self, file: &Path, index: usize) {
+        let is_current = self
+            .current_tab()
+            .and_then(|tab| tab.file().map(|f| f == file))
+            .unwrap_or(false);
+        if is_current {
+            let js = format!(
+                r#"document.querySelectorAll('.markdown-body input[type="checkbox"]')[{index}]?.closest('li')?.scrollIntoView({{ behavior: 'smooth', block: 'center' }});"#
+            );
+            let _ = document::eval(&js);
+            return;
+        }
+
+        self.pending_task.set(Some(index));
+        self.open_file(file);
+    }
+
     /// Navigate to a file in the current tab (for in-tab navigation like markdown links)
     /// Always opens in current tab regardless of whether file is open elsewhere
     pub fn navigate_to_file(&mut self, file: impl Into<PathBuf>) {
@@ -206,6 +305,8 @@ impl AppState {
             tabs.push(Tab {
                 content: TabContent::Preferences,
                 history: HistoryManager::new(),
+                title: None,
+                view_mode: ViewMode::default(),
             });
             let new_index = tabs.len() - 1;
             drop(tabs);
@@ -213,6 +314,58 @@ impl AppState {
         }
     }
 
+    /// Open the book in `root` as one continuous document.
+    /// Reuses an existing tab showing the same book if found.
+    pub fn open_book(&mut self, root: impl Into<PathBuf>) {
+        self.open_document_tab(TabContent::Book(root.into()));
+    }
+
+    /// Open the Markdown files of `dir` as one continuous document.
+    /// Reuses an existing tab showing the same directory if found.
+    pub fn open_folder_document(&mut self, dir: impl Into<PathBuf>) {
+        self.open_document_tab(TabContent::Folder(dir.into()));
+    }
+
+    /// Show the live preview with this ID.
+    /// Reuses an existing tab showing the same preview if found.
+    pub fn open_live_preview(&mut self, id: impl Into<String>) {
+        self.open_document_tab(TabContent::LivePreview(id.into()));
+    }
+
+    /// Switch to a tab showing `content`, or show it in the current or a new tab
+    fn open_document_tab(&mut self, content: TabContent) {
+        let tabs = self.tabs.read();
+        if let Some(index) = tabs.iter().position(|tab| tab.content == content) {
+            drop(tabs);
+            self.switch_to_tab(index);
+            return;
+        }
+        drop(tabs);
+
+        if self.is_current_tab_no_file() {
+            self.update_current_tab(|tab| {
+                tab.content = content;
+            });
+        } else {
+            self.add_tab(
+                Tab {
+                    content,
+                    history: HistoryManager::new(),
+                    title: None,
+                    view_mode: ViewMode::default(),
+                },
+                true,
+            );
+        }
+    }
+
+    /// Show the current tab rendered, as source, or both side by side
+    pub fn set_view_mode(&mut self, mode: ViewMode) {
+        self.update_current_tab(|tab| {
+            tab.view_mode = mode;
+        });
+    }
+
     /// Toggle preferences tab. Opens if not present, closes if currently active.
     pub fn toggle_preferences(&mut self) {
         // Check if preferences tab already exists
@@ -290,7 +443,8 @@ impl AppState {
             // Now change content, which triggers re-render
             let mut tabs = self.tabs.write();
             if let Some(tab) = tabs.get_mut(active_index) {
-                tab.content = TabContent::File(path);
+                tab.content = TabContent::for_path(path);
+                tab.title = None;
             }
             return true;
         }
@@ -328,13 +482,24 @@ impl AppState {
             // Now change content, which triggers re-render
             let mut tabs = self.tabs.write();
             if let Some(tab) = tabs.get_mut(active_index) {
-                tab.content = TabContent::File(path);
+                tab.content = TabContent::for_path(path);
+                tab.title = None;
             }
             return true;
         }
         false
     }
 
+    /// Set the frontmatter title of the current tab if it still shows `file`
+    pub fn set_document_title(&mut self, file: &Path, title: Option<String>) {
+        let needs_update = self
+            .current_tab()
+            .is_some_and(|tab| tab.file() == Some(file) && tab.title != title);
+        if needs_update {
+            self.update_current_tab(|tab| tab.title = title);
+        }
+    }
+
     /// Save the current scroll position to the current history entry.
     ///
     /// Call this before navigating away to preserve scroll position for back/forward.
@@ -1,4 +1,5 @@
 use super::content::TabContent;
+use super::view_mode::ViewMode;
 use crate::history::HistoryManager;
 use std::path::{Path, PathBuf};
 
@@ -7,6 +8,10 @@ use std::path::{Path, PathBuf};
 pub struct Tab {
     pub content: TabContent,
     pub history: HistoryManager,
+    /// Frontmatter `title` of the file shown in the tab, set once it is loaded
+    pub title: Option<String>,
+    /// Rendered, source or side-by-side; kept while navigating within the tab
+    pub view_mode: ViewMode,
 }
 
 impl Tab {
@@ -14,8 +19,13 @@ impl Tab {
         let file = file.into();
         let mut history = HistoryManager::new();
         history.push(file.clone());
-        let content = TabContent::File(file);
-        Self { content, history }
+        let content = TabContent::for_path(file);
+        Self {
+            content,
+            history,
+            title: None,
+            view_mode: ViewMode::default(),
+        }
     }
 
     pub fn with_inline_content(content: impl Into<String>) -> Self {
@@ -23,6 +33,8 @@ impl Tab {
         Self {
             content: TabContent::Inline(content),
             history: HistoryManager::new(),
+            title: None,
+            view_mode: ViewMode::default(),
         }
     }
 
@@ -43,23 +55,44 @@ impl Tab {
     }
 
     /// Get display name for this tab (used in tab bar)
+    ///
+    /// Files with a frontmatter `title` are shown by that title.
     pub fn display_name(&self) -> String {
         match &self.content {
+            TabContent::File(_) if self.title.is_some() => self.title.clone().unwrap_or_default(),
             TabContent::File(path) | TabContent::FileError(path, _) => path
                 .file_name()
                 .map(|name| name.to_string_lossy().into_owned())
                 .unwrap_or_else(|| "Unnamed".to_string()),
             TabContent::Inline(_) => "Welcome".to_string(),
+            TabContent::LivePreview(_) => "Live Preview".to_string(),
             TabContent::Preferences => "Preferences".to_string(),
+            TabContent::Book(root) => root
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "Book".to_string()),
+            TabContent::Folder(dir) | TabContent::Directory(dir) => dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "Folder".to_string()),
             TabContent::None => "New Tab".to_string(),
         }
     }
 
-    /// Navigate to a file in this tab
+    /// Navigate to a file (or directory landing page) in this tab
     pub fn navigate_to(&mut self, file: impl Into<PathBuf>) {
         let file = file.into();
         self.history.push(file.clone());
-        self.content = TabContent::File(file);
+        self.content = TabContent::for_path(file);
+        self.title = None;
+    }
+
+    /// Follow a file renamed on disk, keeping the tab's history
+    pub fn follow_rename(&mut self, from: &Path, to: &Path) {
+        self.history.rename(from, to);
+        if self.content == TabContent::File(from.to_path_buf()) {
+            self.content = TabContent::File(to.to_path_buf());
+        }
     }
 }
 
@@ -118,6 +151,13 @@ mod tests {
             ..Default::default()
         };
         assert!(!tab.is_no_file());
+
+        // Opening a file must not replace a preview the editor keeps updating
+        let tab = Tab {
+            content: TabContent::LivePreview("stdin".to_string()),
+            ..Default::default()
+        };
+        assert!(!tab.is_no_file());
     }
 
     #[test]
@@ -151,6 +191,27 @@ mod tests {
         assert_eq!(tab.file(), None);
     }
 
+    #[test]
+    fn test_follow_rename() {
+        let mut tab = Tab::new("/test/draft.md");
+        tab.follow_rename(Path::new("/test/draft.md"), Path::new("/test/final.md"));
+        assert_eq!(
+            tab.content,
+            TabContent::File(PathBuf::from("/test/final.md"))
+        );
+        assert_eq!(
+            tab.history.current_path(),
+            Some(Path::new("/test/final.md"))
+        );
+
+        // Other files are left alone
+        tab.follow_rename(Path::new("/test/other.md"), Path::new("/test/moved.md"));
+        assert_eq!(
+            tab.content,
+            TabContent::File(PathBuf::from("/test/final.md"))
+        );
+    }
+
     // === display_name() tests ===
 
     #[test]
@@ -192,6 +253,38 @@ mod tests {
         assert_eq!(tab.display_name(), "Preferences");
     }
 
+    #[test]
+    fn test_display_name_book() {
+        let tab = Tab {
+            content: TabContent::Book(PathBuf::from("/path/to/guide")),
+            ..Default::default()
+        };
+        assert_eq!(tab.display_name(), "guide");
+        assert!(tab.file().is_none());
+        assert!(!tab.is_no_file());
+    }
+
+    #[test]
+    fn test_display_name_frontmatter_title() {
+        let mut tab = Tab::new("/path/to/0001-use-rust.md");
+        tab.title = Some("Use Rust".to_string());
+        assert_eq!(tab.display_name(), "Use Rust");
+
+        // Navigating away drops the title of the previous file
+        tab.navigate_to("/path/to/0002-use-dioxus.md");
+        assert_eq!(tab.display_name(), "0002-use-dioxus.md");
+    }
+
+    #[test]
+    fn test_display_name_folder() {
+        let tab = Tab {
+            content: TabContent::Folder(PathBuf::from("/path/to/adr")),
+            ..Default::default()
+        };
+        assert_eq!(tab.display_name(), "adr");
+        assert!(tab.file().is_none());
+    }
+
     // === Edge case tests ===
 
     #[test]
@@ -0,0 +1,17 @@
+/// How a tab shows a Markdown file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewMode {
+    /// Rendered document
+    #[default]
+    Rendered,
+    /// Raw Markdown with line numbers
+    Source,
+    /// Source and rendered document next to each other, scrolling together
+    SideBySide,
+}
+
+impl ViewMode {
+    pub fn shows_rendered(self) -> bool {
+        matches!(self, Self::Rendered | Self::SideBySide)
+    }
+}
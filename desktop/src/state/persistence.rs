@@ -84,7 +84,7 @@ impl From<&AppState> for PersistedState {
         let sidebar = state.sidebar.read();
         Self {
             directory: state.sidebar.read().root_directory.clone(),
-            theme: *state.current_theme.read(),
+            theme: state.current_theme.read().clone(),
             sidebar_open: sidebar.open,
             sidebar_width: sidebar.width,
             sidebar_show_all_files: sidebar.show_all_files,
@@ -98,23 +98,9 @@ impl From<&AppState> for PersistedState {
 }
 
 impl PersistedState {
-    /// Get the state file path (state.json in local data directory)
+    /// Get the state file path (state.json in the data directory)
     pub fn path() -> PathBuf {
-        const FILENAME: &str = "state.json";
-        if let Some(mut path) = dirs::data_local_dir() {
-            path.push("arto");
-            path.push(FILENAME);
-            return path;
-        }
-
-        // Fallback to home directory
-        if let Some(mut path) = dirs::home_dir() {
-            path.push(".arto");
-            path.push(FILENAME);
-            return path;
-        }
-
-        PathBuf::from(FILENAME)
+        crate::storage::data_file("state.json")
     }
 
     /// Load persisted state from file or return default
@@ -0,0 +1,255 @@
+//! Where Arto keeps the files that outlive a session
+//!
+//! Settings (config.json, user styles) and data (window state, bookmarks,
+//! recent items, pinned searches, annotations) go to the platform directories
+//! by default: `~/Library/Application Support/arto` on macOS, and
+//! `$XDG_CONFIG_HOME/arto` / `$XDG_DATA_HOME/arto` on Linux. Everything can be
+//! kept in a single directory instead, chosen in this order:
+//!
+//! 1. `--home <dir>` on the command line
+//! 2. the `ARTO_HOME` environment variable
+//! 3. portable mode: `--portable`, or an `arto-data` directory next to the
+//!    executable (next to `Arto.app` on macOS)
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Environment variable naming the directory to keep everything in
+pub const HOME_ENV: &str = "ARTO_HOME";
+
+/// Command line flag naming the directory to keep everything in
+const HOME_FLAG: &str = "--home";
+
+/// Command line flag enabling portable mode
+const PORTABLE_FLAG: &str = "--portable";
+
+/// Directory next to the executable used in portable mode
+const PORTABLE_DIR: &str = "arto-data";
+
+/// Why the storage directories are where they are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageSource {
+    /// Platform config and data directories
+    Platform,
+    /// `--home` flag
+    Flag,
+    /// `ARTO_HOME` environment variable
+    Env,
+    /// Portable mode, next to the executable
+    Portable,
+}
+
+/// Resolved locations of settings and data files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Storage {
+    pub source: StorageSource,
+    config_dir: PathBuf,
+    data_dir: PathBuf,
+}
+
+impl Storage {
+    /// Resolve the locations for this process from its arguments and environment
+    fn detect() -> Self {
+        Self::resolve(
+            std::env::args_os().skip(1),
+            std::env::var_os(HOME_ENV),
+            std::env::current_exe().ok().as_deref(),
+        )
+    }
+
+    fn resolve(
+        args: impl IntoIterator<Item = OsString>,
+        home_env: Option<OsString>,
+        exe: Option<&Path>,
+    ) -> Self {
+        let args = CommandLine::parse(args);
+        if let Some(dir) = args.home {
+            return Self::single(StorageSource::Flag, dir);
+        }
+        if let Some(dir) = home_env.filter(|dir| !dir.is_empty()) {
+            return Self::single(StorageSource::Env, PathBuf::from(dir));
+        }
+        if let Some(dir) = exe.and_then(|exe| portable_dir(exe, args.portable)) {
+            return Self::single(StorageSource::Portable, dir);
+        }
+        Self::platform()
+    }
+
+    /// Keep settings and data together in `dir`
+    fn single(source: StorageSource, dir: PathBuf) -> Self {
+        let dir = std::path::absolute(&dir).unwrap_or(dir);
+        Self {
+            source,
+            config_dir: dir.clone(),
+            data_dir: dir,
+        }
+    }
+
+    fn platform() -> Self {
+        // Fallback to home directory
+        let fallback = || {
+            dirs::home_dir()
+                .map(|home| home.join(".arto"))
+                .unwrap_or_default()
+        };
+        Self {
+            source: StorageSource::Platform,
+            config_dir: dirs::config_dir()
+                .map(|dir| dir.join("arto"))
+                .unwrap_or_else(fallback),
+            data_dir: dirs::data_local_dir()
+                .map(|dir| dir.join("arto"))
+                .unwrap_or_else(fallback),
+        }
+    }
+
+    /// Directory holding config.json and user styles
+    pub fn config_dir(&self) -> &Path {
+        &self.config_dir
+    }
+
+    /// Directory holding state, bookmarks and other data files
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+}
+
+/// Storage-related command line arguments; anything else is ignored
+#[derive(Debug, Default)]
+struct CommandLine {
+    home: Option<PathBuf>,
+    portable: bool,
+}
+
+impl CommandLine {
+    fn parse(args: impl IntoIterator<Item = OsString>) -> Self {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(arg) = arg.to_str() else {
+                continue;
+            };
+            if arg == HOME_FLAG {
+                parsed.home = args.next().map(PathBuf::from);
+            } else if let Some(dir) = arg
+                .strip_prefix(HOME_FLAG)
+                .and_then(|rest| rest.strip_prefix('='))
+            {
+                parsed.home = Some(PathBuf::from(dir));
+            } else if arg == PORTABLE_FLAG {
+                parsed.portable = true;
+            }
+        }
+        parsed.home = parsed.home.filter(|dir| !dir.as_os_str().is_empty());
+        parsed
+    }
+}
+
+/// Portable data directory for the executable at `exe`
+///
+/// Used when it already exists, or when `create` is set.
+fn portable_dir(exe: &Path, create: bool) -> Option<PathBuf> {
+    // Inside a macOS bundle the directory sits next to the bundle, not
+    // inside it, so the app can be replaced without losing data
+    let bundle = exe
+        .ancestors()
+        .find(|dir| dir.extension().is_some_and(|ext| ext == "app"));
+    let dir = bundle.unwrap_or(exe).parent()?.join(PORTABLE_DIR);
+    (create || dir.is_dir()).then_some(dir)
+}
+
+/// Storage locations of this process, resolved on first use
+pub static STORAGE: LazyLock<Storage> = LazyLock::new(Storage::detect);
+
+/// Path of a settings file such as config.json
+pub fn config_file(name: &str) -> PathBuf {
+    STORAGE.config_dir().join(name)
+}
+
+/// Path of a data file such as bookmarks.json
+pub fn data_file(name: &str) -> PathBuf {
+    STORAGE.data_dir().join(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn test_home_flag() {
+        let storage = Storage::resolve(args(&["--home", "/tmp/arto"]), None, None);
+        assert_eq!(storage.source, StorageSource::Flag);
+        assert_eq!(storage.config_dir(), Path::new("/tmp/arto"));
+        assert_eq!(storage.data_dir(), Path::new("/tmp/arto"));
+
+        let storage = Storage::resolve(args(&["--home=/tmp/arto"]), None, None);
+        assert_eq!(storage.config_dir(), Path::new("/tmp/arto"));
+
+        // A relative directory is resolved against the working directory
+        let storage = Storage::resolve(args(&["--home", "arto"]), None, None);
+        assert!(storage.config_dir().is_absolute());
+        assert!(storage.config_dir().ends_with("arto"));
+    }
+
+    #[test]
+    fn test_home_flag_takes_precedence_over_env() {
+        let storage = Storage::resolve(
+            args(&["--home", "/tmp/flag"]),
+            Some("/tmp/env".into()),
+            None,
+        );
+        assert_eq!(storage.data_dir(), Path::new("/tmp/flag"));
+
+        let storage = Storage::resolve(args(&["other.md"]), Some("/tmp/env".into()), None);
+        assert_eq!(storage.source, StorageSource::Env);
+        assert_eq!(storage.data_dir(), Path::new("/tmp/env"));
+
+        // Empty values are ignored
+        let storage = Storage::resolve(args(&["--home="]), Some("".into()), None);
+        assert_eq!(storage.source, StorageSource::Platform);
+    }
+
+    #[test]
+    fn test_portable_dir_next_to_executable() {
+        let dir = TempDir::new().unwrap();
+        let exe = dir.path().join("arto");
+
+        let storage = Storage::resolve(args(&[]), None, Some(&exe));
+        assert_eq!(storage.source, StorageSource::Platform);
+
+        fs::create_dir(dir.path().join(PORTABLE_DIR)).unwrap();
+        let storage = Storage::resolve(args(&[]), None, Some(&exe));
+        assert_eq!(storage.source, StorageSource::Portable);
+        assert_eq!(storage.config_dir(), dir.path().join(PORTABLE_DIR));
+    }
+
+    #[test]
+    fn test_portable_dir_next_to_app_bundle() {
+        let exe = Path::new("/Applications/Arto.app/Contents/MacOS/arto");
+        assert_eq!(
+            portable_dir(exe, true),
+            Some(PathBuf::from("/Applications/arto-data"))
+        );
+    }
+
+    #[test]
+    fn test_portable_flag() {
+        let dir = TempDir::new().unwrap();
+        let exe = dir.path().join("arto");
+
+        let storage = Storage::resolve(args(&["--portable"]), None, Some(&exe));
+        assert_eq!(storage.source, StorageSource::Portable);
+        assert_eq!(storage.data_dir(), dir.path().join(PORTABLE_DIR));
+
+        // An explicit home wins over portable mode
+        let storage = Storage::resolve(args(&["--portable"]), Some("/tmp/env".into()), Some(&exe));
+        assert_eq!(storage.source, StorageSource::Env);
+    }
+}
@@ -0,0 +1,473 @@
+//! GFM task list items (`- [ ]` / `- [x]`) collected from Markdown documents
+//!
+//! Tasks are grouped by the heading they appear under and may carry
+//! `@owner` and `due:YYYY-MM-DD` annotations, which are parsed out of the text.
+
+use chrono::NaiveDate;
+use lol_html::{element, rewrite_str, RewriteStrSettings};
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use crate::frontmatter::strip_frontmatter;
+use crate::utils::file::{find_markdown_files, write_atomic};
+
+/// Upper bound of files read when scanning a directory, to keep huge trees responsive
+const MAX_TASK_SCAN_FILES: usize = 10_000;
+
+#[derive(Debug, Error)]
+pub enum TaskUpdateError {
+    #[error("The file changed since it was displayed")]
+    Modified,
+    #[error("Task item {0} not found")]
+    NotFound(usize),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// A single task list item
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskItem {
+    /// Position among the task items of the document, matching the order of
+    /// the rendered checkboxes
+    pub index: usize,
+    /// 1-based line of the item in the source file
+    pub line: usize,
+    pub checked: bool,
+    /// Item text with the annotations removed
+    pub text: String,
+    pub owners: Vec<String>,
+    pub due: Option<NaiveDate>,
+    /// Text of the closest heading above the item
+    pub heading: Option<String>,
+}
+
+impl TaskItem {
+    /// Whether the task is still open after its due date
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.checked && self.due.is_some_and(|due| due < today)
+    }
+}
+
+/// Task items of one file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTasks {
+    pub path: PathBuf,
+    pub tasks: Vec<TaskItem>,
+}
+
+/// Number of open and completed tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaskCounts {
+    pub open: usize,
+    pub done: usize,
+}
+
+impl TaskCounts {
+    pub fn of<'a>(tasks: impl IntoIterator<Item = &'a TaskItem>) -> Self {
+        tasks.into_iter().fold(Self::default(), |mut counts, task| {
+            if task.checked {
+                counts.done += 1;
+            } else {
+                counts.open += 1;
+            }
+            counts
+        })
+    }
+}
+
+/// Item being parsed, until its end tag
+#[derive(Default)]
+struct PendingItem {
+    /// (index, line, checked) when the item starts with a task marker
+    marker: Option<(usize, usize, bool)>,
+    text: String,
+    /// Text after a nested list belongs to the nested items
+    in_sublist: bool,
+}
+
+/// Extract the task items of a Markdown document, in document order
+pub fn extract_tasks(markdown: &str) -> Vec<TaskItem> {
+    let content = strip_frontmatter(markdown);
+    let frontmatter_lines = markdown[..markdown.len() - content.len()]
+        .matches('\n')
+        .count();
+
+    let parser = Parser::new_ext(content, Options::all()).into_offset_iter();
+
+    let mut tasks = Vec::new();
+    let mut items: Vec<PendingItem> = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut heading_text: Option<String> = None;
+    let mut next_index = 0;
+    // Line counting resumes from the previous marker, since offsets only grow
+    let mut counted_offset = 0;
+    let mut line = frontmatter_lines + 1;
+
+    for (event, range) in parser {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading_text = Some(String::new()),
+            Event::End(TagEnd::Heading(_)) => {
+                heading = heading_text
+                    .take()
+                    .map(|text| text.trim().to_string())
+                    .filter(|text| !text.is_empty());
+            }
+            Event::Start(Tag::Item) => items.push(PendingItem::default()),
+            Event::Start(Tag::List(_)) => {
+                if let Some(item) = items.last_mut() {
+                    item.in_sublist = true;
+                }
+            }
+            Event::TaskListMarker(checked) => {
+                line += content[counted_offset..range.start].matches('\n').count();
+                counted_offset = range.start;
+                if let Some(item) = items.last_mut() {
+                    item.marker = Some((next_index, line, checked));
+                    next_index += 1;
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading_text) = heading_text.as_mut() {
+                    heading_text.push_str(&text);
+                } else if let Some(item) = items.last_mut().filter(|item| !item.in_sublist) {
+                    item.text.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(heading_text) = heading_text.as_mut() {
+                    heading_text.push(' ');
+                } else if let Some(item) = items.last_mut().filter(|item| !item.in_sublist) {
+                    item.text.push(' ');
+                }
+            }
+            Event::End(TagEnd::Item) => {
+                let Some(item) = items.pop() else {
+                    continue;
+                };
+                let Some((index, line, checked)) = item.marker else {
+                    continue;
+                };
+                let (text, owners, due) = parse_annotations(&item.text);
+                tasks.push(TaskItem {
+                    index,
+                    line,
+                    checked,
+                    text,
+                    owners,
+                    due,
+                    heading: heading.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // Nested items end before their parent
+    tasks.sort_by_key(|task| task.index);
+    tasks
+}
+
+/// Task items of every Markdown file under `root` that has any, sorted by path
+///
+/// Hidden directories are skipped.
+pub fn scan_tasks(root: &Path) -> Vec<FileTasks> {
+    find_markdown_files(root, MAX_TASK_SCAN_FILES)
+        .into_iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let tasks = extract_tasks(&content);
+            (!tasks.is_empty()).then_some(FileTasks { path, tasks })
+        })
+        .collect()
+}
+
+/// Split tasks into runs sharing the same heading, keeping document order
+pub fn group_by_heading(tasks: &[TaskItem]) -> Vec<(Option<String>, Vec<TaskItem>)> {
+    let mut groups: Vec<(Option<String>, Vec<TaskItem>)> = Vec::new();
+    for task in tasks {
+        match groups.last_mut() {
+            Some((heading, group)) if *heading == task.heading => group.push(task.clone()),
+            _ => groups.push((task.heading.clone(), vec![task.clone()])),
+        }
+    }
+    groups
+}
+
+/// Set the `index`-th task item of a document to checked or unchecked
+///
+/// Only the `[ ]`/`[x]` marker is rewritten; returns `None` when the document
+/// has no such task item.
+pub fn set_task_checked(markdown: &str, index: usize, checked: bool) -> Option<String> {
+    let content = strip_frontmatter(markdown);
+    let frontmatter_len = markdown.len() - content.len();
+
+    let marker = Parser::new_ext(content, Options::all())
+        .into_offset_iter()
+        .filter(|(event, _)| matches!(event, Event::TaskListMarker(_)))
+        .nth(index)
+        .map(|(_, range)| range)?;
+
+    // The marker range covers `[ ]`, so the state is the character after `[`
+    let state = frontmatter_len + marker.start + 1;
+    let mut updated = String::with_capacity(markdown.len());
+    updated.push_str(&markdown[..state]);
+    updated.push(if checked { 'x' } else { ' ' });
+    updated.push_str(&markdown[state + 1..]);
+    Some(updated)
+}
+
+/// Toggle a task item in the file, provided it still has the content it was rendered from
+///
+/// The file is replaced atomically; returns the new content.
+pub fn update_task_in_file(
+    path: &Path,
+    rendered: &str,
+    index: usize,
+    checked: bool,
+) -> Result<String, TaskUpdateError> {
+    let current = fs::read_to_string(path)?;
+    if current != rendered {
+        return Err(TaskUpdateError::Modified);
+    }
+    let updated =
+        set_task_checked(&current, index, checked).ok_or(TaskUpdateError::NotFound(index))?;
+    write_atomic(path, &updated)?;
+    Ok(updated)
+}
+
+/// Make the rendered task checkboxes clickable
+///
+/// Only checkboxes tagged with `data-task-index` while rendering are enabled,
+/// so checkboxes written as raw HTML stay as they are. Clicks are reported
+/// through `window.handleTaskCheckboxClick`.
+pub fn enable_task_checkboxes(html: &str) -> String {
+    let settings = RewriteStrSettings {
+        element_content_handlers: vec![element!("input[data-task-index]", |el| {
+            el.remove_attribute("disabled");
+            el.set_attribute("class", "task-list-item-checkbox")?;
+            el.set_attribute("onclick", "handleTaskCheckboxClick(this)")?;
+            Ok(())
+        })],
+        ..RewriteStrSettings::new()
+    };
+    rewrite_str(html, settings).unwrap_or_else(|_| html.to_string())
+}
+
+/// Remove `@owner` and `due:YYYY-MM-DD` annotations from the task text
+fn parse_annotations(text: &str) -> (String, Vec<String>, Option<NaiveDate>) {
+    let mut words = Vec::new();
+    let mut owners = Vec::new();
+    let mut due = None;
+
+    for word in text.split_whitespace() {
+        let trimmed = word.trim_end_matches([',', '.', ';', ':', ')']);
+        if let Some(owner) = trimmed.strip_prefix('@').filter(|owner| !owner.is_empty()) {
+            if !owners.iter().any(|o| o == owner) {
+                owners.push(owner.to_string());
+            }
+        } else if let Some(date) = trimmed
+            .strip_prefix("due:")
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        {
+            due = Some(date);
+        } else {
+            words.push(word);
+        }
+    }
+
+    (words.join(" "), owners, due)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_extract_tasks() {
+        let markdown = indoc! {"
+            ---
+            title: Release
+            ---
+
+            # Release
+
+            - [ ] Write notes @alice due:2024-05-01
+            - [x] Tag `v1.0`
+            - Not a task
+
+            ## Follow-up
+
+            - [ ] Announce
+              - [x] Draft post @bob, @carol
+        "};
+
+        let tasks = extract_tasks(markdown);
+        assert_eq!(tasks.len(), 4);
+
+        assert_eq!(tasks[0].text, "Write notes");
+        assert_eq!(tasks[0].owners, vec!["alice"]);
+        assert_eq!(tasks[0].due, NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(tasks[0].heading.as_deref(), Some("Release"));
+        assert_eq!(tasks[0].line, 7);
+        assert!(!tasks[0].checked);
+
+        assert_eq!(tasks[1].text, "Tag v1.0");
+        assert!(tasks[1].checked);
+
+        // The parent's text stops at its nested list
+        assert_eq!(tasks[2].text, "Announce");
+        assert_eq!(tasks[2].heading.as_deref(), Some("Follow-up"));
+        assert_eq!(tasks[3].text, "Draft post");
+        assert_eq!(tasks[3].owners, vec!["bob", "carol"]);
+        assert_eq!(tasks[3].line, 14);
+
+        let indices: Vec<usize> = tasks.iter().map(|task| task.index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+        assert_eq!(TaskCounts::of(&tasks), TaskCounts { open: 2, done: 2 });
+    }
+
+    #[test]
+    fn test_overdue() {
+        let task = &extract_tasks("- [ ] Ship due:2024-01-31")[0];
+        let today = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        assert!(task.is_overdue(today));
+        assert!(!task.is_overdue(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()));
+
+        // Invalid dates are kept as text
+        let task = &extract_tasks("- [ ] Ship due:soon")[0];
+        assert_eq!(task.text, "Ship due:soon");
+        assert_eq!(task.due, None);
+    }
+
+    #[test]
+    fn test_group_by_heading() {
+        let markdown = indoc! {"
+            - [ ] Before any heading
+
+            # A
+
+            - [ ] One
+            - [ ] Two
+
+            # B
+
+            - [x] Three
+        "};
+
+        let groups = group_by_heading(&extract_tasks(markdown));
+        let summary: Vec<(Option<&str>, usize)> = groups
+            .iter()
+            .map(|(heading, tasks)| (heading.as_deref(), tasks.len()))
+            .collect();
+        assert_eq!(summary, vec![(None, 1), (Some("A"), 2), (Some("B"), 1)]);
+    }
+
+    #[test]
+    fn test_scan_tasks() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("notes")).unwrap();
+        fs::create_dir_all(root.join(".hidden")).unwrap();
+        fs::write(root.join("todo.md"), "- [ ] First\n- [x] Second\n").unwrap();
+        fs::write(root.join("notes/meeting.md"), "- [ ] Follow up\n").unwrap();
+        fs::write(root.join("plain.md"), "- Just a list\n").unwrap();
+        fs::write(root.join(".hidden/skip.md"), "- [ ] Hidden\n").unwrap();
+
+        let files = scan_tasks(root);
+        let names: Vec<PathBuf> = files
+            .iter()
+            .map(|file| file.path.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            names,
+            vec![PathBuf::from("notes/meeting.md"), PathBuf::from("todo.md")]
+        );
+        assert_eq!(files[1].tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_set_task_checked() {
+        let markdown = indoc! {"
+            ---
+            title: \"[ ] not a task\"
+            ---
+
+            - [ ] First
+            - [X] Second
+              - [ ] Nested
+        "};
+
+        let updated = set_task_checked(markdown, 0, true).unwrap();
+        assert!(updated.contains("- [x] First"));
+        assert!(updated.contains("title: \"[ ] not a task\""));
+
+        let updated = set_task_checked(&updated, 1, false).unwrap();
+        assert!(updated.contains("- [ ] Second"));
+
+        let updated = set_task_checked(&updated, 2, true).unwrap();
+        assert!(updated.ends_with("  - [x] Nested\n"));
+
+        assert_eq!(set_task_checked(markdown, 3, true), None);
+    }
+
+    #[test]
+    fn test_update_task_in_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("todo.md");
+        let rendered = "- [ ] First\n- [ ] Second\n";
+        fs::write(&path, rendered).unwrap();
+
+        let updated = update_task_in_file(&path, rendered, 1, true).unwrap();
+        assert_eq!(updated, "- [ ] First\n- [x] Second\n");
+        assert_eq!(fs::read_to_string(&path).unwrap(), updated);
+
+        // Refuse to write over changes made after rendering
+        let result = update_task_in_file(&path, rendered, 0, true);
+        assert!(matches!(result, Err(TaskUpdateError::Modified)));
+        assert_eq!(fs::read_to_string(&path).unwrap(), updated);
+    }
+
+    #[test]
+    fn test_update_task_in_file_through_symlink() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("notes").join("todo.md");
+        let link = temp_dir.path().join("todo.md");
+        let rendered = "- [ ] First\n";
+        fs::create_dir(target.parent().unwrap()).unwrap();
+        fs::write(&target, rendered).unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let updated = update_task_in_file(&link, rendered, 0, true).unwrap();
+        assert_eq!(updated, "- [x] First\n");
+        assert!(fs::symlink_metadata(&link).unwrap().is_symlink());
+        assert_eq!(fs::read_to_string(&target).unwrap(), updated);
+    }
+
+    #[test]
+    fn test_enable_task_checkboxes() {
+        let html = indoc! {r#"
+            <p><input disabled="" type="checkbox"/> raw</p>
+            <ul>
+            <li><input disabled="" type="checkbox" data-task-index="0"/>
+            a</li>
+            <li><input disabled="" type="checkbox" data-task-index="1" checked=""/>
+            b</li>
+            </ul>
+        "#};
+
+        let enabled = enable_task_checkboxes(html);
+        // Checkboxes written as raw HTML are left alone
+        assert!(enabled.contains(r#"<p><input disabled="" type="checkbox"/> raw</p>"#));
+        assert_eq!(enabled.matches("disabled").count(), 1);
+        assert!(enabled.contains(
+            r#"data-task-index="0" class="task-list-item-checkbox" onclick="handleTaskCheckboxClick(this)" />"#
+        ));
+        assert!(enabled.contains(
+            r#"data-task-index="1" checked="" class="task-list-item-checkbox" onclick="handleTaskCheckboxClick(this)" />"#
+        ));
+    }
+}
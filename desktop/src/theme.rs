@@ -1,12 +1,106 @@
 pub use dioxus_sdk_window::theme::Theme as DioxusTheme;
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "lowercase")]
+mod user_styles;
+
+pub use user_styles::{start_watching_user_styles, USER_STYLES, USER_STYLES_CHANGED};
+
+use crate::i18n::t;
+
+/// Prefix of user theme ids in the config (e.g. "user:nord")
+const USER_THEME_PREFIX: &str = "user:";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Theme {
     #[default]
     Auto,
     Light,
     Dark,
+    Sepia,
+    HighContrast,
+    SolarizedLight,
+    SolarizedDark,
+    /// A theme loaded from `<config dir>/themes/<name>.css`
+    User(String),
+}
+
+impl Theme {
+    /// Built-in themes in display order
+    pub const BUILT_IN: [Theme; 7] = [
+        Theme::Auto,
+        Theme::Light,
+        Theme::Dark,
+        Theme::Sepia,
+        Theme::HighContrast,
+        Theme::SolarizedLight,
+        Theme::SolarizedDark,
+    ];
+
+    /// Built-in themes followed by the user themes currently installed
+    pub fn available() -> Vec<Theme> {
+        let user_themes = USER_STYLES
+            .read()
+            .themes
+            .iter()
+            .map(|theme| Theme::User(theme.name.clone()))
+            .collect::<Vec<_>>();
+        Self::BUILT_IN.into_iter().chain(user_themes).collect()
+    }
+
+    /// Identifier stored in the config and state files
+    pub fn id(&self) -> String {
+        match self {
+            Theme::Auto => "auto".to_string(),
+            Theme::Light => "light".to_string(),
+            Theme::Dark => "dark".to_string(),
+            Theme::Sepia => "sepia".to_string(),
+            Theme::HighContrast => "high-contrast".to_string(),
+            Theme::SolarizedLight => "solarized-light".to_string(),
+            Theme::SolarizedDark => "solarized-dark".to_string(),
+            Theme::User(name) => format!("{USER_THEME_PREFIX}{name}"),
+        }
+    }
+
+    /// Display name in the current locale
+    pub fn label(&self) -> String {
+        match self {
+            Theme::Auto => t("theme.auto").to_string(),
+            Theme::Light => t("theme.light").to_string(),
+            Theme::Dark => t("theme.dark").to_string(),
+            Theme::Sepia => t("theme.sepia").to_string(),
+            Theme::HighContrast => t("theme.high_contrast").to_string(),
+            Theme::SolarizedLight => t("theme.solarized_light").to_string(),
+            Theme::SolarizedDark => t("theme.solarized_dark").to_string(),
+            Theme::User(name) => name.clone(),
+        }
+    }
+
+    /// Value of the `data-reading-theme` attribute that selects the palette
+    /// overrides in CSS (None for the plain GitHub light/dark palettes)
+    pub fn reading_theme(&self) -> Option<String> {
+        match self {
+            Theme::Auto | Theme::Light | Theme::Dark => None,
+            _ => Some(self.id()),
+        }
+    }
+
+    /// Light/dark base the theme builds on (None when following the system).
+    ///
+    /// The base selects the GitHub Markdown CSS, syntax highlighting and
+    /// Mermaid palettes, which only come in light and dark variants.
+    pub fn base(&self) -> Option<DioxusTheme> {
+        match self {
+            Theme::Auto => None,
+            Theme::Light | Theme::Sepia | Theme::SolarizedLight => Some(DioxusTheme::Light),
+            Theme::Dark | Theme::HighContrast | Theme::SolarizedDark => Some(DioxusTheme::Dark),
+            Theme::User(name) => Some(
+                USER_STYLES
+                    .read()
+                    .theme(name)
+                    .map_or(DioxusTheme::Light, |theme| theme.base),
+            ),
+        }
+    }
 }
 
 impl From<&str> for Theme {
@@ -14,23 +108,83 @@ impl From<&str> for Theme {
         match s {
             "light" => Theme::Light,
             "dark" => Theme::Dark,
-            _ => Theme::Auto,
+            "sepia" => Theme::Sepia,
+            "high-contrast" => Theme::HighContrast,
+            "solarized-light" => Theme::SolarizedLight,
+            "solarized-dark" => Theme::SolarizedDark,
+            _ => match s.strip_prefix(USER_THEME_PREFIX) {
+                Some(name) if !name.is_empty() => Theme::User(name.to_string()),
+                _ => Theme::Auto,
+            },
         }
     }
 }
 
-pub fn resolve_theme(theme: Theme) -> DioxusTheme {
-    match theme {
+impl From<String> for Theme {
+    fn from(s: String) -> Self {
+        Theme::from(s.as_str())
+    }
+}
+
+impl From<Theme> for String {
+    fn from(theme: Theme) -> Self {
+        theme.id()
+    }
+}
+
+pub fn resolve_theme(theme: &Theme) -> DioxusTheme {
+    theme.base().unwrap_or_else(|| {
         // NOTE:
         // We cannot use dioxus_sdk_window::theme::get_theme here because
         // it requires a Dioxus runtime and cannot be called from outside
         // of Dioxus context. That's why we use dark_light crate instead.
-        Theme::Auto => match dark_light::detect() {
+        match dark_light::detect() {
             Ok(dark_light::Mode::Light) => DioxusTheme::Light,
             Ok(dark_light::Mode::Dark) => DioxusTheme::Dark,
             Ok(dark_light::Mode::Unspecified) | Err(_) => DioxusTheme::Light,
-        },
-        Theme::Light => DioxusTheme::Light,
-        Theme::Dark => DioxusTheme::Dark,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_id_roundtrip() {
+        let themes = Theme::BUILT_IN
+            .into_iter()
+            .chain([Theme::User("nord".to_string())]);
+        for theme in themes {
+            assert_eq!(Theme::from(theme.id().as_str()), theme);
+        }
+    }
+
+    #[test]
+    fn test_theme_from_unknown() {
+        assert_eq!(Theme::from("unknown"), Theme::Auto);
+        assert_eq!(Theme::from("user:"), Theme::Auto);
+    }
+
+    #[test]
+    fn test_theme_serde() {
+        let json = serde_json::to_string(&Theme::SolarizedDark).unwrap();
+        assert_eq!(json, r#""solarized-dark""#);
+        let theme: Theme = serde_json::from_str(r#""user:paper""#).unwrap();
+        assert_eq!(theme, Theme::User("paper".to_string()));
+        // Values written by older versions still parse
+        let theme: Theme = serde_json::from_str(r#""dark""#).unwrap();
+        assert_eq!(theme, Theme::Dark);
+    }
+
+    #[test]
+    fn test_theme_base() {
+        assert_eq!(Theme::Auto.base(), None);
+        assert_eq!(Theme::Sepia.base(), Some(DioxusTheme::Light));
+        assert_eq!(Theme::HighContrast.base(), Some(DioxusTheme::Dark));
+        assert_eq!(
+            Theme::User("missing".to_string()).base(),
+            Some(DioxusTheme::Light)
+        );
     }
 }
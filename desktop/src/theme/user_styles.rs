@@ -0,0 +1,178 @@
+//! User stylesheets from the config directory.
+//!
+//! `user.css` is applied to every window after the built-in styles, and each
+//! `themes/<name>.css` adds a theme that can be selected like the built-in
+//! ones. A user theme builds on the light palette unless its stylesheet
+//! contains `arto-base: dark` (usually in a leading comment).
+//!
+//! The directory is watched with `FILE_WATCHER`, so edits are applied to all
+//! open windows without restarting.
+
+use parking_lot::RwLock;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Once};
+use tokio::sync::broadcast;
+
+use super::DioxusTheme;
+use crate::watcher::FILE_WATCHER;
+
+const USER_CSS_FILENAME: &str = "user.css";
+const THEMES_DIRNAME: &str = "themes";
+
+/// A theme loaded from `themes/<name>.css`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserTheme {
+    pub name: String,
+    pub base: DioxusTheme,
+    pub css: String,
+}
+
+/// Stylesheets found in the config directory
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserStyles {
+    /// Contents of `user.css`, applied regardless of the theme
+    pub user_css: Option<String>,
+    /// User themes sorted by name
+    pub themes: Vec<UserTheme>,
+}
+
+impl UserStyles {
+    /// Load the stylesheets from a config directory (missing files are skipped)
+    pub fn load_from(dir: &Path) -> Self {
+        let user_css = fs::read_to_string(dir.join(USER_CSS_FILENAME)).ok();
+
+        let mut themes: Vec<UserTheme> = fs::read_dir(dir.join(THEMES_DIRNAME))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().is_none_or(|ext| ext != "css") {
+                    return None;
+                }
+                let name = path.file_stem()?.to_str()?.to_string();
+                let css = fs::read_to_string(&path)
+                    .inspect_err(|e| tracing::warn!(?path, %e, "Failed to read user theme"))
+                    .ok()?;
+                Some(UserTheme {
+                    base: parse_base(&css),
+                    name,
+                    css,
+                })
+            })
+            .collect();
+        themes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self { user_css, themes }
+    }
+
+    /// Find a user theme by name
+    pub fn theme(&self, name: &str) -> Option<&UserTheme> {
+        self.themes.iter().find(|theme| theme.name == name)
+    }
+}
+
+/// Read the `arto-base: light|dark` declaration of a user theme
+fn parse_base(css: &str) -> DioxusTheme {
+    let value = css
+        .split("arto-base:")
+        .nth(1)
+        .map(|rest| rest.trim_start())
+        .unwrap_or_default();
+    if value.starts_with("dark") {
+        DioxusTheme::Dark
+    } else {
+        DioxusTheme::Light
+    }
+}
+
+/// Directory holding `config.json`, `user.css` and `themes/`
+fn styles_dir() -> PathBuf {
+    crate::storage::STORAGE.config_dir().to_path_buf()
+}
+
+/// Stylesheets currently applied to all windows
+pub static USER_STYLES: LazyLock<RwLock<UserStyles>> =
+    LazyLock::new(|| RwLock::new(UserStyles::load_from(&styles_dir())));
+
+/// Broadcast channel notifying all windows that USER_STYLES was reloaded
+pub static USER_STYLES_CHANGED: LazyLock<broadcast::Sender<()>> =
+    LazyLock::new(|| broadcast::channel(10).0);
+
+/// Start watching the config directory for stylesheet changes (once per app)
+pub fn start_watching_user_styles() {
+    static STARTED: Once = Once::new();
+    STARTED.call_once(|| {
+        tokio::spawn(async move {
+            let dir = styles_dir();
+            // The directory must exist to be watched
+            if let Err(e) = fs::create_dir_all(dir.join(THEMES_DIRNAME)) {
+                tracing::warn!(?dir, %e, "Failed to create user themes directory");
+                return;
+            }
+            let Ok(mut rx) = FILE_WATCHER.watch_directory(dir.clone()).await else {
+                tracing::warn!(?dir, "Failed to watch user styles");
+                return;
+            };
+            while rx.recv().await.is_some() {
+                let styles = UserStyles::load_from(&dir);
+                if *USER_STYLES.read() == styles {
+                    continue;
+                }
+                tracing::info!(themes = styles.themes.len(), "User styles reloaded");
+                *USER_STYLES.write() = styles;
+                USER_STYLES_CHANGED.send(()).ok();
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_base() {
+        assert_eq!(parse_base("/* arto-base: dark */"), DioxusTheme::Dark);
+        assert_eq!(parse_base("/* arto-base:light */"), DioxusTheme::Light);
+        assert_eq!(parse_base("body { color: red; }"), DioxusTheme::Light);
+    }
+
+    #[test]
+    fn test_load_from() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("user.css"), "body { font-size: 18px; }").unwrap();
+        fs::create_dir(dir.path().join("themes")).unwrap();
+        fs::write(
+            dir.path().join("themes/nord.css"),
+            indoc! {"
+                /* arto-base: dark */
+                [data-reading-theme] { --bg-color: #2e3440; }
+            "},
+        )
+        .unwrap();
+        fs::write(dir.path().join("themes/paper.css"), "").unwrap();
+        fs::write(dir.path().join("themes/notes.txt"), "").unwrap();
+
+        let styles = UserStyles::load_from(dir.path());
+
+        assert_eq!(
+            styles.user_css.as_deref(),
+            Some("body { font-size: 18px; }")
+        );
+        let names: Vec<_> = styles.themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["nord", "paper"]);
+        assert_eq!(styles.theme("nord").unwrap().base, DioxusTheme::Dark);
+        assert_eq!(styles.theme("paper").unwrap().base, DioxusTheme::Light);
+        assert!(styles.theme("notes").is_none());
+    }
+
+    #[test]
+    fn test_load_from_empty_dir() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(UserStyles::load_from(dir.path()), UserStyles::default());
+    }
+}
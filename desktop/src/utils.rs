@@ -1,6 +1,7 @@
 pub mod clipboard;
 pub mod file;
 pub mod file_operations;
+pub mod glob;
 pub mod image;
 pub mod screen;
 pub mod window_title;
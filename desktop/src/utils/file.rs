@@ -1,4 +1,6 @@
-use std::path::Path;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// Check if a file path has a markdown extension (.md or .markdown)
 pub fn is_markdown_file(path: impl AsRef<Path>) -> bool {
@@ -8,3 +10,72 @@ pub fn is_markdown_file(path: impl AsRef<Path>) -> bool {
         .map(|ext| ext == "md" || ext == "markdown")
         .unwrap_or(false)
 }
+
+/// Markdown files under `root`, recursively and sorted by path
+///
+/// Hidden files and directories are skipped, and at most `limit` files are
+/// returned so that huge trees stay responsive.
+pub fn find_markdown_files(root: &Path, limit: usize) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            let is_hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if is_hidden {
+                continue;
+            }
+            if path.is_dir() {
+                pending.push(path);
+            } else if is_markdown_file(&path) {
+                if files.len() == limit {
+                    tracing::warn!(?root, limit, "Too many Markdown files, skipping the rest");
+                    files.sort();
+                    return files;
+                }
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Replace the contents of a file atomically
+///
+/// The contents are written to a temporary file next to `path`, which is then
+/// renamed over it, so readers never observe a partially written file.
+/// Symlinks are resolved first, so the file they point to is replaced rather
+/// than the link itself.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let path = &fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = (|| {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        if let Ok(metadata) = fs::metadata(path) {
+            fs::set_permissions(&temp_path, metadata.permissions())?;
+        }
+        fs::rename(&temp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
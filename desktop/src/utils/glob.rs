@@ -0,0 +1,83 @@
+use std::path::Path;
+
+/// Match a path against a glob where `*` and `?` stop at `/` and `**` does not.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    fn matches(pattern: &[u8], path: &[u8]) -> bool {
+        match pattern {
+            [] => path.is_empty(),
+            [b'*', b'*', b'/', rest @ ..] => {
+                // `**/` also matches zero directories
+                matches(rest, path)
+                    || path
+                        .iter()
+                        .enumerate()
+                        .any(|(i, &c)| c == b'/' && matches(rest, &path[i + 1..]))
+            }
+            [b'*', b'*', rest @ ..] => (0..=path.len()).any(|i| matches(rest, &path[i..])),
+            [b'*', rest @ ..] => (0..=path.len())
+                .take_while(|&i| i == 0 || path[i - 1] != b'/')
+                .any(|i| matches(rest, &path[i..])),
+            [b'?', rest @ ..] => {
+                matches!(path.first(), Some(&c) if c != b'/') && matches(rest, &path[1..])
+            }
+            [c, rest @ ..] => path.first() == Some(c) && matches(rest, &path[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Check if `path` is matched by any of `patterns`
+///
+/// Patterns without a `/` match any single component (`node_modules`,
+/// `*.tmp`); the others match the end of the path (`docs/drafts/**`).
+pub fn path_matches_any(path: &Path, patterns: &[String]) -> bool {
+    let components: Vec<String> = path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+
+    patterns.iter().any(|pattern| {
+        if pattern.contains('/') {
+            let pattern = pattern.trim_start_matches('/');
+            (0..components.len()).any(|i| glob_match(pattern, &components[i..].join("/")))
+        } else {
+            components.iter().any(|c| glob_match(pattern, c))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_matches_any() {
+        let patterns = vec![
+            "node_modules".to_string(),
+            "*.swp".to_string(),
+            "docs/drafts/**".to_string(),
+        ];
+
+        assert!(path_matches_any(
+            Path::new("/repo/node_modules/pkg/README.md"),
+            &patterns
+        ));
+        assert!(path_matches_any(
+            Path::new("/repo/.notes.md.swp"),
+            &patterns
+        ));
+        assert!(path_matches_any(
+            Path::new("/repo/docs/drafts/idea.md"),
+            &patterns
+        ));
+        assert!(!path_matches_any(
+            Path::new("/repo/docs/guide.md"),
+            &patterns
+        ));
+        assert!(!path_matches_any(
+            Path::new("/repo/node_modules.md"),
+            &patterns
+        ));
+        assert!(!path_matches_any(Path::new("/repo/a.md"), &[]));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::state::TabContent;
+use crate::state::{Tab, TabContent};
 use std::path::Path;
 
 /// Extract filename from path, returning "Unknown" if unavailable
@@ -9,11 +9,18 @@ fn extract_filename(path: &Path) -> &str {
 }
 
 /// Generate window title based on active tab content
-pub fn generate_window_title(tab_content: &TabContent) -> String {
-    match tab_content {
-        TabContent::File(path) => format!("Arto - {}", extract_filename(path)),
+pub fn generate_window_title(tab: &Tab) -> String {
+    match &tab.content {
+        TabContent::File(path) => match &tab.title {
+            Some(title) => format!("Arto - {}", title),
+            None => format!("Arto - {}", extract_filename(path)),
+        },
         TabContent::Inline(_) => "Arto - Welcome".to_string(),
+        TabContent::LivePreview(_) => "Arto - Live Preview".to_string(),
         TabContent::Preferences => "Arto - Preferences".to_string(),
+        TabContent::Book(root) => format!("Arto - {} (Book)", extract_filename(root)),
+        TabContent::Folder(dir) => format!("Arto - {} (Folder)", extract_filename(dir)),
+        TabContent::Directory(dir) => format!("Arto - {}/", extract_filename(dir)),
         TabContent::FileError(path, _) => format!("Arto - {} (Error)", extract_filename(path)),
         TabContent::None => "Arto".to_string(),
     }
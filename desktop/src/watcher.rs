@@ -1,12 +1,20 @@
+use crate::config::{WatcherBackend, WatcherConfig, CONFIG};
+use crate::utils::glob::path_matches_any;
+use notify_debouncer_full::notify::event::{AccessKind, AccessMode, ModifyKind, RenameMode};
+use notify_debouncer_full::notify::{
+    self, ErrorKind, Event, EventKind, PollWatcher, RecommendedWatcher,
+};
 use notify_debouncer_full::{
-    new_debouncer, notify::RecursiveMode, DebounceEventResult, Debouncer, RecommendedCache,
+    new_debouncer, new_debouncer_opt, notify::RecursiveMode, DebounceEventResult, Debouncer,
+    NoCache, RecommendedCache,
 };
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock, Mutex};
 use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::mpsc::{self, Receiver, Sender};
+use tokio::sync::watch;
 
 #[derive(Debug, Error)]
 pub enum WatcherError {
@@ -16,185 +24,444 @@ pub enum WatcherError {
 
 type WatcherResult<T> = Result<T, WatcherError>;
 
+/// Change of a watched file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileEvent {
+    /// Written in place, replaced (e.g. by an editor's atomic save) or recreated
+    Modified,
+    /// Deleted; the watch stays armed and reports `Modified` once the file is recreated
+    Removed,
+    /// Moved to another name in the same directory; the watch ends with this event
+    Renamed(PathBuf),
+}
+
+/// Health of the file watcher, shown when live reload is degraded
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WatcherStatus {
+    /// Paths polled because the OS refused to watch them natively
+    pub polled: Vec<PathBuf>,
+    /// Paths not watched at all, with the reason
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+impl WatcherStatus {
+    pub fn is_degraded(&self) -> bool {
+        !self.polled.is_empty() || !self.failed.is_empty()
+    }
+}
+
 /// Global file watcher that manages file change notifications
 pub struct FileWatcher {
     command_tx: Sender<FileWatcherCommand>,
+    status_rx: watch::Receiver<WatcherStatus>,
 }
 
 enum FileWatcherCommand {
-    Watch(PathBuf, Sender<()>),
+    Watch(PathBuf, Sender<FileEvent>),
     Unwatch(PathBuf),
     WatchDirectory(PathBuf, Sender<()>),
     UnwatchDirectory(PathBuf),
+    Configure(WatcherConfig),
 }
 
-impl FileWatcher {
-    fn new() -> Self {
-        let (command_tx, mut command_rx) = mpsc::channel::<FileWatcherCommand>(100);
+/// Notification channels of the watched files and directories
+#[derive(Default)]
+struct Watchers {
+    files: HashMap<PathBuf, Vec<Sender<FileEvent>>>,
+    /// Watched recursively
+    directories: HashMap<PathBuf, Vec<Sender<()>>>,
+    /// Glob patterns of paths not reported to directory watchers
+    ignore: Vec<String>,
+}
 
-        // Spawn a dedicated thread for the file watcher
-        std::thread::spawn(move || {
-            // Map of file paths to their notification channels
-            let file_watchers: Arc<Mutex<HashMap<PathBuf, Vec<Sender<()>>>>> =
-                Arc::new(Mutex::new(HashMap::new()));
-            let file_watchers_clone = file_watchers.clone();
-
-            // Map of directory paths to their notification channels (for recursive watching)
-            let dir_watchers: Arc<Mutex<HashMap<PathBuf, Vec<Sender<()>>>>> =
-                Arc::new(Mutex::new(HashMap::new()));
-            let dir_watchers_clone = dir_watchers.clone();
-
-            // Create a debouncer with 500ms delay
-            let mut debouncer: Debouncer<
-                notify_debouncer_full::notify::RecommendedWatcher,
-                RecommendedCache,
-            > = match new_debouncer(
-                Duration::from_millis(500),
-                None,
-                move |result: DebounceEventResult| match result {
-                    Ok(events) => {
-                        // Collect unique paths that changed
-                        let mut changed_paths = std::collections::HashSet::new();
-                        for event in events {
-                            for path in &event.paths {
-                                changed_paths.insert(path.clone());
-                            }
-                        }
-
-                        // Notify file watchers (exact path match)
-                        let file_watchers = file_watchers_clone.lock().unwrap();
-                        for path in &changed_paths {
-                            if let Some(senders) = file_watchers.get(path) {
-                                tracing::debug!("File changed: {:?}", path);
-                                for sender in senders {
-                                    let _ = sender.blocking_send(());
-                                }
-                            }
-                        }
-                        drop(file_watchers);
-
-                        // Notify directory watchers (path starts with watched directory)
-                        let dir_watchers = dir_watchers_clone.lock().unwrap();
-                        let mut notified_dirs = std::collections::HashSet::new();
-                        for changed_path in &changed_paths {
-                            // Skip .git directory changes (too noisy)
-                            if changed_path.components().any(|c| c.as_os_str() == ".git") {
-                                continue;
-                            }
-
-                            for (watched_dir, senders) in dir_watchers.iter() {
-                                if changed_path.starts_with(watched_dir)
-                                    && !notified_dirs.contains(watched_dir)
-                                {
-                                    tracing::trace!(
-                                        ?watched_dir,
-                                        ?changed_path,
-                                        "Directory content changed"
-                                    );
-                                    for sender in senders {
-                                        let _ = sender.blocking_send(());
-                                    }
-                                    notified_dirs.insert(watched_dir.clone());
-                                }
-                            }
-                        }
+impl Watchers {
+    fn apply(&mut self, command: FileWatcherCommand) {
+        match command {
+            FileWatcherCommand::Watch(path, tx) => self.files.entry(path).or_default().push(tx),
+            FileWatcherCommand::Unwatch(path) => remove_sender(&mut self.files, &path),
+            FileWatcherCommand::WatchDirectory(path, tx) => {
+                self.directories.entry(path).or_default().push(tx)
+            }
+            FileWatcherCommand::UnwatchDirectory(path) => {
+                remove_sender(&mut self.directories, &path)
+            }
+            FileWatcherCommand::Configure(config) => self.ignore = config.ignore,
+        }
+    }
+
+    /// Paths to register with the OS watcher
+    ///
+    /// Files are watched through their directory: a watch on the file itself
+    /// stops firing once an atomic save renames another file over it, and
+    /// cannot report the file being recreated after a deletion.
+    fn roots(&self) -> HashMap<PathBuf, RecursiveMode> {
+        let mut roots: HashMap<PathBuf, RecursiveMode> = self
+            .directories
+            .keys()
+            .map(|dir| (dir.clone(), RecursiveMode::Recursive))
+            .collect();
+        for dir in self.files.keys().filter_map(|path| path.parent()) {
+            let covered = self.directories.keys().any(|root| dir.starts_with(root));
+            if !covered && !dir.as_os_str().is_empty() {
+                roots.insert(dir.to_path_buf(), RecursiveMode::NonRecursive);
+            }
+        }
+        roots
+    }
+
+    fn notify(&mut self, events: &[Event]) {
+        // Notify file watchers (exact path match)
+        for (path, event) in file_events(events, self.files.keys()) {
+            tracing::debug!(?path, ?event, "File changed");
+            let senders = if matches!(event, FileEvent::Renamed(_)) {
+                // Dropping the senders ends the watch once the event is received
+                self.files.remove(&path).unwrap_or_default()
+            } else {
+                self.files.get(&path).cloned().unwrap_or_default()
+            };
+            for sender in senders {
+                let _ = sender.blocking_send(event.clone());
+            }
+        }
+
+        // Notify directory watchers (path starts with watched directory)
+        let mut notified_dirs = HashSet::new();
+        for changed_path in events.iter().flat_map(|event| &event.paths) {
+            // Skip .git directory changes (too noisy) and ignored paths
+            if changed_path.components().any(|c| c.as_os_str() == ".git")
+                || path_matches_any(changed_path, &self.ignore)
+            {
+                continue;
+            }
+
+            for (watched_dir, senders) in self.directories.iter() {
+                if changed_path.starts_with(watched_dir) && notified_dirs.insert(watched_dir) {
+                    tracing::trace!(?watched_dir, ?changed_path, "Directory content changed");
+                    for sender in senders {
+                        let _ = sender.blocking_send(());
                     }
-                    Err(errors) => {
-                        for error in errors {
-                            tracing::error!("File watcher error: {:?}", error);
-                        }
+                }
+            }
+        }
+    }
+}
+
+/// Drop one channel of a path, preferring one whose receiver is already gone
+fn remove_sender<T>(watchers: &mut HashMap<PathBuf, Vec<Sender<T>>>, path: &Path) {
+    let Some(senders) = watchers.get_mut(path) else {
+        return;
+    };
+    match senders.iter().position(Sender::is_closed) {
+        Some(index) => {
+            senders.remove(index);
+        }
+        None => {
+            senders.pop();
+        }
+    }
+    if senders.is_empty() {
+        watchers.remove(path);
+    }
+}
+
+/// Events worth reporting; opening or reading a file is not a change
+fn is_change(event: &Event) -> bool {
+    match event.kind {
+        EventKind::Access(kind) => kind == AccessKind::Close(AccessMode::Write),
+        _ => true,
+    }
+}
+
+/// What happened to each watched file touched by `events`
+///
+/// Judged by the file system once the events settled rather than by the
+/// event kinds, as editors save in many ways: a file that still exists was
+/// modified, however it got there.
+fn file_events<'a>(
+    events: &[Event],
+    watched: impl Iterator<Item = &'a PathBuf>,
+) -> Vec<(PathBuf, FileEvent)> {
+    let changed: HashSet<&Path> = events
+        .iter()
+        .flat_map(|event| &event.paths)
+        .map(PathBuf::as_path)
+        .collect();
+    let renames: HashMap<&Path, &Path> = events
+        .iter()
+        .filter(|event| event.kind == EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+        .filter_map(|event| match event.paths.as_slice() {
+            [from, to] => Some((from.as_path(), to.as_path())),
+            _ => None,
+        })
+        .collect();
+
+    watched
+        .filter(|path| changed.contains(path.as_path()))
+        .map(|path| {
+            let event = if path.exists() {
+                FileEvent::Modified
+            } else {
+                match renames.get(path.as_path()) {
+                    Some(to) if to.exists() && to.parent() == path.parent() => {
+                        FileEvent::Renamed(to.to_path_buf())
                     }
-                },
-            ) {
-                Ok(d) => d,
-                Err(e) => {
-                    tracing::error!("Failed to create file watcher: {:?}", e);
-                    return;
+                    _ => FileEvent::Removed,
                 }
             };
+            (path.clone(), event)
+        })
+        .collect()
+}
+
+/// Debouncer callback forwarding settled changes to the subscribers
+fn event_handler(
+    watchers: Arc<Mutex<Watchers>>,
+) -> impl FnMut(DebounceEventResult) + Send + 'static {
+    move |result| match result {
+        Ok(events) => {
+            let events: Vec<Event> = events
+                .into_iter()
+                .map(|event| event.event)
+                .filter(is_change)
+                .collect();
+            if !events.is_empty() {
+                watchers.lock().unwrap().notify(&events);
+            }
+        }
+        Err(errors) => {
+            for error in errors {
+                tracing::error!("File watcher error: {:?}", error);
+            }
+        }
+    }
+}
+
+/// A path that does not exist cannot be polled either
+fn is_missing(error: &notify::Error) -> bool {
+    match &error.kind {
+        ErrorKind::PathNotFound => true,
+        ErrorKind::Io(e) => e.kind() == std::io::ErrorKind::NotFound,
+        _ => false,
+    }
+}
+
+/// OS watcher a root is registered with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Via {
+    Native,
+    Polling,
+    /// Polled because native watching failed
+    Fallback,
+}
+
+/// OS watchers behind their debouncers, started when first needed
+struct Backends {
+    config: WatcherConfig,
+    watchers: Arc<Mutex<Watchers>>,
+    native: Option<Debouncer<RecommendedWatcher, RecommendedCache>>,
+    polling: Option<Debouncer<PollWatcher, NoCache>>,
+    /// Roots registered with an OS watcher
+    active: HashMap<PathBuf, (RecursiveMode, Via)>,
+    /// Roots that could not be registered, retried with the next command
+    failed: HashMap<PathBuf, String>,
+}
+
+impl Backends {
+    fn new(config: WatcherConfig, watchers: Arc<Mutex<Watchers>>) -> Self {
+        Self {
+            config,
+            watchers,
+            native: None,
+            polling: None,
+            active: HashMap::new(),
+            failed: HashMap::new(),
+        }
+    }
+
+    /// Take new settings, registering every root afresh if the watchers change
+    fn configure(&mut self, config: WatcherConfig) {
+        let restart = config.backend != self.config.backend
+            || config.debounce_ms != self.config.debounce_ms
+            || config.poll_interval_ms != self.config.poll_interval_ms;
+        self.config = config;
+        if restart {
+            tracing::info!(config = ?self.config, "Restarting file watcher");
+            // Dropping the debouncers releases all of their watches
+            self.native = None;
+            self.polling = None;
+            self.active.clear();
+            self.failed.clear();
+        }
+    }
+
+    fn debounce(&self) -> Duration {
+        Duration::from_millis(self.config.debounce_ms)
+    }
+
+    fn native(&mut self) -> notify::Result<&mut Debouncer<RecommendedWatcher, RecommendedCache>> {
+        let debouncer = match self.native.take() {
+            Some(debouncer) => debouncer,
+            None => new_debouncer(self.debounce(), None, event_handler(self.watchers.clone()))?,
+        };
+        Ok(self.native.insert(debouncer))
+    }
+
+    fn polling(&mut self) -> notify::Result<&mut Debouncer<PollWatcher, NoCache>> {
+        let debouncer = match self.polling.take() {
+            Some(debouncer) => debouncer,
+            None => {
+                let interval = Duration::from_millis(self.config.poll_interval_ms);
+                new_debouncer_opt(
+                    self.debounce(),
+                    None,
+                    event_handler(self.watchers.clone()),
+                    NoCache,
+                    notify::Config::default().with_poll_interval(interval),
+                )?
+            }
+        };
+        Ok(self.polling.insert(debouncer))
+    }
+
+    fn watch(&mut self, path: &Path, mode: RecursiveMode) -> notify::Result<Via> {
+        if self.config.backend == WatcherBackend::Polling {
+            self.polling()?.watch(path, mode)?;
+            return Ok(Via::Polling);
+        }
+
+        let error = match self
+            .native()
+            .and_then(|debouncer| debouncer.watch(path, mode))
+        {
+            Ok(()) => return Ok(Via::Native),
+            Err(e) => e,
+        };
+        if self.config.backend == WatcherBackend::Native || is_missing(&error) {
+            return Err(error);
+        }
+
+        // e.g. the inotify watch limit was hit while walking a huge tree
+        tracing::warn!("Polling {:?}, native watching failed: {:?}", path, error);
+        if let Some(native) = self.native.as_mut() {
+            // Release what a partially registered recursive watch left behind
+            let _ = native.unwatch(path);
+        }
+        self.polling()?.watch(path, mode)?;
+        Ok(Via::Fallback)
+    }
+
+    fn unwatch(&mut self, path: &Path, via: Via) -> notify::Result<()> {
+        match via {
+            Via::Native => self.native.as_mut().map_or(Ok(()), |d| d.unwatch(path)),
+            Via::Polling | Via::Fallback => {
+                self.polling.as_mut().map_or(Ok(()), |d| d.unwatch(path))
+            }
+        }
+    }
+
+    /// Register and release OS watches so that exactly `roots` are watched
+    fn sync(&mut self, roots: HashMap<PathBuf, RecursiveMode>) {
+        self.failed.retain(|path, _| roots.contains_key(path));
+
+        // Release first, so a directory switching modes is registered afresh
+        let stale: Vec<PathBuf> = self
+            .active
+            .iter()
+            .filter(|(path, (mode, _))| roots.get(*path) != Some(mode))
+            .map(|(path, _)| path.clone())
+            .collect();
+        for path in stale {
+            let Some((_, via)) = self.active.remove(&path) else {
+                continue;
+            };
+            if let Err(e) = self.unwatch(&path, via) {
+                tracing::warn!("Failed to unwatch {:?}: {:?}", path, e);
+            } else {
+                tracing::info!("Stopped watching: {:?}", path);
+            }
+        }
+
+        for (path, mode) in roots {
+            if self.active.contains_key(&path) {
+                continue;
+            }
+            match self.watch(&path, mode) {
+                Ok(via) => {
+                    tracing::info!("Started watching: {:?} ({:?}, {:?})", path, mode, via);
+                    self.failed.remove(&path);
+                    self.active.insert(path, (mode, via));
+                }
+                Err(e) => {
+                    tracing::error!("Failed to watch {:?}: {:?}", path, e);
+                    self.failed.insert(path, e.to_string());
+                }
+            }
+        }
+    }
+
+    fn status(&self) -> WatcherStatus {
+        let mut polled: Vec<PathBuf> = self
+            .active
+            .iter()
+            .filter(|(_, (_, via))| *via == Via::Fallback)
+            .map(|(path, _)| path.clone())
+            .collect();
+        polled.sort();
+        let mut failed: Vec<(PathBuf, String)> = self
+            .failed
+            .iter()
+            .map(|(path, reason)| (path.clone(), reason.clone()))
+            .collect();
+        failed.sort();
+        WatcherStatus { polled, failed }
+    }
+}
+
+impl FileWatcher {
+    fn new(config: WatcherConfig) -> Self {
+        let (command_tx, mut command_rx) = mpsc::channel::<FileWatcherCommand>(100);
+        let (status_tx, status_rx) = watch::channel(WatcherStatus::default());
+
+        // Spawn a dedicated thread for the file watcher
+        std::thread::spawn(move || {
+            let watchers = Arc::new(Mutex::new(Watchers {
+                ignore: config.ignore.clone(),
+                ..Default::default()
+            }));
+            let mut backends = Backends::new(config, watchers.clone());
 
             tracing::info!("Global file watcher started");
 
             // Process commands
-            loop {
-                match command_rx.blocking_recv() {
-                    Some(FileWatcherCommand::Watch(path, tx)) => {
-                        let mut watchers = file_watchers.lock().unwrap();
-                        let is_first = !watchers.contains_key(&path);
-
-                        watchers.entry(path.clone()).or_default().push(tx);
-
-                        // Only start watching if this is the first watcher for this file
-                        if is_first {
-                            if let Err(e) = debouncer.watch(&path, RecursiveMode::NonRecursive) {
-                                tracing::error!("Failed to watch file {:?}: {:?}", path, e);
-                            } else {
-                                tracing::info!("Started watching file: {:?}", path);
-                            }
-                        }
-                    }
-                    Some(FileWatcherCommand::Unwatch(path)) => {
-                        let mut watchers = file_watchers.lock().unwrap();
-                        if let Some(senders) = watchers.get_mut(&path) {
-                            senders.pop();
-                            // If no more watchers for this file, stop watching
-                            if senders.is_empty() {
-                                watchers.remove(&path);
-                                if let Err(e) = debouncer.unwatch(&path) {
-                                    tracing::error!("Failed to unwatch file {:?}: {:?}", path, e);
-                                } else {
-                                    tracing::info!("Stopped watching file: {:?}", path);
-                                }
-                            }
-                        }
-                    }
-                    Some(FileWatcherCommand::WatchDirectory(path, tx)) => {
-                        let mut watchers = dir_watchers.lock().unwrap();
-                        let is_first = !watchers.contains_key(&path);
-
-                        watchers.entry(path.clone()).or_default().push(tx);
-
-                        // Only start watching if this is the first watcher for this directory
-                        if is_first {
-                            if let Err(e) = debouncer.watch(&path, RecursiveMode::Recursive) {
-                                tracing::error!("Failed to watch directory {:?}: {:?}", path, e);
-                            } else {
-                                tracing::info!("Started watching directory: {:?}", path);
-                            }
-                        }
-                    }
-                    Some(FileWatcherCommand::UnwatchDirectory(path)) => {
-                        let mut watchers = dir_watchers.lock().unwrap();
-                        if let Some(senders) = watchers.get_mut(&path) {
-                            senders.pop();
-                            // If no more watchers for this directory, stop watching
-                            if senders.is_empty() {
-                                watchers.remove(&path);
-                                if let Err(e) = debouncer.unwatch(&path) {
-                                    tracing::error!(
-                                        "Failed to unwatch directory {:?}: {:?}",
-                                        path,
-                                        e
-                                    );
-                                } else {
-                                    tracing::info!("Stopped watching directory: {:?}", path);
-                                }
-                            }
-                        }
-                    }
-                    None => {
-                        tracing::info!("File watcher command channel closed");
-                        break;
-                    }
+            while let Some(command) = command_rx.blocking_recv() {
+                if let FileWatcherCommand::Configure(config) = &command {
+                    backends.configure(config.clone());
                 }
+                let roots = {
+                    let mut watchers = watchers.lock().unwrap();
+                    watchers.apply(command);
+                    watchers.roots()
+                };
+                backends.sync(roots);
+
+                let status = backends.status();
+                status_tx.send_if_modified(|current| {
+                    let changed = *current != status;
+                    *current = status;
+                    changed
+                });
             }
+            tracing::info!("File watcher command channel closed");
         });
 
-        Self { command_tx }
+        Self {
+            command_tx,
+            status_rx,
+        }
     }
 
     /// Watch a file and receive notifications when it changes
-    pub async fn watch(&self, path: impl Into<PathBuf>) -> WatcherResult<Receiver<()>> {
+    pub async fn watch(&self, path: impl Into<PathBuf>) -> WatcherResult<Receiver<FileEvent>> {
         let path = path.into();
         let (tx, rx) = mpsc::channel(100);
         self.command_tx
@@ -204,6 +471,23 @@ impl FileWatcher {
         Ok(rx)
     }
 
+    /// Watch several files through one channel, notified when any of them changes
+    ///
+    /// Each file must be released with [`FileWatcher::unwatch`] afterwards.
+    pub async fn watch_all(
+        &self,
+        paths: impl IntoIterator<Item = PathBuf>,
+    ) -> WatcherResult<Receiver<FileEvent>> {
+        let (tx, rx) = mpsc::channel(100);
+        for path in paths {
+            self.command_tx
+                .send(FileWatcherCommand::Watch(path, tx.clone()))
+                .await
+                .map_err(|_| WatcherError::CommandFailed)?;
+        }
+        Ok(rx)
+    }
+
     /// Stop watching a file
     pub async fn unwatch(&self, path: impl Into<PathBuf>) -> WatcherResult<()> {
         let path = path.into();
@@ -232,6 +516,262 @@ impl FileWatcher {
             .await
             .map_err(|_| WatcherError::CommandFailed)
     }
+
+    /// Switch backend, timings or ignore patterns; current watches carry over
+    pub async fn configure(&self, config: WatcherConfig) -> WatcherResult<()> {
+        self.command_tx
+            .send(FileWatcherCommand::Configure(config))
+            .await
+            .map_err(|_| WatcherError::CommandFailed)
+    }
+
+    /// Health of the watcher, updated whenever watched paths change
+    pub fn status(&self) -> watch::Receiver<WatcherStatus> {
+        self.status_rx.clone()
+    }
 }
 
-pub static FILE_WATCHER: LazyLock<FileWatcher> = LazyLock::new(FileWatcher::new);
+pub static FILE_WATCHER: LazyLock<FileWatcher> =
+    LazyLock::new(|| FileWatcher::new(CONFIG.read().watcher.clone()));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify_debouncer_full::notify::event::RemoveKind;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Comfortably longer than the debounce delay
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Canonical temp dir, as the OS reports events with resolved paths
+    fn temp_dir() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        (temp, root)
+    }
+
+    /// Let the watcher thread register the watch before touching files
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+    }
+
+    async fn next_event<T>(rx: &mut Receiver<T>) -> Option<T> {
+        tokio::time::timeout(TIMEOUT, rx.recv())
+            .await
+            .expect("no event within timeout")
+    }
+
+    #[tokio::test]
+    async fn test_watch_modification() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("doc.md");
+        fs::write(&file, "# One").unwrap();
+
+        let watcher = FileWatcher::new(WatcherConfig::default());
+        let mut rx = watcher.watch(&file).await.unwrap();
+        settle().await;
+
+        fs::write(&file, "# Two").unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Modified));
+    }
+
+    #[tokio::test]
+    async fn test_watch_atomic_save() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("doc.md");
+        fs::write(&file, "# One").unwrap();
+
+        let watcher = FileWatcher::new(WatcherConfig::default());
+        let mut rx = watcher.watch(&file).await.unwrap();
+        settle().await;
+
+        // Write a temp file and rename it over the original, like Vim or JetBrains IDEs
+        let temp_file = root.join(".doc.md.swp");
+        fs::write(&temp_file, "# Two").unwrap();
+        fs::rename(&temp_file, &file).unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Modified));
+
+        // The watch still follows the replaced file
+        fs::write(&file, "# Three").unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Modified));
+    }
+
+    #[tokio::test]
+    async fn test_watch_removal_and_recreation() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("doc.md");
+        fs::write(&file, "# One").unwrap();
+
+        let watcher = FileWatcher::new(WatcherConfig::default());
+        let mut rx = watcher.watch(&file).await.unwrap();
+        settle().await;
+
+        fs::remove_file(&file).unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Removed));
+
+        fs::write(&file, "# Back").unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Modified));
+    }
+
+    #[tokio::test]
+    async fn test_watch_rename() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("draft.md");
+        let renamed = root.join("final.md");
+        fs::write(&file, "# Draft").unwrap();
+
+        let watcher = FileWatcher::new(WatcherConfig::default());
+        let mut rx = watcher.watch(&file).await.unwrap();
+        settle().await;
+
+        fs::rename(&file, &renamed).unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Renamed(renamed)));
+        // The watch ends with the rename
+        assert_eq!(next_event(&mut rx).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_watch_directory_with_watched_file() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("doc.md");
+        fs::write(&file, "# One").unwrap();
+
+        let watcher = FileWatcher::new(WatcherConfig::default());
+        let mut file_rx = watcher.watch(&file).await.unwrap();
+        let mut dir_rx = watcher.watch_directory(&root).await.unwrap();
+        settle().await;
+
+        // Releasing the directory keeps the file watched through its own root
+        fs::write(root.join("other.md"), "# Other").unwrap();
+        assert_eq!(next_event(&mut dir_rx).await, Some(()));
+        watcher.unwatch_directory(&root).await.unwrap();
+        settle().await;
+
+        fs::write(&file, "# Two").unwrap();
+        assert_eq!(next_event(&mut file_rx).await, Some(FileEvent::Modified));
+    }
+
+    /// The polling backend compares modification times in whole seconds
+    async fn settle_polling() {
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+    }
+
+    /// Polling fast enough for tests
+    fn polling_config() -> WatcherConfig {
+        WatcherConfig {
+            backend: WatcherBackend::Polling,
+            poll_interval_ms: 100,
+            debounce_ms: 200,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch_polling() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("doc.md");
+        fs::write(&file, "# One").unwrap();
+
+        let watcher = FileWatcher::new(polling_config());
+        let mut rx = watcher.watch(&file).await.unwrap();
+        settle_polling().await;
+
+        fs::write(&file, "# Two").unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Modified));
+
+        // Polling on request is not a degraded state
+        assert!(!watcher.status().borrow().is_degraded());
+    }
+
+    #[tokio::test]
+    async fn test_configure_keeps_watches() {
+        let (_temp, root) = temp_dir();
+        let file = root.join("doc.md");
+        fs::write(&file, "# One").unwrap();
+
+        let watcher = FileWatcher::new(WatcherConfig::default());
+        let mut rx = watcher.watch(&file).await.unwrap();
+        watcher.configure(polling_config()).await.unwrap();
+        settle_polling().await;
+
+        fs::write(&file, "# Two").unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(FileEvent::Modified));
+    }
+
+    #[tokio::test]
+    async fn test_watch_directory_ignore() {
+        let (_temp, root) = temp_dir();
+        let config = WatcherConfig {
+            ignore: vec!["*.tmp".to_string()],
+            ..Default::default()
+        };
+
+        let watcher = FileWatcher::new(config);
+        let mut rx = watcher.watch_directory(&root).await.unwrap();
+        settle().await;
+
+        fs::write(root.join("scratch.tmp"), "").unwrap();
+        let ignored = tokio::time::timeout(Duration::from_secs(1), rx.recv()).await;
+        assert!(ignored.is_err(), "ignored path reported");
+
+        fs::write(root.join("doc.md"), "# Doc").unwrap();
+        assert_eq!(next_event(&mut rx).await, Some(()));
+    }
+
+    #[test]
+    fn test_watch_status_failed() {
+        let watchers = Arc::new(Mutex::new(Watchers::default()));
+        let mut backends = Backends::new(WatcherConfig::default(), watchers);
+        let missing = PathBuf::from("/nonexistent/markdown-viewer-test");
+
+        backends.sync(HashMap::from([(
+            missing.clone(),
+            RecursiveMode::NonRecursive,
+        )]));
+        let status = backends.status();
+        assert!(status.is_degraded());
+        assert_eq!(status.failed.len(), 1);
+        assert_eq!(status.failed[0].0, missing);
+
+        // Released roots no longer count
+        backends.sync(HashMap::new());
+        assert!(!backends.status().is_degraded());
+    }
+
+    #[test]
+    fn test_file_events_classification() {
+        let (_temp, root) = temp_dir();
+        let modified = root.join("modified.md");
+        let removed = root.join("removed.md");
+        let renamed = root.join("renamed.md");
+        let untouched = root.join("untouched.md");
+        fs::write(&modified, "").unwrap();
+        fs::write(root.join("new-name.md"), "").unwrap();
+
+        let events = vec![
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(modified.clone()),
+            Event::new(EventKind::Remove(RemoveKind::File)).add_path(removed.clone()),
+            Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+                .add_path(renamed.clone())
+                .add_path(root.join("new-name.md")),
+        ];
+        let watched = [
+            modified.clone(),
+            removed.clone(),
+            renamed.clone(),
+            untouched,
+        ];
+
+        let mut result = file_events(&events, watched.iter());
+        result.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            result,
+            vec![
+                (modified, FileEvent::Modified),
+                (removed, FileEvent::Removed),
+                (renamed, FileEvent::Renamed(root.join("new-name.md"))),
+            ]
+        );
+    }
+}
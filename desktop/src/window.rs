@@ -49,7 +49,7 @@ pub fn get_chrome_inset() -> Offset {
 
 pub use child::{
     close_child_windows_for_last_focused, close_child_windows_for_parent,
-    open_or_focus_mermaid_window,
+    open_or_focus_link_graph_window, open_or_focus_mermaid_window,
 };
 pub use main::{
     clear_last_focused_if_matches, close_all_main_windows, create_main_window_config,
@@ -4,12 +4,15 @@ use dioxus::prelude::*;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use crate::assets::MAIN_STYLE;
+use crate::components::link_graph_window::{LinkGraphWindow, LinkGraphWindowProps};
 use crate::components::mermaid_window::{generate_diagram_id, MermaidWindow, MermaidWindowProps};
+use crate::i18n::t;
 use crate::theme::Theme;
 
-use super::index::build_mermaid_window_index;
+use super::index::{build_link_graph_window_index, build_mermaid_window_index};
 use super::main::get_last_focused_window;
 
 struct ChildWindowEntry {
@@ -134,15 +137,15 @@ async fn create_and_register_mermaid_window(
         MermaidWindowProps {
             source,
             diagram_id: diagram_id.clone(),
-            theme,
+            theme: theme.clone(),
         },
     );
 
     let config = Config::new()
         .with_menu(None)
-        .with_window(WindowBuilder::new().with_title("Mermaid Viewer"))
+        .with_window(WindowBuilder::new().with_title(t("mermaid.window_title")))
         .with_custom_head(indoc::formatdoc! {r#"<link rel="stylesheet" href="{MAIN_STYLE}">"#})
-        .with_custom_index(build_mermaid_window_index(theme));
+        .with_custom_index(build_mermaid_window_index(&theme));
 
     let pending = window().new_window(dom, config);
     let ctx = pending.await;
@@ -160,3 +163,70 @@ async fn create_and_register_mermaid_window(
         );
     });
 }
+
+pub fn open_or_focus_link_graph_window(root: PathBuf, theme: Theme) {
+    let key = format!("link-graph:{}", root.display());
+    let parent_id = window().id();
+
+    // Check if window already exists and can be focused
+    let needs_creation = CHILD_WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        windows.retain(|_, state| match state {
+            ChildWindowState::Pending { .. } => true,
+            ChildWindowState::Created(entry) => entry.is_alive(),
+        });
+
+        match windows.get(&key) {
+            Some(ChildWindowState::Created(entry)) => !entry.focus(),
+            Some(ChildWindowState::Pending { .. }) => false,
+            None => {
+                windows.insert(key.clone(), ChildWindowState::Pending { parent_id });
+                true
+            }
+        }
+    });
+
+    if needs_creation {
+        dioxus_core::spawn(create_and_register_link_graph_window(
+            root, key, theme, parent_id,
+        ));
+    }
+}
+
+async fn create_and_register_link_graph_window(
+    root: PathBuf,
+    key: String,
+    theme: Theme,
+    parent_id: WindowId,
+) {
+    let dom = VirtualDom::new_with_props(
+        LinkGraphWindow,
+        LinkGraphWindowProps {
+            root,
+            parent_id,
+            theme: theme.clone(),
+        },
+    );
+
+    let config = Config::new()
+        .with_menu(None)
+        .with_window(WindowBuilder::new().with_title(t("link_graph.window_title")))
+        .with_custom_head(indoc::formatdoc! {r#"<link rel="stylesheet" href="{MAIN_STYLE}">"#})
+        .with_custom_index(build_link_graph_window_index(&theme));
+
+    let pending = window().new_window(dom, config);
+    let ctx = pending.await;
+    let weak_handle = std::rc::Rc::downgrade(&ctx);
+    let window_id = ctx.window.id();
+
+    CHILD_WINDOWS.with(|windows| {
+        windows.borrow_mut().insert(
+            key,
+            ChildWindowState::Created(ChildWindowEntry {
+                handle: weak_handle,
+                window_id,
+                parent_id,
+            }),
+        );
+    });
+}
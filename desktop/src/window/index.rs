@@ -1,7 +1,8 @@
 use crate::theme::{resolve_theme, Theme};
 
-pub fn build_custom_index(theme: Theme) -> String {
+pub fn build_custom_index(theme: &Theme) -> String {
     let resolved = resolve_theme(theme);
+    let reading_theme = reading_theme_attribute(theme);
     indoc::formatdoc! {r#"
     <!DOCTYPE html>
     <html>
@@ -10,7 +11,7 @@ pub fn build_custom_index(theme: Theme) -> String {
             <meta name="viewport" content="width=device-width, initial-scale=1.0, maximum-scale=1.0, user-scalable=no">
             <!-- CUSTOM HEAD -->
         </head>
-        <body data-theme="{resolved}">
+        <body data-theme="{resolved}"{reading_theme}>
             <div id="main"></div>
             <!-- MODULE LOADER -->
         </body>
@@ -18,8 +19,9 @@ pub fn build_custom_index(theme: Theme) -> String {
     "#}
 }
 
-pub(crate) fn build_mermaid_window_index(theme: Theme) -> String {
+pub(crate) fn build_mermaid_window_index(theme: &Theme) -> String {
     let resolved = resolve_theme(theme);
+    let reading_theme = reading_theme_attribute(theme);
     indoc::formatdoc! {r#"
     <!DOCTYPE html>
     <html>
@@ -28,10 +30,37 @@ pub(crate) fn build_mermaid_window_index(theme: Theme) -> String {
             <meta name="viewport" content="width=device-width, initial-scale=1.0">
             <!-- CUSTOM HEAD -->
         </head>
-        <body data-theme="{resolved}" class="mermaid-window-body">
+        <body data-theme="{resolved}"{reading_theme} class="mermaid-window-body">
             <div id="main"></div>
             <!-- MODULE LOADER -->
         </body>
     </html>
     "#}
 }
+
+pub(crate) fn build_link_graph_window_index(theme: &Theme) -> String {
+    let resolved = resolve_theme(theme);
+    let reading_theme = reading_theme_attribute(theme);
+    indoc::formatdoc! {r#"
+    <!DOCTYPE html>
+    <html>
+        <head>
+            <title>Link Graph - Arto</title>
+            <meta name="viewport" content="width=device-width, initial-scale=1.0">
+            <!-- CUSTOM HEAD -->
+        </head>
+        <body data-theme="{resolved}"{reading_theme} class="mermaid-window-body">
+            <div id="main"></div>
+            <!-- MODULE LOADER -->
+        </body>
+    </html>
+    "#}
+}
+
+/// Set the reading theme before the first paint to avoid a flash of the base palette
+fn reading_theme_attribute(theme: &Theme) -> String {
+    theme
+        .reading_theme()
+        .map(|id| format!(r#" data-reading-theme="{id}""#))
+        .unwrap_or_default()
+}
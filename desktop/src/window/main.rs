@@ -36,7 +36,7 @@ pub fn create_main_window_config(params: &CreateMainWindowConfigParams) -> Confi
         // the window appears unstyled for a brief moment.
         .with_custom_head(indoc::formatdoc! {r#"<link rel="stylesheet" href="{MAIN_STYLE}">"#})
         // Use a custom index to set the initial theme correctly
-        .with_custom_index(build_custom_index(params.theme))
+        .with_custom_index(build_custom_index(&params.theme))
 }
 
 /// Parameters for creating a new main window
@@ -233,7 +233,7 @@ pub(crate) async fn create_new_main_window(
         AppProps {
             tab,
             directory,
-            theme: params.theme,
+            theme: params.theme.clone(),
             sidebar_open: params.sidebar_open,
             sidebar_width: params.sidebar_width,
             sidebar_show_all_files: params.sidebar_show_all_files,
@@ -203,8 +203,8 @@ pub fn get_theme_preference(is_first_window: bool) -> ThemePreference {
         is_first_window,
         cfg.theme.on_startup,
         cfg.theme.on_new_window,
-        || cfg.theme.default_theme,
-        || LAST_FOCUSED_STATE.read().theme,
+        || cfg.theme.default_theme.clone(),
+        || LAST_FOCUSED_STATE.read().theme.clone(),
     );
     ThemePreference { theme }
 }
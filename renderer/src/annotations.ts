@@ -0,0 +1,223 @@
+/**
+ * Reader annotations: anchors user highlights to the rendered document.
+ *
+ * Each annotation is stored as a text quote with a short prefix/suffix. After
+ * every render the quote is re-located in the flattened text of
+ * `.markdown-body`, preferring the occurrence whose surrounding text best
+ * matches the stored context, so highlights survive edits to the file.
+ */
+
+/** Text quote selector shared with Rust (`TextAnchor`). */
+export interface TextAnchor {
+  quote: string;
+  prefix: string;
+  suffix: string;
+}
+
+/** Annotation definition from Rust. */
+export interface AnnotationDef {
+  id: string;
+  anchor: TextAnchor;
+  color: "green" | "blue" | "pink" | "orange" | "purple";
+  comment: string;
+}
+
+type AnnotationCallback = (data: { detached: string[] }) => void;
+
+/** Number of characters captured on each side of the quote. */
+const CONTEXT_CHARS = 32;
+
+const HIGHLIGHT_CLASS = "annotation-highlight";
+
+let annotations: AnnotationDef[] = [];
+let callback: AnnotationCallback | null = null;
+
+/**
+ * Collect text nodes that can be annotated, in document order.
+ */
+function collectTextNodes(container: HTMLElement): Text[] {
+  const nodes: Text[] = [];
+  const walker = document.createTreeWalker(container, NodeFilter.SHOW_TEXT, {
+    acceptNode: (node) => {
+      // Diagrams and math are re-rendered asynchronously, so their text is not stable
+      if (node.parentElement?.closest(".mermaid, .preprocessed-mermaid, .katex")) {
+        return NodeFilter.FILTER_REJECT;
+      }
+      return NodeFilter.FILTER_ACCEPT;
+    },
+  });
+
+  let node: Node | null;
+  while ((node = walker.nextNode())) {
+    nodes.push(node as Text);
+  }
+  return nodes;
+}
+
+function commonPrefixLength(a: string, b: string): number {
+  let i = 0;
+  while (i < a.length && i < b.length && a[i] === b[i]) i++;
+  return i;
+}
+
+function commonSuffixLength(a: string, b: string): number {
+  let i = 0;
+  while (i < a.length && i < b.length && a[a.length - 1 - i] === b[b.length - 1 - i]) i++;
+  return i;
+}
+
+/**
+ * Find the best position of an anchor in the flattened text.
+ * Returns null when the quote no longer exists in the document.
+ */
+function locate(text: string, anchor: TextAnchor): { start: number; end: number } | null {
+  if (!anchor.quote) return null;
+
+  let best: { start: number; score: number } | null = null;
+  let index = text.indexOf(anchor.quote);
+  while (index !== -1) {
+    const before = text.slice(Math.max(0, index - anchor.prefix.length), index);
+    const afterStart = index + anchor.quote.length;
+    const after = text.slice(afterStart, afterStart + anchor.suffix.length);
+    const score =
+      commonSuffixLength(before, anchor.prefix) + commonPrefixLength(after, anchor.suffix);
+    if (!best || score > best.score) {
+      best = { start: index, score };
+    }
+    index = text.indexOf(anchor.quote, index + 1);
+  }
+
+  return best ? { start: best.start, end: best.start + anchor.quote.length } : null;
+}
+
+/**
+ * Wrap the text range [start, end) of the flattened text in highlight marks.
+ * A range spanning several text nodes produces one mark per node.
+ */
+function wrapRange(container: HTMLElement, start: number, end: number, def: AnnotationDef): void {
+  let offset = 0;
+  for (const node of collectTextNodes(container)) {
+    const length = node.data.length;
+    const nodeStart = offset;
+    offset += length;
+    if (offset <= start) continue;
+    if (nodeStart >= end) break;
+
+    let target = node;
+    const localStart = Math.max(0, start - nodeStart);
+    const localEnd = Math.min(length, end - nodeStart);
+    if (localEnd < length) target.splitText(localEnd);
+    if (localStart > 0) target = target.splitText(localStart);
+    // Skip whitespace-only segments between block elements
+    if (!target.data.trim()) continue;
+
+    const mark = document.createElement("mark");
+    mark.className = HIGHLIGHT_CLASS;
+    mark.setAttribute("data-annotation-id", def.id);
+    mark.setAttribute("data-color", def.color);
+    if (def.comment) mark.title = def.comment;
+    target.parentNode?.replaceChild(mark, target);
+    mark.appendChild(target);
+  }
+}
+
+function clearHighlights(container: HTMLElement): void {
+  for (const mark of container.querySelectorAll(`.${HIGHLIGHT_CLASS}`)) {
+    const parent = mark.parentNode;
+    if (!parent) continue;
+    // Unwrap instead of replacing with text to keep nested search highlights intact
+    while (mark.firstChild) {
+      parent.insertBefore(mark.firstChild, mark);
+    }
+    parent.removeChild(mark);
+    parent.normalize();
+  }
+}
+
+/**
+ * Describe a DOM range as a text anchor relative to `.markdown-body`.
+ * Returns null when the range is collapsed or outside the document.
+ */
+export function describeRange(range: Range): TextAnchor | null {
+  const container = document.querySelector(".markdown-body") as HTMLElement | null;
+  if (!container || range.collapsed || !container.contains(range.commonAncestorContainer)) {
+    return null;
+  }
+
+  let text = "";
+  let start = -1;
+  let end = -1;
+  for (const node of collectTextNodes(container)) {
+    if (range.intersectsNode(node)) {
+      const localStart = node === range.startContainer ? range.startOffset : 0;
+      const localEnd = node === range.endContainer ? range.endOffset : node.data.length;
+      if (start === -1) start = text.length + localStart;
+      end = text.length + localEnd;
+    }
+    text += node.data;
+  }
+
+  if (start === -1 || end <= start) return null;
+  const quote = text.slice(start, end);
+  if (!quote.trim()) return null;
+
+  return {
+    quote,
+    prefix: text.slice(Math.max(0, start - CONTEXT_CHARS), start),
+    suffix: text.slice(end, end + CONTEXT_CHARS),
+  };
+}
+
+/**
+ * Re-anchor and re-apply all annotation highlights.
+ * Called after DOM content changes, before search highlights are re-applied.
+ */
+export function reapply(): void {
+  const container = document.querySelector(".markdown-body") as HTMLElement | null;
+  if (!container) return;
+
+  clearHighlights(container);
+
+  const detached: string[] = [];
+  for (const def of annotations) {
+    const text = collectTextNodes(container)
+      .map((node) => node.data)
+      .join("");
+    const position = locate(text, def.anchor);
+    if (position) {
+      wrapRange(container, position.start, position.end, def);
+    } else {
+      detached.push(def.id);
+    }
+  }
+
+  callback?.({ detached });
+}
+
+/**
+ * Set the annotations of the current document and re-apply highlights.
+ */
+export function set(defs: AnnotationDef[]): void {
+  annotations = defs;
+  reapply();
+}
+
+export function setup(cb: AnnotationCallback): void {
+  callback = cb;
+}
+
+/**
+ * Scroll to the first highlight of an annotation.
+ */
+export function scrollTo(id: string): void {
+  const target = document.querySelector(`.${HIGHLIGHT_CLASS}[data-annotation-id="${id}"]`);
+  if (!target) return;
+
+  target.scrollIntoView({ behavior: "smooth", block: "center" });
+
+  // Brief highlight effect (shared with pinned search)
+  target.classList.add("pinned-highlight-flash");
+  setTimeout(() => {
+    target.classList.remove("pinned-highlight-flash");
+  }, 500);
+}
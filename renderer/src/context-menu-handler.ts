@@ -3,6 +3,8 @@
  * Detects the type of element that was right-clicked and reports to Rust.
  */
 
+import { type TextAnchor, describeRange } from "./annotations";
+
 export type ContentContextType =
   | { type: "general" }
   | { type: "link"; href: string }
@@ -16,6 +18,13 @@ export interface ContextMenuData {
   y: number;
   has_selection: boolean;
   selected_text: string;
+  /** Anchor of the selection for annotations (null if not annotatable) */
+  selection_anchor: TextAnchor | null;
+  /** ID of the heading of the section under the cursor (null if none) */
+  section_heading: string | null;
+  /** Source position of the block under the cursor (null if unknown) */
+  source_line: number | null;
+  source_column: number | null;
 }
 
 /**
@@ -97,7 +106,11 @@ let savedRange: Range | null = null;
 /**
  * Get the current text selection and save the range for later restoration
  */
-function getTextSelection(): { hasSelection: boolean; selectedText: string } {
+function getTextSelection(): {
+  hasSelection: boolean;
+  selectedText: string;
+  anchor: TextAnchor | null;
+} {
   const selection = window.getSelection();
   const selectedText = selection?.toString() ?? "";
 
@@ -111,6 +124,7 @@ function getTextSelection(): { hasSelection: boolean; selectedText: string } {
   return {
     hasSelection: selectedText.length > 0,
     selectedText,
+    anchor: savedRange ? describeRange(savedRange) : null,
   };
 }
 
@@ -127,6 +141,40 @@ export function restoreSelection(): void {
   }
 }
 
+/**
+ * Find the heading of the section containing the target by walking back from
+ * its top-level block to the nearest preceding heading
+ */
+function findSectionHeading(target: HTMLElement, markdownBody: Element): string | null {
+  let block: Element | null = target;
+  while (block && block.parentElement !== markdownBody) {
+    block = block.parentElement;
+  }
+
+  for (let current = block; current; current = current.previousElementSibling) {
+    if (/^H[1-6]$/.test(current.tagName) && current.id) {
+      return current.id;
+    }
+  }
+  return null;
+}
+
+/**
+ * Capture the current selection for commands run outside the context menu
+ * (e.g. from the command palette). The range is saved for `restoreSelection`.
+ */
+export function captureSelection(): Pick<
+  ContextMenuData,
+  "has_selection" | "selected_text" | "selection_anchor"
+> {
+  const { hasSelection, selectedText, anchor } = getTextSelection();
+  return {
+    has_selection: hasSelection,
+    selected_text: selectedText,
+    selection_anchor: anchor,
+  };
+}
+
 const MENU_MARGIN = 8;
 
 /**
@@ -201,13 +249,18 @@ export function setup(sendToRust: (data: ContextMenuData) => void): void {
     // Detect context and send to Rust
     // Position adjustment is handled by MutationObserver after menu renders
     const context = detectContext(target);
-    const { hasSelection, selectedText } = getTextSelection();
+    const block = target.closest<HTMLElement>("[data-source-line]");
+    const { hasSelection, selectedText, anchor } = getTextSelection();
     const data: ContextMenuData = {
       context,
       x: event.clientX,
       y: event.clientY,
       has_selection: hasSelection,
       selected_text: selectedText,
+      selection_anchor: anchor,
+      section_heading: findSectionHeading(target, markdownBody),
+      source_line: block ? Number(block.dataset.sourceLine) : null,
+      source_column: block ? Number(block.dataset.sourceColumn) : null,
     };
 
     sendToRust(data);
@@ -1,11 +1,21 @@
+/**
+ * How a pattern is matched: plain text, whole word or regular expression.
+ */
+export type PatternKind = "text" | "word" | "regex";
+
 /**
  * Pinned search definition from Rust.
+ *
+ * Only searches that apply to the current file and have a valid pattern are
+ * sent; scope and validation are handled on the Rust side.
  */
 export interface PinnedSearchDef {
   /** Unique identifier */
   id: string;
-  /** Search pattern (plain text) */
+  /** Search pattern (interpreted according to `kind`) */
   pattern: string;
+  /** How the pattern is matched */
+  kind: PatternKind;
   /** Highlight color: green, blue, pink, orange, purple */
   color: "green" | "blue" | "pink" | "orange" | "purple";
   /** Case-sensitive matching */
@@ -16,6 +26,9 @@ export interface PinnedSearchDef {
 
 interface SearchState {
   query: string;
+  kind: PatternKind;
+  /** Compile error of the query, shown in the search bar */
+  error: string | null;
   currentIndex: number;
   highlightElements: HTMLElement[];
   // Pinned search state
@@ -25,6 +38,8 @@ interface SearchState {
 
 const state: SearchState = {
   query: "",
+  kind: "text",
+  error: null,
   currentIndex: 0,
   highlightElements: [],
   pinnedSearches: [],
@@ -63,19 +78,86 @@ type SearchCallback = (data: {
   count: number;
   current: number;
   query: string;
+  error: string | null;
   matches: SearchMatch[];
   pinnedMatches: Record<string, SearchMatch[]>;
 }) => void;
 
 let callback: SearchCallback | null = null;
 
+/**
+ * Escape a string for use in a regular expression.
+ */
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+}
+
+/**
+ * Build a global regular expression for word and regex patterns.
+ * Returns null for plain text (matched with indexOf).
+ * Throws a SyntaxError for invalid patterns.
+ */
+function buildRegExp(query: string, kind: PatternKind, caseSensitive: boolean): RegExp | null {
+  const flags = caseSensitive ? "g" : "gi";
+  switch (kind) {
+    case "regex":
+      return new RegExp(query, flags);
+    case "word":
+      // \b is ASCII-only, so use Unicode-aware lookarounds instead
+      return new RegExp(
+        `(?<![\\p{L}\\p{N}_])${escapeRegExp(query)}(?![\\p{L}\\p{N}_])`,
+        `${flags}u`,
+      );
+    case "text":
+      return null;
+  }
+}
+
+/**
+ * Find all matches of a query in a text.
+ */
+function findMatches(
+  text: string,
+  query: string,
+  caseSensitive: boolean,
+  regexp: RegExp | null,
+): { start: number; end: number }[] {
+  const matches: { start: number; end: number }[] = [];
+
+  if (regexp) {
+    regexp.lastIndex = 0;
+    let match: RegExpExecArray | null;
+    while ((match = regexp.exec(text)) !== null) {
+      if (match[0].length === 0) {
+        // Skip empty matches to avoid looping forever
+        regexp.lastIndex++;
+        continue;
+      }
+      matches.push({ start: match.index, end: match.index + match[0].length });
+    }
+    return matches;
+  }
+
+  const textToSearch = caseSensitive ? text : text.toLowerCase();
+  const queryToMatch = caseSensitive ? query : query.toLowerCase();
+  let startIndex = 0;
+  while (true) {
+    const index = textToSearch.indexOf(queryToMatch, startIndex);
+    if (index === -1) break;
+    matches.push({ start: index, end: index + query.length });
+    startIndex = index + 1;
+  }
+  return matches;
+}
+
 /**
  * Apply highlights for a search query.
- * Returns the highlight elements created.
+ * Returns the highlight elements created; throws for invalid patterns.
  */
 function applyHighlights(
   container: HTMLElement,
   query: string,
+  kind: PatternKind,
   caseSensitive: boolean,
   className: string,
   dataAttributes?: Record<string, string>,
@@ -84,6 +166,8 @@ function applyHighlights(
     return [];
   }
 
+  const regexp = buildRegExp(query, kind, caseSensitive);
+
   const textNodes: Text[] = [];
   const walker = document.createTreeWalker(container, NodeFilter.SHOW_TEXT, {
     acceptNode: (node) => {
@@ -106,23 +190,12 @@ function applyHighlights(
     textNodes.push(node as Text);
   }
 
-  const queryToMatch = caseSensitive ? query : query.toLowerCase();
   const elements: HTMLElement[] = [];
 
   // Process each text node
   for (const textNode of textNodes) {
     const text = textNode.textContent || "";
-    const textToSearch = caseSensitive ? text : text.toLowerCase();
-    let startIndex = 0;
-
-    // Find all matches in this text node
-    const matches: { start: number; end: number }[] = [];
-    while (true) {
-      const index = textToSearch.indexOf(queryToMatch, startIndex);
-      if (index === -1) break;
-      matches.push({ start: index, end: index + query.length });
-      startIndex = index + 1;
-    }
+    const matches = findMatches(text, query, caseSensitive, regexp);
 
     if (matches.length === 0) continue;
 
@@ -168,26 +241,40 @@ function applyHighlights(
   return elements;
 }
 
-function highlightMatches(container: HTMLElement, query: string): number {
+function highlightMatches(container: HTMLElement, query: string, kind: PatternKind): number {
   // Clear existing search highlights first (not pinned)
   clearSearchHighlights();
 
-  state.highlightElements = applyHighlights(container, query, false, "search-highlight");
+  try {
+    state.highlightElements = applyHighlights(container, query, kind, false, "search-highlight");
+    state.error = null;
+  } catch (error) {
+    // Reported to Rust, which shows it on the search input
+    state.error = error instanceof Error ? error.message : String(error);
+  }
 
   return state.highlightElements.length;
 }
 
+/**
+ * Remove a highlight mark while keeping its children.
+ * Children may include annotation highlights, which must survive search changes.
+ */
+function unwrapMark(mark: HTMLElement): void {
+  const parent = mark.parentNode;
+  if (!parent) return;
+  while (mark.firstChild) {
+    parent.insertBefore(mark.firstChild, mark);
+  }
+  parent.removeChild(mark);
+  // Normalize to merge adjacent text nodes
+  parent.normalize();
+}
+
 function clearSearchHighlights(): void {
   // Remove all search highlight marks and restore original text
   for (const mark of state.highlightElements) {
-    const parent = mark.parentNode;
-    if (parent) {
-      // Replace mark with its text content
-      const textNode = document.createTextNode(mark.textContent || "");
-      parent.replaceChild(textNode, mark);
-      // Normalize to merge adjacent text nodes
-      parent.normalize();
-    }
+    unwrapMark(mark);
   }
   state.highlightElements = [];
   state.currentIndex = 0;
@@ -197,12 +284,7 @@ function clearPinnedHighlights(): void {
   // Remove all pinned highlight marks (including disabled ones)
   for (const elements of state.pinnedHighlights.values()) {
     for (const mark of elements) {
-      const parent = mark.parentNode;
-      if (parent) {
-        const textNode = document.createTextNode(mark.textContent || "");
-        parent.replaceChild(textNode, mark);
-        parent.normalize();
-      }
+      unwrapMark(mark);
     }
   }
   state.pinnedHighlights.clear();
@@ -247,26 +329,33 @@ function applyPinnedHighlights(): void {
   for (const pinned of state.pinnedSearches) {
     // Use invisible class for disabled searches (DOM exists, but no visual highlight)
     const className = pinned.disabled ? "pinned-highlight-disabled" : "pinned-highlight";
-    const elements = applyHighlights(
-      container as HTMLElement,
-      pinned.pattern,
-      pinned.caseSensitive,
-      className,
-      { "data-color": pinned.color, "data-pinned-id": pinned.id },
-    );
-    state.pinnedHighlights.set(pinned.id, elements);
+    try {
+      const elements = applyHighlights(
+        container as HTMLElement,
+        pinned.pattern,
+        pinned.kind,
+        pinned.caseSensitive,
+        className,
+        { "data-color": pinned.color, "data-pinned-id": pinned.id },
+      );
+      state.pinnedHighlights.set(pinned.id, elements);
+    } catch (error) {
+      // Patterns are validated in Rust before they are sent
+      console.warn(`Invalid pinned search pattern: ${pinned.pattern}`, error);
+    }
   }
 }
 
-export function find(query: string): void {
+export function find(query: string, kind: PatternKind = "text"): void {
   state.query = query;
+  state.kind = kind;
   const container = document.querySelector(".markdown-body");
   if (!container) {
-    callback?.({ count: 0, current: 0, query: "", matches: [], pinnedMatches: {} });
+    callback?.({ count: 0, current: 0, query: "", error: null, matches: [], pinnedMatches: {} });
     return;
   }
 
-  const count = highlightMatches(container as HTMLElement, query);
+  const count = highlightMatches(container as HTMLElement, query, kind);
   state.currentIndex = count > 0 ? 0 : -1;
 
   // Activate first match (no auto-scroll to avoid focus issues with IME)
@@ -276,7 +365,14 @@ export function find(query: string): void {
 
   const matches = collectSearchMatches();
   const pinnedMatches = collectPinnedMatches();
-  callback?.({ count, current: count > 0 ? 1 : 0, query: state.query, matches, pinnedMatches });
+  callback?.({
+    count,
+    current: count > 0 ? 1 : 0,
+    query: state.query,
+    error: state.error,
+    matches,
+    pinnedMatches,
+  });
 }
 
 export function navigate(direction: "next" | "prev"): void {
@@ -287,6 +383,7 @@ export function navigate(direction: "next" | "prev"): void {
     count: state.highlightElements.length,
     current,
     query: state.query,
+    error: state.error,
     matches,
     pinnedMatches,
   });
@@ -294,9 +391,10 @@ export function navigate(direction: "next" | "prev"): void {
 
 export function clear(): void {
   state.query = "";
+  state.error = null;
   clearSearchHighlights();
   const pinnedMatches = collectPinnedMatches();
-  callback?.({ count: 0, current: 0, query: "", matches: [], pinnedMatches });
+  callback?.({ count: 0, current: 0, query: "", error: null, matches: [], pinnedMatches });
 }
 
 export function setup(cb: SearchCallback): void {
@@ -313,11 +411,11 @@ export function reapply(): void {
 
   // Then re-apply search if there's a query
   if (state.query) {
-    find(state.query);
+    find(state.query, state.kind);
   } else {
     // Just notify with pinned matches
     const pinnedMatches = collectPinnedMatches();
-    callback?.({ count: 0, current: 0, query: "", matches: [], pinnedMatches });
+    callback?.({ count: 0, current: 0, query: "", error: null, matches: [], pinnedMatches });
   }
 }
 
@@ -348,6 +446,7 @@ export function navigateTo(index: number): void {
     count: state.highlightElements.length,
     current: newCurrent,
     query: state.query,
+    error: state.error,
     matches,
     pinnedMatches,
   });
@@ -366,6 +465,7 @@ export function setPinned(pinned: PinnedSearchDef[]): void {
     count: state.highlightElements.length,
     current: state.currentIndex >= 0 ? state.currentIndex + 1 : 0,
     query: state.query,
+    error: state.error,
     matches: collectSearchMatches(),
     pinnedMatches,
   });
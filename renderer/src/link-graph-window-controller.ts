@@ -0,0 +1,403 @@
+const SVG_NS = "http://www.w3.org/2000/svg";
+
+/** Minimum pointer movement (px) before a press on a node becomes a pan */
+const DRAG_THRESHOLD = 3;
+
+/** Layout iterations for a fresh layout; incremental updates run fewer */
+const LAYOUT_ITERATIONS = 300;
+const UPDATE_ITERATIONS = 80;
+
+export interface GraphNode {
+  path: string;
+  label: string;
+  incoming: number;
+  outgoing: number;
+  orphan: boolean;
+}
+
+export interface GraphData {
+  nodes: GraphNode[];
+  edges: [number, number][];
+}
+
+interface Point {
+  x: number;
+  y: number;
+}
+
+interface ViewerState {
+  scale: number;
+  offsetX: number;
+  offsetY: number;
+  isDragging: boolean;
+  moved: number;
+  lastMouseX: number;
+  lastMouseY: number;
+}
+
+class LinkGraphWindowController {
+  #container: HTMLElement | null = null;
+  #svg: SVGSVGElement | null = null;
+  #viewport: SVGGElement | null = null;
+  #data: GraphData = { nodes: [], edges: [] };
+  /** Node positions by path, kept across updates so the graph stays stable */
+  #positions = new Map<string, Point>();
+  #maxZoom: number = 20.0;
+  #state: ViewerState = {
+    scale: 1.0,
+    offsetX: 0,
+    offsetY: 0,
+    isDragging: false,
+    moved: 0,
+    lastMouseX: 0,
+    lastMouseY: 0,
+  };
+
+  init(data: GraphData): void {
+    this.#container = document.getElementById("link-graph-canvas");
+    if (!this.#container) {
+      throw new Error("Link graph container not found");
+    }
+
+    this.#svg = document.createElementNS(SVG_NS, "svg");
+    this.#svg.classList.add("link-graph-svg");
+    this.#svg.innerHTML = `
+      <defs>
+        <marker id="link-graph-arrow" viewBox="0 0 10 10" refX="10" refY="5"
+                markerWidth="6" markerHeight="6" orient="auto-start-reverse">
+          <path d="M 0 0 L 10 5 L 0 10 z" class="link-graph-arrow"></path>
+        </marker>
+      </defs>`;
+    this.#viewport = document.createElementNS(SVG_NS, "g");
+    this.#svg.appendChild(this.#viewport);
+    this.#container.appendChild(this.#svg);
+
+    this.#setupEventListeners();
+
+    // Colors come from CSS variables, so only the body attribute needs updating
+    document.addEventListener("arto:theme-changed", ((event: CustomEvent) => {
+      document.body.setAttribute("data-theme", event.detail);
+    }) as EventListener);
+
+    this.update(data);
+    this.#fitToWindow();
+  }
+
+  /** Replace the graph, keeping the positions of nodes that are still present */
+  update(data: GraphData): void {
+    const isFirstLayout = this.#positions.size === 0;
+    this.#data = data;
+    this.#layout(isFirstLayout ? LAYOUT_ITERATIONS : UPDATE_ITERATIONS);
+    this.#render();
+  }
+
+  /** Standalone SVG of the whole graph with the current colors inlined */
+  exportSvg(): string | null {
+    if (!this.#svg || !this.#viewport) return null;
+
+    const bbox = this.#viewport.getBBox();
+    const padding = 20;
+    const clone = this.#svg.cloneNode(true) as SVGSVGElement;
+    clone.setAttribute("xmlns", SVG_NS);
+    clone.setAttribute(
+      "viewBox",
+      `${bbox.x - padding} ${bbox.y - padding} ${bbox.width + padding * 2} ${bbox.height + padding * 2}`,
+    );
+    clone.setAttribute("width", String(bbox.width + padding * 2));
+    clone.setAttribute("height", String(bbox.height + padding * 2));
+    clone.querySelector("g")?.removeAttribute("transform");
+
+    const styles = getComputedStyle(this.#container ?? document.body);
+    const color = (name: string, fallback: string) =>
+      styles.getPropertyValue(name).trim() || fallback;
+    const style = document.createElementNS(SVG_NS, "style");
+    style.textContent = `
+      .link-graph-edge { stroke: ${color("--link-graph-edge", "#999")}; stroke-width: 1; }
+      .link-graph-arrow { fill: ${color("--link-graph-edge", "#999")}; }
+      .link-graph-node circle { fill: ${color("--link-graph-node", "#0969da")}; }
+      .link-graph-node.orphan circle { fill: none; stroke: ${color("--link-graph-orphan", "#cf222e")}; stroke-dasharray: 3 2; }
+      .link-graph-node text { fill: ${color("--link-graph-label", "#1f2328")}; font: 11px sans-serif; }
+    `;
+    clone.insertBefore(style, clone.firstChild);
+
+    return new XMLSerializer().serializeToString(clone);
+  }
+
+  /** Force-directed layout: nodes repel each other, edges pull linked nodes together */
+  #layout(iterations: number): void {
+    const nodes = this.#data.nodes;
+    if (nodes.length === 0) return;
+
+    const ideal = 80;
+    const positions = nodes.map((node, index) => {
+      const known = this.#positions.get(node.path);
+      if (known) return { ...known };
+      // New nodes start near a linked node when there is one, otherwise on a circle
+      const neighbor = this.#data.edges.find(([s, t]) => s === index || t === index);
+      const anchor =
+        neighbor && this.#positions.get(nodes[neighbor[0] === index ? neighbor[1] : neighbor[0]].path);
+      const angle = (index / nodes.length) * Math.PI * 2;
+      const radius = anchor ? ideal : ideal * Math.sqrt(nodes.length);
+      return {
+        x: (anchor?.x ?? 0) + Math.cos(angle) * radius,
+        y: (anchor?.y ?? 0) + Math.sin(angle) * radius,
+      };
+    });
+
+    for (let step = 0; step < iterations; step++) {
+      const temperature = ideal * (1 - step / iterations);
+      const forces = positions.map(() => ({ x: 0, y: 0 }));
+
+      for (let i = 0; i < positions.length; i++) {
+        for (let j = i + 1; j < positions.length; j++) {
+          const dx = positions[i].x - positions[j].x;
+          const dy = positions[i].y - positions[j].y;
+          const distance = Math.max(Math.hypot(dx, dy), 0.01);
+          const force = (ideal * ideal) / distance;
+          forces[i].x += (dx / distance) * force;
+          forces[i].y += (dy / distance) * force;
+          forces[j].x -= (dx / distance) * force;
+          forces[j].y -= (dy / distance) * force;
+        }
+      }
+
+      for (const [source, target] of this.#data.edges) {
+        const dx = positions[source].x - positions[target].x;
+        const dy = positions[source].y - positions[target].y;
+        const distance = Math.max(Math.hypot(dx, dy), 0.01);
+        const force = (distance * distance) / ideal;
+        forces[source].x -= (dx / distance) * force;
+        forces[source].y -= (dy / distance) * force;
+        forces[target].x += (dx / distance) * force;
+        forces[target].y += (dy / distance) * force;
+      }
+
+      // Weak gravity keeps disconnected parts from drifting apart
+      positions.forEach((position, index) => {
+        forces[index].x -= position.x * 0.05;
+        forces[index].y -= position.y * 0.05;
+
+        const length = Math.max(Math.hypot(forces[index].x, forces[index].y), 0.01);
+        const move = Math.min(length, temperature);
+        position.x += (forces[index].x / length) * move;
+        position.y += (forces[index].y / length) * move;
+      });
+    }
+
+    this.#positions = new Map(nodes.map((node, index) => [node.path, positions[index]]));
+  }
+
+  #render(): void {
+    if (!this.#viewport) return;
+    this.#viewport.replaceChildren();
+
+    const nodes = this.#data.nodes;
+    const radius = (node: GraphNode) => 4 + Math.min(Math.sqrt(node.incoming) * 2, 10);
+
+    const edgeGroup = document.createElementNS(SVG_NS, "g");
+    for (const [source, target] of this.#data.edges) {
+      const from = this.#positions.get(nodes[source].path)!;
+      const to = this.#positions.get(nodes[target].path)!;
+      // Stop the arrow at the target's circle
+      const distance = Math.max(Math.hypot(to.x - from.x, to.y - from.y), 0.01);
+      const shorten = radius(nodes[target]) + 2;
+      const line = document.createElementNS(SVG_NS, "line");
+      line.classList.add("link-graph-edge");
+      line.dataset.source = String(source);
+      line.dataset.target = String(target);
+      line.setAttribute("x1", String(from.x));
+      line.setAttribute("y1", String(from.y));
+      line.setAttribute("x2", String(to.x - ((to.x - from.x) / distance) * shorten));
+      line.setAttribute("y2", String(to.y - ((to.y - from.y) / distance) * shorten));
+      line.setAttribute("marker-end", "url(#link-graph-arrow)");
+      edgeGroup.appendChild(line);
+    }
+    this.#viewport.appendChild(edgeGroup);
+
+    const nodeGroup = document.createElementNS(SVG_NS, "g");
+    nodes.forEach((node, index) => {
+      const position = this.#positions.get(node.path)!;
+      const group = document.createElementNS(SVG_NS, "g");
+      group.classList.add("link-graph-node");
+      group.classList.toggle("orphan", node.orphan);
+      group.dataset.index = String(index);
+      group.dataset.path = node.path;
+      group.setAttribute("transform", `translate(${position.x}, ${position.y})`);
+
+      const title = document.createElementNS(SVG_NS, "title");
+      title.textContent = `${node.path}\n${node.incoming} in, ${node.outgoing} out`;
+      const circle = document.createElementNS(SVG_NS, "circle");
+      circle.setAttribute("r", String(radius(node)));
+      const label = document.createElementNS(SVG_NS, "text");
+      label.setAttribute("x", String(radius(node) + 4));
+      label.setAttribute("dy", "0.35em");
+      label.textContent = node.label;
+
+      group.append(title, circle, label);
+      nodeGroup.appendChild(group);
+    });
+    this.#viewport.appendChild(nodeGroup);
+
+    this.#updateTransform();
+  }
+
+  #setupEventListeners(): void {
+    if (!this.#container) return;
+
+    document.addEventListener("keydown", this.#handleKeyDown.bind(this));
+    this.#container.addEventListener("mousedown", this.#handleMouseDown.bind(this));
+    document.addEventListener("mousemove", this.#handleMouseMove.bind(this));
+    document.addEventListener("mouseup", this.#handleMouseUp.bind(this));
+    this.#container.addEventListener("wheel", this.#handleWheel.bind(this), { passive: false });
+    this.#container.addEventListener("dblclick", () => this.#fitToWindow());
+    this.#container.addEventListener("mouseover", this.#handleMouseOver.bind(this));
+  }
+
+  #handleKeyDown(event: KeyboardEvent): void {
+    if (!(event.metaKey || event.ctrlKey)) return;
+
+    if (event.key === "=" || event.key === "+") {
+      event.preventDefault();
+      this.#zoomAt(1.1);
+    } else if (event.key === "-") {
+      event.preventDefault();
+      this.#zoomAt(1 / 1.1);
+    } else if (event.key === "0") {
+      event.preventDefault();
+      this.#fitToWindow();
+    }
+  }
+
+  #handleMouseDown(event: MouseEvent): void {
+    if (event.button !== 0) return;
+    this.#state.isDragging = true;
+    this.#state.moved = 0;
+    this.#state.lastMouseX = event.clientX;
+    this.#state.lastMouseY = event.clientY;
+  }
+
+  #handleMouseMove(event: MouseEvent): void {
+    if (!this.#state.isDragging) return;
+
+    const dx = event.clientX - this.#state.lastMouseX;
+    const dy = event.clientY - this.#state.lastMouseY;
+    this.#state.moved += Math.abs(dx) + Math.abs(dy);
+    this.#state.offsetX += dx;
+    this.#state.offsetY += dy;
+    this.#state.lastMouseX = event.clientX;
+    this.#state.lastMouseY = event.clientY;
+
+    if (this.#state.moved > DRAG_THRESHOLD && this.#container) {
+      this.#container.style.cursor = "grabbing";
+    }
+    this.#updateTransform();
+  }
+
+  #handleMouseUp(event: MouseEvent): void {
+    if (!this.#state.isDragging) return;
+    this.#state.isDragging = false;
+    if (this.#container) {
+      this.#container.style.cursor = "";
+    }
+
+    // A press without movement on a node opens it
+    if (this.#state.moved <= DRAG_THRESHOLD) {
+      const node = (event.target as Element | null)?.closest?.(".link-graph-node");
+      const path = node?.getAttribute("data-path");
+      if (path) {
+        window.handleLinkGraphNodeClick(path);
+      }
+    }
+  }
+
+  /** Highlight the links of the hovered node */
+  #handleMouseOver(event: MouseEvent): void {
+    const node = (event.target as Element | null)?.closest?.(".link-graph-node");
+    const index = node?.getAttribute("data-index") ?? null;
+    this.#svg?.classList.toggle("has-focus", index !== null);
+    this.#svg?.querySelectorAll<SVGLineElement>(".link-graph-edge").forEach((line) => {
+      line.classList.toggle(
+        "focused",
+        index !== null && (line.dataset.source === index || line.dataset.target === index),
+      );
+    });
+  }
+
+  #handleWheel(event: WheelEvent): void {
+    event.preventDefault();
+    if (!this.#container) return;
+
+    const deltaScale = event.deltaMode === WheelEvent.DOM_DELTA_LINE ? 10 : 1;
+    const factor = Math.exp(-event.deltaY * deltaScale * 0.01);
+    const rect = this.#container.getBoundingClientRect();
+    this.#zoomAt(factor, event.clientX - rect.left, event.clientY - rect.top);
+  }
+
+  /** Zoom by `factor`, keeping the given point (default: center) in place */
+  #zoomAt(factor: number, x?: number, y?: number): void {
+    if (!this.#container) return;
+
+    const oldScale = this.#state.scale;
+    const newScale = Math.max(0.05, Math.min(this.#maxZoom, oldScale * factor));
+    if (newScale === oldScale) return;
+
+    const pointX = x ?? this.#container.clientWidth / 2;
+    const pointY = y ?? this.#container.clientHeight / 2;
+    this.#state.offsetX = pointX - ((pointX - this.#state.offsetX) / oldScale) * newScale;
+    this.#state.offsetY = pointY - ((pointY - this.#state.offsetY) / oldScale) * newScale;
+    this.#state.scale = newScale;
+
+    this.#updateTransform();
+    this.#updateZoomDisplay();
+  }
+
+  #fitToWindow(): void {
+    if (!this.#container || !this.#viewport || this.#data.nodes.length === 0) return;
+
+    const bbox = this.#viewport.getBBox();
+    const padding = 40;
+    const availableWidth = this.#container.clientWidth - padding * 2;
+    const availableHeight = this.#container.clientHeight - padding * 2;
+    const scale = Math.min(
+      availableWidth / Math.max(bbox.width, 1),
+      availableHeight / Math.max(bbox.height, 1),
+      2,
+    );
+
+    this.#state.scale = scale;
+    this.#state.offsetX = (this.#container.clientWidth - bbox.width * scale) / 2 - bbox.x * scale;
+    this.#state.offsetY = (this.#container.clientHeight - bbox.height * scale) / 2 - bbox.y * scale;
+
+    this.#updateTransform();
+    this.#updateZoomDisplay();
+  }
+
+  #updateTransform(): void {
+    this.#viewport?.setAttribute(
+      "transform",
+      `translate(${this.#state.offsetX}, ${this.#state.offsetY}) scale(${this.#state.scale})`,
+    );
+  }
+
+  #updateZoomDisplay(): void {
+    window.updateZoomLevel(Math.round(this.#state.scale * 100));
+  }
+}
+
+// Global instance
+let controller: LinkGraphWindowController | null = null;
+
+declare global {
+  interface Window {
+    handleLinkGraphNodeClick: (path: string) => void;
+    linkGraphController?: LinkGraphWindowController;
+  }
+}
+
+export function initLinkGraphWindow(data: GraphData): void {
+  controller = new LinkGraphWindowController();
+  controller.init(data);
+
+  // Expose globally for Rust to call
+  window.linkGraphController = controller;
+}
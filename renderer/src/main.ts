@@ -5,8 +5,15 @@ import * as markdownViewer from "./markdown-viewer";
 import * as syntaxHighlighter from "./syntax-highlighter";
 import * as mermaidRenderer from "./mermaid-renderer";
 import { renderCoordinator } from "./render-coordinator";
-import { setup as setupContextMenu, restoreSelection } from "./context-menu-handler";
+import {
+  setup as setupContextMenu,
+  restoreSelection,
+  captureSelection,
+} from "./context-menu-handler";
 import * as findInPage from "./find-in-page";
+import * as annotations from "./annotations";
+import * as vimNavigation from "./vim-navigation";
+import * as scrollSync from "./scroll-sync";
 
 // Declare global Arto namespace
 declare global {
@@ -14,6 +21,7 @@ declare global {
     Arto: {
       setupContextMenu: typeof setupContextMenu;
       restoreSelection: typeof restoreSelection;
+      captureSelection: typeof captureSelection;
       /** Register a callback to be called when rendering (Mermaid, KaTeX, etc.) completes */
       onRenderComplete: (callback: () => void) => void;
       search: {
@@ -26,6 +34,16 @@ declare global {
         setPinned: typeof findInPage.setPinned;
         scrollToPinnedMatch: typeof findInPage.scrollToPinnedMatch;
       };
+      annotations: {
+        setup: typeof annotations.setup;
+        set: typeof annotations.set;
+        reapply: typeof annotations.reapply;
+        scrollTo: typeof annotations.scrollTo;
+      };
+      vim: {
+        setEnabled: typeof vimNavigation.setEnabled;
+        setHeadings: typeof vimNavigation.setHeadings;
+      };
     };
   }
 }
@@ -54,11 +72,13 @@ export function init(): void {
   syntaxHighlighter.mount();
   mermaidRenderer.init();
   renderCoordinator.init();
+  scrollSync.setup();
 
   // Expose Arto API on window for Rust interop
   window.Arto = {
     setupContextMenu,
     restoreSelection,
+    captureSelection,
     onRenderComplete: (callback) => renderCoordinator.onRenderComplete(callback),
     search: {
       setup: findInPage.setup,
@@ -70,6 +90,16 @@ export function init(): void {
       setPinned: findInPage.setPinned,
       scrollToPinnedMatch: findInPage.scrollToPinnedMatch,
     },
+    annotations: {
+      setup: annotations.setup,
+      set: annotations.set,
+      reapply: annotations.reapply,
+      scrollTo: annotations.scrollTo,
+    },
+    vim: {
+      setEnabled: vimNavigation.setEnabled,
+      setHeadings: vimNavigation.setHeadings,
+    },
   };
 
   // Listen for theme changes from Rust
@@ -83,3 +113,6 @@ export function init(): void {
 
 // Re-export mermaid window functions
 export { initMermaidWindow } from "./mermaid-window-controller";
+
+// Re-export link graph window functions
+export { initLinkGraphWindow } from "./link-graph-window-controller";
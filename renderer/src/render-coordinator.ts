@@ -124,12 +124,14 @@ class RenderCoordinator {
     this.#isRendering = true;
 
     const markdownBodies = document.querySelectorAll(".markdown-body");
-    if (markdownBodies.length === 0) {
+    const sourceViews = document.querySelectorAll(".source-view");
+    if (markdownBodies.length === 0 && sourceViews.length === 0) {
       this.#isRendering = false;
       return;
     }
 
     try {
+      sourceViews.forEach((sourceView) => syntaxHighlighter.highlightCodeBlocks(sourceView));
       await Promise.all(
         Array.from(markdownBodies).map(async (markdownBody) => {
           mathRenderer.renderMath(markdownBody);
@@ -0,0 +1,92 @@
+/**
+ * Scroll synchronization of the side-by-side view.
+ *
+ * Top-level blocks of the rendered document carry `data-source-line`, their
+ * first source line (from pulldown-cmark offsets in Rust). Positions between
+ * two blocks are interpolated, so scrolling either pane brings the same part
+ * of the document into view in the other.
+ */
+
+/**
+ * Source line and offset of a rendered block within its pane.
+ *
+ * Offsets are relative to the panes, which are the offset parents.
+ */
+interface Anchor {
+  line: number;
+  top: number;
+}
+
+/** Pane scrolled by us, whose scroll events must not be echoed back. */
+let syncing: Element | null = null;
+
+function collectAnchors(rendered: HTMLElement, lineCount: number): Anchor[] {
+  const anchors: Anchor[] = [{ line: 1, top: 0 }];
+  rendered.querySelectorAll<HTMLElement>(".markdown-body > [data-source-line]").forEach((block) => {
+    anchors.push({ line: Number(block.dataset.sourceLine), top: block.offsetTop });
+  });
+  anchors.push({ line: lineCount + 1, top: rendered.scrollHeight });
+
+  // Keep both coordinates increasing so interpolation never divides by zero
+  return anchors.reduce<Anchor[]>((kept, anchor) => {
+    const last = kept[kept.length - 1];
+    if (!last || (anchor.line > last.line && anchor.top > last.top)) kept.push(anchor);
+    return kept;
+  }, []);
+}
+
+/** Map `value` from the `from` coordinate of the anchors to the `to` one. */
+function interpolate(anchors: Anchor[], value: number, from: keyof Anchor, to: keyof Anchor): number {
+  for (let i = 1; i < anchors.length; i++) {
+    const [a, b] = [anchors[i - 1], anchors[i]];
+    if (value < b[from] || i === anchors.length - 1) {
+      const ratio = Math.min(Math.max((value - a[from]) / (b[from] - a[from]), 0), 1);
+      return a[to] + ratio * (b[to] - a[to]);
+    }
+  }
+  return anchors[0]?.[to] ?? 0;
+}
+
+function onScroll(event: Event): void {
+  const pane = event.target;
+  if (!(pane instanceof HTMLElement) || pane === syncing) return;
+
+  const view = pane.parentElement;
+  if (!view?.classList.contains("split-view")) return;
+  const source = view.querySelector<HTMLElement>(".source-pane");
+  const rendered = view.querySelector<HTMLElement>(".rendered-pane");
+  const code = source?.querySelector<HTMLElement>(".source-code");
+  if (!source || !rendered || !code) return;
+
+  const lineHeight = parseFloat(getComputedStyle(code).lineHeight) || 20;
+  const lineCount = Math.round(code.offsetHeight / lineHeight);
+  const anchors = collectAnchors(rendered, lineCount);
+  if (anchors.length < 2) return;
+
+  let target: HTMLElement;
+  let top: number;
+  if (pane === source) {
+    const line = (source.scrollTop - code.offsetTop) / lineHeight + 1;
+    target = rendered;
+    top = interpolate(anchors, line, "line", "top");
+  } else if (pane === rendered) {
+    const line = interpolate(anchors, rendered.scrollTop, "top", "line");
+    target = source;
+    top = code.offsetTop + (line - 1) * lineHeight;
+  } else {
+    return;
+  }
+
+  if (Math.abs(target.scrollTop - top) < 1) return;
+  syncing = target;
+  target.scrollTop = top;
+  // Scroll events of this frame are dispatched before animation frame callbacks
+  requestAnimationFrame(() => {
+    syncing = null;
+  });
+}
+
+export function setup(): void {
+  // Scroll events do not bubble, so listen in the capture phase
+  document.addEventListener("scroll", onScroll, true);
+}
@@ -0,0 +1,275 @@
+/**
+ * Vim-style keyboard navigation of the document.
+ *
+ * Keys are only handled while the document (not an input, the sidebar tree or
+ * the tab bar) has focus, and never with Cmd/Ctrl/Alt held so menu shortcuts
+ * keep working. Heading ids are provided by Rust from the TOC.
+ */
+
+import * as findInPage from "./find-in-page";
+
+/** Distance scrolled by j/k in pixels. */
+const LINE_SCROLL = 60;
+
+/** Time to wait for the second key of a sequence (gg, ]], [[). */
+const SEQUENCE_TIMEOUT_MS = 800;
+
+/** Characters used to build link hint labels (home row first). */
+const HINT_CHARS = "asdfghjklqwertyuiopzxcvbnm";
+
+/** Headings within this distance of the top count as "current". */
+const HEADING_THRESHOLD = 8;
+
+const HINT_CLASS = "vim-link-hint";
+
+let enabled = false;
+let headingIds: string[] = [];
+let pendingKey: string | null = null;
+let pendingTimer: number | null = null;
+
+interface LinkHint {
+  label: string;
+  target: HTMLElement;
+  element: HTMLElement;
+}
+
+/** Active link hints (null when hint mode is off). */
+let hints: LinkHint[] | null = null;
+let hintInput = "";
+/** Mouse button passed to the link handler (0 = same tab, 1 = new tab). */
+let hintButton = 0;
+
+function scrollContainer(): HTMLElement | null {
+  return document.querySelector(".content");
+}
+
+function isDocumentFocused(): boolean {
+  const active = document.activeElement;
+  if (!active || active === document.body) return true;
+  return active.closest(".content") !== null && !active.matches("input, textarea, select");
+}
+
+function clearPending(): void {
+  pendingKey = null;
+  if (pendingTimer !== null) {
+    clearTimeout(pendingTimer);
+    pendingTimer = null;
+  }
+}
+
+function setPending(key: string): void {
+  clearPending();
+  pendingKey = key;
+  pendingTimer = window.setTimeout(clearPending, SEQUENCE_TIMEOUT_MS);
+}
+
+function scrollBy(top: number): void {
+  scrollContainer()?.scrollBy({ top, behavior: "smooth" });
+}
+
+function scrollToEdge(edge: "top" | "bottom"): void {
+  const container = scrollContainer();
+  if (!container) return;
+  container.scrollTo({
+    top: edge === "top" ? 0 : container.scrollHeight,
+    behavior: "smooth",
+  });
+}
+
+/**
+ * Jump to the next or previous TOC heading relative to the viewport top.
+ */
+function jumpToHeading(direction: "next" | "prev"): void {
+  const container = scrollContainer();
+  if (!container) return;
+  const containerTop = container.getBoundingClientRect().top;
+
+  const headings = headingIds
+    .map((id) => document.getElementById(id))
+    .filter((el): el is HTMLElement => el !== null)
+    .map((el) => ({ el, offset: el.getBoundingClientRect().top - containerTop }));
+
+  const target =
+    direction === "next"
+      ? headings.find((h) => h.offset > HEADING_THRESHOLD)
+      : headings.filter((h) => h.offset < -HEADING_THRESHOLD).pop();
+
+  target?.el.scrollIntoView({ behavior: "smooth", block: "start" });
+}
+
+/**
+ * Generate `count` unique labels of equal length from HINT_CHARS.
+ */
+function hintLabels(count: number): string[] {
+  let length = 1;
+  while (HINT_CHARS.length ** length < count) length++;
+
+  const labels: string[] = [];
+  for (let i = 0; i < count; i++) {
+    let label = "";
+    let n = i;
+    for (let j = 0; j < length; j++) {
+      label = HINT_CHARS[n % HINT_CHARS.length] + label;
+      n = Math.floor(n / HINT_CHARS.length);
+    }
+    labels.push(label);
+  }
+  return labels;
+}
+
+function showHints(button: number): void {
+  const container = scrollContainer();
+  if (!container) return;
+  const viewport = container.getBoundingClientRect();
+
+  const targets = Array.from(
+    document.querySelectorAll<HTMLElement>(".markdown-body .md-link:not(.md-link-invalid)"),
+  ).filter((el) => {
+    const rect = el.getBoundingClientRect();
+    return rect.bottom > viewport.top && rect.top < viewport.bottom && rect.width > 0;
+  });
+  if (targets.length === 0) return;
+
+  hints = hintLabels(targets.length).map((label, i) => {
+    const target = targets[i];
+    const rect = target.getBoundingClientRect();
+    const element = document.createElement("span");
+    element.className = HINT_CLASS;
+    element.textContent = label;
+    element.style.left = `${rect.left}px`;
+    element.style.top = `${rect.top}px`;
+    document.body.appendChild(element);
+    return { label, target, element };
+  });
+  hintInput = "";
+  hintButton = button;
+}
+
+function hideHints(): void {
+  for (const hint of hints ?? []) {
+    hint.element.remove();
+  }
+  hints = null;
+  hintInput = "";
+}
+
+function handleHintKey(event: KeyboardEvent): void {
+  event.preventDefault();
+  event.stopPropagation();
+
+  if (event.key === "Escape") {
+    hideHints();
+    return;
+  }
+  if (event.key === "Backspace") {
+    hintInput = hintInput.slice(0, -1);
+  } else if (event.key.length === 1) {
+    hintInput += event.key.toLowerCase();
+  } else {
+    return;
+  }
+
+  const matching = (hints ?? []).filter((hint) => hint.label.startsWith(hintInput));
+  if (matching.length === 0) {
+    hideHints();
+    return;
+  }
+  if (matching.length === 1 && matching[0].label === hintInput) {
+    const { target } = matching[0];
+    const button = hintButton;
+    hideHints();
+    // md-link spans navigate on mousedown (see markdown.rs)
+    target.dispatchEvent(new MouseEvent("mousedown", { button, bubbles: true, cancelable: true }));
+    return;
+  }
+  for (const hint of hints ?? []) {
+    hint.element.classList.toggle("hidden", !hint.label.startsWith(hintInput));
+  }
+}
+
+function handleKeydown(event: KeyboardEvent): void {
+  if (!enabled) return;
+
+  if (hints) {
+    handleHintKey(event);
+    return;
+  }
+
+  if (event.metaKey || event.ctrlKey || event.altKey || event.isComposing) return;
+  if (!isDocumentFocused()) return;
+
+  const key = event.key;
+  const pending = pendingKey;
+  clearPending();
+
+  if (pending === "g" && key === "g") {
+    scrollToEdge("top");
+  } else if (pending === "]" && key === "]") {
+    jumpToHeading("next");
+  } else if (pending === "[" && key === "[") {
+    jumpToHeading("prev");
+  } else {
+    const container = scrollContainer();
+    const halfPage = (container?.clientHeight ?? 0) / 2;
+    switch (key) {
+      case "j":
+        scrollBy(LINE_SCROLL);
+        break;
+      case "k":
+        scrollBy(-LINE_SCROLL);
+        break;
+      case "d":
+        scrollBy(halfPage);
+        break;
+      case "u":
+        scrollBy(-halfPage);
+        break;
+      case "G":
+        scrollToEdge("bottom");
+        break;
+      case "n":
+        findInPage.navigate("next");
+        break;
+      case "N":
+        findInPage.navigate("prev");
+        break;
+      case "f":
+        showHints(0);
+        break;
+      case "F":
+        showHints(1);
+        break;
+      case "g":
+      case "]":
+      case "[":
+        setPending(key);
+        break;
+      default:
+        return;
+    }
+  }
+
+  event.preventDefault();
+}
+
+// Hints are positioned in viewport coordinates, so drop them when scrolling
+document.addEventListener("scroll", () => hideHints(), true);
+document.addEventListener("keydown", handleKeydown, true);
+
+/**
+ * Enable or disable vim-style keys (from the keymap config).
+ */
+export function setEnabled(value: boolean): void {
+  enabled = value;
+  if (!enabled) {
+    clearPending();
+    hideHints();
+  }
+}
+
+/**
+ * Set the heading ids of the current document (from the TOC) for ]] and [[.
+ */
+export function setHeadings(ids: string[]): void {
+  headingIds = ids;
+}